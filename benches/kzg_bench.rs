@@ -0,0 +1,58 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use tess::{
+    CurvePoint, DensePolynomial, FieldElement, Fr, KZG, PairingBackend, PairingEngine,
+    PolynomialCommitment, SRS,
+};
+
+/// Compares KZG commitments through the backend MSM against the naive
+/// add/multiply loop they used to be computed with.
+pub fn bench_kzg_commit(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0xdead_beef);
+
+    type BE = PairingEngine;
+    type G1 = <BE as PairingBackend>::G1;
+    type G2 = <BE as PairingBackend>::G2;
+
+    let degree = 1023usize;
+    let params: SRS<BE> = KZG::setup(degree, &[7u8; 32]).expect("setup failed");
+    let coeffs: Vec<Fr> = (0..=degree).map(|_| Fr::random(&mut rng)).collect();
+    let poly = DensePolynomial::from_coefficients_vec(coeffs.clone());
+
+    c.bench_function("kzg_commit_g1_1024", |b| {
+        b.iter(|| KZG::commit_g1(black_box(&params), black_box(&poly)).expect("commit failed"))
+    });
+
+    c.bench_function("kzg_commit_g1_1024_serial", |b| {
+        b.iter(|| {
+            params
+                .powers_of_g
+                .iter()
+                .zip(black_box(&coeffs))
+                .fold(G1::identity(), |acc, (base, coeff)| {
+                    acc.add(&base.mul_scalar(coeff))
+                })
+        })
+    });
+
+    c.bench_function("kzg_commit_g2_1024", |b| {
+        b.iter(|| KZG::commit_g2(black_box(&params), black_box(&poly)).expect("commit failed"))
+    });
+
+    c.bench_function("kzg_commit_g2_1024_serial", |b| {
+        b.iter(|| {
+            params
+                .powers_of_h
+                .iter()
+                .zip(black_box(&coeffs))
+                .fold(G2::identity(), |acc, (base, coeff)| {
+                    acc.add(&base.mul_scalar(coeff))
+                })
+        })
+    });
+}
+
+criterion_group!(benches, bench_kzg_commit);
+criterion_main!(benches);
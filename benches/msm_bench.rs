@@ -0,0 +1,174 @@
+use std::time::{Duration, Instant};
+
+use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use tess::{CurvePoint, FieldElement, MsmProvider, PairingBackend};
+
+/// MSM sizes benchmarked, as powers of two.
+const LOG_SIZES: std::ops::RangeInclusive<u32> = 8..=18;
+
+/// Random points used as addends when building the base vectors.
+const SEED_POINTS: usize = 16;
+
+/// One MSM implementation to measure: a backend together with a provider.
+///
+/// GPU or other accelerated providers are added to [`providers`] as further
+/// entries for the backend they accelerate.
+struct Provider {
+    name: &'static str,
+    g1: fn(&mut Criterion, &'static str),
+    g2: fn(&mut Criterion, &'static str),
+    time: fn(u32) -> (Duration, Duration),
+}
+
+macro_rules! provider {
+    ($name:expr, $backend:ty, $msm:ty) => {
+        Provider {
+            name: $name,
+            g1: bench_g1::<$backend, $msm>,
+            g2: bench_g2::<$backend, $msm>,
+            time: time_once::<$backend, $msm>,
+        }
+    };
+}
+
+fn providers() -> Vec<Provider> {
+    Vec::from([
+        #[cfg(feature = "blst")]
+        provider!(
+            "blst",
+            tess::backends::blst::PairingEngine,
+            <tess::backends::blst::PairingEngine as PairingBackend>::Msm
+        ),
+        #[cfg(feature = "ark_bls12381")]
+        provider!(
+            "ark_bls12",
+            tess::backends::ark_bls12::PairingEngine,
+            <tess::backends::ark_bls12::PairingEngine as PairingBackend>::Msm
+        ),
+        #[cfg(feature = "ark_bn254")]
+        provider!(
+            "ark_bn254",
+            tess::backends::ark_bn254::PairingEngine,
+            <tess::backends::ark_bn254::PairingEngine as PairingBackend>::Msm
+        ),
+        #[cfg(not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254")))]
+        provider!(
+            "mock",
+            tess::backends::mock::PairingEngine,
+            <tess::backends::mock::PairingEngine as PairingBackend>::Msm
+        ),
+    ])
+}
+
+/// Builds `len` distinct bases as running sums of a few random points, which
+/// is much cheaper than sampling every base at SRS-scale sizes.
+fn bases<C: CurvePoint<S>, S: FieldElement>(rng: &mut StdRng, len: usize) -> Vec<C> {
+    let seeds: Vec<C> = (0..SEED_POINTS).map(|_| C::random(rng)).collect();
+    let mut acc = C::generator();
+    (0..len)
+        .map(|i| {
+            acc = acc.add(&seeds[i % SEED_POINTS]);
+            acc
+        })
+        .collect()
+}
+
+fn inputs<B: PairingBackend, C: CurvePoint<B::Scalar>>(log: u32) -> (Vec<C>, Vec<B::Scalar>) {
+    let mut rng = StdRng::seed_from_u64(0xdead_beef ^ u64::from(log));
+    let len = 1usize << log;
+    let points = bases::<C, B::Scalar>(&mut rng, len);
+    let scalars = (0..len).map(|_| B::Scalar::random(&mut rng)).collect();
+    (points, scalars)
+}
+
+fn bench_g1<B: PairingBackend, M: MsmProvider<B>>(c: &mut Criterion, name: &'static str) {
+    let mut group = c.benchmark_group("msm_g1");
+    group.sample_size(10);
+    for log in LOG_SIZES {
+        let (points, scalars) = inputs::<B, B::G1>(log);
+        group.throughput(Throughput::Elements(points.len() as u64));
+        group.bench_with_input(BenchmarkId::new(name, 1u64 << log), &log, |b, _| {
+            b.iter(|| M::msm_g1(black_box(&points), black_box(&scalars)).expect("msm failed"))
+        });
+    }
+    group.finish();
+}
+
+fn bench_g2<B: PairingBackend, M: MsmProvider<B>>(c: &mut Criterion, name: &'static str) {
+    let mut group = c.benchmark_group("msm_g2");
+    group.sample_size(10);
+    for log in LOG_SIZES {
+        let (points, scalars) = inputs::<B, B::G2>(log);
+        group.throughput(Throughput::Elements(points.len() as u64));
+        group.bench_with_input(BenchmarkId::new(name, 1u64 << log), &log, |b, _| {
+            b.iter(|| M::msm_g2(black_box(&points), black_box(&scalars)).expect("msm failed"))
+        });
+    }
+    group.finish();
+}
+
+/// Times one G1 and one G2 MSM of size `2^log` for the summary table.
+fn time_once<B: PairingBackend, M: MsmProvider<B>>(log: u32) -> (Duration, Duration) {
+    let (g1, scalars) = inputs::<B, B::G1>(log);
+    let start = Instant::now();
+    black_box(M::msm_g1(&g1, &scalars).expect("msm failed"));
+    let g1_time = start.elapsed();
+
+    let (g2, scalars) = inputs::<B, B::G2>(log);
+    let start = Instant::now();
+    black_box(M::msm_g2(&g2, &scalars).expect("msm failed"));
+    (g1_time, start.elapsed())
+}
+
+/// Benchmarks every provider over the size matrix in both groups.
+///
+/// Criterion groups the results by `msm_g1` and `msm_g2`, with one line per
+/// provider, so its report plots the providers against each other.
+pub fn bench_msm_matrix(c: &mut Criterion) {
+    for provider in providers() {
+        (provider.g1)(c, provider.name);
+        (provider.g2)(c, provider.name);
+    }
+}
+
+/// Prints nanoseconds per element for each provider and size, relative to the
+/// first provider, from a single timed run per cell.
+pub fn print_summary(_: &mut Criterion) {
+    let providers = providers();
+    let Some(baseline) = providers.first() else {
+        return;
+    };
+    println!("\nMSM summary (ns/element, speedup vs {}):", baseline.name);
+    println!("{:>8} {:>12} {:>24} {:>24}", "size", "provider", "G1", "G2");
+    for log in LOG_SIZES {
+        let len = (1u64 << log) as f64;
+        let base = (baseline.time)(log);
+        for provider in &providers {
+            let (g1, g2) = if provider.name == baseline.name {
+                base
+            } else {
+                (provider.time)(log)
+            };
+            let cell = |t: Duration, b: Duration| {
+                format!(
+                    "{:.1} ({:.2}x)",
+                    t.as_nanos() as f64 / len,
+                    b.as_secs_f64() / t.as_secs_f64()
+                )
+            };
+            println!(
+                "{:>8} {:>12} {:>24} {:>24}",
+                1u64 << log,
+                provider.name,
+                cell(g1, base.0),
+                cell(g2, base.1)
+            );
+        }
+    }
+}
+
+criterion_group!(benches, bench_msm_matrix, print_summary);
+criterion_main!(benches);
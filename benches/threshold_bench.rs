@@ -48,6 +48,23 @@ pub fn bench_threshold(c: &mut Criterion) {
         })
     });
 
+    // Measure batch encryption of many payloads to the same committee
+    let batch: Vec<&[u8]> = vec![b"The quick brown fox jumps over the lazy dog"; 64];
+    c.bench_function("threshold_encrypt_batch_64", |b| {
+        b.iter(|| {
+            let cts = scheme
+                .encrypt_batch(
+                    &mut rng,
+                    &key_material.aggregate_key,
+                    &params,
+                    threshold,
+                    &batch,
+                )
+                .expect("encrypt_batch failed");
+            black_box(cts);
+        })
+    });
+
     // Prepare a ciphertext and partial decryptions for decryption benchmark
     let payload = b"benchmark payload for threshold decryption";
     let ct = scheme
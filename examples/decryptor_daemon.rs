@@ -0,0 +1,345 @@
+//! Participant-side decryptor service.
+//!
+//! A long-running process that holds one committee member's key share, reads
+//! decryption requests as JSON lines from stdin or a TCP socket, verifies each
+//! ciphertext against the committee's aggregate key, rate-limits requests, and
+//! answers with a [`PartialDecryption`] signed by the node's identity key.
+//!
+//! ```text
+//! # Create a demo committee with one keystore per member, plus a sample request.
+//! # Key shares and node keys are written with mode 0600.
+//! cargo run --example decryptor_daemon -- setup /tmp/tess-keystore --parties 4 --threshold 2
+//!
+//! # Serve requests for member 0 over stdin ...
+//! cargo run --example decryptor_daemon -- run /tmp/tess-keystore/node-0 \
+//!     < /tmp/tess-keystore/sample-request.json
+//!
+//! # ... or over TCP, at most 5 requests per second.
+//! cargo run --example decryptor_daemon -- run /tmp/tess-keystore/node-0 \
+//!     --listen 127.0.0.1:7070 --rate 5
+//! ```
+//!
+//! Each request line is `{"id": "...", "ciphertext": <Ciphertext>}`; each
+//! response line is `{"id": "...", "partial": <PartialDecryption>, "signature":
+//! "<hex>"}` or `{"id": "...", "error": "..."}`. The signature is a BLS
+//! signature in G2 over the request id and the partial, verifiable against
+//! `node.pub` with `e(g, σ) = e(pk, H(m))`.
+
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    net::TcpListener,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+#[cfg(feature = "tracing-subscriber")]
+use tracing_subscriber::fmt;
+
+use tess::{
+    AggregateKey, Ciphertext, CurvePoint, PairingBackend, PairingEngine, Params, PartialDecryption,
+    SecretKey, SilentThresholdScheme, TessRng, ThresholdEncryption, decode_share, encode_share,
+};
+
+type B = PairingEngine;
+type G1 = <B as PairingBackend>::G1;
+type G2 = <B as PairingBackend>::G2;
+type Scalar = <B as PairingBackend>::Scalar;
+
+/// Domain separation tag for response signatures.
+const RESPONSE_DST: &[u8] = b"TESS-DECRYPTOR-DAEMON-V01-BLS-G2";
+
+/// A decryption request read from the input stream.
+#[derive(Deserialize)]
+struct Request {
+    id: String,
+    ciphertext: Ciphertext<B>,
+}
+
+/// A response written back to the requester.
+#[derive(Serialize)]
+struct Response {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partial: Option<PartialDecryption<B>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Everything a member needs to serve decryption requests.
+struct Keystore {
+    params: Params<B>,
+    aggregate_key: AggregateKey<B>,
+    secret_key: SecretKey<B>,
+    node_key: Scalar,
+}
+
+impl Keystore {
+    /// Keystore layout: `params.json`, `aggregate_key.json`, `share.txt`
+    /// (the text encoding of [`encode_share`]) and `node.key`/`node.pub`.
+    fn load(dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let params = serde_json::from_slice(&fs::read(dir.join("params.json"))?)?;
+        let aggregate_key = serde_json::from_slice(&fs::read(dir.join("aggregate_key.json"))?)?;
+        let secret_key = decode_share::<B>(&fs::read_to_string(dir.join("share.txt"))?)?;
+        let node_key =
+            B::deserialize_scalar(&from_hex(&fs::read_to_string(dir.join("node.key"))?)?)?;
+        Ok(Self {
+            params,
+            aggregate_key,
+            secret_key,
+            node_key,
+        })
+    }
+}
+
+/// Token bucket allowing `rate` requests per second with bursts of `rate`.
+struct RateLimiter {
+    rate: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl RateLimiter {
+    fn new(rate: u32) -> Self {
+        Self {
+            rate: f64::from(rate),
+            tokens: f64::from(rate),
+            last: Instant::now(),
+        }
+    }
+
+    fn allow(&mut self) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Serves requests for one committee member.
+struct Daemon {
+    scheme: SilentThresholdScheme<B>,
+    keystore: Keystore,
+    limiter: RateLimiter,
+}
+
+impl Daemon {
+    fn handle_line(&mut self, line: &str) -> Response {
+        let request: Request = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(e) => return Response::error(String::new(), format!("invalid request: {e}")),
+        };
+        if !self.limiter.allow() {
+            warn!(id = %request.id, "rate limit exceeded");
+            return Response::error(request.id, "rate limit exceeded".into());
+        }
+
+        let keystore = &self.keystore;
+        if let Err(e) = self.scheme.verify_ciphertext(
+            &keystore.aggregate_key,
+            &keystore.params,
+            &request.ciphertext,
+        ) {
+            warn!(id = %request.id, error = %e, "rejected ciphertext");
+            return Response::error(request.id, format!("invalid ciphertext: {e}"));
+        }
+
+        match self
+            .scheme
+            .partial_decrypt(&keystore.secret_key, &request.ciphertext)
+        {
+            Ok(partial) => {
+                let signature = sign_response(&keystore.node_key, &request.id, &partial);
+                info!(id = %request.id, "answered decryption request");
+                Response {
+                    id: request.id,
+                    partial: Some(partial),
+                    signature: Some(to_hex(&B::serialize_g2(&signature))),
+                    error: None,
+                }
+            }
+            Err(e) => Response::error(request.id, format!("partial decryption failed: {e}")),
+        }
+    }
+
+    /// Answers every line of `input` on `output` until end of stream.
+    fn serve(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        for line in input.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = self.handle_line(&line);
+            serde_json::to_writer(&mut output, &response)?;
+            writeln!(output)?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl Response {
+    fn error(id: String, error: String) -> Self {
+        Self {
+            id,
+            partial: None,
+            signature: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Signs `H(id || partial)` with the node key: `σ = sk·H(m)` in G2.
+fn sign_response(node_key: &Scalar, id: &str, partial: &PartialDecryption<B>) -> G2 {
+    let mut message = Vec::new();
+    message.extend_from_slice(&(id.len() as u64).to_le_bytes());
+    message.extend_from_slice(id.as_bytes());
+    message.extend_from_slice(&(partial.participant_id as u64).to_le_bytes());
+    message.extend_from_slice(&B::serialize_g2(&partial.response));
+    <G2 as CurvePoint<Scalar>>::hash_to_curve(&message, RESPONSE_DST).mul_scalar(node_key)
+}
+
+/// Runs a demo trusted setup and writes one keystore per member into `dir`.
+fn setup(dir: &Path, parties: usize, threshold: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = TessRng::new();
+    let scheme = SilentThresholdScheme::<B>::new();
+    let params = scheme.param_gen(&mut rng, parties, threshold)?;
+    let keys = scheme.keygen_unsafe(&mut rng, parties, &params)?;
+
+    let params_json = serde_json::to_vec(&params)?;
+    let aggregate_key_json = serde_json::to_vec(&keys.aggregate_key)?;
+    for secret_key in &keys.secret_keys {
+        let node_dir = dir.join(format!("node-{}", secret_key.participant_id));
+        fs::create_dir_all(&node_dir)?;
+        fs::write(node_dir.join("params.json"), &params_json)?;
+        fs::write(node_dir.join("aggregate_key.json"), &aggregate_key_json)?;
+        write_secret(
+            &node_dir.join("share.txt"),
+            encode_share(secret_key).as_bytes(),
+        )?;
+
+        let node_key = <Scalar as tess::FieldElement>::random(&mut rng);
+        write_secret(
+            &node_dir.join("node.key"),
+            to_hex(&B::serialize_scalar(&node_key)).as_bytes(),
+        )?;
+        fs::write(
+            node_dir.join("node.pub"),
+            to_hex(&B::serialize_g1(&G1::mul_generator(&node_key))),
+        )?;
+    }
+
+    let ciphertext = scheme.encrypt(
+        &mut rng,
+        &keys.aggregate_key,
+        &params,
+        threshold,
+        b"hello from the decryptor daemon example",
+    )?;
+    let request = serde_json::json!({ "id": "sample-1", "ciphertext": ciphertext });
+    fs::write(dir.join("sample-request.json"), format!("{request}\n"))?;
+    info!(dir = %dir.display(), parties, threshold, "wrote demo keystores");
+    Ok(())
+}
+
+/// Writes secret material to a fresh file readable only by its owner
+/// (mode `0600` on Unix), replacing any previous file at `path`.
+fn write_secret(path: &Path, contents: &[u8]) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let hex = hex.trim();
+    if !hex.len().is_multiple_of(2) {
+        return Err("hex string has odd length".into());
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| Ok(u8::from_str_radix(&hex[i..i + 2], 16)?))
+        .collect()
+}
+
+/// Parses `--flag value` pairs following the positional arguments.
+fn flag(args: &[String], name: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1).cloned())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "tracing-subscriber")]
+    fmt().with_writer(io::stderr).init();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let usage = "usage: decryptor_daemon setup DIR [--parties N] [--threshold T]\n       \
+                 decryptor_daemon run KEYSTORE [--listen ADDR] [--rate PER_SECOND]";
+    let (command, path) = match (args.first(), args.get(1)) {
+        (Some(command), Some(path)) => (command.as_str(), PathBuf::from(path)),
+        _ => return Err(usage.into()),
+    };
+
+    match command {
+        "setup" => {
+            let parties = flag(&args, "--parties").map_or(Ok(4), |v| v.parse())?;
+            let threshold = flag(&args, "--threshold").map_or(Ok(2), |v| v.parse())?;
+            setup(&path, parties, threshold)
+        }
+        "run" => {
+            let rate = flag(&args, "--rate").map_or(Ok(10), |v| v.parse())?;
+            let mut daemon = Daemon {
+                scheme: SilentThresholdScheme::new(),
+                keystore: Keystore::load(&path)?,
+                limiter: RateLimiter::new(rate),
+            };
+            info!(
+                participant = daemon.keystore.secret_key.participant_id,
+                "decryptor ready"
+            );
+
+            match flag(&args, "--listen") {
+                Some(addr) => {
+                    let listener = TcpListener::bind(&addr)?;
+                    info!(%addr, "listening");
+                    for stream in listener.incoming() {
+                        let stream = stream?;
+                        stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+                        let reader = BufReader::new(stream.try_clone()?);
+                        if let Err(e) = daemon.serve(reader, stream) {
+                            warn!(error = %e, "connection closed");
+                        }
+                    }
+                    Ok(())
+                }
+                None => Ok(daemon.serve(io::stdin().lock(), io::stdout().lock())?),
+            }
+        }
+        _ => Err(usage.into()),
+    }
+}
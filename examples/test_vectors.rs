@@ -0,0 +1,294 @@
+//! Emits language-agnostic test vectors for the crate-root backend.
+//!
+//! The output is a JSON document of deterministic cases (seeded keys,
+//! ciphertexts, partial decryptions and the plaintext they recover) with every
+//! group and field element hex-encoded in the backend's canonical encoding.
+//! Implementations in other languages can check they parse each element,
+//! verify the partials and reproduce the plaintext.
+//!
+//! Each build covers the backend the crate re-exports at its root, so run it
+//! once per backend:
+//!
+//! ```text
+//! cargo run --example test_vectors -- --output vectors-blst.json
+//! cargo run --example test_vectors --no-default-features --features ark_bls12381,std -- --output vectors-ark-bls12381.json
+//! cargo run --example test_vectors --no-default-features --features ark_bn254,std -- --output vectors-ark-bn254.json
+//! ```
+//!
+//! Without `--output` the document is written to stdout.
+
+use std::fs;
+
+use rand::{SeedableRng, rngs::StdRng};
+use serde::Serialize;
+
+use tess::{
+    Ciphertext, CurvePoint, FieldElement, PairingEngine, PartialDecryption, SilentThresholdScheme,
+    TargetGroup, ThresholdEncryption, verify_partial_decryption,
+};
+
+/// Version of the document layout, bumped when fields change meaning.
+const FORMAT: &str = "tess-test-vectors/1";
+
+// Mirrors the precedence of the crate-root backend re-exports.
+#[cfg(feature = "blst")]
+const BACKEND: &str = "blst";
+#[cfg(all(feature = "ark_bls12381", not(feature = "blst")))]
+const BACKEND: &str = "ark_bls12381";
+#[cfg(all(
+    feature = "ark_bn254",
+    not(any(feature = "blst", feature = "ark_bls12381"))
+))]
+const BACKEND: &str = "ark_bn254";
+#[cfg(all(
+    feature = "mock",
+    not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))
+))]
+const BACKEND: &str = "mock";
+
+#[cfg(any(feature = "blst", feature = "ark_bls12381"))]
+const CURVE: &str = "BLS12-381";
+#[cfg(all(
+    feature = "ark_bn254",
+    not(any(feature = "blst", feature = "ark_bls12381"))
+))]
+const CURVE: &str = "BN254";
+#[cfg(not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254")))]
+const CURVE: &str = "mock";
+
+/// Parameters of one generated case.
+struct CaseSpec {
+    name: &'static str,
+    seed: u64,
+    parties: usize,
+    threshold: usize,
+    epoch: u64,
+    label: &'static [u8],
+    plaintext: &'static [u8],
+}
+
+const CASES: &[CaseSpec] = &[
+    CaseSpec {
+        name: "small-committee",
+        seed: 1,
+        parties: 4,
+        threshold: 2,
+        epoch: 0,
+        label: b"",
+        plaintext: b"hello, tess",
+    },
+    CaseSpec {
+        name: "labelled-header",
+        seed: 2,
+        parties: 8,
+        threshold: 5,
+        epoch: 7,
+        label: b"test-vectors",
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+    },
+    CaseSpec {
+        name: "empty-payload",
+        seed: 3,
+        parties: 8,
+        threshold: 3,
+        epoch: 0,
+        label: b"",
+        plaintext: b"",
+    },
+];
+
+#[derive(Serialize)]
+struct Document {
+    format: &'static str,
+    backend: &'static str,
+    curve: &'static str,
+    /// Encoded lengths in bytes of each element kind.
+    sizes: Sizes,
+    cases: Vec<Case>,
+}
+
+#[derive(Serialize)]
+struct Sizes {
+    scalar: usize,
+    g1: usize,
+    g2: usize,
+    gt: usize,
+}
+
+#[derive(Serialize)]
+struct Case {
+    name: &'static str,
+    seed: u64,
+    parties: usize,
+    threshold: usize,
+    /// Secret key scalars indexed by participant id.
+    secret_keys: Vec<String>,
+    /// BLS public keys (G1) indexed by participant id.
+    public_keys: Vec<String>,
+    ciphertext: CiphertextVector,
+    partials: Vec<PartialVector>,
+    /// Participants whose valid partials recover `plaintext`.
+    selector: Vec<bool>,
+    plaintext: String,
+}
+
+#[derive(Serialize)]
+struct CiphertextVector {
+    gamma_g2: String,
+    proof_g1: Vec<String>,
+    proof_g2: Vec<String>,
+    shared_secret: String,
+    threshold: usize,
+    epoch: u64,
+    committee: String,
+    label: String,
+    payload: String,
+    compressed: bool,
+    padded: bool,
+    deterministic: bool,
+    kdf: u8,
+    validity_proof: Option<ProofVector>,
+}
+
+#[derive(Serialize)]
+struct ProofVector {
+    challenge: String,
+    responses: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct PartialVector {
+    participant_id: usize,
+    /// G2 response.
+    response: String,
+    /// Whether the partial verifies against the participant's public key.
+    valid: bool,
+}
+
+fn to_hex(bytes: impl AsRef<[u8]>) -> String {
+    bytes.as_ref().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn ciphertext_vector(ct: &Ciphertext<PairingEngine>) -> CiphertextVector {
+    CiphertextVector {
+        gamma_g2: to_hex(ct.gamma_g2.to_repr()),
+        proof_g1: ct.proof_g1.iter().map(|p| to_hex(p.to_repr())).collect(),
+        proof_g2: ct.proof_g2.iter().map(|p| to_hex(p.to_repr())).collect(),
+        shared_secret: to_hex(TargetGroup::to_repr(&ct.shared_secret)),
+        threshold: ct.threshold,
+        epoch: ct.epoch,
+        committee: to_hex(ct.committee),
+        label: to_hex(&ct.label),
+        payload: to_hex(&ct.payload),
+        compressed: ct.compressed,
+        padded: ct.padded,
+        deterministic: ct.deterministic,
+        kdf: ct.kdf.to_byte(),
+        validity_proof: ct.validity_proof.as_ref().map(|proof| ProofVector {
+            challenge: to_hex(proof.challenge.to_repr()),
+            responses: proof
+                .responses
+                .iter()
+                .map(|r| to_hex(r.to_repr()))
+                .collect(),
+        }),
+    }
+}
+
+fn generate(spec: &CaseSpec) -> Result<Case, Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(spec.seed);
+    let scheme = SilentThresholdScheme::<PairingEngine>::new()
+        .with_epoch(spec.epoch)
+        .with_label(spec.label);
+    let params = scheme.param_gen(&mut rng, spec.parties, spec.threshold)?;
+    let keys = scheme.keygen_unsafe(&mut rng, spec.parties, &params)?;
+    let ct = scheme.encrypt(
+        &mut rng,
+        &keys.aggregate_key,
+        &params,
+        spec.threshold,
+        spec.plaintext,
+    )?;
+
+    let mut selector = vec![false; spec.parties];
+    let mut partials = Vec::with_capacity(spec.threshold + 1);
+    for sk in &keys.secret_keys[..spec.threshold] {
+        selector[sk.participant_id] = true;
+        partials.push(scheme.partial_decrypt(sk, &ct)?);
+    }
+    let result = scheme.aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)?;
+    if result.plaintext.as_deref() != Some(spec.plaintext) {
+        return Err(format!("case {} did not round-trip", spec.name).into());
+    }
+
+    // A share relabelled with another participant's id, which must be rejected.
+    let forged_id = spec.threshold;
+    partials.push(PartialDecryption {
+        participant_id: forged_id,
+        response: partials[0].response,
+    });
+
+    Ok(Case {
+        name: spec.name,
+        seed: spec.seed,
+        parties: spec.parties,
+        threshold: spec.threshold,
+        secret_keys: keys
+            .secret_keys
+            .iter()
+            .map(|sk| to_hex(sk.scalar.to_repr()))
+            .collect(),
+        public_keys: keys
+            .public_keys
+            .iter()
+            .map(|pk| to_hex(pk.bls_key.to_repr()))
+            .collect(),
+        ciphertext: ciphertext_vector(&ct),
+        partials: partials
+            .iter()
+            .map(|partial| PartialVector {
+                participant_id: partial.participant_id,
+                response: to_hex(partial.response.to_repr()),
+                valid: verify_partial_decryption(
+                    &keys.public_keys[partial.participant_id],
+                    &ct,
+                    partial,
+                ),
+            })
+            .collect(),
+        selector,
+        plaintext: to_hex(spec.plaintext),
+    })
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut output = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = Some(args.next().ok_or("missing value for --output")?),
+            other => return Err(format!("unknown argument: {other}").into()),
+        }
+    }
+
+    let cases = CASES.iter().map(generate).collect::<Result<Vec<_>, _>>()?;
+    let first = &cases[0];
+    let document = Document {
+        format: FORMAT,
+        backend: BACKEND,
+        curve: CURVE,
+        sizes: Sizes {
+            scalar: first.secret_keys[0].len() / 2,
+            g1: first.public_keys[0].len() / 2,
+            g2: first.ciphertext.gamma_g2.len() / 2,
+            gt: first.ciphertext.shared_secret.len() / 2,
+        },
+        cases,
+    };
+    let json = serde_json::to_string_pretty(&document)?;
+    match output {
+        Some(path) => fs::write(path, json + "\n")?,
+        None => println!("{json}"),
+    }
+    Ok(())
+}
@@ -1,20 +1,204 @@
+use std::time::{Duration, Instant};
+
 use rand::{SeedableRng, rngs::StdRng};
 
 #[cfg(feature = "parallel")]
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use serde::Serialize;
 use tracing::info;
 #[cfg(feature = "tracing-subscriber")]
 use tracing_subscriber::fmt;
 
-use tess::{AggregateKey, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+use tess::{
+    AggregateKey, Ciphertext, CurvePoint, DEK_LEN, FieldElement, PairingBackend, PairingEngine,
+    PartialDecryption, PublicKey, SilentThresholdScheme, TargetGroup, ThresholdEncryption,
+};
 
 const PARTIES: usize = 2048;
 const THRESHOLD: usize = 1400;
 
+// Mirrors the precedence of the crate-root backend re-exports.
+#[cfg(feature = "blst")]
+const BACKEND: &str = "blst";
+#[cfg(all(feature = "ark_bls12381", not(feature = "blst")))]
+const BACKEND: &str = "ark_bls12381";
+#[cfg(all(
+    feature = "ark_bn254",
+    not(any(feature = "blst", feature = "ark_bls12381"))
+))]
+const BACKEND: &str = "ark_bn254";
+#[cfg(all(
+    feature = "mock",
+    not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))
+))]
+const BACKEND: &str = "mock";
+
+/// Output format for the run report.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Log,
+    Json,
+    Csv,
+}
+
+/// Command-line options: `--parties N --threshold T --format log|json|csv --output PATH`.
+struct Options {
+    parties: usize,
+    threshold: usize,
+    format: Format,
+    output: Option<String>,
+}
+
+impl Options {
+    fn from_args() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut opts = Options {
+            parties: PARTIES,
+            threshold: THRESHOLD,
+            format: Format::Log,
+            output: None,
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+            match arg.as_str() {
+                "--parties" => opts.parties = value()?.parse()?,
+                "--threshold" => opts.threshold = value()?.parse()?,
+                "--output" => opts.output = Some(value()?),
+                "--format" => {
+                    opts.format = match value()?.as_str() {
+                        "log" => Format::Log,
+                        "json" => Format::Json,
+                        "csv" => Format::Csv,
+                        other => return Err(format!("unknown format: {other}").into()),
+                    }
+                }
+                other => return Err(format!("unknown argument: {other}").into()),
+            }
+        }
+        Ok(opts)
+    }
+}
+
+/// Per-stage latencies in milliseconds.
+#[derive(Serialize)]
+struct StageLatencies {
+    param_gen_ms: f64,
+    keygen_ms: f64,
+    aggregate_ms: f64,
+    encrypt_ms: f64,
+    partial_decrypt_ms: f64,
+    aggregate_decrypt_ms: f64,
+}
+
+/// Compressed encoding sizes in bytes.
+#[derive(Serialize)]
+struct ArtifactSizes {
+    public_key_bytes: usize,
+    aggregate_key_bytes: usize,
+    ciphertext_bytes: usize,
+    partial_decryption_bytes: usize,
+}
+
+/// Machine-readable summary of a single run.
+#[derive(Serialize)]
+struct Report {
+    backend: &'static str,
+    parties: usize,
+    threshold: usize,
+    payload_bytes: usize,
+    decrypted: bool,
+    latency: StageLatencies,
+    sizes: ArtifactSizes,
+}
+
+impl Report {
+    const CSV_HEADER: &'static str = "backend,parties,threshold,payload_bytes,decrypted,\
+param_gen_ms,keygen_ms,aggregate_ms,encrypt_ms,partial_decrypt_ms,aggregate_decrypt_ms,\
+public_key_bytes,aggregate_key_bytes,ciphertext_bytes,partial_decryption_bytes";
+
+    fn to_csv(&self) -> String {
+        let l = &self.latency;
+        let s = &self.sizes;
+        format!(
+            "{}\n{},{},{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3},{},{},{},{}\n",
+            Self::CSV_HEADER,
+            self.backend,
+            self.parties,
+            self.threshold,
+            self.payload_bytes,
+            self.decrypted,
+            l.param_gen_ms,
+            l.keygen_ms,
+            l.aggregate_ms,
+            l.encrypt_ms,
+            l.partial_decrypt_ms,
+            l.aggregate_decrypt_ms,
+            s.public_key_bytes,
+            s.aggregate_key_bytes,
+            s.ciphertext_bytes,
+            s.partial_decryption_bytes,
+        )
+    }
+}
+
+type B = PairingEngine;
+
+fn g1_len(p: &<B as PairingBackend>::G1) -> usize {
+    p.to_repr().len()
+}
+
+fn g2_len(p: &<B as PairingBackend>::G2) -> usize {
+    p.to_repr().len()
+}
+
+fn public_key_size(pk: &PublicKey<B>) -> usize {
+    8 + g1_len(&pk.bls_key)
+        + g1_len(&pk.lagrange_li)
+        + g1_len(&pk.lagrange_li_minus0)
+        + g1_len(&pk.lagrange_li_x)
+        + pk.lagrange_li_lj_z.iter().map(g1_len).sum::<usize>()
+}
+
+fn aggregate_key_size(agg: &AggregateKey<B>) -> usize {
+    agg.public_keys.iter().map(public_key_size).sum::<usize>()
+        + g1_len(&agg.ask)
+        + g2_len(&agg.z_g2)
+        + agg.lagrange_row_sums.iter().map(g1_len).sum::<usize>()
+        + TargetGroup::to_repr(&agg.precomputed_pairing).len()
+        + agg.kzg_params.powers_of_g.iter().map(g1_len).sum::<usize>()
+        + agg.kzg_params.powers_of_h.iter().map(g2_len).sum::<usize>()
+        + TargetGroup::to_repr(&agg.kzg_params.e_gh).len()
+}
+
+fn ciphertext_size(ct: &Ciphertext<B>) -> usize {
+    g2_len(&ct.gamma_g2)
+        + ct.proof_g1.iter().map(g1_len).sum::<usize>()
+        + ct.proof_g2.iter().map(g2_len).sum::<usize>()
+        + TargetGroup::to_repr(&ct.shared_secret).len()
+        + 8
+        + ct.payload.len()
+        + 3
+        + ct.validity_proof.as_ref().map_or(0, |proof| {
+            (1 + proof.responses.len()) * proof.challenge.to_repr().len()
+        })
+        + ct.broadcast_keys.len() * DEK_LEN
+}
+
+fn partial_size(partial: &PartialDecryption<B>) -> usize {
+    8 + g2_len(&partial.response)
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1e3
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize logging when tracing-subscriber is available.
+    let opts = Options::from_args()?;
+
+    // Initialize logging when tracing-subscriber is available. Structured output
+    // modes keep stdout clean for the report.
     #[cfg(feature = "tracing-subscriber")]
-    {
+    if opts.format == Format::Log {
         fmt()
             .with_max_level(tracing::Level::INFO)
             .with_span_events(fmt::format::FmtSpan::ENTER | fmt::format::FmtSpan::CLOSE)
@@ -23,23 +207,24 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .init();
     }
 
+    let parties = opts.parties;
+    let threshold = opts.threshold;
     let mut rng = StdRng::seed_from_u64(42);
 
     let scheme = SilentThresholdScheme::<PairingEngine>::new();
 
-    info!(
-        parties = PARTIES,
-        threshold = THRESHOLD,
-        "starting threshold example"
-    );
+    info!(parties, threshold, "starting threshold example");
 
     // Generate params (SRS + precomputed lagrange powers)
-    let params = scheme.param_gen(&mut rng, PARTIES, THRESHOLD)?;
+    let start = Instant::now();
+    let params = scheme.param_gen(&mut rng, parties, threshold)?;
+    let param_gen_time = start.elapsed();
 
     // Generate key material for all participants
+    let start = Instant::now();
     #[cfg(feature = "parallel")]
     let validator_keys = {
-        let rngs = (0..PARTIES)
+        let rngs = (0..parties)
             .map(|_| StdRng::from_rng(&mut rng).unwrap())
             .collect::<Vec<_>>();
         rngs.into_par_iter()
@@ -49,9 +234,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     #[cfg(not(feature = "parallel"))]
-    let validator_keys = (0..PARTIES)
+    let validator_keys = (0..parties)
         .map(|i| scheme.keygen_single_validator(&mut rng, i, &params))
         .collect::<Result<Vec<_>, _>>()?;
+    let keygen_time = start.elapsed();
 
     let validator_public_keys = validator_keys
         .iter()
@@ -60,37 +246,78 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .collect::<Vec<_>>();
     let validator_secret_keys = validator_keys.into_iter().map(|k| k.0).collect::<Vec<_>>();
 
-    let aggregate_key = AggregateKey::aggregate_keys(&validator_public_keys, &params, PARTIES)?;
+    let start = Instant::now();
+    let aggregate_key = AggregateKey::aggregate_keys(&validator_public_keys, &params, parties)?;
+    let aggregate_time = start.elapsed();
 
     // Example message
     let message = vec![0u8; 32];
 
     // Encrypt
-    let ciphertext = scheme.encrypt(&mut rng, &aggregate_key, &params, THRESHOLD, &message)?;
+    let start = Instant::now();
+    let ciphertext = scheme.encrypt(&mut rng, &aggregate_key, &params, threshold, &message)?;
+    let encrypt_time = start.elapsed();
 
-    // Prepare selector and collect partials from the first `THRESHOLD` participants
-    let share_count = THRESHOLD;
-    let mut selector = vec![false; PARTIES];
+    // Prepare selector and collect partials from the first `threshold` participants
+    let share_count = threshold;
+    let mut selector = vec![false; parties];
     let mut partials = Vec::with_capacity(share_count);
+    let start = Instant::now();
     for (i, selected) in selector.iter_mut().enumerate().take(share_count) {
         *selected = true;
         let p = scheme.partial_decrypt(&validator_secret_keys[i], &ciphertext)?;
         partials.push(p);
     }
+    let partial_time = start.elapsed();
 
     // Aggregate decrypt
+    let start = Instant::now();
     let result = scheme.aggregate_decrypt(&ciphertext, &partials, &selector, &aggregate_key)?;
+    let aggregate_decrypt_time = start.elapsed();
 
     info!(
         recovered = result.plaintext.is_some(),
         "decryption finished"
     );
 
-    if let Some(plain) = result.plaintext {
-        info!(matches = (plain == message), "plaintext equals original");
+    let decrypted = result.plaintext.as_deref() == Some(message.as_slice());
+    if result.plaintext.is_some() {
+        info!(matches = decrypted, "plaintext equals original");
     } else {
         info!("no plaintext recovered");
     }
 
+    let report = Report {
+        backend: BACKEND,
+        parties,
+        threshold,
+        payload_bytes: message.len(),
+        decrypted,
+        latency: StageLatencies {
+            param_gen_ms: millis(param_gen_time),
+            keygen_ms: millis(keygen_time),
+            aggregate_ms: millis(aggregate_time),
+            encrypt_ms: millis(encrypt_time),
+            partial_decrypt_ms: millis(partial_time),
+            aggregate_decrypt_ms: millis(aggregate_decrypt_time),
+        },
+        sizes: ArtifactSizes {
+            public_key_bytes: public_key_size(&validator_public_keys[0]),
+            aggregate_key_bytes: aggregate_key_size(&aggregate_key),
+            ciphertext_bytes: ciphertext_size(&ciphertext),
+            partial_decryption_bytes: partial_size(&partials[0]),
+        },
+    };
+
+    let rendered = match opts.format {
+        Format::Log => return Ok(()),
+        Format::Json => serde_json::to_string_pretty(&report)? + "\n",
+        Format::Csv => report.to_csv(),
+    };
+    match opts.output {
+        Some(path) => std::fs::write(path, rendered)?,
+        None => print!("{rendered}"),
+    }
+
     Ok(())
 }
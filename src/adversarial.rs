@@ -0,0 +1,227 @@
+//! Subtly invalid artifacts for negative testing.
+//!
+//! Integrators that accept keys, ciphertexts or partial decryptions from the
+//! network should check that their verification paths reject malformed input,
+//! not just that honest input passes. The helpers here produce artifacts that
+//! are well formed on the wire but must fail verification:
+//!
+//! - [`off_subgroup_g1`] / [`off_subgroup_g2`]: encodings of curve points
+//!   outside the prime-order subgroup, which decoders must reject
+//! - [`swap_participant_ids`]: valid shares relabelled as each other
+//! - [`mutated_proofs`]: ciphertexts with one KZG proof element perturbed
+//! - [`stale_epoch_partial`]: a share from a rotated-out key for the same slot
+//!
+//! Available with the `test-utils` feature.
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{
+//!     PairingEngine, SilentThresholdScheme, ThresholdEncryption, adversarial,
+//!     verify_partial_decryption,
+//! };
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let ct = scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"msg").unwrap();
+//!
+//! for bad in adversarial::mutated_proofs(&ct) {
+//!     assert!(scheme.verify_ciphertext(&keys.aggregate_key, &params, &bad).is_err());
+//! }
+//! let stale = adversarial::stale_epoch_partial(&mut rng, &scheme, &params, 1, &ct).unwrap();
+//! assert!(!verify_partial_decryption(&keys.public_keys[1], &ct, &stale));
+//! ```
+
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+
+use crate::{
+    Ciphertext, CurvePoint, Error, Fr, PairingBackend, Params, PartialDecryption,
+    SilentThresholdScheme, ThresholdEncryption,
+};
+
+/// Compressed encoding of a G1 point that lies on the curve but outside the
+/// prime-order subgroup, for the backend selected by the crate features.
+///
+/// Returns `None` when G1 has cofactor one (BN254), where no such point exists,
+/// and on the mock backend, which has no curve.
+pub fn off_subgroup_g1() -> Option<Vec<u8>> {
+    #[cfg(feature = "blst")]
+    {
+        use blstrs::G1Affine;
+
+        (1u8..=255).find_map(|x| {
+            let mut bytes = [0u8; 48];
+            bytes[0] = 0x80;
+            bytes[47] = x;
+            Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&bytes))
+                .filter(|point| !bool::from(point.is_torsion_free()))
+                .map(|_| bytes.to_vec())
+        })
+    }
+    #[cfg(all(feature = "ark_bls12381", not(feature = "blst")))]
+    {
+        ark_off_subgroup::<ark_bls12_381::g1::Config>()
+    }
+    #[cfg(not(any(feature = "blst", feature = "ark_bls12381")))]
+    {
+        None
+    }
+}
+
+/// Compressed encoding of a G2 point that lies on the curve but outside the
+/// prime-order subgroup, for the backend selected by the crate features.
+///
+/// Returns `None` on the mock backend, which has no curve.
+pub fn off_subgroup_g2() -> Option<Vec<u8>> {
+    #[cfg(feature = "blst")]
+    {
+        use blstrs::G2Affine;
+
+        (1u8..=255).find_map(|x| {
+            let mut bytes = [0u8; 96];
+            bytes[0] = 0x80;
+            bytes[95] = x;
+            Option::<G2Affine>::from(G2Affine::from_compressed_unchecked(&bytes))
+                .filter(|point| !bool::from(point.is_torsion_free()))
+                .map(|_| bytes.to_vec())
+        })
+    }
+    #[cfg(all(feature = "ark_bls12381", not(feature = "blst")))]
+    {
+        ark_off_subgroup::<ark_bls12_381::g2::Config>()
+    }
+    #[cfg(all(
+        feature = "ark_bn254",
+        not(any(feature = "blst", feature = "ark_bls12381"))
+    ))]
+    {
+        ark_off_subgroup::<ark_bn254::g2::Config>()
+    }
+    #[cfg(not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254")))]
+    {
+        None
+    }
+}
+
+/// Searches small x-coordinates for a curve point outside the subgroup.
+#[cfg(all(
+    any(feature = "ark_bls12381", feature = "ark_bn254"),
+    not(feature = "blst")
+))]
+fn ark_off_subgroup<P: ark_ec::short_weierstrass::SWCurveConfig>() -> Option<Vec<u8>> {
+    use ark_ec::short_weierstrass::Affine;
+    use ark_serialize::CanonicalSerialize;
+
+    (1u64..1024).find_map(|x| {
+        let point = Affine::<P>::get_point_from_x_unchecked(P::BaseField::from(x), false)?;
+        if point.is_in_correct_subgroup_assuming_on_curve() {
+            return None;
+        }
+        let mut bytes = Vec::new();
+        point.serialize_compressed(&mut bytes).ok()?;
+        Some(bytes)
+    })
+}
+
+/// Exchanges the participant ids of two partial decryptions.
+///
+/// Each share stays valid for its original participant, so an aggregator that
+/// trusts the claimed id instead of checking the share against that
+/// participant's key will interpolate garbage.
+pub fn swap_participant_ids<B: PairingBackend>(
+    a: &mut PartialDecryption<B>,
+    b: &mut PartialDecryption<B>,
+) {
+    core::mem::swap(&mut a.participant_id, &mut b.participant_id);
+}
+
+/// Returns one copy of `ciphertext` per KZG proof element, each with that
+/// element shifted by the group generator.
+///
+/// The payload and header are untouched, so only proof verification can tell
+/// these apart from the original.
+pub fn mutated_proofs<B: PairingBackend>(ciphertext: &Ciphertext<B>) -> Vec<Ciphertext<B>> {
+    let mut mutated = Vec::with_capacity(ciphertext.proof_g1.len() + ciphertext.proof_g2.len());
+    for index in 0..ciphertext.proof_g1.len() {
+        let mut ct = ciphertext.clone();
+        ct.proof_g1[index] = ct.proof_g1[index].add(&B::G1::generator());
+        mutated.push(ct);
+    }
+    for index in 0..ciphertext.proof_g2.len() {
+        let mut ct = ciphertext.clone();
+        ct.proof_g2[index] = ct.proof_g2[index].add(&B::G2::generator());
+        mutated.push(ct);
+    }
+    mutated
+}
+
+/// Partial decryption of `ciphertext` for `participant_id` under a freshly
+/// generated key, as a participant would send after its key was rotated out.
+///
+/// The share is well formed and carries the right id, but it does not match
+/// the participant's current public key.
+pub fn stale_epoch_partial<B: PairingBackend<Scalar = Fr>, R: RngCore + ?Sized>(
+    rng: &mut R,
+    scheme: &SilentThresholdScheme<B>,
+    params: &Params<B>,
+    participant_id: usize,
+    ciphertext: &Ciphertext<B>,
+) -> Result<PartialDecryption<B>, Error> {
+    let (stale_key, _) = scheme.keygen_single_validator(rng, participant_id, params)?;
+    scheme.partial_decrypt(&stale_key, ciphertext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, verify_partial_decryption};
+
+    type B = PairingEngine;
+
+    #[test]
+    fn verification_rejects_adversarial_artifacts() {
+        let mut rng = StdRng::seed_from_u64(1449);
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let agg_key = &keys.aggregate_key;
+        let ct = scheme
+            .encrypt(&mut rng, agg_key, &params, 2, b"adversarial")
+            .unwrap();
+
+        if let Some(bytes) = off_subgroup_g1() {
+            assert!(B::deserialize_g1(&bytes).is_err());
+        }
+        // Every real curve has a G2 cofactor; the mock backend has no curve.
+        #[cfg(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))]
+        {
+            let bytes = off_subgroup_g2().expect("G2 has a non-trivial cofactor");
+            assert!(B::deserialize_g2(&bytes).is_err());
+        }
+
+        let mutated = mutated_proofs(&ct);
+        assert_eq!(mutated.len(), ct.proof_g1.len() + ct.proof_g2.len());
+        for bad in &mutated {
+            assert!(scheme.verify_ciphertext(agg_key, &params, bad).is_err());
+        }
+
+        let mut a = scheme.partial_decrypt(&keys.secret_keys[0], &ct).unwrap();
+        let mut b = scheme.partial_decrypt(&keys.secret_keys[1], &ct).unwrap();
+        swap_participant_ids(&mut a, &mut b);
+        assert!(!verify_partial_decryption(&keys.public_keys[1], &ct, &a));
+        assert!(!verify_partial_decryption(&keys.public_keys[0], &ct, &b));
+
+        let stale = stale_epoch_partial(&mut rng, &scheme, &params, 1, &ct).unwrap();
+        assert_eq!(stale.participant_id, 1);
+        assert!(!verify_partial_decryption(
+            &keys.public_keys[1],
+            &ct,
+            &stale
+        ));
+    }
+}
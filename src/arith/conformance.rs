@@ -0,0 +1,373 @@
+//! Conformance checks for [`PairingBackend`] implementations.
+//!
+//! Each function exercises one area of the backend contract against a fixed
+//! seed and panics on the first violation. The
+//! [`backend_conformance_tests!`](crate::backend_conformance_tests) macro wraps
+//! them in `#[test]` functions, so a new backend is validated with one line:
+//!
+//! ```rust,ignore
+//! #[cfg(test)]
+//! mod tests {
+//!     tess::backend_conformance_tests!(my_crate::MyBackend);
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+use crate::{CurvePoint, FieldElement, Fr, MsmProvider, PairingBackend, TargetGroup};
+
+/// Number of terms in the MSM checks; large enough to leave the trivial paths.
+const MSM_TERMS: usize = 37;
+
+fn rng(domain: u64) -> ChaCha20Rng {
+    ChaCha20Rng::seed_from_u64(0x7e55_c0f0 ^ domain)
+}
+
+fn assert_points_eq<C: CurvePoint<Fr>>(a: &C, b: &C, what: &str) {
+    assert!(a.sub(b).is_identity(), "{what}");
+}
+
+fn check_group_laws<C: CurvePoint<Fr>>(rng: &mut ChaCha20Rng, group: &str) {
+    let p = C::random(rng);
+    let q = C::random(rng);
+    let r = C::random(rng);
+    let a = Fr::random(rng);
+    let b = Fr::random(rng);
+    let identity = C::identity();
+
+    assert!(identity.is_identity(), "{group}: identity is not identity");
+    assert!(
+        !C::generator().is_identity(),
+        "{group}: generator is identity"
+    );
+    assert_points_eq(&p.add(&identity), &p, "P + 0 != P");
+    assert_points_eq(&p.add(&q), &q.add(&p), "addition is not commutative");
+    assert_points_eq(
+        &p.add(&q).add(&r),
+        &p.add(&q.add(&r)),
+        "addition is not associative",
+    );
+    assert!(p.add(&p.negate()).is_identity(), "{group}: P + (-P) != 0");
+    assert_points_eq(&p.sub(&q), &p.add(&q.negate()), "P - Q != P + (-Q)");
+
+    assert!(
+        p.mul_scalar(&Fr::zero()).is_identity(),
+        "{group}: 0 * P != 0"
+    );
+    assert_points_eq(&p.mul_scalar(&Fr::one()), &p, "1 * P != P");
+    assert_points_eq(
+        &p.mul_scalar(&Fr::from_u64(2)),
+        &p.add(&p),
+        "2 * P != P + P",
+    );
+    assert_points_eq(
+        &p.mul_scalar(&(a + b)),
+        &p.mul_scalar(&a).add(&p.mul_scalar(&b)),
+        "(a + b) * P != a * P + b * P",
+    );
+    assert_points_eq(
+        &p.mul_scalar(&(a * b)),
+        &p.mul_scalar(&a).mul_scalar(&b),
+        "(a * b) * P != a * (b * P)",
+    );
+    assert_points_eq(
+        &p.add(&q).mul_scalar(&a),
+        &p.mul_scalar(&a).add(&q.mul_scalar(&a)),
+        "a * (P + Q) != a * P + a * Q",
+    );
+    assert!(
+        p.mul_scalar(&-Fr::one()).add(&p).is_identity(),
+        "{group}: (-1) * P != -P"
+    );
+    assert_points_eq(
+        &C::mul_generator(&a),
+        &C::generator().mul_scalar(&a),
+        "mul_generator disagrees with mul_scalar",
+    );
+
+    assert!(identity.is_torsion_free(), "{group}: identity has torsion");
+    assert!(p.is_torsion_free(), "{group}: random point has torsion");
+    let cleared = p.clear_cofactor();
+    assert!(
+        cleared.is_torsion_free(),
+        "{group}: cleared point has torsion"
+    );
+    assert_points_eq(
+        &p.add(&q).clear_cofactor(),
+        &cleared.add(&q.clear_cofactor()),
+        "cofactor clearing is not linear",
+    );
+
+    let affine = C::batch_normalize(&[p, q]);
+    assert_points_eq(&C::from_affine(&affine[0]), &p, "affine round trip");
+    assert_points_eq(&C::from_affine(&affine[1]), &q, "affine round trip");
+    assert_points_eq(&C::from_affine(&p.to_affine()), &p, "affine round trip");
+}
+
+/// Checks the group axioms and scalar multiplication in G1 and G2.
+pub fn group_laws<B: PairingBackend<Scalar = Fr>>() {
+    let mut rng = rng(1);
+    check_group_laws::<B::G1>(&mut rng, "G1");
+    check_group_laws::<B::G2>(&mut rng, "G2");
+}
+
+/// Checks that the pairing is bilinear and non-degenerate, and that
+/// `multi_pairing` agrees with the product of single pairings.
+pub fn pairing_bilinearity<B: PairingBackend<Scalar = Fr>>() {
+    let mut rng = rng(2);
+    let g = B::G1::generator();
+    let h = B::G2::generator();
+    let a = Fr::random(&mut rng);
+    let b = Fr::random(&mut rng);
+    let e_gh = B::pairing(&g, &h);
+
+    assert!(
+        e_gh != B::Target::identity(),
+        "pairing of generators is degenerate"
+    );
+    assert!(
+        B::pairing(&B::G1::identity(), &h) == B::Target::identity(),
+        "e(0, h) != 1"
+    );
+    assert!(
+        B::pairing(&g.mul_scalar(&a), &h.mul_scalar(&b)) == e_gh.mul_scalar(&(a * b)),
+        "e(a * g, b * h) != e(g, h)^(ab)"
+    );
+    assert!(
+        B::pairing(&g.mul_scalar(&a), &h) == B::pairing(&g, &h.mul_scalar(&a)),
+        "e(a * g, h) != e(g, a * h)"
+    );
+    assert!(
+        bool::from(e_gh.ct_eq(&B::pairing(&g, &h))),
+        "ct_eq(e(g, h), e(g, h)) is false"
+    );
+    assert!(
+        !bool::from(e_gh.ct_eq(&B::Target::identity())),
+        "ct_eq(e(g, h), 1) is true"
+    );
+
+    let p = B::G1::random(&mut rng);
+    let q = B::G1::random(&mut rng);
+    let r = B::G2::random(&mut rng);
+    let s = B::G2::random(&mut rng);
+    assert!(
+        B::pairing(&p.add(&q), &r) == B::pairing(&p, &r).combine(&B::pairing(&q, &r)),
+        "e(P + Q, R) != e(P, R) * e(Q, R)"
+    );
+
+    let product = B::pairing(&p, &r).combine(&B::pairing(&q, &s));
+    let multi = B::multi_pairing(&[p, q], &[r, s]).expect("multi pairing");
+    assert!(multi == product, "multi_pairing disagrees with pairing");
+    assert!(
+        B::multi_pairing(&[p, q], &[r]).is_err(),
+        "multi_pairing accepted mismatched lengths"
+    );
+    let cancel = B::multi_pairing(&[p, p.negate()], &[r, r]).expect("multi pairing");
+    assert!(cancel == B::Target::identity(), "e(P, R) * e(-P, R) != 1");
+}
+
+/// Checks that scalars and group elements survive encoding and decoding, and
+/// that truncated encodings are rejected.
+pub fn serialization_roundtrips<B: PairingBackend<Scalar = Fr>>() {
+    let mut rng = rng(3);
+    let scalar = Fr::random(&mut rng);
+    let g1 = B::G1::random(&mut rng);
+    let g2 = B::G2::random(&mut rng);
+    let target = B::pairing(&g1, &g2);
+
+    for value in [Fr::zero(), Fr::one(), scalar] {
+        let decoded = B::deserialize_scalar(&B::serialize_scalar(&value)).expect("scalar");
+        assert!(decoded == value, "scalar round trip");
+        let decoded = Fr::from_repr(&value.to_repr()).expect("scalar repr");
+        assert!(decoded == value, "scalar repr round trip");
+        let decoded = <Fr as FieldElement>::from_bytes_le(&FieldElement::to_bytes_le(&value))
+            .expect("scalar le");
+        assert!(decoded == value, "little-endian scalar round trip");
+        let decoded = <Fr as FieldElement>::from_bytes_be(&FieldElement::to_bytes_be(&value))
+            .expect("scalar be");
+        assert!(decoded == value, "big-endian scalar round trip");
+    }
+    let mut le = [0u8; 32];
+    le[0] = 0x2a;
+    let mut be = [0u8; 32];
+    be[31] = 0x2a;
+    assert!(
+        <Fr as FieldElement>::from_bytes_le(&le).expect("le") == Fr::from_u64(0x2a)
+            && <Fr as FieldElement>::from_bytes_be(&be).expect("be") == Fr::from_u64(0x2a),
+        "explicit-endian scalar encodings disagree on byte order"
+    );
+    assert!(
+        <Fr as FieldElement>::from_bytes_le(&[0xff; 32]).is_err(),
+        "non-canonical scalar accepted"
+    );
+    for point in [B::G1::identity(), B::G1::generator(), g1] {
+        let decoded = B::deserialize_g1(&B::serialize_g1(&point)).expect("G1");
+        assert_points_eq(&decoded, &point, "G1 round trip");
+    }
+    for point in [B::G2::identity(), B::G2::generator(), g2] {
+        let decoded = B::deserialize_g2(&B::serialize_g2(&point)).expect("G2");
+        assert_points_eq(&decoded, &point, "G2 round trip");
+    }
+    let decoded = B::deserialize_target(&B::serialize_target(&target)).expect("GT");
+    assert!(decoded == target, "GT round trip");
+
+    let bytes = B::serialize_g1(&g1);
+    assert!(
+        B::deserialize_g1(&bytes[..bytes.len() - 1]).is_err(),
+        "truncated G1 accepted"
+    );
+    let bytes = B::serialize_g2(&g2);
+    assert!(
+        B::deserialize_g2(&bytes[..bytes.len() - 1]).is_err(),
+        "truncated G2 accepted"
+    );
+    assert!(B::deserialize_scalar(&[]).is_err(), "empty scalar accepted");
+}
+
+/// Checks the backend MSMs against naive sums of scalar multiplications.
+pub fn msm_matches_naive<B: PairingBackend<Scalar = Fr>>() {
+    let mut rng = rng(4);
+    let scalars: Vec<Fr> = (0..MSM_TERMS).map(|_| Fr::random(&mut rng)).collect();
+    let g1: Vec<B::G1> = (0..MSM_TERMS).map(|_| B::G1::random(&mut rng)).collect();
+    let g2: Vec<B::G2> = (0..MSM_TERMS).map(|_| B::G2::random(&mut rng)).collect();
+    let gt: Vec<B::Target> = g1.iter().map(|p| B::pairing(p, &g2[0])).collect();
+
+    let naive_g1 = g1
+        .iter()
+        .zip(&scalars)
+        .fold(B::G1::identity(), |acc, (p, s)| acc.add(&p.mul_scalar(s)));
+    let naive_g2 = g2
+        .iter()
+        .zip(&scalars)
+        .fold(B::G2::identity(), |acc, (p, s)| acc.add(&p.mul_scalar(s)));
+    let naive_gt = gt
+        .iter()
+        .zip(&scalars)
+        .fold(B::Target::identity(), |acc, (t, s)| {
+            acc.combine(&t.mul_scalar(s))
+        });
+
+    let msm_g1 = B::Msm::msm_g1(&g1, &scalars).expect("G1 MSM");
+    assert_points_eq(&msm_g1, &naive_g1, "G1 MSM disagrees with naive sum");
+    let msm_g1 = B::G1::multi_scalar_multiplication(&g1, &scalars);
+    assert_points_eq(&msm_g1, &naive_g1, "G1 multi_scalar_multiplication");
+    let msm_g2 = B::Msm::msm_g2(&g2, &scalars).expect("G2 MSM");
+    assert_points_eq(&msm_g2, &naive_g2, "G2 MSM disagrees with naive sum");
+    let msm_gt = B::Msm::msm_gt(&gt, &scalars).expect("GT MSM");
+    assert!(msm_gt == naive_gt, "GT MSM disagrees with naive product");
+
+    let empty = B::Msm::msm_g1(&[], &[]).expect("empty MSM");
+    assert!(empty.is_identity(), "empty MSM is not identity");
+    assert!(
+        B::Msm::msm_g1(&g1, &scalars[1..]).is_err(),
+        "MSM accepted mismatched lengths"
+    );
+}
+
+/// Checks the scalar field axioms and helpers.
+pub fn scalar_field_axioms<B: PairingBackend<Scalar = Fr>>() {
+    let mut rng = rng(5);
+    let a = Fr::random(&mut rng);
+    let b = Fr::random(&mut rng);
+    let c = Fr::random(&mut rng);
+    let zero = Fr::zero();
+    let one = Fr::one();
+
+    assert!(a + zero == a, "a + 0 != a");
+    assert!(a * one == a, "a * 1 != a");
+    assert!(a * zero == zero, "a * 0 != 0");
+    assert!(a + b == b + a, "addition is not commutative");
+    assert!(a * b == b * a, "multiplication is not commutative");
+    assert!((a + b) + c == a + (b + c), "addition is not associative");
+    assert!(
+        (a * b) * c == a * (b * c),
+        "multiplication is not associative"
+    );
+    assert!(
+        a * (b + c) == a * b + a * c,
+        "multiplication does not distribute"
+    );
+    assert!(a + (-a) == zero, "a + (-a) != 0");
+    assert!(a - b == a + (-b), "a - b != a + (-b)");
+    assert!(bool::from(a.ct_eq(&(a + zero))), "ct_eq(a, a) is false");
+    assert!(!bool::from(a.ct_eq(&b)), "ct_eq(a, b) is true");
+
+    assert!(zero.invert().is_none(), "zero is invertible");
+    let inverse = a.invert().expect("non-zero inverse");
+    assert!(a * inverse == one, "a * a^-1 != 1");
+    assert!(
+        Fr::from_u64(6) == Fr::from_u64(2) * Fr::from_u64(3),
+        "from_u64 is not multiplicative"
+    );
+    assert!(a.pow(&[3, 0, 0, 0]) == a * a * a, "a^3 != a * a * a");
+    assert!(a.pow(&[0, 0, 0, 0]) == one, "a^0 != 1");
+
+    let mut batch = [a, b, c];
+    Fr::batch_inversion(&mut batch).expect("batch inversion");
+    assert!(batch[0] * a == one, "batch inversion");
+    assert!(batch[1] * b == one, "batch inversion");
+    assert!(batch[2] * c == one, "batch inversion");
+
+    let bytes = Fr::from_u64(0x0102).to_bytes_le();
+    assert_eq!(bytes[..2], [0x02, 0x01], "to_bytes_le is not little endian");
+    assert!(bytes[2..].iter().all(|b| *b == 0), "to_bytes_le high bytes");
+
+    let root = Fr::two_adicity_generator(8);
+    assert!(root.pow(&[8, 0, 0, 0]) == one, "8th root of unity");
+    assert!(
+        root.pow(&[4, 0, 0, 0]) != one,
+        "8th root of unity is not primitive"
+    );
+}
+
+/// Generates `#[test]` functions running every [`conformance`](crate::conformance)
+/// check against a [`PairingBackend`](crate::PairingBackend).
+///
+/// The backend must use this crate's scalar field, `Scalar = tess::Fr`. Invoke
+/// it inside a test module or an integration test file; an optional leading
+/// identifier names the generated module (default `backend_conformance`), so
+/// several backends can be checked side by side.
+///
+/// ```rust,ignore
+/// tess::backend_conformance_tests!(tess::PairingEngine);
+/// tess::backend_conformance_tests!(gpu_conformance, my_crate::GpuBackend);
+/// ```
+#[macro_export]
+macro_rules! backend_conformance_tests {
+    ($backend:ty) => {
+        $crate::backend_conformance_tests!(backend_conformance, $backend);
+    };
+    ($name:ident, $backend:ty) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn group_laws() {
+                $crate::conformance::group_laws::<$backend>();
+            }
+
+            #[test]
+            fn pairing_bilinearity() {
+                $crate::conformance::pairing_bilinearity::<$backend>();
+            }
+
+            #[test]
+            fn serialization_roundtrips() {
+                $crate::conformance::serialization_roundtrips::<$backend>();
+            }
+
+            #[test]
+            fn msm_matches_naive() {
+                $crate::conformance::msm_matches_naive::<$backend>();
+            }
+
+            #[test]
+            fn scalar_field_axioms() {
+                $crate::conformance::scalar_field_axioms::<$backend>();
+            }
+        }
+    };
+}
@@ -15,11 +15,11 @@
 
 use alloc::vec::Vec;
 use ark_bn254::Fr as ArkFr;
-use ark_ff::{FftField, Field, One as ArkOne, UniformRand, Zero};
+use ark_ff::{BigInt, BigInteger, FftField, Field, One as ArkOne, PrimeField, UniformRand, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use rand_core::RngCore;
 
-use crate::{BackendError, FieldElement};
+use crate::{BackendError, FieldElement, errors::ArkError};
 
 /// Scalar field type for the Arkworks BN254 backend.
 pub type Fr = ArkFr;
@@ -51,12 +51,13 @@ impl FieldElement for Fr {
         let mut bytes = Vec::new();
         self.serialize_compressed(&mut bytes)
             .expect("scalar serialization");
+        metric!(bytes_serialized += bytes.len());
         bytes
     }
 
     fn from_repr(repr: &Self::Repr) -> Result<Self, BackendError> {
         Self::deserialize_compressed(repr.as_slice())
-            .map_err(|_| BackendError::Serialization("invalid scalar bytes"))
+            .map_err(|err| BackendError::library("invalid scalar bytes", ArkError(err)))
     }
 
     fn two_adic_root_of_unity() -> Self {
@@ -87,4 +88,21 @@ impl FieldElement for Fr {
     fn from_u64(n: u64) -> Self {
         Fr::from(n)
     }
+
+    fn to_bytes_le(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        let limbs = self.into_bigint().to_bytes_le();
+        bytes[..limbs.len()].copy_from_slice(&limbs);
+        bytes
+    }
+
+    fn from_bytes_le(bytes: &[u8; 32]) -> Result<Self, BackendError> {
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("8-byte limb"));
+        }
+        Fr::from_bigint(BigInt(limbs)).ok_or(BackendError::Serialization(
+            "scalar not below field modulus",
+        ))
+    }
 }
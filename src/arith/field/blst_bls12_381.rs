@@ -23,6 +23,7 @@ use blstrs::Scalar;
 use ff::Field;
 use ff::PrimeField;
 use rand_core::RngCore;
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::{BackendError, FieldElement};
 
@@ -53,6 +54,7 @@ impl FieldElement for Scalar {
     }
 
     fn to_repr(&self) -> Self::Repr {
+        metric!(bytes_serialized += 32);
         self.to_bytes_be().to_vec()
     }
 
@@ -122,4 +124,28 @@ impl FieldElement for Scalar {
     fn from_u64(n: u64) -> Self {
         Scalar::from(n)
     }
+
+    fn to_bytes_le(&self) -> [u8; 32] {
+        Scalar::to_bytes_le(self)
+    }
+
+    fn to_bytes_be(&self) -> [u8; 32] {
+        Scalar::to_bytes_be(self)
+    }
+
+    fn from_bytes_le(bytes: &[u8; 32]) -> Result<Self, BackendError> {
+        Option::<Scalar>::from(Scalar::from_bytes_le(bytes)).ok_or(BackendError::Serialization(
+            "scalar not below field modulus",
+        ))
+    }
+
+    fn from_bytes_be(bytes: &[u8; 32]) -> Result<Self, BackendError> {
+        Option::<Scalar>::from(Scalar::from_bytes_be(bytes)).ok_or(BackendError::Serialization(
+            "scalar not below field modulus",
+        ))
+    }
+
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ConstantTimeEq::ct_eq(self, other)
+    }
 }
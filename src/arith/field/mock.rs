@@ -0,0 +1,294 @@
+//! Small prime field for the insecure mock backend.
+//!
+//! [`MockFr`] is arithmetic modulo the 64-bit prime `p = 2^64 - 2^32 + 1`.
+//! Its multiplicative group has order divisible by `2^32` and by 3, so the
+//! radix-2 and mixed-radix evaluation domains the protocol builds exist for
+//! every committee size a test uses. It backs
+//! [`MockBackend`](crate::MockBackend) when no real backend is enabled, so
+//! `--no-default-features --features mock` builds without any curve library.
+//!
+//! Nothing here is constant time. Never use it outside tests.
+
+use alloc::vec::Vec;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use rand_core::RngCore;
+
+use crate::{BackendError, FieldElement};
+
+/// The field modulus `2^64 - 2^32 + 1`.
+const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+/// Number of factors of two in `p - 1`.
+const TWO_ADICITY: u32 = 32;
+
+/// Generator of the multiplicative group.
+const GENERATOR: u64 = 7;
+
+/// Element of the mock scalar field, stored reduced below the modulus.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MockFr(u64);
+
+impl MockFr {
+    /// Returns the canonical integer value.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    fn reduce(value: u128) -> Self {
+        Self((value % MODULUS as u128) as u64)
+    }
+
+    fn pow_u64(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut acc = Self(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        acc
+    }
+}
+
+impl Add for MockFr {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::reduce(self.0 as u128 + other.0 as u128)
+    }
+}
+
+impl Sub for MockFr {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for MockFr {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::reduce(self.0 as u128 * other.0 as u128)
+    }
+}
+
+impl Neg for MockFr {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(MODULUS - self.0)
+        }
+    }
+}
+
+macro_rules! forward_ref_ops {
+    ($($op:ident::$method:ident, $assign:ident::$assign_method:ident;)*) => {$(
+        impl $op<&MockFr> for MockFr {
+            type Output = MockFr;
+
+            fn $method(self, other: &MockFr) -> MockFr {
+                $op::$method(self, *other)
+            }
+        }
+
+        impl $op<MockFr> for &MockFr {
+            type Output = MockFr;
+
+            fn $method(self, other: MockFr) -> MockFr {
+                $op::$method(*self, other)
+            }
+        }
+
+        impl $op<&MockFr> for &MockFr {
+            type Output = MockFr;
+
+            fn $method(self, other: &MockFr) -> MockFr {
+                $op::$method(*self, *other)
+            }
+        }
+
+        impl $assign for MockFr {
+            fn $assign_method(&mut self, other: MockFr) {
+                *self = $op::$method(*self, other);
+            }
+        }
+
+        impl $assign<&MockFr> for MockFr {
+            fn $assign_method(&mut self, other: &MockFr) {
+                *self = $op::$method(*self, *other);
+            }
+        }
+    )*};
+}
+
+forward_ref_ops! {
+    Add::add, AddAssign::add_assign;
+    Sub::sub, SubAssign::sub_assign;
+    Mul::mul, MulAssign::mul_assign;
+}
+
+impl Neg for &MockFr {
+    type Output = MockFr;
+
+    fn neg(self) -> MockFr {
+        -*self
+    }
+}
+
+impl Sum for MockFr {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a MockFr> for MockFr {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self(0), |acc, x| acc + x)
+    }
+}
+
+impl Product for MockFr {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(1), Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a MockFr> for MockFr {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self(1), |acc, x| acc * x)
+    }
+}
+
+impl From<u64> for MockFr {
+    fn from(n: u64) -> Self {
+        Self::reduce(n as u128)
+    }
+}
+
+impl FieldElement for MockFr {
+    type Repr = Vec<u8>;
+
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn one() -> Self {
+        Self(1)
+    }
+
+    fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let candidate = rng.next_u64();
+            if candidate < MODULUS {
+                return Self(candidate);
+            }
+        }
+    }
+
+    fn invert(&self) -> Option<Self> {
+        (self.0 != 0).then(|| self.pow_u64(MODULUS - 2))
+    }
+
+    fn pow(&self, exp: &[u64; 4]) -> Self {
+        let mut acc = Self(1);
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc * acc;
+                if (limb >> bit) & 1 == 1 {
+                    acc *= *self;
+                }
+            }
+        }
+        acc
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        metric!(bytes_serialized += 8);
+        self.0.to_le_bytes().to_vec()
+    }
+
+    fn from_repr(repr: &Self::Repr) -> Result<Self, BackendError> {
+        let bytes: [u8; 8] = repr
+            .as_slice()
+            .try_into()
+            .map_err(|_| BackendError::Serialization("mock scalar must be 8 bytes"))?;
+        let value = u64::from_le_bytes(bytes);
+        if value >= MODULUS {
+            return Err(BackendError::Serialization(
+                "scalar not below field modulus",
+            ));
+        }
+        Ok(Self(value))
+    }
+
+    fn two_adic_root_of_unity() -> Self {
+        Self(GENERATOR).pow_u64((MODULUS - 1) >> TWO_ADICITY)
+    }
+
+    fn two_adicity_generator(n: usize) -> Self {
+        assert!(n.is_power_of_two(), "domain size must be a power of two");
+        let log_n = n.trailing_zeros();
+        assert!(
+            log_n <= TWO_ADICITY,
+            "requested domain exceeds scalar field two-adicity"
+        );
+        Self::two_adic_root_of_unity().pow_u64(1 << (TWO_ADICITY - log_n))
+    }
+
+    fn batch_inversion(elements: &mut [Self]) -> Result<(), BackendError> {
+        if elements.iter().any(|elem| elem.0 == 0) {
+            return Err(BackendError::Math("cannot invert zero element"));
+        }
+        for elem in elements.iter_mut() {
+            *elem = elem.invert().expect("non-zero element");
+        }
+        Ok(())
+    }
+
+    fn from_u64(n: u64) -> Self {
+        Self::from(n)
+    }
+
+    fn to_bytes_le(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+        bytes
+    }
+
+    fn from_bytes_le(bytes: &[u8; 32]) -> Result<Self, BackendError> {
+        let value = u64::from_le_bytes(bytes[..8].try_into().expect("8-byte limb"));
+        if bytes[8..].iter().any(|&b| b != 0) || value >= MODULUS {
+            return Err(BackendError::Serialization(
+                "scalar not below field modulus",
+            ));
+        }
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_has_the_documented_roots_of_unity() {
+        let root = MockFr::two_adic_root_of_unity();
+        assert_eq!(root.pow_u64(1 << TWO_ADICITY), MockFr::one());
+        assert_ne!(root.pow_u64(1 << (TWO_ADICITY - 1)), MockFr::one());
+
+        let a = MockFr::from_u64(MODULUS - 5);
+        assert_eq!(a * a.invert().unwrap(), MockFr::one());
+        assert_eq!(a + MockFr::from_u64(5), MockFr::zero());
+        assert!(MockFr::from_repr(&MODULUS.to_le_bytes().to_vec()).is_err());
+        assert_eq!(MockFr::from_bytes_le(&a.to_bytes_le()).unwrap(), a);
+    }
+}
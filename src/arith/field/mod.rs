@@ -1,23 +1,23 @@
 use core::fmt::Debug;
 
 use rand_core::RngCore;
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::BackendError;
 
 #[cfg(feature = "blst")]
-mod blst_bls12_381;
-#[cfg(feature = "blst")]
-pub use blst_bls12_381::Fr;
+pub(crate) mod blst_bls12_381;
 
 #[cfg(feature = "ark_bls12381")]
-mod ark_bls12_381;
-#[cfg(feature = "ark_bls12381")]
-pub use ark_bls12_381::Fr;
+pub(crate) mod ark_bls12_381;
 
 #[cfg(feature = "ark_bn254")]
-mod ark_bn254;
-#[cfg(feature = "ark_bn254")]
-pub use ark_bn254::Fr;
+pub(crate) mod ark_bn254;
+
+#[cfg(any(test, feature = "mock"))]
+mod mock;
+#[cfg(any(test, feature = "mock"))]
+pub use mock::MockFr;
 
 /// Field element abstraction for scalar field operations.
 ///
@@ -87,4 +87,41 @@ pub trait FieldElement: Clone + Send + Sync + Debug + 'static + Copy {
 
     /// Convert u64 to self.
     fn from_u64(n: u64) -> Self;
+
+    /// Returns the canonical integer value as 32 little-endian bytes, independent
+    /// of the backend's serialization format.
+    fn to_bytes_le(&self) -> [u8; 32];
+
+    /// Returns the canonical integer value as 32 big-endian bytes, independent
+    /// of the backend's serialization format.
+    fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Parses the canonical integer value from 32 little-endian bytes.
+    ///
+    /// Unlike [`from_repr`](Self::from_repr), whose byte order depends on the
+    /// backend (big-endian for blst, little-endian for Arkworks), this is the
+    /// same on every backend. Returns an error for values not below the field
+    /// modulus rather than reducing them.
+    fn from_bytes_le(bytes: &[u8; 32]) -> Result<Self, BackendError>;
+
+    /// Parses the canonical integer value from 32 big-endian bytes.
+    ///
+    /// See [`from_bytes_le`](Self::from_bytes_le).
+    fn from_bytes_be(bytes: &[u8; 32]) -> Result<Self, BackendError> {
+        let mut le = *bytes;
+        le.reverse();
+        Self::from_bytes_le(&le)
+    }
+
+    /// Compares two elements in constant time.
+    ///
+    /// Use this instead of `==` whenever either side is secret, such as key
+    /// shares or derived keys.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_repr().as_ref().ct_eq(other.to_repr().as_ref())
+    }
 }
@@ -23,7 +23,10 @@ use ark_ff::{PrimeField, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use tracing::instrument;
 
-use crate::{BackendError, CurvePoint, Fr, TargetGroup};
+#[cfg(feature = "std")]
+use super::FixedBaseTable;
+use super::ark_wnaf_mul;
+use crate::{BackendError, CurvePoint, TargetGroup, arith::field::ark_bn254::Fr, errors::ArkError};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 /// G1 group element wrapper for the Arkworks BN254 backend.
@@ -74,7 +77,27 @@ impl CurvePoint<Fr> for G1 {
     }
 
     fn mul_scalar(&self, scalar: &Fr) -> Self {
-        G1(self.0 * scalar)
+        metric!(scalar_muls += 1);
+        G1(ark_wnaf_mul(&self.0, scalar))
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        G1(self.0.into_affine().clear_cofactor().into_group())
+    }
+
+    fn is_torsion_free(&self) -> bool {
+        self.0
+            .into_affine()
+            .is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    #[cfg(feature = "std")]
+    fn mul_generator(scalar: &Fr) -> Self {
+        metric!(scalar_muls += 1);
+        static TABLE: std::sync::OnceLock<FixedBaseTable<G1>> = std::sync::OnceLock::new();
+        TABLE
+            .get_or_init(|| FixedBaseTable::new(&<G1 as CurvePoint<Fr>>::generator()))
+            .mul(scalar)
     }
 
     fn batch_normalize(points: &[Self]) -> Vec<Self::Affine> {
@@ -99,14 +122,19 @@ impl CurvePoint<Fr> for G1 {
         self.0
             .serialize_compressed(&mut bytes)
             .expect("g1 serialization");
+        metric!(bytes_serialized += bytes.len());
         bytes
     }
 
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
         let affine = G1Affine::deserialize_compressed(bytes.as_slice())
-            .map_err(|_| BackendError::Serialization("invalid G1 bytes"))?;
+            .map_err(|err| BackendError::library("invalid G1 bytes", ArkError(err)))?;
         Ok(G1(affine.into_group()))
     }
+
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        G1(super::ark_hash_to_curve::<ark_bn254::g1::Config>(msg, dst))
+    }
 }
 
 impl CurvePoint<Fr> for G2 {
@@ -146,7 +174,27 @@ impl CurvePoint<Fr> for G2 {
     }
 
     fn mul_scalar(&self, scalar: &Fr) -> Self {
-        G2(self.0 * scalar)
+        metric!(scalar_muls += 1);
+        G2(ark_wnaf_mul(&self.0, scalar))
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        G2(self.0.into_affine().clear_cofactor().into_group())
+    }
+
+    fn is_torsion_free(&self) -> bool {
+        self.0
+            .into_affine()
+            .is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    #[cfg(feature = "std")]
+    fn mul_generator(scalar: &Fr) -> Self {
+        metric!(scalar_muls += 1);
+        static TABLE: std::sync::OnceLock<FixedBaseTable<G2>> = std::sync::OnceLock::new();
+        TABLE
+            .get_or_init(|| FixedBaseTable::new(&<G2 as CurvePoint<Fr>>::generator()))
+            .mul(scalar)
     }
 
     fn batch_normalize(points: &[Self]) -> Vec<Self::Affine> {
@@ -171,14 +219,19 @@ impl CurvePoint<Fr> for G2 {
         self.0
             .serialize_compressed(&mut bytes)
             .expect("g2 serialization");
+        metric!(bytes_serialized += bytes.len());
         bytes
     }
 
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
         let affine = G2Affine::deserialize_compressed(bytes.as_slice())
-            .map_err(|_| BackendError::Serialization("invalid G2 bytes"))?;
+            .map_err(|err| BackendError::library("invalid G2 bytes", ArkError(err)))?;
         Ok(G2(affine.into_group()))
     }
+
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        G2(super::ark_hash_to_curve::<ark_bn254::g2::Config>(msg, dst))
+    }
 }
 
 impl From<&G1> for G1Projective {
@@ -213,7 +266,12 @@ impl TargetGroup for Gt {
         <Gt as Zero>::zero()
     }
 
+    fn generator() -> Self {
+        <Gt as PrimeGroup>::generator()
+    }
+
     fn mul_scalar(&self, scalar: &Self::Scalar) -> Self {
+        metric!(scalar_muls += 1);
         let bigint = scalar.into_bigint();
         self.mul_bigint(bigint.as_ref())
     }
@@ -222,15 +280,26 @@ impl TargetGroup for Gt {
         self + other
     }
 
+    #[instrument(level = "trace", skip_all, fields(len = elements.len()))]
+    fn multi_scalar_multiplication(elements: &[Self], scalars: &[Self::Scalar]) -> Self {
+        assert_eq!(
+            elements.len(),
+            scalars.len(),
+            "elements and scalars must have the same length"
+        );
+        Gt::msm(elements, scalars).unwrap()
+    }
+
     fn to_repr(&self) -> Self::Repr {
         let mut bytes = Vec::new();
         self.serialize_compressed(&mut bytes)
             .expect("target serialization");
+        metric!(bytes_serialized += bytes.len());
         bytes
     }
 
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
         Gt::deserialize_compressed(bytes.as_slice())
-            .map_err(|_| BackendError::Serialization("invalid GT bytes"))
+            .map_err(|err| BackendError::library("invalid GT bytes", ArkError(err)))
     }
 }
@@ -20,11 +20,16 @@
 
 use alloc::vec::Vec;
 use std::io::Cursor;
+use std::sync::OnceLock;
 
 use blstrs::{Compress, G1Affine, G1Projective, G2Affine, G2Projective, Gt as BlstGt, Scalar};
 use group::{Curve, Group, prime::PrimeCurveAffine};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use subtle::Choice;
 use tracing::instrument;
 
+use super::{FixedBaseTable, mul_by_limbs};
 use crate::{BackendError, CurvePoint, TargetGroup};
 
 /// G1 projective group element for the blst BLS12-381 backend.
@@ -34,6 +39,72 @@ pub type G2 = G2Projective;
 /// Target group element for the blst BLS12-381 backend.
 pub type Gt = BlstGt;
 
+/// Effective G1 cofactor `1 - x` from RFC 9380, section 8.8.1.
+const G1_H_EFF: [u64; 1] = [0xd201_0000_0001_0001];
+
+/// Effective G2 cofactor from RFC 9380, section 8.8.2, as little-endian limbs.
+const G2_H_EFF: [u64; 10] = [
+    0xe802_0005_aaa9_5551,
+    0x5989_4c0a_debb_f6b4,
+    0xe954_cbc0_6689_f6a3,
+    0x2ec0_ec69_d747_7c1a,
+    0x6d82_bf01_5d12_12b0,
+    0x329c_2f17_8731_db95,
+    0x9986_ff03_1508_ffe1,
+    0x88e2_a8e9_145a_d768,
+    0x584c_6a0e_a91b_3528,
+    0x0bc6_9f08_f2ee_75b3,
+];
+
+/// Decodes compressed G1 points for
+/// [`PairingBackend::deserialize_g1_batch`](crate::PairingBackend::deserialize_g1_batch).
+///
+/// `G1Affine::from_compressed` tests curve membership a second time after
+/// decompression, which already only yields points on the curve. The batch
+/// path decompresses, runs blst's endomorphism-based subgroup check once per
+/// point, and spreads the points over the Rayon pool with `parallel`.
+pub(crate) fn decode_g1_batch<T: AsRef<[u8]> + Sync>(
+    encoded: &[T],
+) -> Result<Vec<G1>, BackendError> {
+    decode_batch(encoded, |bytes| {
+        let affine =
+            Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(bytes.try_into().ok()?))?;
+        bool::from(affine.is_torsion_free()).then(|| affine.into())
+    })
+    .ok_or(BackendError::Serialization("invalid G1 bytes"))
+}
+
+/// Decodes compressed G2 points like [`decode_g1_batch`].
+pub(crate) fn decode_g2_batch<T: AsRef<[u8]> + Sync>(
+    encoded: &[T],
+) -> Result<Vec<G2>, BackendError> {
+    decode_batch(encoded, |bytes| {
+        let affine =
+            Option::<G2Affine>::from(G2Affine::from_compressed_unchecked(bytes.try_into().ok()?))?;
+        bool::from(affine.is_torsion_free()).then(|| affine.into())
+    })
+    .ok_or(BackendError::Serialization("invalid G2 bytes"))
+}
+
+fn decode_batch<T, P, F>(encoded: &[T], decode: F) -> Option<Vec<P>>
+where
+    T: AsRef<[u8]> + Sync,
+    P: Send,
+    F: Fn(&[u8]) -> Option<P> + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        encoded
+            .par_iter()
+            .map(|bytes| decode(bytes.as_ref()))
+            .collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        encoded.iter().map(|bytes| decode(bytes.as_ref())).collect()
+    }
+}
+
 impl CurvePoint<Scalar> for G1 {
     type Affine = G1Affine;
 
@@ -72,9 +143,27 @@ impl CurvePoint<Scalar> for G1 {
     }
 
     fn mul_scalar(&self, scalar: &Scalar) -> Self {
+        metric!(scalar_muls += 1);
+        // blst's `mult` is already a windowed Booth-encoded ladder.
         self * scalar
     }
 
+    fn clear_cofactor(&self) -> Self {
+        mul_by_limbs(self, &G1_H_EFF)
+    }
+
+    fn is_torsion_free(&self) -> bool {
+        G1Affine::from(self).is_torsion_free().into()
+    }
+
+    fn mul_generator(scalar: &Scalar) -> Self {
+        metric!(scalar_muls += 1);
+        static TABLE: OnceLock<FixedBaseTable<G1>> = OnceLock::new();
+        TABLE
+            .get_or_init(|| FixedBaseTable::new(&<G1 as CurvePoint<Scalar>>::generator()))
+            .mul(scalar)
+    }
+
     fn batch_normalize(points: &[Self]) -> Vec<Self::Affine> {
         let mut affines = vec![G1Affine::identity(); points.len()];
         <G1Projective as Curve>::batch_normalize(points, &mut affines);
@@ -92,10 +181,12 @@ impl CurvePoint<Scalar> for G1 {
     }
 
     fn to_repr(&self) -> Self::Repr {
-        CurvePoint::to_affine(self)
+        let bytes = CurvePoint::to_affine(self)
             .to_compressed()
             .as_ref()
-            .to_vec()
+            .to_vec();
+        metric!(bytes_serialized += bytes.len());
+        bytes
     }
 
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
@@ -108,6 +199,10 @@ impl CurvePoint<Scalar> for G1 {
             .ok_or(BackendError::Serialization("invalid G1 bytes"))?;
         Ok(affine.into())
     }
+
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        G1Projective::hash_to_curve(msg, dst, &[])
+    }
 }
 
 impl CurvePoint<Scalar> for G2 {
@@ -147,9 +242,27 @@ impl CurvePoint<Scalar> for G2 {
     }
 
     fn mul_scalar(&self, scalar: &Scalar) -> Self {
+        metric!(scalar_muls += 1);
+        // blst's `mult` is already a windowed Booth-encoded ladder.
         self * scalar
     }
 
+    fn clear_cofactor(&self) -> Self {
+        mul_by_limbs(self, &G2_H_EFF)
+    }
+
+    fn is_torsion_free(&self) -> bool {
+        G2Affine::from(self).is_torsion_free().into()
+    }
+
+    fn mul_generator(scalar: &Scalar) -> Self {
+        metric!(scalar_muls += 1);
+        static TABLE: OnceLock<FixedBaseTable<G2>> = OnceLock::new();
+        TABLE
+            .get_or_init(|| FixedBaseTable::new(&<G2 as CurvePoint<Scalar>>::generator()))
+            .mul(scalar)
+    }
+
     fn batch_normalize(points: &[Self]) -> Vec<Self::Affine> {
         let mut affines = vec![G2Affine::identity(); points.len()];
         <G2Projective as Curve>::batch_normalize(points, &mut affines);
@@ -167,10 +280,12 @@ impl CurvePoint<Scalar> for G2 {
     }
 
     fn to_repr(&self) -> Self::Repr {
-        CurvePoint::to_affine(self)
+        let bytes = CurvePoint::to_affine(self)
             .to_compressed()
             .as_ref()
-            .to_vec()
+            .to_vec();
+        metric!(bytes_serialized += bytes.len());
+        bytes
     }
 
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
@@ -183,6 +298,10 @@ impl CurvePoint<Scalar> for G2 {
             .ok_or(BackendError::Serialization("invalid G2 bytes"))?;
         Ok(affine.into())
     }
+
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+        G2Projective::hash_to_curve(msg, dst, &[])
+    }
 }
 
 impl TargetGroup for Gt {
@@ -193,7 +312,12 @@ impl TargetGroup for Gt {
         <Gt as Group>::identity()
     }
 
+    fn generator() -> Self {
+        <Gt as Group>::generator()
+    }
+
     fn mul_scalar(&self, scalar: &Self::Scalar) -> Self {
+        metric!(scalar_muls += 1);
         self * scalar
     }
 
@@ -201,17 +325,50 @@ impl TargetGroup for Gt {
         self + other
     }
 
+    #[instrument(level = "trace", skip_all, fields(len = elements.len()))]
+    fn multi_scalar_multiplication(elements: &[Self], scalars: &[Self::Scalar]) -> Self {
+        assert_eq!(
+            elements.len(),
+            scalars.len(),
+            "elements and scalars must have the same length"
+        );
+        #[cfg(feature = "parallel")]
+        {
+            elements
+                .par_iter()
+                .zip(scalars.par_iter())
+                .map(|(elem, scalar)| elem * scalar)
+                .reduce(<Gt as Group>::identity, |acc, val| acc + val)
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            elements
+                .iter()
+                .zip(scalars.iter())
+                .fold(<Gt as Group>::identity(), |acc, (elem, scalar)| {
+                    acc + elem * scalar
+                })
+        }
+    }
+
     fn to_repr(&self) -> Self::Repr {
         let mut bytes = Vec::with_capacity(288);
         self.write_compressed(&mut bytes)
             .map_err(|_| BackendError::Serialization("gt serialization failure"))
             .expect("in-memory serialization should not fail");
+        metric!(bytes_serialized += bytes.len());
         bytes
     }
 
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
         let mut cursor = Cursor::new(bytes.as_slice());
         Gt::read_compressed(&mut cursor)
-            .map_err(|_| BackendError::Serialization("invalid GT bytes"))
+            .map_err(|err| BackendError::library("invalid GT bytes", err))
+    }
+
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The compressed encoding is undefined for the identity, so test the
+        // quotient against the identity, which blstrs does in constant time.
+        Group::is_identity(&(*self - *other))
     }
 }
@@ -1,22 +1,98 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
+use rand_core::RngCore;
+use subtle::{Choice, ConstantTimeEq};
 
 use crate::{BackendError, FieldElement};
 
 #[cfg(feature = "blst")]
-mod blst_bls12_381;
-#[cfg(feature = "blst")]
-pub use blst_bls12_381::{G1, G2, Gt};
+pub(crate) mod blst_bls12_381;
 
 #[cfg(feature = "ark_bls12381")]
-mod ark_bls12_381;
-#[cfg(feature = "ark_bls12381")]
-pub use ark_bls12_381::{G1, G2, Gt};
+pub(crate) mod ark_bls12_381;
 
 #[cfg(feature = "ark_bn254")]
-mod ark_bn254;
-#[cfg(feature = "ark_bn254")]
-pub use ark_bn254::{G1, G2, Gt};
+pub(crate) mod ark_bn254;
+
+/// Hashes to a short Weierstrass curve by try-and-increment.
+///
+/// Candidate x-coordinates are drawn from a BLAKE3 XOF over `(dst, msg, counter)`
+/// until one lies on the curve; the cofactor is then cleared. This is not
+/// constant time, which is acceptable for hashing public messages.
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+pub(crate) fn ark_hash_to_curve<P: ark_ec::short_weierstrass::SWCurveConfig>(
+    msg: &[u8],
+    dst: &[u8],
+) -> ark_ec::short_weierstrass::Projective<P> {
+    use ark_ec::{AffineRepr, short_weierstrass::Affine};
+    use ark_ff::{Field, PrimeField};
+
+    let degree = P::BaseField::extension_degree() as usize;
+    for counter in 0u64.. {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&(dst.len() as u64).to_le_bytes());
+        hasher.update(dst);
+        hasher.update(msg);
+        hasher.update(&counter.to_le_bytes());
+        let mut reader = hasher.finalize_xof();
+
+        let mut elems = Vec::with_capacity(degree);
+        for _ in 0..degree {
+            let mut bytes = [0u8; 64];
+            reader.fill(&mut bytes);
+            elems.push(<P::BaseField as Field>::BasePrimeField::from_le_bytes_mod_order(&bytes));
+        }
+        let mut sign = [0u8; 1];
+        reader.fill(&mut sign);
+
+        let Some(x) = P::BaseField::from_base_prime_field_elems(elems) else {
+            continue;
+        };
+        if let Some(point) = Affine::<P>::get_point_from_x_unchecked(x, sign[0] & 1 == 1) {
+            let point = point.clear_cofactor();
+            if !point.is_zero() {
+                return point.into_group();
+            }
+        }
+    }
+    unreachable!("hash to curve exhausted its counter")
+}
+
+/// Multiplies `point` by the integer with little-endian 64-bit `limbs`.
+///
+/// Plain double-and-add over point addition, so it is valid for points outside
+/// the prime-order subgroup and for integers wider than the scalar field, such
+/// as effective cofactors. Backend `mul_scalar` routines may use endomorphisms
+/// that assume subgroup membership and cannot be used here.
+#[cfg(feature = "blst")]
+pub(crate) fn mul_by_limbs<F: FieldElement, C: CurvePoint<F>>(point: &C, limbs: &[u64]) -> C {
+    let mut acc = C::identity();
+    for limb in limbs.iter().rev() {
+        for bit in (0..64).rev() {
+            acc = acc.add(&acc);
+            if (limb >> bit) & 1 == 1 {
+                acc = acc.add(point);
+            }
+        }
+    }
+    acc
+}
+
+/// Window width used by [`ark_wnaf_mul`].
+///
+/// A width of 4 keeps the per-call table at eight points, which pays for itself
+/// on a single 255-bit scalar.
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+const ARK_WNAF_WINDOW: usize = 4;
+
+/// Variable-base scalar multiplication over windowed non-adjacent form.
+///
+/// Used by the Arkworks wrappers' `mul_scalar`. The digit pattern depends on
+/// the scalar, so this is not constant time.
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+pub(crate) fn ark_wnaf_mul<G: ark_ec::PrimeGroup>(base: &G, scalar: &G::ScalarField) -> G {
+    ark_ec::scalar_mul::wnaf::WnafContext::new(ARK_WNAF_WINDOW).mul(*base, scalar)
+}
 
 /// Elliptic curve point abstraction for G1 and G2 groups.
 ///
@@ -91,6 +167,87 @@ pub trait CurvePoint<F: FieldElement>: Clone + Send + Sync + Debug + 'static + C
 
     /// Deserializes a point from its byte representation.
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError>;
+
+    /// Hashes `msg` to a point of the prime-order subgroup under the domain
+    /// separation tag `dst`.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self;
+
+    /// Maps a point of the full curve group into the prime-order subgroup by
+    /// multiplying by the effective cofactor.
+    ///
+    /// Points produced by this crate's constructors and decoders are already in
+    /// the subgroup; this is for hash-to-curve style constructions that start
+    /// from an arbitrary curve point.
+    fn clear_cofactor(&self) -> Self;
+
+    /// Returns `true` if the point lies in the prime-order subgroup.
+    ///
+    /// Check externally supplied points with this before using them, in
+    /// particular in G2 whose cofactor is large.
+    fn is_torsion_free(&self) -> bool;
+
+    /// Multiplies the group generator by `scalar`.
+    ///
+    /// Backends built with `std` override this with a [`FixedBaseTable`]
+    /// initialised once per process; lookups are not hardened against
+    /// cache-timing side channels.
+    fn mul_generator(scalar: &F) -> Self {
+        Self::generator().mul_scalar(scalar)
+    }
+
+    /// Samples a uniformly random point of the prime-order subgroup.
+    fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self::mul_generator(&F::random(rng))
+    }
+}
+
+/// Bits per window of a [`FixedBaseTable`].
+const WINDOW_BITS: usize = 4;
+/// Number of windows covering a 256-bit scalar.
+const WINDOWS: usize = 256 / WINDOW_BITS;
+
+/// Fixed-base multiplication table: `windows[i][j] = j·2^{4i}·P`.
+///
+/// A multiplication is one table addition per 4-bit window of the scalar
+/// instead of a full double-and-add.
+#[derive(Clone, Debug)]
+pub(crate) struct FixedBaseTable<C> {
+    windows: Vec<Vec<C>>,
+}
+
+impl<C> FixedBaseTable<C> {
+    pub(crate) fn new<F: FieldElement>(base: &C) -> Self
+    where
+        C: CurvePoint<F>,
+    {
+        let mut windows = Vec::with_capacity(WINDOWS);
+        let mut window_base = *base;
+        for _ in 0..WINDOWS {
+            let mut row = Vec::with_capacity(1 << WINDOW_BITS);
+            let mut acc = C::identity();
+            for _ in 0..1 << WINDOW_BITS {
+                row.push(acc);
+                acc = acc.add(&window_base);
+            }
+            window_base = acc;
+            windows.push(row);
+        }
+        Self { windows }
+    }
+
+    pub(crate) fn mul<F: FieldElement>(&self, scalar: &F) -> C
+    where
+        C: CurvePoint<F>,
+    {
+        let bytes = scalar.to_bytes_le();
+        self.windows
+            .iter()
+            .enumerate()
+            .fold(C::identity(), |acc, (i, row)| {
+                let nibble = (bytes[i / 2] >> (4 * (i % 2))) & 0x0f;
+                acc.add(&row[nibble as usize])
+            })
+    }
 }
 
 /// Pairing target group (GT) abstraction.
@@ -119,15 +276,107 @@ pub trait TargetGroup: Clone + Send + Sync + Debug + 'static {
     /// Returns the multiplicative identity element.
     fn identity() -> Self;
 
+    /// Returns the generator `e(g1, g2)` of the target group.
+    fn generator() -> Self;
+
+    /// Samples a uniformly random element of the target group.
+    fn random<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self::generator().mul_scalar(&Self::Scalar::random(rng))
+    }
+
     /// Performs scalar multiplication (exponentiation in multiplicative notation).
     fn mul_scalar(&self, scalar: &Self::Scalar) -> Self;
 
     /// Combines (multiplies) two target group elements.
     fn combine(&self, other: &Self) -> Self;
 
+    /// Performs multi-exponentiation: returns `∏ elements[i]^{scalars[i]}`.
+    fn multi_scalar_multiplication(elements: &[Self], scalars: &[Self::Scalar]) -> Self;
+
     /// Serializes this element to its byte representation.
     fn to_repr(&self) -> Self::Repr;
 
     /// Deserializes an element from its byte representation.
     fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError>;
+
+    /// Compares two elements in constant time.
+    ///
+    /// Use this instead of `==` whenever either side is secret, such as a
+    /// recovered shared secret.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_repr().as_ref().ct_eq(other.to_repr().as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, PairingBackend, PairingEngine};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    type G1 = <PairingEngine as PairingBackend>::G1;
+    type G2 = <PairingEngine as PairingBackend>::G2;
+
+    #[test]
+    fn fixed_base_table_matches_scalar_multiplication() {
+        let mut rng = StdRng::seed_from_u64(89);
+        let base = G2::random(&mut rng);
+        let table = FixedBaseTable::new(&base);
+        for scalar in [Fr::zero(), Fr::one(), -Fr::one(), Fr::random(&mut rng)] {
+            assert!(
+                table
+                    .mul(&scalar)
+                    .sub(&base.mul_scalar(&scalar))
+                    .is_identity()
+            );
+        }
+    }
+
+    #[test]
+    fn mul_generator_matches_scalar_multiplication() {
+        let mut rng = StdRng::seed_from_u64(1426);
+        for scalar in [Fr::zero(), Fr::one(), -Fr::one(), Fr::random(&mut rng)] {
+            assert!(
+                G1::mul_generator(&scalar)
+                    .sub(&G1::generator().mul_scalar(&scalar))
+                    .is_identity()
+            );
+            assert!(
+                G2::mul_generator(&scalar)
+                    .sub(&G2::generator().mul_scalar(&scalar))
+                    .is_identity()
+            );
+        }
+    }
+
+    #[cfg(feature = "blst")]
+    #[test]
+    fn clear_cofactor_moves_points_into_subgroup() {
+        use blstrs::{G1Affine, G2Affine};
+
+        // Small x-coordinates give curve points outside the prime-order subgroup.
+        let g1 = (1u8..=255)
+            .find_map(|x| {
+                let mut bytes = [0u8; 48];
+                bytes[0] = 0x80;
+                bytes[47] = x;
+                Option::<G1Affine>::from(G1Affine::from_compressed_unchecked(&bytes))
+            })
+            .map(G1::from)
+            .expect("curve point");
+        let g2 = (1u8..=255)
+            .find_map(|x| {
+                let mut bytes = [0u8; 96];
+                bytes[0] = 0x80;
+                bytes[95] = x;
+                Option::<G2Affine>::from(G2Affine::from_compressed_unchecked(&bytes))
+            })
+            .map(G2::from)
+            .expect("curve point");
+
+        assert!(!g1.is_torsion_free());
+        assert!(g1.clear_cofactor().is_torsion_free());
+        assert!(!g2.is_torsion_free());
+        assert!(g2.clear_cofactor().is_torsion_free());
+    }
 }
@@ -35,7 +35,7 @@
 //!
 //! This simplifies to efficient FFT-based computation in the evaluation domain.
 
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 #[cfg(feature = "parallel")]
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
@@ -43,7 +43,11 @@ use rayon::iter::{
 use tracing::instrument;
 
 use crate::arith::group::CurvePoint;
-use crate::{BackendError, DensePolynomial, FieldElement, Fr, PairingBackend, Polynomial};
+use crate::kzg::{domain_generator, group_fft};
+use crate::{
+    BackendError, DensePolynomial, FFT_THRESHOLD, FieldElement, Fr, LagrangeSRS,
+    MixedRadixEvaluationDomain, PairingBackend, Polynomial, SRS, root_of_unity,
+};
 
 /// Precomputed Lagrange polynomial commitments for efficient key derivation.
 ///
@@ -95,7 +99,7 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
     ///
     /// # Algorithm Details
     ///
-    /// 1. Evaluate all Lagrange polynomials at τ in parallel
+    /// 1. Evaluate all Lagrange polynomials at τ in barycentric form
     /// 2. Compute the vanishing polynomial z(τ) = τ^n - 1
     /// 3. Compute commitments to L_i, L_i - L_i(0), and x·L_i in parallel
     /// 4. Compute all n² commitments to L_i·L_j/z in parallel
@@ -128,23 +132,12 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
     ) -> Result<Self, BackendError> {
         let n = lagranges.len();
 
-        // Evaluate all Lagrange polynomials at tau
-        let li_evals: Vec<B::Scalar> = {
-            #[cfg(feature = "parallel")]
-            {
-                lagranges
-                    .par_iter()
-                    .map(|li_poly| li_poly.evaluate(tau))
-                    .collect()
-            }
-            #[cfg(not(feature = "parallel"))]
-            {
-                lagranges
-                    .iter()
-                    .map(|li_poly| li_poly.evaluate(tau))
-                    .collect()
-            }
-        };
+        // Evaluate all Lagrange polynomials at tau in barycentric form
+        let domain: Vec<Fr> = MixedRadixEvaluationDomain::new(n)
+            .ok_or(BackendError::Math("unsupported domain size"))?
+            .elements();
+        let weights = domain_barycentric_weights(n)?;
+        let li_evals = lagrange_basis_at(&domain, &weights, tau)?;
 
         // Compute tau^n - 1 (the vanishing polynomial evaluated at tau)
         let tau_n = tau.pow(&[domain_size as u64, 0, 0, 0]);
@@ -168,7 +161,7 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
                         let li_eval = &li_evals[i];
 
                         // li = g * L_i(tau)
-                        let lagrange_li = B::G1::generator().mul_scalar(li_eval);
+                        let lagrange_li = B::G1::mul_generator(li_eval);
 
                         // li_minus0 = g * (L_i(tau) - L_i(0))
                         let li_0 = li_poly
@@ -177,11 +170,11 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
                             .cloned()
                             .unwrap_or_else(<B::Scalar as FieldElement>::zero);
                         let li_minus0_eval = *li_eval - li_0;
-                        let lagrange_li_minus0 = B::G1::generator().mul_scalar(&li_minus0_eval);
+                        let lagrange_li_minus0 = B::G1::mul_generator(&li_minus0_eval);
 
                         // li_x = g * (L_i(tau) - L_i(0)) / tau
                         let li_x_eval = li_minus0_eval * tau_inv;
-                        let lagrange_li_x = B::G1::generator().mul_scalar(&li_x_eval);
+                        let lagrange_li_x = B::G1::mul_generator(&li_x_eval);
 
                         (lagrange_li, lagrange_li_minus0, lagrange_li_x)
                     })
@@ -196,7 +189,7 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
                         let li_eval = &li_evals[i];
 
                         // li = g * L_i(tau)
-                        let lagrange_li = B::G1::generator().mul_scalar(li_eval);
+                        let lagrange_li = B::G1::mul_generator(li_eval);
 
                         // li_minus0 = g * (L_i(tau) - L_i(0))
                         let li_0 = li_poly
@@ -205,11 +198,11 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
                             .cloned()
                             .unwrap_or_else(<B::Scalar as FieldElement>::zero);
                         let li_minus0_eval = *li_eval - li_0;
-                        let lagrange_li_minus0 = B::G1::generator().mul_scalar(&li_minus0_eval);
+                        let lagrange_li_minus0 = B::G1::mul_generator(&li_minus0_eval);
 
                         // li_x = g * (L_i(tau) - L_i(0)) / tau
                         let li_x_eval = li_minus0_eval * tau_inv;
-                        let lagrange_li_x = B::G1::generator().mul_scalar(&li_x_eval);
+                        let lagrange_li_x = B::G1::mul_generator(&li_x_eval);
 
                         (lagrange_li, lagrange_li_minus0, lagrange_li_x)
                     })
@@ -244,7 +237,7 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
                                     // (L_i(tau) * L_j(tau)) / z(tau)
                                     (li_evals[i] * li_evals[j]) * z_eval_inv
                                 };
-                                B::G1::generator().mul_scalar(&scalar)
+                                B::G1::mul_generator(&scalar)
                             })
                             .collect::<Vec<_>>()
                     })
@@ -264,7 +257,7 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
                                     // (L_i(tau) * L_j(tau)) / z(tau)
                                     (li_evals[i] * li_evals[j]) * z_eval_inv
                                 };
-                                B::G1::generator().mul_scalar(&scalar)
+                                B::G1::mul_generator(&scalar)
                             })
                             .collect::<Vec<_>>()
                     })
@@ -279,6 +272,346 @@ impl<B: PairingBackend<Scalar = Fr>> LagrangePowers<B> {
             li_lj_z,
         })
     }
+
+    /// Derives the commitments from the monomial powers of an SRS, without tau.
+    ///
+    /// Produces the same values as [`LagrangePowers::precompute_lagrange_powers`]
+    /// for the SRS trapdoor, so a single ceremony output of degree at least
+    /// `domain_size` serves any committee size up to that degree. With
+    /// `c_i = ω^i / n` and `L_i(x) = (1/n) Σ_k ω^{-ik} x^k`:
+    ///
+    /// - `L_i` comes from a G1 FFT, as in [`LagrangeSRS::from_monomial`],
+    /// - `L_i(0) = 1/n`, and `(L_i(x) - L_i(0)) / x` is a second FFT over the
+    ///   shifted powers,
+    /// - for `i ≠ j`, `L_i L_j / Z = (c_j L_i - c_i L_j) / (ω^i - ω^j)`,
+    /// - `(L_i² - L_i) / Z = (1/n²) Σ_m (n - 1 - m) ω^{-im} x^m`, a third FFT.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` if `domain_size` is not a supported domain
+    /// size or exceeds the number of G1 powers.
+    #[instrument(level = "info", skip_all, fields(size = domain_size))]
+    pub fn from_srs(srs: &SRS<B>, domain_size: usize) -> Result<Self, BackendError> {
+        let n = domain_size;
+        let li = LagrangeSRS::from_monomial(srs, n)?.lagrange_g;
+
+        let omega = domain_generator(n)?;
+        let omega_inv = omega
+            .invert()
+            .ok_or(BackendError::Math("invalid generator inversion"))?;
+        let n_inv = Fr::from_u64(n as u64)
+            .invert()
+            .ok_or(BackendError::Math("domain size is not invertible"))?;
+        let omega_pows: Vec<Fr> = core::iter::successors(Some(Fr::one()), |w| Some(*w * omega))
+            .take(n)
+            .collect();
+        // ω^{-i} = ω^{n-i}
+        let omega_inv_pow = |i: usize| omega_pows[(n - i) % n];
+
+        let g_over_n = srs.powers_of_g[0].mul_scalar(&n_inv);
+        let li_minus0: Vec<B::G1> = li.iter().map(|l| l.sub(&g_over_n)).collect();
+
+        let mut shifted = srs.powers_of_g[..n - 1].to_vec();
+        shifted.push(B::G1::identity());
+        let li_x = group_fft(&shifted, omega_inv)
+            .into_iter()
+            .enumerate()
+            .map(|(i, point)| point.mul_scalar(&(omega_inv_pow(i) * n_inv)))
+            .collect();
+
+        let n_inv_sq = n_inv * n_inv;
+        let weighted: Vec<B::G1> = (0..n)
+            .map(|m| srs.powers_of_g[m].mul_scalar(&Fr::from_u64((n - 1 - m) as u64)))
+            .collect();
+        let diagonal: Vec<B::G1> = group_fft(&weighted, omega_inv)
+            .into_iter()
+            .map(|point| point.mul_scalar(&n_inv_sq))
+            .collect();
+
+        // 1/(ω^i - ω^j) = ω^{-j} / (ω^{i-j} - 1), so n inversions cover all pairs.
+        let mut diff_inv = Vec::with_capacity(n);
+        diff_inv.push(Fr::zero());
+        for w in &omega_pows[1..] {
+            diff_inv.push(
+                (*w - Fr::one())
+                    .invert()
+                    .ok_or(BackendError::Math("repeated domain element"))?,
+            );
+        }
+        let c: Vec<Fr> = omega_pows.iter().map(|w| *w * n_inv).collect();
+
+        let row = |i: usize| -> Vec<B::G1> {
+            (0..n)
+                .map(|j| {
+                    if i == j {
+                        return diagonal[i];
+                    }
+                    let inv = omega_inv_pow(j) * diff_inv[(n + i - j) % n];
+                    li[i]
+                        .mul_scalar(&(c[j] * inv))
+                        .sub(&li[j].mul_scalar(&(c[i] * inv)))
+                })
+                .collect()
+        };
+        let li_lj_z: Vec<Vec<B::G1>> = {
+            #[cfg(feature = "parallel")]
+            {
+                (0..n).into_par_iter().map(row).collect()
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                (0..n).map(row).collect()
+            }
+        };
+
+        Ok(LagrangePowers {
+            li,
+            li_minus0,
+            li_x,
+            li_lj_z,
+        })
+    }
+}
+
+/// Barycentric weights `w_i = 1 / ∏_{j≠i} (x_i - x_j)` for distinct `points`.
+///
+/// Together with [`lagrange_basis_at`] and [`barycentric_evaluate`] these let
+/// callers evaluate interpolants without building coefficient-form Lagrange
+/// polynomials. Computing the weights costs O(n²) once; every evaluation
+/// afterwards is O(n).
+///
+/// # Errors
+///
+/// Returns `BackendError::Math` if two points coincide.
+pub fn barycentric_weights(points: &[Fr]) -> Result<Vec<Fr>, BackendError> {
+    let mut weights: Vec<Fr> = points
+        .iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Fr::one(), |acc, (_, xj)| acc * (*xi - *xj))
+        })
+        .collect();
+    if weights.iter().any(|w| *w == Fr::zero()) {
+        return Err(BackendError::Math("duplicate interpolation points"));
+    }
+    Fr::batch_inversion(&mut weights)?;
+    Ok(weights)
+}
+
+/// Barycentric weights for the size-`n` roots-of-unity domain, in O(n).
+///
+/// `n` may be any size supported by [`MixedRadixEvaluationDomain`].
+///
+/// For `x_i = ω^i` the weights reduce to `w_i = ω^i / n`.
+pub fn domain_barycentric_weights(n: usize) -> Result<Vec<Fr>, BackendError> {
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+    let omega = root_of_unity::<Fr>(n).ok_or(BackendError::Math("unsupported domain size"))?;
+    let n_inv = Fr::from_u64(n as u64)
+        .invert()
+        .ok_or(BackendError::Math("domain size is not invertible"))?;
+
+    let mut weights = Vec::with_capacity(n);
+    let mut cur = n_inv;
+    for _ in 0..n {
+        weights.push(cur);
+        cur *= omega;
+    }
+    Ok(weights)
+}
+
+/// Values `L_i(x)` of every Lagrange basis polynomial over `points` at `x`.
+///
+/// Uses the barycentric form `L_i(x) = ℓ(x) · w_i / (x - x_i)` with
+/// `ℓ(x) = ∏ (x - x_j)`, which needs a single batch inversion. If `x` is one of
+/// the points, the result is the corresponding unit vector.
+///
+/// # Errors
+///
+/// Returns `BackendError::Math` if `points` and `weights` differ in length.
+pub fn lagrange_basis_at(points: &[Fr], weights: &[Fr], x: &Fr) -> Result<Vec<Fr>, BackendError> {
+    if points.len() != weights.len() {
+        return Err(BackendError::Math("mismatched points and weights"));
+    }
+    if let Some(hit) = points.iter().position(|p| p == x) {
+        let mut unit = vec![Fr::zero(); points.len()];
+        unit[hit] = Fr::one();
+        return Ok(unit);
+    }
+
+    let mut diffs: Vec<Fr> = points.iter().map(|p| *x - *p).collect();
+    let vanishing = diffs.iter().fold(Fr::one(), |acc, d| acc * *d);
+    Fr::batch_inversion(&mut diffs)?;
+    Ok(diffs
+        .iter()
+        .zip(weights)
+        .map(|(inv, w)| vanishing * *w * *inv)
+        .collect())
+}
+
+/// Evaluates the interpolant through `(points[i], values[i])` at `x` in O(n).
+///
+/// # Errors
+///
+/// Returns `BackendError::Math` if the slices differ in length.
+pub fn barycentric_evaluate(
+    points: &[Fr],
+    weights: &[Fr],
+    values: &[Fr],
+    x: &Fr,
+) -> Result<Fr, BackendError> {
+    if points.len() != values.len() {
+        return Err(BackendError::Math("mismatched points and values"));
+    }
+    let basis = lagrange_basis_at(points, weights, x)?;
+    Ok(basis
+        .iter()
+        .zip(values)
+        .fold(Fr::zero(), |acc, (l, v)| acc + *l * *v))
+}
+
+/// Selector polynomial `b(x)` for a subset of an evaluation domain.
+///
+/// `b` vanishes at `ω^i` for every unselected party `i` and satisfies
+/// `b(ω^0) = 1`, so `selector[0]` must be set. Aggregate decryption commits to
+/// `b` and weights partial decryptions by its evaluations; see
+/// [`subset_lagrange_coefficients`] for the weights alone.
+///
+/// # Errors
+///
+/// Returns `BackendError::Math` if the selector length is not a supported
+/// domain size or `selector[0]` is unset.
+pub fn selector_polynomial(selector: &[bool]) -> Result<DensePolynomial, BackendError> {
+    let domain = MixedRadixEvaluationDomain::new(selector.len())
+        .ok_or(BackendError::Math("unsupported selector length"))?;
+    if !selector[0] {
+        return Err(BackendError::Math(
+            "selector[0] must be set to anchor interpolation",
+        ));
+    }
+
+    let elements = domain.elements();
+    let points: Vec<Fr> = core::iter::once(elements[0])
+        .chain(
+            selector
+                .iter()
+                .zip(&elements)
+                .filter(|(selected, _)| !**selected)
+                .map(|(_, point)| *point),
+        )
+        .collect();
+    interp_mostly_zero(Fr::one(), &points)
+}
+
+/// Weights aggregate decryption applies to each party's partial decryption.
+///
+/// Entry `i` is `b(ω^i) / n` for the [`selector_polynomial`] `b` and zero for
+/// unselected parties, so external verifiers can recombine partials without
+/// running the scheme. The evaluations come from one FFT over `b`.
+///
+/// # Errors
+///
+/// Same conditions as [`selector_polynomial`].
+pub fn subset_lagrange_coefficients(selector: &[bool]) -> Result<Vec<Fr>, BackendError> {
+    let b_polynomial = selector_polynomial(selector)?;
+    let domain = MixedRadixEvaluationDomain::new(selector.len())
+        .ok_or(BackendError::Math("unsupported selector length"))?;
+    let n_inv = Fr::from_u64(selector.len() as u64)
+        .invert()
+        .ok_or(BackendError::Math("failed to invert party count"))?;
+
+    Ok(domain
+        .fft(b_polynomial.coeffs())
+        .into_iter()
+        .zip(selector)
+        .map(|(eval, selected)| if *selected { eval * n_inv } else { Fr::zero() })
+        .collect())
+}
+
+/// Constructs a polynomial that evaluates to `eval` at the first point and zero at all others.
+///
+/// This is a specialized Lagrange interpolation that efficiently constructs a polynomial
+/// b(x) such that:
+/// - b(points[0]) = eval
+/// - b(points[i]) = 0 for i > 0
+///
+/// This backs [`selector_polynomial`], which aggregate decryption uses to combine
+/// partial decryption shares.
+///
+/// # Algorithm
+///
+/// The polynomial is constructed by:
+/// 1. Building the product (x - points[1])(x - points[2])...(x - points[n-1]), with a
+///    subproduct tree in O(n log² n) above [`FFT_THRESHOLD`] points
+/// 2. Evaluating this product at points[0] to get the normalization factor
+/// 3. Scaling the coefficients so that b(points[0]) = eval
+///
+/// # Arguments
+///
+/// * `eval` - The desired evaluation at the first point (typically 1)
+/// * `points` - The evaluation points, where points[0] is the "anchor" point
+///
+/// # Returns
+///
+/// A polynomial satisfying the interpolation constraints, or an error if the
+/// anchor point is a root of the vanishing polynomial.
+///
+/// # Errors
+///
+/// Returns `BackendError::Math` if the interpolation anchor cannot be inverted,
+/// which would indicate that points[0] is equal to one of the other points.
+#[instrument(level = "info", skip_all)]
+fn interp_mostly_zero(eval: Fr, points: &[Fr]) -> Result<DensePolynomial, BackendError> {
+    if points.is_empty() {
+        return Ok(DensePolynomial::from_coefficients_vec(vec![Fr::one()]));
+    }
+
+    let mut coeffs = if points.len() > FFT_THRESHOLD {
+        DensePolynomial::from_roots(&points[1..]).coeffs
+    } else {
+        incremental_product(&points[1..])
+    };
+
+    let mut scale = *coeffs.last().unwrap();
+    let anchor = points[0];
+    for coef in coeffs.iter().rev().skip(1) {
+        scale = scale * anchor + *coef;
+    }
+
+    let scale_inv = scale
+        .invert()
+        .ok_or(BackendError::Math("failed to invert interpolation anchor"))?;
+    let multiplier = eval * scale_inv;
+
+    for coeff in coeffs.iter_mut() {
+        *coeff *= multiplier;
+    }
+
+    Ok(DensePolynomial::from_coefficients_vec(coeffs))
+}
+
+/// Coefficients of `∏ (x - p)` built one linear factor at a time in O(n²).
+///
+/// Cheaper than a subproduct tree for up to [`FFT_THRESHOLD`] points.
+fn incremental_product(points: &[Fr]) -> Vec<Fr> {
+    let mut coeffs = vec![Fr::one()];
+    for point in points {
+        let neg_point = -*point;
+        coeffs.push(Fr::zero());
+        for i in (0..coeffs.len() - 1).rev() {
+            let (left, right) = coeffs.split_at_mut(i + 1);
+            let coef = &mut left[i];
+            let next = &mut right[0];
+            *next += *coef;
+            *coef *= neg_point;
+        }
+    }
+    coeffs
 }
 
 /// Builds Lagrange basis polynomials for an evaluation domain of size n.
@@ -345,7 +678,7 @@ pub(crate) fn build_lagrange_polys(n: usize) -> Result<Vec<DensePolynomial>, Bac
     }
 
     // Follow the same construction as `lagrange_polys_impl` in arith::lagrange
-    let omega = Fr::two_adicity_generator(n);
+    let omega = root_of_unity::<Fr>(n).ok_or(BackendError::Math("unsupported domain size"))?;
     let omega_inv = omega
         .invert()
         .ok_or(BackendError::Math("invalid generator inversion"))?;
@@ -385,9 +718,83 @@ pub(crate) fn build_lagrange_polys(n: usize) -> Result<Vec<DensePolynomial>, Bac
     Ok(polys)
 }
 
+/// Upper bound on the total number of coefficients held by the Lagrange cache.
+///
+/// `n` basis polynomials cost `n²` coefficients, so the default keeps one
+/// 2048-party domain or many smaller ones (about 128 MiB of 32-byte scalars).
+#[cfg(feature = "std")]
+const LAGRANGE_CACHE_MAX_COEFFS: usize = 1 << 22;
+
+/// Cache entry: scalar field, domain size, and the shared basis.
+#[cfg(feature = "std")]
+type LagrangeCacheEntry = (core::any::TypeId, usize, Arc<Vec<DensePolynomial>>);
+
+/// Least-recently-used cache of Lagrange bases, most recent last.
+#[cfg(feature = "std")]
+static LAGRANGE_CACHE: std::sync::Mutex<Vec<LagrangeCacheEntry>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// Returns the Lagrange basis for a domain of size `n`, memoized per process.
+///
+/// With the `std` feature, bases are kept in a thread-safe LRU cache keyed by
+/// scalar field and `n` and bounded by [`LAGRANGE_CACHE_MAX_COEFFS`]; the O(n²)
+/// construction runs outside the lock. Without `std` this is
+/// [`build_lagrange_polys`].
+pub(crate) fn cached_lagrange_polys(n: usize) -> Result<Arc<Vec<DensePolynomial>>, BackendError> {
+    #[cfg(feature = "std")]
+    {
+        let key = core::any::TypeId::of::<Fr>();
+        {
+            let mut cache = LAGRANGE_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(pos) = cache
+                .iter()
+                .position(|(f, size, _)| *f == key && *size == n)
+            {
+                let entry = cache.remove(pos);
+                let polys = entry.2.clone();
+                cache.push(entry);
+                return Ok(polys);
+            }
+        }
+
+        let polys = Arc::new(build_lagrange_polys(n)?);
+        let cost = n.saturating_mul(n);
+        if cost <= LAGRANGE_CACHE_MAX_COEFFS {
+            let mut cache = LAGRANGE_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+            if !cache.iter().any(|(f, size, _)| *f == key && *size == n) {
+                let mut total: usize = cache.iter().map(|(_, size, _)| size * size).sum();
+                while total + cost > LAGRANGE_CACHE_MAX_COEFFS {
+                    let (_, evicted, _) = cache.remove(0);
+                    total -= evicted * evicted;
+                }
+                cache.push((key, n, polys.clone()));
+            }
+        }
+        Ok(polys)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        build_lagrange_polys(n).map(Arc::new)
+    }
+}
+
+/// Drops every Lagrange basis memoized by parameter generation.
+///
+/// Long-running processes that are done generating parameters can call this to
+/// release the cached memory.
+#[cfg(feature = "std")]
+pub fn clear_lagrange_cache() {
+    LAGRANGE_CACHE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Radix2EvaluationDomain;
 
     #[test]
     fn build_lagrange_polys_evaluate_at_domain() {
@@ -413,4 +820,92 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn barycentric_matches_polynomial_evaluation() {
+        let n = 8;
+        let polys = build_lagrange_polys(n).unwrap();
+        let domain = Radix2EvaluationDomain::new(n).unwrap().elements();
+        let weights = domain_barycentric_weights(n).unwrap();
+        assert_eq!(weights, barycentric_weights(&domain).unwrap());
+
+        let x = Fr::from_u64(123_456);
+        let basis = lagrange_basis_at(&domain, &weights, &x).unwrap();
+        for (poly, value) in polys.iter().zip(&basis) {
+            assert_eq!(poly.evaluate(&x), *value);
+        }
+        assert_eq!(
+            lagrange_basis_at(&domain, &weights, &domain[3]).unwrap()[3],
+            Fr::one()
+        );
+
+        let points: Vec<Fr> = (1..=4).map(Fr::from_u64).collect();
+        let values: Vec<Fr> = points.iter().map(|p| *p * *p + Fr::one()).collect();
+        let weights = barycentric_weights(&points).unwrap();
+        let at = Fr::from_u64(9);
+        assert_eq!(
+            barycentric_evaluate(&points, &weights, &values, &at).unwrap(),
+            at * at + Fr::one()
+        );
+        assert!(barycentric_weights(&[Fr::one(), Fr::one()]).is_err());
+    }
+
+    #[test]
+    fn interp_mostly_zero_respects_constraints() {
+        let points = vec![Fr::one(), Fr::from_u64(3), Fr::from_u64(5)];
+        let poly = interp_mostly_zero(Fr::one(), &points).unwrap();
+
+        assert_eq!(poly.evaluate(&points[0]), Fr::one());
+        for point in points.iter().skip(1) {
+            assert_eq!(poly.evaluate(point), Fr::zero());
+        }
+    }
+
+    #[test]
+    fn interp_mostly_zero_subproduct_tree_matches_incremental() {
+        let points: Vec<Fr> = (1..=3 * FFT_THRESHOLD as u64).map(Fr::from_u64).collect();
+        let fast = interp_mostly_zero(Fr::from_u64(5), &points).unwrap();
+
+        let mut expected = incremental_product(&points[1..]);
+        let scale = DensePolynomial::from_coefficients_vec(expected.clone()).evaluate(&points[0]);
+        let multiplier = Fr::from_u64(5) * scale.invert().unwrap();
+        for coeff in expected.iter_mut() {
+            *coeff *= multiplier;
+        }
+        assert_eq!(fast, DensePolynomial::from_coefficients_vec(expected));
+    }
+
+    #[test]
+    fn subset_coefficients_match_selector_polynomial() {
+        let n = 8;
+        let selector = [true, false, true, true, false, false, true, true];
+        let poly = selector_polynomial(&selector).unwrap();
+        let domain = Radix2EvaluationDomain::new(n).unwrap().elements();
+        assert_eq!(poly.evaluate(&domain[0]), Fr::one());
+
+        let coefficients = subset_lagrange_coefficients(&selector).unwrap();
+        let n_inv = Fr::from_u64(n as u64).invert().unwrap();
+        for ((point, selected), coefficient) in domain.iter().zip(selector).zip(&coefficients) {
+            if selected {
+                assert_eq!(*coefficient, poly.evaluate(point) * n_inv);
+            } else {
+                assert_eq!(poly.evaluate(point), Fr::zero());
+                assert_eq!(*coefficient, Fr::zero());
+            }
+        }
+
+        assert!(subset_lagrange_coefficients(&selector[..5]).is_err());
+        let mut unanchored = selector;
+        unanchored[0] = false;
+        assert!(selector_polynomial(&unanchored).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn cached_lagrange_polys_reuses_basis() {
+        let first = cached_lagrange_polys(16).unwrap();
+        let second = cached_lagrange_polys(16).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(*first, build_lagrange_polys(16).unwrap());
+    }
 }
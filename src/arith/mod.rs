@@ -10,8 +10,10 @@
 //! - **[`field`]**: Scalar field operations (Fr) - addition, multiplication, inversion
 //! - **[`group`]**: Elliptic curve point operations (G1, G2, GT) - addition, scalar multiplication
 //! - **[`pairing`]**: Bilinear pairing operations - `e(G1, G2) -> GT`
+//! - **[`msm`]**: Multi-scalar multiplication providers for G1, G2, and GT
 //! - **[`poly`]**: Polynomial operations - evaluation, interpolation, FFT
 //! - **[`lagrange`]**: Lagrange polynomial helpers - precomputed commitments for efficient key generation
+//! - **[`conformance`]**: Checks every backend must pass, wrapped by [`backend_conformance_tests!`](crate::backend_conformance_tests)
 //!
 //! # Backend Support
 //!
@@ -44,17 +46,24 @@
 //! println!("{:?}", gt);
 //! ```
 
-mod field;
+pub(crate) mod field;
 pub use field::*;
 
-mod group;
+pub(crate) mod group;
 pub use group::*;
 
-mod pairing;
+pub(crate) mod pairing;
 pub use pairing::*;
 
+mod msm;
+pub use msm::*;
+
 mod poly;
 pub use poly::*;
 
 mod lagrange;
 pub use lagrange::*;
+
+pub mod conformance;
+
+pub use subtle::Choice;
@@ -0,0 +1,449 @@
+//! Multi-scalar multiplication (MSM) providers.
+//!
+//! This module defines the [`MsmProvider`] abstraction used by the protocol layer
+//! whenever it needs a large linear combination of group elements. Backends select
+//! their provider through [`PairingBackend::Msm`], which allows accelerated
+//! implementations (e.g., GPU or out-of-core) to be swapped in without touching
+//! protocol code.
+//!
+//! # Supported Groups
+//!
+//! - **G1**: `∑ s_i · P_i` for `P_i ∈ G1`
+//! - **G2**: `∑ s_i · Q_i` for `Q_i ∈ G2`
+//! - **GT**: `∏ T_i^{s_i}` for `T_i ∈ GT` (multi-exponentiation)
+//!
+//! # Streaming
+//!
+//! SRS-scale MSMs (millions of points) do not need to be materialized in memory.
+//! [`MsmProvider::msm_g1_streaming`] and [`MsmProvider::msm_g2_streaming`] pull
+//! `(base, scalar)` pairs from an iterator in fixed-size chunks, run one MSM per
+//! chunk, and merge the partial results, so peak memory is bounded by the chunk size.
+//!
+//! Callers issuing many MSMs in a row (e.g., the keygen loop) can hold an
+//! [`MsmSession`], which keeps the chunk staging buffers alive across calls so
+//! that per-call allocation does not dominate small-to-medium MSMs. Accelerated
+//! providers are expected to attach their device buffers to the same session
+//! lifetime.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tess::{CurvePoint, FieldElement, MsmProvider, PairingBackend, PairingEngine};
+//!
+//! type B = PairingEngine;
+//! let bases = vec![<B as PairingBackend>::G2::generator(); 4];
+//! let scalars: Vec<_> = (1..=4u64)
+//!     .map(<B as PairingBackend>::Scalar::from_u64)
+//!     .collect();
+//!
+//! let combined = <B as PairingBackend>::Msm::msm_g2(&bases, &scalars).unwrap();
+//! let expected = <B as PairingBackend>::G2::generator()
+//!     .mul_scalar(&<B as PairingBackend>::Scalar::from_u64(10));
+//! assert_eq!(combined, expected);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use tracing::instrument;
+
+use crate::{BackendError, CurvePoint, PairingBackend, TargetGroup};
+
+/// Multi-scalar multiplication provider for a pairing backend.
+///
+/// Implementations compute linear combinations over the three groups exposed by
+/// a [`PairingBackend`]. All methods return an error when `bases` and `scalars`
+/// have different lengths, and the identity element for empty inputs.
+pub trait MsmProvider<B: PairingBackend>: Send + Sync + Debug + 'static {
+    /// Computes `∑ scalars[i] · bases[i]` in G1.
+    fn msm_g1(bases: &[B::G1], scalars: &[B::Scalar]) -> Result<B::G1, BackendError>;
+
+    /// Computes `∑ scalars[i] · bases[i]` in G2.
+    fn msm_g2(bases: &[B::G2], scalars: &[B::Scalar]) -> Result<B::G2, BackendError>;
+
+    /// Computes the multi-exponentiation `∏ bases[i]^{scalars[i]}` in GT.
+    fn msm_gt(bases: &[B::Target], scalars: &[B::Scalar]) -> Result<B::Target, BackendError>;
+
+    /// Computes a G1 MSM over `(base, scalar)` pairs consumed in chunks of `chunk_size`.
+    ///
+    /// Only one chunk of bases and scalars is held in memory at a time. Returns an
+    /// error if `chunk_size` is zero.
+    fn msm_g1_streaming<I>(pairs: I, chunk_size: usize) -> Result<B::G1, BackendError>
+    where
+        I: IntoIterator<Item = (B::G1, B::Scalar)>,
+    {
+        let mut bases = Vec::new();
+        let mut scalars = Vec::new();
+        streaming_msm(
+            pairs,
+            chunk_size,
+            (&mut bases, &mut scalars),
+            B::G1::identity(),
+            Self::msm_g1,
+            |a, b| a.add(b),
+        )
+    }
+
+    /// Computes a G2 MSM over `(base, scalar)` pairs consumed in chunks of `chunk_size`.
+    ///
+    /// Only one chunk of bases and scalars is held in memory at a time. Returns an
+    /// error if `chunk_size` is zero.
+    fn msm_g2_streaming<I>(pairs: I, chunk_size: usize) -> Result<B::G2, BackendError>
+    where
+        I: IntoIterator<Item = (B::G2, B::Scalar)>,
+    {
+        let mut bases = Vec::new();
+        let mut scalars = Vec::new();
+        streaming_msm(
+            pairs,
+            chunk_size,
+            (&mut bases, &mut scalars),
+            B::G2::identity(),
+            Self::msm_g2,
+            |a, b| a.add(b),
+        )
+    }
+}
+
+/// Default chunk size for streaming MSMs (65,536 pairs per chunk).
+pub const DEFAULT_MSM_CHUNK_SIZE: usize = 1 << 16;
+
+/// CPU MSM provider delegating to the backend's native group routines.
+///
+/// G1 and G2 use the Pippenger implementations exposed through
+/// [`CurvePoint::multi_scalar_multiplication`]; GT uses
+/// [`TargetGroup::multi_scalar_multiplication`].
+#[derive(Debug)]
+pub struct CpuMsm<B: PairingBackend>(PhantomData<B>);
+
+impl<B: PairingBackend> MsmProvider<B> for CpuMsm<B> {
+    #[instrument(level = "trace", skip_all, fields(len = bases.len()))]
+    fn msm_g1(bases: &[B::G1], scalars: &[B::Scalar]) -> Result<B::G1, BackendError> {
+        ensure_same_length(bases.len(), scalars.len())?;
+        metric!(msm_g1_calls += 1);
+        metric!(msm_g1_terms += bases.len());
+        if bases.is_empty() {
+            return Ok(B::G1::identity());
+        }
+        Ok(B::G1::multi_scalar_multiplication(bases, scalars))
+    }
+
+    #[instrument(level = "trace", skip_all, fields(len = bases.len()))]
+    fn msm_g2(bases: &[B::G2], scalars: &[B::Scalar]) -> Result<B::G2, BackendError> {
+        ensure_same_length(bases.len(), scalars.len())?;
+        metric!(msm_g2_calls += 1);
+        metric!(msm_g2_terms += bases.len());
+        if bases.is_empty() {
+            return Ok(B::G2::identity());
+        }
+        Ok(B::G2::multi_scalar_multiplication(bases, scalars))
+    }
+
+    #[instrument(level = "trace", skip_all, fields(len = bases.len()))]
+    fn msm_gt(bases: &[B::Target], scalars: &[B::Scalar]) -> Result<B::Target, BackendError> {
+        ensure_same_length(bases.len(), scalars.len())?;
+        metric!(msm_gt_calls += 1);
+        metric!(msm_gt_terms += bases.len());
+        if bases.is_empty() {
+            return Ok(B::Target::identity());
+        }
+        Ok(B::Target::multi_scalar_multiplication(bases, scalars))
+    }
+}
+
+/// Reusable staging buffers for a sequence of MSM calls.
+///
+/// Each streaming MSM needs a chunk-sized buffer of bases and scalars. A session
+/// keeps those buffers alive between calls so repeated MSMs against the same
+/// provider (for example, inside the keygen loop) reuse one allocation instead of
+/// paying for a fresh one per call.
+///
+/// # Example
+///
+/// ```rust
+/// use tess::{CurvePoint, FieldElement, MsmSession, PairingBackend, PairingEngine};
+///
+/// type B = PairingEngine;
+/// let g = <B as PairingBackend>::G1::generator();
+/// let mut session = MsmSession::<B>::new(4);
+///
+/// for _ in 0..3 {
+///     let pairs = (1..=8u64).map(|i| (g, <B as PairingBackend>::Scalar::from_u64(i)));
+///     let sum = session.msm_g1_streaming(pairs).unwrap();
+///     assert_eq!(sum, g.mul_scalar(&<B as PairingBackend>::Scalar::from_u64(36)));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MsmSession<B: PairingBackend> {
+    chunk_size: usize,
+    g1_bases: Vec<B::G1>,
+    g2_bases: Vec<B::G2>,
+    scalars: Vec<B::Scalar>,
+}
+
+impl<B: PairingBackend> MsmSession<B> {
+    /// Creates a session whose streaming MSMs process `chunk_size` pairs at a time.
+    ///
+    /// Buffers are allocated lazily on first use and retained until the session is dropped.
+    pub fn new(chunk_size: usize) -> Self {
+        Self {
+            chunk_size,
+            g1_bases: Vec::new(),
+            g2_bases: Vec::new(),
+            scalars: Vec::new(),
+        }
+    }
+
+    /// Returns the configured chunk size.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Streaming G1 MSM through the backend provider, reusing this session's buffers.
+    pub fn msm_g1_streaming<I>(&mut self, pairs: I) -> Result<B::G1, BackendError>
+    where
+        I: IntoIterator<Item = (B::G1, B::Scalar)>,
+    {
+        streaming_msm(
+            pairs,
+            self.chunk_size,
+            (&mut self.g1_bases, &mut self.scalars),
+            B::G1::identity(),
+            B::Msm::msm_g1,
+            |a, b| a.add(b),
+        )
+    }
+
+    /// Streaming G2 MSM through the backend provider, reusing this session's buffers.
+    pub fn msm_g2_streaming<I>(&mut self, pairs: I) -> Result<B::G2, BackendError>
+    where
+        I: IntoIterator<Item = (B::G2, B::Scalar)>,
+    {
+        streaming_msm(
+            pairs,
+            self.chunk_size,
+            (&mut self.g2_bases, &mut self.scalars),
+            B::G2::identity(),
+            B::Msm::msm_g2,
+            |a, b| a.add(b),
+        )
+    }
+
+    /// Releases the staging buffers while keeping the session usable.
+    pub fn shrink(&mut self) {
+        self.g1_bases = Vec::new();
+        self.g2_bases = Vec::new();
+        self.scalars = Vec::new();
+    }
+}
+
+impl<B: PairingBackend> Default for MsmSession<B> {
+    fn default() -> Self {
+        Self::new(DEFAULT_MSM_CHUNK_SIZE)
+    }
+}
+
+/// Drives a chunked MSM: fills the staging buffers from `pairs`, runs `msm` on each
+/// chunk, and folds the partial results with `combine`.
+///
+/// The buffers are cleared before use and left allocated afterwards so callers can
+/// reuse them.
+#[instrument(level = "trace", skip_all, fields(chunk_size))]
+fn streaming_msm<P, S, I, M, C>(
+    pairs: I,
+    chunk_size: usize,
+    buffers: (&mut Vec<P>, &mut Vec<S>),
+    identity: P,
+    msm: M,
+    combine: C,
+) -> Result<P, BackendError>
+where
+    I: IntoIterator<Item = (P, S)>,
+    M: Fn(&[P], &[S]) -> Result<P, BackendError>,
+    C: Fn(&P, &P) -> P,
+{
+    if chunk_size == 0 {
+        return Err(BackendError::Math("msm chunk size must be non-zero"));
+    }
+
+    let (bases, scalars) = buffers;
+    bases.clear();
+    scalars.clear();
+    bases.reserve(chunk_size);
+    scalars.reserve(chunk_size);
+
+    let mut acc = identity;
+    for (base, scalar) in pairs {
+        bases.push(base);
+        scalars.push(scalar);
+        if bases.len() == chunk_size {
+            acc = combine(&acc, &msm(bases, scalars)?);
+            bases.clear();
+            scalars.clear();
+        }
+    }
+    if !bases.is_empty() {
+        acc = combine(&acc, &msm(bases, scalars)?);
+        bases.clear();
+        scalars.clear();
+    }
+    Ok(acc)
+}
+
+fn ensure_same_length(bases: usize, scalars: usize) -> Result<(), BackendError> {
+    if bases != scalars {
+        return Err(BackendError::Math("msm length mismatch"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{FieldElement, PairingEngine};
+
+    type B = PairingEngine;
+    type Scalar = <B as PairingBackend>::Scalar;
+
+    fn random_scalars(rng: &mut StdRng, n: usize) -> Vec<Scalar> {
+        (0..n).map(|_| Scalar::random(rng)).collect()
+    }
+
+    #[test]
+    fn msm_g1_matches_naive() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let g = <B as PairingBackend>::G1::generator();
+        let bases: Vec<_> = random_scalars(&mut rng, 16)
+            .iter()
+            .map(|s| g.mul_scalar(s))
+            .collect();
+        let scalars = random_scalars(&mut rng, 16);
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(<B as PairingBackend>::G1::identity(), |acc, (p, s)| {
+                acc.add(&p.mul_scalar(s))
+            });
+        let got = <B as PairingBackend>::Msm::msm_g1(&bases, &scalars).unwrap();
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn msm_g2_matches_naive() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let h = <B as PairingBackend>::G2::generator();
+        let bases: Vec<_> = random_scalars(&mut rng, 16)
+            .iter()
+            .map(|s| h.mul_scalar(s))
+            .collect();
+        let scalars = random_scalars(&mut rng, 16);
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(<B as PairingBackend>::G2::identity(), |acc, (p, s)| {
+                acc.add(&p.mul_scalar(s))
+            });
+        let got = <B as PairingBackend>::Msm::msm_g2(&bases, &scalars).unwrap();
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn msm_gt_matches_naive() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let e = B::pairing(
+            &<B as PairingBackend>::G1::generator(),
+            &<B as PairingBackend>::G2::generator(),
+        );
+        let bases: Vec<_> = random_scalars(&mut rng, 8)
+            .iter()
+            .map(|s| e.mul_scalar(s))
+            .collect();
+        let scalars = random_scalars(&mut rng, 8);
+
+        let expected = bases
+            .iter()
+            .zip(scalars.iter())
+            .fold(<B as PairingBackend>::Target::identity(), |acc, (t, s)| {
+                acc.combine(&t.mul_scalar(s))
+            });
+        let got = <B as PairingBackend>::Msm::msm_gt(&bases, &scalars).unwrap();
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn msm_rejects_length_mismatch() {
+        let bases = vec![<B as PairingBackend>::G2::generator(); 3];
+        let scalars = vec![Scalar::one(); 2];
+        assert!(<B as PairingBackend>::Msm::msm_g2(&bases, &scalars).is_err());
+    }
+
+    #[test]
+    fn streaming_msm_matches_one_shot() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let g = <B as PairingBackend>::G1::generator();
+        let bases: Vec<_> = random_scalars(&mut rng, 37)
+            .iter()
+            .map(|s| g.mul_scalar(s))
+            .collect();
+        let scalars = random_scalars(&mut rng, 37);
+        let expected = <B as PairingBackend>::Msm::msm_g1(&bases, &scalars).unwrap();
+
+        for chunk_size in [1, 4, 16, 37, 100] {
+            let pairs = bases.iter().copied().zip(scalars.iter().copied());
+            let got = <B as PairingBackend>::Msm::msm_g1_streaming(pairs, chunk_size).unwrap();
+            assert_eq!(got, expected, "chunk size {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn streaming_msm_g2_from_lazy_iterator() {
+        let h = <B as PairingBackend>::G2::generator();
+        let pairs = (1..=10u64).map(|i| (h, Scalar::from_u64(i)));
+        let got = <B as PairingBackend>::Msm::msm_g2_streaming(pairs, 3).unwrap();
+        assert_eq!(got, h.mul_scalar(&Scalar::from_u64(55)));
+    }
+
+    #[test]
+    fn streaming_msm_rejects_zero_chunk() {
+        let pairs = core::iter::empty();
+        assert!(<B as PairingBackend>::Msm::msm_g1_streaming(pairs, 0).is_err());
+    }
+
+    #[test]
+    fn session_reuses_buffers_across_calls() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let h = <B as PairingBackend>::G2::generator();
+        let bases: Vec<_> = random_scalars(&mut rng, 20)
+            .iter()
+            .map(|s| h.mul_scalar(s))
+            .collect();
+        let scalars = random_scalars(&mut rng, 20);
+        let expected = <B as PairingBackend>::Msm::msm_g2(&bases, &scalars).unwrap();
+
+        let mut session = MsmSession::<B>::new(8);
+        let first = session
+            .msm_g2_streaming(bases.iter().copied().zip(scalars.iter().copied()))
+            .unwrap();
+        let capacity = session.g2_bases.capacity();
+        let second = session
+            .msm_g2_streaming(bases.iter().copied().zip(scalars.iter().copied()))
+            .unwrap();
+
+        assert_eq!(first, expected);
+        assert_eq!(second, expected);
+        assert!(capacity >= 8);
+        assert_eq!(session.g2_bases.capacity(), capacity);
+    }
+
+    #[test]
+    fn msm_empty_is_identity() {
+        let got = <B as PairingBackend>::Msm::msm_gt(&[], &[]).unwrap();
+        assert_eq!(got, <B as PairingBackend>::Target::identity());
+    }
+}
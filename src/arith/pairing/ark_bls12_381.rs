@@ -16,10 +16,17 @@
 //! - Zero-knowledge proof verification
 
 use alloc::vec::Vec;
-use ark_bls12_381::Bls12_381;
-use ark_ec::pairing::Pairing;
+use ark_bls12_381::{Bls12_381, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup, pairing::Pairing};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 
-use crate::{BackendError, Fr, G1, G2, Gt, PairingBackend};
+use crate::{
+    BackendError, CpuMsm, CurvePoint, FieldElement, PairingBackend, TargetGroup,
+    arith::{
+        field::ark_bls12_381::Fr,
+        group::ark_bls12_381::{G1, G2, Gt},
+    },
+};
 
 #[derive(Debug, Clone, Copy)]
 /// Pairing engine for the Arkworks BLS12-381 backend.
@@ -30,12 +37,20 @@ impl PairingBackend for PairingEngine {
     type G1 = G1;
     type G2 = G2;
     type Target = Gt;
+    type Msm = CpuMsm<Self>;
+
+    fn security_bits() -> u32 {
+        // BLS12-381: the tower-NFS attacks on GT place it at roughly 117 bits.
+        117
+    }
 
     fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Target {
+        metric!(pairings += 1);
         Bls12_381::pairing(g1.0, g2.0)
     }
 
     fn multi_pairing(g1: &[Self::G1], g2: &[Self::G2]) -> Result<Self::Target, BackendError> {
+        metric!(pairings += g1.len());
         if g1.len() != g2.len() {
             return Err(BackendError::Math("pairing length mismatch"));
         }
@@ -43,4 +58,87 @@ impl PairingBackend for PairingEngine {
         let g2_proj: Vec<_> = g2.iter().map(|p| p.0).collect();
         Ok(Bls12_381::multi_pairing(&g1_proj, &g2_proj))
     }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, BackendError> {
+        Fr::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g1(bytes: &[u8]) -> Result<Self::G1, BackendError> {
+        G1::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g2(bytes: &[u8]) -> Result<Self::G2, BackendError> {
+        G2::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_target(bytes: &[u8]) -> Result<Self::Target, BackendError> {
+        Gt::from_repr(&bytes.to_vec())
+    }
+}
+
+/// Zcash point encodings, which arkworks uses for BLS12-381.
+impl crate::PowersOfTauCurve for PairingEngine {
+    fn g1_len(compressed: bool) -> usize {
+        if compressed { 48 } else { 96 }
+    }
+
+    fn g2_len(compressed: bool) -> usize {
+        if compressed { 96 } else { 192 }
+    }
+
+    fn decode_g1(bytes: &[u8], compressed: bool) -> Result<Self::G1, BackendError> {
+        let compress = if compressed {
+            Compress::Yes
+        } else {
+            Compress::No
+        };
+        G1Affine::deserialize_with_mode(bytes, compress, Validate::Yes)
+            .ok()
+            .filter(|_| bytes.len() == Self::g1_len(compressed))
+            .map(|affine| G1(affine.into_group()))
+            .ok_or(BackendError::Serialization("invalid G1 bytes"))
+    }
+
+    fn decode_g2(bytes: &[u8], compressed: bool) -> Result<Self::G2, BackendError> {
+        let compress = if compressed {
+            Compress::Yes
+        } else {
+            Compress::No
+        };
+        G2Affine::deserialize_with_mode(bytes, compress, Validate::Yes)
+            .ok()
+            .filter(|_| bytes.len() == Self::g2_len(compressed))
+            .map(|affine| G2(affine.into_group()))
+            .ok_or(BackendError::Serialization("invalid G2 bytes"))
+    }
+
+    fn encode_g1(point: &Self::G1, compressed: bool) -> Vec<u8> {
+        let compress = if compressed {
+            Compress::Yes
+        } else {
+            Compress::No
+        };
+        let mut out = Vec::with_capacity(Self::g1_len(compressed));
+        point
+            .0
+            .into_affine()
+            .serialize_with_mode(&mut out, compress)
+            .expect("writing to a Vec cannot fail");
+        out
+    }
+
+    fn encode_g2(point: &Self::G2, compressed: bool) -> Vec<u8> {
+        let compress = if compressed {
+            Compress::Yes
+        } else {
+            Compress::No
+        };
+        let mut out = Vec::with_capacity(Self::g2_len(compressed));
+        point
+            .0
+            .into_affine()
+            .serialize_with_mode(&mut out, compress)
+            .expect("writing to a Vec cannot fail");
+        out
+    }
 }
@@ -16,10 +16,22 @@
 //! - Zero-knowledge proof verification
 
 use alloc::vec::Vec;
-use ark_bn254::Bn254;
-use ark_ec::pairing::Pairing;
+use ark_bn254::{Bn254, Fq, Fq2};
+use ark_ec::{
+    AffineRepr, CurveGroup,
+    pairing::Pairing,
+    short_weierstrass::{Affine, SWCurveConfig},
+};
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
-use crate::{BackendError, Fr, G1, G2, Gt, PairingBackend};
+use crate::{
+    BackendError, CpuMsm, CurvePoint, FieldElement, PairingBackend, TargetGroup,
+    arith::{
+        field::ark_bn254::Fr,
+        group::ark_bn254::{G1, G2, Gt},
+    },
+};
 
 #[derive(Debug, Clone, Copy)]
 /// Pairing engine for the Arkworks BN254 backend.
@@ -30,12 +42,20 @@ impl PairingBackend for PairingEngine {
     type G1 = G1;
     type G2 = G2;
     type Target = Gt;
+    type Msm = CpuMsm<Self>;
+
+    fn security_bits() -> u32 {
+        // BN254: the special tower-NFS attacks on GT reduce it to roughly 100 bits.
+        100
+    }
 
     fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Target {
+        metric!(pairings += 1);
         Bn254::pairing(g1.0, g2.0)
     }
 
     fn multi_pairing(g1: &[Self::G1], g2: &[Self::G2]) -> Result<Self::Target, BackendError> {
+        metric!(pairings += g1.len());
         if g1.len() != g2.len() {
             return Err(BackendError::Math("pairing length mismatch"));
         }
@@ -43,4 +63,142 @@ impl PairingBackend for PairingEngine {
         let g2_proj: Vec<_> = g2.iter().map(|p| p.0).collect();
         Ok(Bn254::multi_pairing(&g1_proj, &g2_proj))
     }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, BackendError> {
+        Fr::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g1(bytes: &[u8]) -> Result<Self::G1, BackendError> {
+        G1::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g2(bytes: &[u8]) -> Result<Self::G2, BackendError> {
+        G2::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_target(bytes: &[u8]) -> Result<Self::Target, BackendError> {
+        Gt::from_repr(&bytes.to_vec())
+    }
+}
+
+/// Bellman point encodings used by the Perpetual Powers of Tau ceremony.
+impl crate::PowersOfTauCurve for PairingEngine {
+    fn g1_len(compressed: bool) -> usize {
+        if compressed { 32 } else { 64 }
+    }
+
+    fn g2_len(compressed: bool) -> usize {
+        if compressed { 64 } else { 128 }
+    }
+
+    fn decode_g1(bytes: &[u8], compressed: bool) -> Result<Self::G1, BackendError> {
+        if bytes.len() != Self::g1_len(compressed) {
+            return Err(BackendError::Serialization("invalid G1 bytes"));
+        }
+        decode_point::<ark_bn254::g1::Config>(bytes, compressed, fq_from_be)
+            .map(|affine| G1(affine.into_group()))
+            .ok_or(BackendError::Serialization("invalid G1 bytes"))
+    }
+
+    fn decode_g2(bytes: &[u8], compressed: bool) -> Result<Self::G2, BackendError> {
+        if bytes.len() != Self::g2_len(compressed) {
+            return Err(BackendError::Serialization("invalid G2 bytes"));
+        }
+        decode_point::<ark_bn254::g2::Config>(bytes, compressed, |b| {
+            Some(Fq2::new(fq_from_be(&b[32..])?, fq_from_be(&b[..32])?))
+        })
+        .map(|affine| G2(affine.into_group()))
+        .ok_or(BackendError::Serialization("invalid G2 bytes"))
+    }
+
+    fn encode_g1(point: &Self::G1, compressed: bool) -> Vec<u8> {
+        encode_point(&point.0.into_affine(), compressed, |x, out| {
+            out.extend(fq_to_be(x))
+        })
+    }
+
+    fn encode_g2(point: &Self::G2, compressed: bool) -> Vec<u8> {
+        encode_point(&point.0.into_affine(), compressed, |x, out| {
+            out.extend(fq_to_be(&x.c1));
+            out.extend(fq_to_be(&x.c0));
+        })
+    }
+}
+
+const INFINITY_FLAG: u8 = 1 << 6;
+const LARGEST_Y_FLAG: u8 = 1 << 7;
+
+/// Decodes `x || y`, or `x` with the sign of `y` in the flags when compressed.
+fn decode_point<P: SWCurveConfig>(
+    bytes: &[u8],
+    compressed: bool,
+    field: impl Fn(&[u8]) -> Option<P::BaseField>,
+) -> Option<Affine<P>> {
+    let flags = bytes[0] & (INFINITY_FLAG | LARGEST_Y_FLAG);
+    let mut raw = bytes.to_vec();
+    raw[0] &= !flags;
+    if flags & INFINITY_FLAG != 0 {
+        let canonical = (compressed || flags == INFINITY_FLAG) && raw.iter().all(|&b| b == 0);
+        return canonical.then(Affine::identity);
+    }
+    let point = if compressed {
+        let x = field(&raw)?;
+        let (smaller, larger) = Affine::<P>::get_ys_from_x_unchecked(x)?;
+        let y = if flags & LARGEST_Y_FLAG != 0 {
+            larger
+        } else {
+            smaller
+        };
+        Affine::new_unchecked(x, y)
+    } else {
+        if flags != 0 {
+            return None;
+        }
+        let half = raw.len() / 2;
+        let point = Affine::new_unchecked(field(&raw[..half])?, field(&raw[half..])?);
+        if !point.is_on_curve() {
+            return None;
+        }
+        point
+    };
+    point
+        .is_in_correct_subgroup_assuming_on_curve()
+        .then_some(point)
+}
+
+fn encode_point<P: SWCurveConfig>(
+    point: &Affine<P>,
+    compressed: bool,
+    field: impl Fn(&P::BaseField, &mut Vec<u8>),
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    let Some((x, y)) = point.xy() else {
+        let len = if compressed { 1 } else { 2 } * 32 * P::BaseField::extension_degree() as usize;
+        out.resize(len, 0);
+        out[0] = INFINITY_FLAG;
+        return out;
+    };
+    field(&x, &mut out);
+    if compressed {
+        if y > -y {
+            out[0] |= LARGEST_Y_FLAG;
+        }
+    } else {
+        field(&y, &mut out);
+    }
+    out
+}
+
+fn fq_from_be(bytes: &[u8]) -> Option<Fq> {
+    let mut le = bytes.to_vec();
+    le.reverse();
+    Fq::deserialize_compressed(le.as_slice()).ok()
+}
+
+fn fq_to_be(x: &Fq) -> Vec<u8> {
+    let mut out = Vec::with_capacity(32);
+    x.serialize_compressed(&mut out)
+        .expect("writing to a Vec cannot fail");
+    out.reverse();
+    out
 }
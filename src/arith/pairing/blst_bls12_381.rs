@@ -8,7 +8,13 @@ use group::prime::PrimeCurveAffine;
 use pairing::MillerLoopResult;
 use pairing::MultiMillerLoop;
 
-use crate::{BackendError, Fr, G1, G2, Gt, PairingBackend};
+use crate::{
+    BackendError, CpuMsm, PairingBackend,
+    arith::{
+        field::blst_bls12_381::Fr,
+        group::blst_bls12_381::{G1, G2, Gt, decode_g1_batch, decode_g2_batch},
+    },
+};
 
 /// Pairing engine implementation for the blst BLS12-381 backend.
 #[derive(Debug, Clone, Copy)]
@@ -24,12 +30,20 @@ impl PairingBackend for PairingEngine {
     type G1 = G1;
     type G2 = G2;
     type Target = Gt;
+    type Msm = CpuMsm<Self>;
+
+    fn security_bits() -> u32 {
+        // BLS12-381: the tower-NFS attacks on GT place it at roughly 117 bits.
+        117
+    }
 
     fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Target {
+        metric!(pairings += 1);
         blstrs::pairing(&g1.to_affine(), &g2.to_affine())
     }
 
     fn multi_pairing(g1: &[Self::G1], g2: &[Self::G2]) -> Result<Self::Target, BackendError> {
+        metric!(pairings += g1.len());
         if g1.len() != g2.len() {
             return Err(BackendError::Math("pairing length mismatch"));
         }
@@ -43,4 +57,95 @@ impl PairingBackend for PairingEngine {
         let result = Bls12::multi_miller_loop(&terms).final_exponentiation();
         Ok(result)
     }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, BackendError> {
+        <Fr as crate::FieldElement>::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g1(bytes: &[u8]) -> Result<Self::G1, BackendError> {
+        <G1 as crate::CurvePoint<Fr>>::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g2(bytes: &[u8]) -> Result<Self::G2, BackendError> {
+        <G2 as crate::CurvePoint<Fr>>::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g1_batch<T: AsRef<[u8]> + Sync>(
+        encoded: &[T],
+    ) -> Result<Vec<Self::G1>, BackendError> {
+        decode_g1_batch(encoded)
+    }
+
+    fn deserialize_g2_batch<T: AsRef<[u8]> + Sync>(
+        encoded: &[T],
+    ) -> Result<Vec<Self::G2>, BackendError> {
+        decode_g2_batch(encoded)
+    }
+
+    fn deserialize_target(bytes: &[u8]) -> Result<Self::Target, BackendError> {
+        <Gt as crate::TargetGroup>::from_repr(&bytes.to_vec())
+    }
+}
+
+/// Zcash point encodings, which blstrs implements natively.
+impl crate::PowersOfTauCurve for PairingEngine {
+    fn g1_len(compressed: bool) -> usize {
+        if compressed { 48 } else { 96 }
+    }
+
+    fn g2_len(compressed: bool) -> usize {
+        if compressed { 96 } else { 192 }
+    }
+
+    fn decode_g1(bytes: &[u8], compressed: bool) -> Result<Self::G1, BackendError> {
+        let affine = if compressed {
+            bytes
+                .try_into()
+                .ok()
+                .and_then(|raw| Option::<G1Affine>::from(G1Affine::from_compressed(raw)))
+        } else {
+            bytes
+                .try_into()
+                .ok()
+                .and_then(|raw| Option::<G1Affine>::from(G1Affine::from_uncompressed(raw)))
+        };
+        affine
+            .map(G1::from)
+            .ok_or(BackendError::Serialization("invalid G1 bytes"))
+    }
+
+    fn decode_g2(bytes: &[u8], compressed: bool) -> Result<Self::G2, BackendError> {
+        let affine = if compressed {
+            bytes
+                .try_into()
+                .ok()
+                .and_then(|raw| Option::<G2Affine>::from(G2Affine::from_compressed(raw)))
+        } else {
+            bytes
+                .try_into()
+                .ok()
+                .and_then(|raw| Option::<G2Affine>::from(G2Affine::from_uncompressed(raw)))
+        };
+        affine
+            .map(G2::from)
+            .ok_or(BackendError::Serialization("invalid G2 bytes"))
+    }
+
+    fn encode_g1(point: &Self::G1, compressed: bool) -> Vec<u8> {
+        let affine = point.to_affine();
+        if compressed {
+            affine.to_compressed().to_vec()
+        } else {
+            affine.to_uncompressed().to_vec()
+        }
+    }
+
+    fn encode_g2(point: &Self::G2, compressed: bool) -> Vec<u8> {
+        let affine = point.to_affine();
+        if compressed {
+            affine.to_compressed().to_vec()
+        } else {
+            affine.to_uncompressed().to_vec()
+        }
+    }
 }
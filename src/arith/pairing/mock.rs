@@ -0,0 +1,290 @@
+//! Insecure mock backend for fast, deterministic protocol tests.
+//!
+//! [`MockBackend`] uses the crate root scalar field [`Fr`](crate::Fr), which
+//! is the 64-bit [`MockFr`](crate::MockFr) when `mock` is the only backend
+//! feature, and represents every group element by its discrete logarithm: `a·G1` is stored as `a`, and the pairing
+//! `e(a·G1, b·G2)` is the target element `ab`. All group operations reduce to
+//! a handful of field multiplications, so full keygen, encryption and
+//! decryption runs finish in a fraction of the time a real curve needs, while
+//! still exercising every equation the protocol checks.
+//!
+//! Discrete logarithms are trivial in this representation. Never use it
+//! outside tests.
+//!
+//! Enable it with the `mock` feature:
+//!
+//! ```rust
+//! use rand::{SeedableRng, rngs::StdRng};
+//! use tess::{MockBackend, SilentThresholdScheme, ThresholdEncryption};
+//!
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let scheme = SilentThresholdScheme::<MockBackend>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let ct = scheme
+//!     .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"plumbing")
+//!     .unwrap();
+//!
+//! let partials: Vec<_> = keys.secret_keys[..3]
+//!     .iter()
+//!     .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+//!     .collect();
+//! let selector = [true, true, true, false];
+//! let result = scheme
+//!     .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+//!     .unwrap();
+//! assert_eq!(result.plaintext.unwrap(), b"plumbing");
+//! ```
+
+use alloc::vec::Vec;
+
+use subtle::Choice;
+
+use crate::{BackendError, CpuMsm, CurvePoint, FieldElement, Fr, PairingBackend, TargetGroup};
+
+/// Insecure [`PairingBackend`] over discrete-log representations of the groups.
+///
+/// See the [module documentation](self) for the representation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MockBackend;
+
+/// Mock group element `log·G`, tagged with its group so G1 and G2 encodings
+/// cannot be confused.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MockPoint<const GROUP: u8>(Fr);
+
+/// Mock G1 element.
+pub type MockG1 = MockPoint<1>;
+/// Mock G2 element.
+pub type MockG2 = MockPoint<2>;
+/// Mock target group element.
+pub type MockGt = MockPoint<3>;
+
+impl<const GROUP: u8> MockPoint<GROUP> {
+    /// Returns the discrete logarithm of this element to the generator.
+    pub fn log(&self) -> Fr {
+        self.0
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(33);
+        bytes.push(GROUP);
+        bytes.extend_from_slice(self.0.to_repr().as_ref());
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, BackendError> {
+        match bytes.split_first() {
+            Some((&tag, scalar)) if tag == GROUP => Fr::from_repr(&scalar.to_vec()).map(Self),
+            _ => Err(BackendError::Serialization("invalid mock group element")),
+        }
+    }
+}
+
+// Only G1 and G2 are curve groups; the target group implements
+// `TargetGroup` alone so its methods are never ambiguous.
+macro_rules! impl_curve_point {
+    ($group:literal) => {
+        impl CurvePoint<Fr> for MockPoint<$group> {
+            type Affine = Self;
+            type Repr = Vec<u8>;
+
+            fn identity() -> Self {
+                Self(Fr::zero())
+            }
+
+            fn generator() -> Self {
+                Self(Fr::one())
+            }
+
+            fn is_identity(&self) -> bool {
+                self.0 == Fr::zero()
+            }
+
+            fn from_affine(affine: &Self::Affine) -> Self {
+                *affine
+            }
+
+            fn to_affine(&self) -> Self::Affine {
+                *self
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                Self(self.0 + other.0)
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                Self(self.0 - other.0)
+            }
+
+            fn negate(&self) -> Self {
+                Self(-self.0)
+            }
+
+            fn mul_scalar(&self, scalar: &Fr) -> Self {
+                metric!(scalar_muls += 1);
+                Self(self.0 * scalar)
+            }
+
+            fn batch_normalize(points: &[Self]) -> Vec<Self::Affine> {
+                points.to_vec()
+            }
+
+            fn multi_scalar_multiplication(points: &[Self], scalars: &[Fr]) -> Self {
+                points
+                    .iter()
+                    .zip(scalars)
+                    .fold(Self::identity(), |acc, (point, scalar)| {
+                        acc.add(&Self(point.0 * scalar))
+                    })
+            }
+
+            fn to_repr(&self) -> Self::Repr {
+                self.encode()
+            }
+
+            fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
+                Self::decode(bytes)
+            }
+
+            fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+                let mut hasher = blake3::Hasher::new();
+                hasher.update(&[$group]);
+                hasher.update(&(dst.len() as u64).to_le_bytes());
+                hasher.update(dst);
+                hasher.update(msg);
+                let mut limbs = [0u8; 64];
+                hasher.finalize_xof().fill(&mut limbs);
+
+                // Reduce 512 uniform bits into the field, one 64-bit limb at a time.
+                let shift = Fr::from_u64(1 << 32) * Fr::from_u64(1 << 32);
+                let log = limbs.chunks_exact(8).fold(Fr::zero(), |acc, limb| {
+                    let limb = u64::from_le_bytes(limb.try_into().expect("8-byte limb"));
+                    acc * shift + Fr::from_u64(limb)
+                });
+                Self(log)
+            }
+
+            fn clear_cofactor(&self) -> Self {
+                *self
+            }
+
+            fn is_torsion_free(&self) -> bool {
+                true
+            }
+        }
+    };
+}
+
+impl_curve_point!(1);
+impl_curve_point!(2);
+
+impl TargetGroup for MockGt {
+    type Scalar = Fr;
+    type Repr = Vec<u8>;
+
+    fn identity() -> Self {
+        Self(Fr::zero())
+    }
+
+    fn generator() -> Self {
+        Self(Fr::one())
+    }
+
+    fn mul_scalar(&self, scalar: &Fr) -> Self {
+        metric!(scalar_muls += 1);
+        Self(self.0 * scalar)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    fn multi_scalar_multiplication(elements: &[Self], scalars: &[Fr]) -> Self {
+        elements.iter().zip(scalars).fold(
+            <Self as TargetGroup>::identity(),
+            |acc, (element, scalar)| acc.combine(&Self(element.0 * scalar)),
+        )
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.encode()
+    }
+
+    fn from_repr(bytes: &Self::Repr) -> Result<Self, BackendError> {
+        Self::decode(bytes)
+    }
+
+    fn ct_eq(&self, other: &Self) -> Choice {
+        FieldElement::ct_eq(&self.0, &other.0)
+    }
+}
+
+impl PairingBackend for MockBackend {
+    type Scalar = Fr;
+    type G1 = MockG1;
+    type G2 = MockG2;
+    type Target = MockGt;
+    type Msm = CpuMsm<Self>;
+
+    fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Target {
+        metric!(pairings += 1);
+        MockPoint(g1.0 * g2.0)
+    }
+
+    fn multi_pairing(g1: &[Self::G1], g2: &[Self::G2]) -> Result<Self::Target, BackendError> {
+        metric!(pairings += g1.len());
+        if g1.len() != g2.len() {
+            return Err(BackendError::Math("pairing length mismatch"));
+        }
+        Ok(MockPoint(
+            g1.iter()
+                .zip(g2)
+                .fold(Fr::zero(), |acc, (a, b)| acc + a.0 * b.0),
+        ))
+    }
+
+    fn security_bits() -> u32 {
+        // Discrete logarithms are stored in the clear.
+        0
+    }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, BackendError> {
+        Fr::from_repr(&bytes.to_vec())
+    }
+
+    fn deserialize_g1(bytes: &[u8]) -> Result<Self::G1, BackendError> {
+        MockG1::decode(bytes)
+    }
+
+    fn deserialize_g2(bytes: &[u8]) -> Result<Self::G2, BackendError> {
+        MockG2::decode(bytes)
+    }
+
+    fn deserialize_target(bytes: &[u8]) -> Result<Self::Target, BackendError> {
+        MockGt::decode(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    crate::backend_conformance_tests!(crate::MockBackend);
+
+    #[test]
+    fn group_encodings_are_not_interchangeable() {
+        let mut rng = StdRng::seed_from_u64(1444);
+        let g1 = MockG1::random(&mut rng);
+        assert!(MockBackend::deserialize_g2(&MockBackend::serialize_g1(&g1)).is_err());
+        assert_eq!(
+            MockBackend::deserialize_g1(&MockBackend::serialize_g1(&g1)).unwrap(),
+            g1
+        );
+        assert_ne!(
+            MockG1::hash_to_curve(b"msg", b"dst"),
+            MockG1::hash_to_curve(b"msg", b"other")
+        );
+    }
+}
@@ -1,21 +1,24 @@
+use alloc::vec::Vec;
 use core::fmt::Debug;
 
 #[cfg(feature = "blst")]
-mod blst_bls12_381;
-#[cfg(feature = "blst")]
-pub use blst_bls12_381::PairingEngine;
+pub(crate) mod blst_bls12_381;
 
 #[cfg(feature = "ark_bls12381")]
-mod ark_bls12_381;
-#[cfg(feature = "ark_bls12381")]
-pub use ark_bls12_381::PairingEngine;
+pub(crate) mod ark_bls12_381;
 
 #[cfg(feature = "ark_bn254")]
-mod ark_bn254;
-#[cfg(feature = "ark_bn254")]
-pub use ark_bn254::PairingEngine;
+pub(crate) mod ark_bn254;
+
+mod swapped;
+pub use swapped::{Swapped, SwappedMsm};
 
-use crate::{BackendError, CurvePoint, FieldElement, TargetGroup};
+#[cfg(any(test, feature = "mock"))]
+mod mock;
+#[cfg(any(test, feature = "mock"))]
+pub use mock::{MockBackend, MockG1, MockG2, MockGt, MockPoint};
+
+use crate::{BackendError, CurvePoint, FieldElement, MsmProvider, TargetGroup};
 
 /// Main backend trait that ties together all cryptographic operations.
 ///
@@ -53,6 +56,8 @@ pub trait PairingBackend: Clone + Send + Sync + Debug + Sized + 'static {
     type G2: CurvePoint<Self::Scalar>;
     /// Pairing target group (GT).
     type Target: TargetGroup<Scalar = Self::Scalar> + PartialEq;
+    /// Multi-scalar multiplication provider for G1, G2, and GT.
+    type Msm: MsmProvider<Self>;
 
     /// Computes the bilinear pairing: `e(g1, g2) -> GT`.
     ///
@@ -64,4 +69,168 @@ pub trait PairingBackend: Clone + Send + Sync + Debug + Sized + 'static {
     /// This is more efficient than computing individual pairings and multiplying.
     /// Returns an error if the input arrays have different lengths.
     fn multi_pairing(g1: &[Self::G1], g2: &[Self::G2]) -> Result<Self::Target, BackendError>;
+
+    /// Estimated security level of the pairing in bits.
+    ///
+    /// This is the cost of the best known attack on the weaker of the curve
+    /// discrete logarithm and the target-group discrete logarithm, not the
+    /// nominal size of the scalar field.
+    fn security_bits() -> u32;
+
+    /// Encodes a scalar in the backend's canonical byte form.
+    fn serialize_scalar(scalar: &Self::Scalar) -> Vec<u8> {
+        scalar.to_repr().as_ref().to_vec()
+    }
+
+    /// Encodes a G1 point in the backend's canonical (compressed) byte form.
+    fn serialize_g1(point: &Self::G1) -> Vec<u8> {
+        point.to_repr().as_ref().to_vec()
+    }
+
+    /// Encodes a G2 point in the backend's canonical (compressed) byte form.
+    fn serialize_g2(point: &Self::G2) -> Vec<u8> {
+        point.to_repr().as_ref().to_vec()
+    }
+
+    /// Encodes a target group element in the backend's canonical byte form.
+    fn serialize_target(element: &Self::Target) -> Vec<u8> {
+        element.to_repr().as_ref().to_vec()
+    }
+
+    /// Decodes a scalar produced by [`serialize_scalar`](Self::serialize_scalar).
+    ///
+    /// Returns `BackendError::Serialization` (or `BackendError::Library` with
+    /// the curve library's error) for bytes of the wrong length or outside
+    /// the field.
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, BackendError>;
+
+    /// Decodes a G1 point produced by [`serialize_g1`](Self::serialize_g1).
+    ///
+    /// Returns `BackendError::Serialization` (or `BackendError::Library` with
+    /// the curve library's error) for bytes that do not encode a point in the
+    /// prime-order subgroup.
+    fn deserialize_g1(bytes: &[u8]) -> Result<Self::G1, BackendError>;
+
+    /// Decodes a G2 point produced by [`serialize_g2`](Self::serialize_g2).
+    ///
+    /// Returns `BackendError::Serialization` (or `BackendError::Library` with
+    /// the curve library's error) for bytes that do not encode a point in the
+    /// prime-order subgroup.
+    fn deserialize_g2(bytes: &[u8]) -> Result<Self::G2, BackendError>;
+
+    /// Decodes many G1 points with the same checks as
+    /// [`deserialize_g1`](Self::deserialize_g1), failing on the first invalid
+    /// encoding.
+    ///
+    /// Backends override this where validating a batch is cheaper than
+    /// validating each point on its own; the default decodes one at a time.
+    fn deserialize_g1_batch<T: AsRef<[u8]> + Sync>(
+        encoded: &[T],
+    ) -> Result<Vec<Self::G1>, BackendError> {
+        encoded
+            .iter()
+            .map(|bytes| Self::deserialize_g1(bytes.as_ref()))
+            .collect()
+    }
+
+    /// Decodes many G2 points with the same checks as
+    /// [`deserialize_g2`](Self::deserialize_g2), failing on the first invalid
+    /// encoding.
+    fn deserialize_g2_batch<T: AsRef<[u8]> + Sync>(
+        encoded: &[T],
+    ) -> Result<Vec<Self::G2>, BackendError> {
+        encoded
+            .iter()
+            .map(|bytes| Self::deserialize_g2(bytes.as_ref()))
+            .collect()
+    }
+
+    /// Decodes a target group element produced by
+    /// [`serialize_target`](Self::serialize_target).
+    fn deserialize_target(bytes: &[u8]) -> Result<Self::Target, BackendError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fr, PairingEngine};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    crate::backend_conformance_tests!(crate::PairingEngine);
+    crate::backend_conformance_tests!(swapped_conformance, crate::Swapped<crate::PairingEngine>);
+
+    #[test]
+    fn backend_serialization_roundtrip() {
+        type B = PairingEngine;
+        let mut rng = StdRng::seed_from_u64(1424);
+        let scalar = Fr::random(&mut rng);
+        let g1 = <B as PairingBackend>::G1::mul_generator(&scalar);
+        let g2 = <B as PairingBackend>::G2::mul_generator(&scalar);
+        let target = B::pairing(&g1, &g2);
+
+        let decoded = B::deserialize_scalar(&B::serialize_scalar(&scalar)).unwrap();
+        assert_eq!(decoded, scalar);
+        let decoded = B::deserialize_g1(&B::serialize_g1(&g1)).unwrap();
+        assert!(decoded.sub(&g1).is_identity());
+        let decoded = B::deserialize_g2(&B::serialize_g2(&g2)).unwrap();
+        assert!(decoded.sub(&g2).is_identity());
+        let decoded = B::deserialize_target(&B::serialize_target(&target)).unwrap();
+        assert!(decoded == target);
+
+        let bytes = B::serialize_g1(&g1);
+        assert!(B::deserialize_g1(&bytes[1..]).is_err());
+        assert!(B::deserialize_scalar(&[]).is_err());
+    }
+
+    #[test]
+    fn batch_deserialization_checks_every_point() {
+        type B = PairingEngine;
+        let mut rng = StdRng::seed_from_u64(1477);
+        let g1: Vec<_> = (0..9)
+            .map(|_| <B as PairingBackend>::G1::random(&mut rng))
+            .chain([<B as PairingBackend>::G1::identity()])
+            .collect();
+        let g2: Vec<_> = (0..5)
+            .map(|_| <B as PairingBackend>::G2::random(&mut rng))
+            .collect();
+        let g1_bytes: Vec<_> = g1.iter().map(B::serialize_g1).collect();
+        let g2_bytes: Vec<_> = g2.iter().map(B::serialize_g2).collect();
+
+        let decoded = B::deserialize_g1_batch(&g1_bytes).unwrap();
+        assert!(decoded.iter().zip(&g1).all(|(a, b)| a.sub(b).is_identity()));
+        let decoded = B::deserialize_g2_batch(&g2_bytes).unwrap();
+        assert!(decoded.iter().zip(&g2).all(|(a, b)| a.sub(b).is_identity()));
+        let decoded = Swapped::<B>::deserialize_g1_batch(&g2_bytes).unwrap();
+        assert!(decoded.iter().zip(&g2).all(|(a, b)| a.sub(b).is_identity()));
+        assert!(B::deserialize_g1_batch::<Vec<u8>>(&[]).unwrap().is_empty());
+
+        let mut truncated = g1_bytes.clone();
+        truncated[4].pop();
+        assert!(B::deserialize_g1_batch(&truncated).is_err());
+        #[cfg(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))]
+        {
+            let mut g2_bytes = g2_bytes;
+            g2_bytes[3] = crate::adversarial::off_subgroup_g2().unwrap();
+            assert!(B::deserialize_g2_batch(&g2_bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn backend_random_sampling() {
+        type B = PairingEngine;
+        let mut rng = StdRng::seed_from_u64(1425);
+        let a = <B as PairingBackend>::G1::random(&mut rng);
+        let b = <B as PairingBackend>::G1::random(&mut rng);
+        assert!(!a.is_identity());
+        assert!(!a.sub(&b).is_identity());
+        assert!(!<B as PairingBackend>::G2::random(&mut rng).is_identity());
+
+        let gt = <B as PairingBackend>::Target::random(&mut rng);
+        assert!(gt != <B as PairingBackend>::Target::identity());
+        let expected = B::pairing(
+            &<B as PairingBackend>::G1::generator(),
+            &<B as PairingBackend>::G2::generator(),
+        );
+        assert!(<B as PairingBackend>::Target::generator() == expected);
+    }
 }
@@ -0,0 +1,96 @@
+//! Backend adapter with the roles of G1 and G2 exchanged.
+//!
+//! The threshold scheme keeps public keys and KZG powers in G1 and sends
+//! partial decryptions in G2. Running it over [`Swapped<B>`] puts public keys
+//! in `B::G2` and partial responses in `B::G1` instead, which halves the size
+//! of every partial decryption on BLS12-381 (48 instead of 96 bytes compressed)
+//! at the cost of larger keys and ciphertext headers. This pays off when
+//! thousands of shares cross the network per decryption.
+//!
+//! The two variants are not wire compatible; keys, ciphertexts and partial
+//! decryptions must all be produced under the same backend type.
+//!
+//! ```rust
+//! use tess::{PairingEngine, SilentThresholdScheme, Swapped};
+//!
+//! let scheme = SilentThresholdScheme::<Swapped<PairingEngine>>::new();
+//! ```
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{BackendError, MsmProvider, PairingBackend};
+
+/// [`PairingBackend`] adapter that exchanges G1 and G2 of `B`.
+///
+/// `pairing(a, b)` evaluates `B::pairing(b, a)`, so the adapter is a valid
+/// pairing backend with the same target group and scalar field.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Swapped<B>(PhantomData<B>);
+
+impl<B: PairingBackend> PairingBackend for Swapped<B> {
+    type Scalar = B::Scalar;
+    type G1 = B::G2;
+    type G2 = B::G1;
+    type Target = B::Target;
+    type Msm = SwappedMsm<B>;
+
+    fn pairing(g1: &Self::G1, g2: &Self::G2) -> Self::Target {
+        B::pairing(g2, g1)
+    }
+
+    fn multi_pairing(g1: &[Self::G1], g2: &[Self::G2]) -> Result<Self::Target, BackendError> {
+        B::multi_pairing(g2, g1)
+    }
+
+    fn security_bits() -> u32 {
+        B::security_bits()
+    }
+
+    fn deserialize_scalar(bytes: &[u8]) -> Result<Self::Scalar, BackendError> {
+        B::deserialize_scalar(bytes)
+    }
+
+    fn deserialize_g1(bytes: &[u8]) -> Result<Self::G1, BackendError> {
+        B::deserialize_g2(bytes)
+    }
+
+    fn deserialize_g2(bytes: &[u8]) -> Result<Self::G2, BackendError> {
+        B::deserialize_g1(bytes)
+    }
+
+    fn deserialize_g1_batch<T: AsRef<[u8]> + Sync>(
+        encoded: &[T],
+    ) -> Result<Vec<Self::G1>, BackendError> {
+        B::deserialize_g2_batch(encoded)
+    }
+
+    fn deserialize_g2_batch<T: AsRef<[u8]> + Sync>(
+        encoded: &[T],
+    ) -> Result<Vec<Self::G2>, BackendError> {
+        B::deserialize_g1_batch(encoded)
+    }
+
+    fn deserialize_target(bytes: &[u8]) -> Result<Self::Target, BackendError> {
+        B::deserialize_target(bytes)
+    }
+}
+
+/// MSM provider for [`Swapped<B>`], forwarding to `B::Msm` with the groups
+/// exchanged.
+#[derive(Debug)]
+pub struct SwappedMsm<B>(PhantomData<B>);
+
+impl<B: PairingBackend> MsmProvider<Swapped<B>> for SwappedMsm<B> {
+    fn msm_g1(bases: &[B::G2], scalars: &[B::Scalar]) -> Result<B::G2, BackendError> {
+        B::Msm::msm_g2(bases, scalars)
+    }
+
+    fn msm_g2(bases: &[B::G1], scalars: &[B::Scalar]) -> Result<B::G1, BackendError> {
+        B::Msm::msm_g1(bases, scalars)
+    }
+
+    fn msm_gt(bases: &[B::Target], scalars: &[B::Scalar]) -> Result<B::Target, BackendError> {
+        B::Msm::msm_gt(bases, scalars)
+    }
+}
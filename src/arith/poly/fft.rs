@@ -0,0 +1,200 @@
+//! Quasi-linear polynomial arithmetic built on the radix-2 FFT.
+//!
+//! These complement the schoolbook routines on [`DensePolynomialGeneric`]:
+//!
+//! - [`DensePolynomialGeneric::from_roots`] builds `∏ (x - r_i)` with a product tree
+//! - [`DensePolynomialGeneric::div_rem`] divides via Newton inversion of the reversed divisor
+//! - [`DensePolynomialGeneric::evaluate_over_domain`] evaluates on every point of a domain
+//!
+//! Small inputs fall back to the quadratic routines, which are faster below
+//! [`FFT_THRESHOLD`] coefficients.
+
+use alloc::vec::Vec;
+
+use super::{DensePolynomialGeneric, EvaluationDomain, FieldArithmetic};
+
+/// Operand length below which schoolbook arithmetic is used instead of FFTs.
+pub const FFT_THRESHOLD: usize = 64;
+
+impl<F: FieldArithmetic> DensePolynomialGeneric<F> {
+    /// Builds the monic polynomial `∏ (x - r_i)` vanishing on `roots`.
+    ///
+    /// Runs in `O(n log² n)` by multiplying balanced halves with FFTs.
+    pub fn from_roots(roots: &[F]) -> Self {
+        if roots.len() <= FFT_THRESHOLD {
+            return roots.iter().fold(
+                DensePolynomialGeneric::from_coefficients_vec(vec![F::one()]),
+                |poly, root| poly.mul_by_linear(*root),
+            );
+        }
+        let (left, right) = roots.split_at(roots.len() / 2);
+        Self::from_roots(left).fft_mul(&Self::from_roots(right))
+    }
+
+    /// Divides by `divisor`, returning `(quotient, remainder)`.
+    ///
+    /// Large divisions compute the reversed quotient as `rev(self) · rev(divisor)⁻¹`
+    /// modulo `x^{deg q + 1}`, so the cost is a few FFT multiplications rather
+    /// than `O(deg q · deg divisor)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &DensePolynomialGeneric<F>) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "division by zero polynomial");
+
+        if self.degree() < divisor.degree() || self.is_zero() {
+            return (DensePolynomialGeneric::zero(), self.clone());
+        }
+
+        let quotient_len = self.degree() - divisor.degree() + 1;
+        if quotient_len.min(divisor.coeffs.len()) <= FFT_THRESHOLD {
+            return self.long_division(divisor);
+        }
+
+        let mut rev_divisor = divisor.coeffs.clone();
+        rev_divisor.reverse();
+        let inverse = inverse_mod_xk(&rev_divisor, quotient_len);
+
+        let mut rev_self = self.coeffs.clone();
+        rev_self.reverse();
+        rev_self.truncate(quotient_len);
+        let mut quotient = DensePolynomialGeneric { coeffs: rev_self }
+            .fft_mul(&DensePolynomialGeneric { coeffs: inverse })
+            .coeffs;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+
+        let quotient = DensePolynomialGeneric::from_coefficients_vec(quotient);
+        let remainder = self.clone() - quotient.fft_mul(divisor);
+        (quotient, remainder)
+    }
+
+    /// Evaluates the polynomial at every element of `domain`.
+    ///
+    /// Unlike [`EvaluationDomain::fft`], polynomials of degree at least
+    /// `domain.size()` are first reduced modulo `x^n - 1`, which leaves their
+    /// values on the domain unchanged.
+    pub fn evaluate_over_domain<D: EvaluationDomain<F>>(&self, domain: &D) -> Vec<F> {
+        let size = domain.size();
+        let mut folded = vec![F::zero(); size];
+        for (i, coeff) in self.coeffs.iter().enumerate() {
+            folded[i % size] = folded[i % size] + *coeff;
+        }
+        domain.fft(&folded)
+    }
+
+    /// Schoolbook long division.
+    fn long_division(&self, divisor: &DensePolynomialGeneric<F>) -> (Self, Self) {
+        let divisor_degree = divisor.degree();
+        let mut remainder = self.coeffs.clone();
+        let mut quotient = vec![F::zero(); self.degree() - divisor_degree + 1];
+
+        let divisor_leading_inv = divisor.coeffs[divisor_degree].invert().unwrap();
+
+        for i in (0..quotient.len()).rev() {
+            let coeff = remainder[i + divisor_degree] * divisor_leading_inv;
+            quotient[i] = coeff;
+
+            for (j, &div_coeff) in divisor.coeffs.iter().enumerate() {
+                remainder[i + j] = remainder[i + j] - coeff * div_coeff;
+            }
+        }
+
+        remainder.truncate(divisor_degree.max(1));
+        (
+            DensePolynomialGeneric::from_coefficients_vec(quotient),
+            DensePolynomialGeneric::from_coefficients_vec(remainder),
+        )
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coeffs.iter().all(|coeff| *coeff == F::zero())
+    }
+}
+
+/// Power-series inverse of `f` modulo `x^k` by Newton iteration.
+///
+/// Each step doubles the precision with `g ← g · (2 - f·g)`. Requires
+/// `f[0] ≠ 0`, which holds for the reversal of any nonzero-leading divisor.
+fn inverse_mod_xk<F: FieldArithmetic>(f: &[F], k: usize) -> Vec<F> {
+    let mut inverse = vec![f[0].invert().expect("constant term must be invertible")];
+    let mut precision = 1;
+    while precision < k {
+        precision = (precision * 2).min(k);
+
+        let truncated = DensePolynomialGeneric {
+            coeffs: f[..precision.min(f.len())].to_vec(),
+        };
+        let current = DensePolynomialGeneric {
+            coeffs: inverse.clone(),
+        };
+        let mut correction = truncated.fft_mul(&current).coeffs;
+        correction.resize(precision, F::zero());
+        for coeff in correction.iter_mut() {
+            *coeff = F::zero() - *coeff;
+        }
+        correction[0] = correction[0] + F::from_u64(2);
+
+        inverse = current
+            .fft_mul(&DensePolynomialGeneric { coeffs: correction })
+            .coeffs;
+        inverse.resize(precision, F::zero());
+    }
+    inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{DensePolynomial, FieldElement, Fr, Radix2EvaluationDomain};
+
+    fn random_poly(rng: &mut StdRng, len: usize) -> DensePolynomial {
+        DensePolynomial::from_coefficients_vec((0..len).map(|_| Fr::random(rng)).collect())
+    }
+
+    #[test]
+    fn from_roots_vanishes_on_roots() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let roots: Vec<Fr> = (0..200).map(|_| Fr::random(&mut rng)).collect();
+        let poly = DensePolynomial::from_roots(&roots);
+        assert_eq!(poly.degree(), roots.len());
+        assert_eq!(*poly.coeffs.last().unwrap(), Fr::one());
+        for root in &roots {
+            assert_eq!(poly.evaluate(root), Fr::zero());
+        }
+    }
+
+    #[test]
+    fn div_rem_matches_long_division() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for (num_len, den_len) in [(500, 130), (300, 300), (90, 3), (10, 20)] {
+            let numerator = random_poly(&mut rng, num_len);
+            let divisor = random_poly(&mut rng, den_len);
+
+            let (quotient, remainder) = numerator.div_rem(&divisor);
+            assert!(remainder.degree() < divisor.degree() || divisor.degree() == 0);
+            assert_eq!(quotient.fft_mul(&divisor) + remainder.clone(), numerator);
+
+            if num_len >= den_len {
+                assert_eq!(
+                    (quotient.clone(), remainder),
+                    numerator.long_division(&divisor)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_over_domain_folds_high_degree() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let poly = random_poly(&mut rng, 37);
+        let domain = Radix2EvaluationDomain::new(16).unwrap();
+        let evals = poly.evaluate_over_domain(&domain);
+        for (point, eval) in domain.elements().iter().zip(&evals) {
+            assert_eq!(poly.evaluate(point), *eval);
+        }
+    }
+}
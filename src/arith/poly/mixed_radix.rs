@@ -0,0 +1,251 @@
+//! Evaluation domains of size `2^a · 3^b`.
+//!
+//! A committee whose size is not a power of two would otherwise have to be
+//! padded to the next one, roughly doubling setup work in the worst case.
+//! Multiplicative subgroups of every order dividing `r - 1` exist, so sizes
+//! such as `3 · 2^k` are served directly when the scalar field supports them:
+//! `3 | r - 1` for BLS12-381 and `9 | r - 1` for BN254.
+//!
+//! For power-of-two sizes [`MixedRadixEvaluationDomainGeneric`] uses the same
+//! generator and in-place FFT as [`Radix2EvaluationDomainGeneric`], so both
+//! domain types agree element for element.
+
+use alloc::vec::Vec;
+
+use super::{EvaluationDomain, FieldArithmetic, Radix2EvaluationDomainGeneric};
+
+/// Number of small integers tried when searching for a primitive `3^b`-th root.
+const ROOT_SEARCH_LIMIT: u64 = 1 << 10;
+
+/// Returns a primitive `n`-th root of unity for `n = 2^a · 3^b`.
+///
+/// Returns `None` if `n` has other prime factors or the field has no subgroup
+/// of order `n`. Power-of-two sizes use [`FieldElement::two_adicity_generator`],
+/// which panics beyond the field's two-adicity.
+///
+/// [`FieldElement::two_adicity_generator`]: crate::FieldElement::two_adicity_generator
+pub fn root_of_unity<F: FieldArithmetic>(n: usize) -> Option<F> {
+    let (twos, threes) = smooth_factors(n)?;
+    let two_part = F::two_adicity_generator(1 << twos);
+    if threes == 0 {
+        return Some(two_part);
+    }
+
+    // r - 1 is the canonical value of -1.
+    let order = le_bytes_to_limbs(&(F::zero() - F::one()).to_bytes_le());
+    let cofactor = divide_limbs(order, 3u64.pow(threes))?;
+    let three_part = (2..ROOT_SEARCH_LIMIT)
+        .map(|c| F::from_u64(c).pow(&cofactor))
+        .find(|x| {
+            // x has order dividing 3^b; it is primitive iff x^(3^(b-1)) ≠ 1.
+            let mut y = *x;
+            for _ in 1..threes {
+                y = y * y * y;
+            }
+            y != F::one()
+        })?;
+    Some(two_part * three_part)
+}
+
+/// Multiplicative subgroup of order `2^a · 3^b` with a mixed-radix FFT.
+#[derive(Clone, Debug)]
+pub struct MixedRadixEvaluationDomainGeneric<F: FieldArithmetic> {
+    /// Domain size (`2^a · 3^b`)
+    pub size: usize,
+    group_gen: F,
+    group_gen_inv: F,
+}
+
+impl<F: FieldArithmetic> MixedRadixEvaluationDomainGeneric<F> {
+    /// Create a domain of the specified size, if the field supports it.
+    pub fn new(size: usize) -> Option<Self> {
+        if size.is_power_of_two() {
+            let radix2 = Radix2EvaluationDomainGeneric::<F>::new(size)?;
+            return Some(Self {
+                size,
+                group_gen: radix2.group_gen,
+                group_gen_inv: radix2.group_gen_inv,
+            });
+        }
+        let group_gen = root_of_unity::<F>(size)?;
+        Some(Self {
+            size,
+            group_gen,
+            group_gen_inv: group_gen.invert()?,
+        })
+    }
+
+    /// Domain elements `ω^0, ω^1, …, ω^{n-1}`.
+    pub fn elements(&self) -> Vec<F> {
+        let mut current = F::one();
+        let mut elements = Vec::with_capacity(self.size);
+        for _ in 0..self.size {
+            elements.push(current);
+            current = current * self.group_gen;
+        }
+        elements
+    }
+
+    /// Forward FFT: coefficient -> evaluation.
+    pub fn fft(&self, coeffs: &[F]) -> Vec<F> {
+        let mut a = coeffs.to_vec();
+        a.resize(self.size, F::zero());
+        match self.as_radix2() {
+            Some(radix2) => radix2.fft(&a),
+            None => mixed_radix_fft(&a, self.group_gen),
+        }
+    }
+
+    /// Inverse FFT: evaluation -> coefficient.
+    pub fn ifft(&self, evals: &[F]) -> Vec<F> {
+        let mut a = evals.to_vec();
+        a.resize(self.size, F::zero());
+        if let Some(radix2) = self.as_radix2() {
+            return radix2.ifft(&a);
+        }
+        let n_inv = F::from_u64(self.size as u64).invert().unwrap();
+        mixed_radix_fft(&a, self.group_gen_inv)
+            .into_iter()
+            .map(|coeff| coeff * n_inv)
+            .collect()
+    }
+
+    fn as_radix2(&self) -> Option<Radix2EvaluationDomainGeneric<F>> {
+        self.size
+            .is_power_of_two()
+            .then_some(Radix2EvaluationDomainGeneric {
+                size: self.size,
+                group_gen: self.group_gen,
+                group_gen_inv: self.group_gen_inv,
+            })
+    }
+}
+
+impl<F: FieldArithmetic> EvaluationDomain<F> for MixedRadixEvaluationDomainGeneric<F> {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn elements(&self) -> Vec<F> {
+        MixedRadixEvaluationDomainGeneric::elements(self)
+    }
+
+    fn fft(&self, coeffs: &[F]) -> Vec<F> {
+        MixedRadixEvaluationDomainGeneric::fft(self, coeffs)
+    }
+
+    fn ifft(&self, evals: &[F]) -> Vec<F> {
+        MixedRadixEvaluationDomainGeneric::ifft(self, evals)
+    }
+}
+
+/// Recursive decimation-in-time FFT over radices 2 and 3.
+fn mixed_radix_fft<F: FieldArithmetic>(a: &[F], omega: F) -> Vec<F> {
+    let n = a.len();
+    if n == 1 {
+        return a.to_vec();
+    }
+    let radix = if n.is_multiple_of(2) { 2 } else { 3 };
+    let m = n / radix;
+
+    let mut omega_radix = F::one();
+    for _ in 0..radix {
+        omega_radix = omega_radix * omega;
+    }
+    let subs: Vec<Vec<F>> = (0..radix)
+        .map(|r| {
+            let strided: Vec<F> = a.iter().skip(r).step_by(radix).copied().collect();
+            mixed_radix_fft(&strided, omega_radix)
+        })
+        .collect();
+
+    // X[k] = Σ_r ω^{rk} · S_r[k mod m]
+    let mut out = Vec::with_capacity(n);
+    let mut omega_k = F::one();
+    for k in 0..n {
+        let mut twiddle = F::one();
+        let mut acc = F::zero();
+        for sub in &subs {
+            acc = acc + sub[k % m] * twiddle;
+            twiddle = twiddle * omega_k;
+        }
+        out.push(acc);
+        omega_k = omega_k * omega;
+    }
+    out
+}
+
+/// Splits `n` as `2^a · 3^b`, or `None` for other sizes.
+fn smooth_factors(mut n: usize) -> Option<(u32, u32)> {
+    if n == 0 {
+        return None;
+    }
+    let twos = n.trailing_zeros();
+    n >>= twos;
+    let mut threes = 0;
+    while n.is_multiple_of(3) {
+        n /= 3;
+        threes += 1;
+    }
+    (n == 1).then_some((twos, threes))
+}
+
+fn le_bytes_to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    limbs
+}
+
+/// Exact division of a little-endian 256-bit integer by `divisor`.
+fn divide_limbs(limbs: [u64; 4], divisor: u64) -> Option<[u64; 4]> {
+    let mut quotient = [0u64; 4];
+    let mut remainder = 0u128;
+    for i in (0..4).rev() {
+        let current = (remainder << 64) | limbs[i] as u128;
+        quotient[i] = (current / divisor as u128) as u64;
+        remainder = current % divisor as u128;
+    }
+    (remainder == 0).then_some(quotient)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DensePolynomial, FieldElement, Fr, MixedRadixEvaluationDomain, Polynomial};
+
+    #[test]
+    fn mixed_radix_fft_matches_direct_evaluation() {
+        let domain = MixedRadixEvaluationDomain::new(12).unwrap();
+        let elements = domain.elements();
+        assert_eq!(
+            elements[11] * domain.group_gen,
+            Fr::one(),
+            "generator must have order 12"
+        );
+        assert_ne!(elements[6], Fr::one());
+        assert_ne!(elements[4], Fr::one());
+
+        let poly = DensePolynomial::from_coefficients_vec((1..=12).map(Fr::from_u64).collect());
+        let evals = domain.fft(poly.coeffs());
+        for (point, eval) in elements.iter().zip(&evals) {
+            assert_eq!(poly.evaluate(point), *eval);
+        }
+        assert_eq!(domain.ifft(&evals), poly.coeffs);
+    }
+
+    #[test]
+    fn power_of_two_sizes_match_radix2() {
+        let mixed = MixedRadixEvaluationDomain::new(8).unwrap();
+        let radix2 = crate::Radix2EvaluationDomain::new(8).unwrap();
+        assert_eq!(mixed.elements(), radix2.elements());
+    }
+
+    #[test]
+    fn unsupported_sizes_are_rejected() {
+        assert!(MixedRadixEvaluationDomain::new(0).is_none());
+        assert!(MixedRadixEvaluationDomain::new(10).is_none());
+        // Beyond the 3-adicity of the scalar field.
+        assert!(MixedRadixEvaluationDomain::new(27).is_none());
+    }
+}
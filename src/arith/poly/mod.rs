@@ -11,6 +11,13 @@
 //! - **[`EvaluationDomain`]**: FFT operations over multiplicative subgroups
 //! - **[`DensePolynomial`]**: Dense coefficient polynomials with helpers like FFT multiplication
 //! - **[`Radix2EvaluationDomain`]**: FFT-friendly evaluation domain implementation
+//! - FFT-based division, product-of-roots construction, and domain evaluation on
+//!   [`DensePolynomial`] for large operands
+//!
+//! All types are generic over [`FieldArithmetic`] and are instantiated with the
+//! active backend's scalar field, so the blst backend runs interpolation, FFTs,
+//! and division directly on `blstrs::Scalar` without converting through
+//! arkworks types.
 //!
 //! # Polynomial Representation
 //!
@@ -25,7 +32,8 @@
 //! - Lagrange basis generation
 //! - Fast polynomial multiplication
 //!
-//! Domain size must be a power of two for FFT.
+//! [`Radix2EvaluationDomain`] sizes must be powers of two; [`MixedRadixEvaluationDomain`]
+//! additionally accepts sizes of the form `2^a · 3^b` where the field allows it.
 //!
 //! # Example
 //!
@@ -50,6 +58,12 @@ use core::ops::{Add, Div, Mul, Sub};
 
 use crate::{FieldElement, Fr};
 
+mod fft;
+pub use fft::FFT_THRESHOLD;
+
+mod mixed_radix;
+pub use mixed_radix::{MixedRadixEvaluationDomainGeneric, root_of_unity};
+
 /// Polynomial interface for univariate polynomials.
 pub trait Polynomial<F: FieldElement>: Clone + Send + Sync + Debug + 'static {
     /// Returns the degree of this polynomial.
@@ -66,6 +80,15 @@ pub trait Polynomial<F: FieldElement>: Clone + Send + Sync + Debug + 'static {
 
     /// Constructs a polynomial from its coefficients (ascending order).
     fn from_coefficients_vec(coeffs: Vec<F>) -> Self;
+
+    /// Builds the monic vanishing polynomial `∏ (x - r_i)` over `roots`.
+    fn from_roots(roots: &[F]) -> Self;
+
+    /// Divides by `(x - point)`, returning the quotient and the remainder `p(point)`.
+    fn divide_by_linear(&self, point: F) -> (Self, F);
+
+    /// Evaluates the polynomial at every element of `domain`, in domain order.
+    fn evaluate_over_domain<D: EvaluationDomain<F>>(&self, domain: &D) -> Vec<F>;
 }
 
 /// FFT evaluation domain for polynomial operations.
@@ -192,8 +215,13 @@ impl<F: FieldArithmetic> DensePolynomialGeneric<F> {
     }
 
     /// Synthetic division by (x - root).
+    ///
+    /// A constant polynomial yields a zero quotient and itself as the remainder.
     pub fn divide_by_linear(&self, root: F) -> (DensePolynomialGeneric<F>, F) {
-        assert!(self.coeffs.len() > 1, "cannot divide constant polynomial");
+        if self.coeffs.len() <= 1 {
+            let constant = self.coeffs.first().copied().unwrap_or_else(F::zero);
+            return (DensePolynomialGeneric::zero(), constant);
+        }
 
         let n = self.coeffs.len() - 1;
         let mut quotient = vec![F::zero(); n];
@@ -265,6 +293,28 @@ impl<F: FieldArithmetic> DensePolynomialGeneric<F> {
 
         (quot_poly, rem_poly)
     }
+
+    /// Lagrange interpolation through `(points[i], values[i])`.
+    ///
+    /// Returns `None` if the slices differ in length or the points are not
+    /// distinct. Runs in `O(n²)` field operations plus one product tree.
+    pub fn interpolate(points: &[F], values: &[F]) -> Option<DensePolynomialGeneric<F>> {
+        if points.len() != values.len() {
+            return None;
+        }
+
+        let vanishing = DensePolynomialGeneric::from_roots(points);
+        let mut result = vec![F::zero(); points.len().max(1)];
+        for (point, value) in points.iter().zip(values) {
+            let (numerator, _) = vanishing.divide_by_linear(*point);
+            let scale = *value * numerator.evaluate(point).invert()?;
+            for (acc, coeff) in result.iter_mut().zip(&numerator.coeffs) {
+                *acc = *acc + *coeff * scale;
+            }
+        }
+
+        Some(DensePolynomialGeneric::from_coefficients_vec(result))
+    }
 }
 
 impl<F: FieldArithmetic> Polynomial<F> for DensePolynomialGeneric<F> {
@@ -288,6 +338,18 @@ impl<F: FieldArithmetic> Polynomial<F> for DensePolynomialGeneric<F> {
     fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
         DensePolynomialGeneric::from_coefficients_vec(coeffs)
     }
+
+    fn from_roots(roots: &[F]) -> Self {
+        DensePolynomialGeneric::from_roots(roots)
+    }
+
+    fn divide_by_linear(&self, point: F) -> (Self, F) {
+        DensePolynomialGeneric::divide_by_linear(self, point)
+    }
+
+    fn evaluate_over_domain<D: EvaluationDomain<F>>(&self, domain: &D) -> Vec<F> {
+        DensePolynomialGeneric::evaluate_over_domain(self, domain)
+    }
 }
 
 impl<F: FieldArithmetic> Add for DensePolynomialGeneric<F> {
@@ -347,31 +409,7 @@ impl<F: FieldArithmetic> Div for &DensePolynomialGeneric<F> {
     type Output = DensePolynomialGeneric<F>;
 
     fn div(self, divisor: &DensePolynomialGeneric<F>) -> DensePolynomialGeneric<F> {
-        assert!(
-            !(divisor.coeffs.len() == 1 && divisor.coeffs[0] == F::zero()),
-            "division by zero polynomial"
-        );
-
-        if self.degree() < divisor.degree() {
-            return DensePolynomialGeneric::zero();
-        }
-
-        let mut remainder = self.clone();
-        let mut quotient = vec![F::zero(); self.degree() - divisor.degree() + 1];
-
-        let divisor_leading_inv = divisor.coeffs.last().unwrap().invert().unwrap();
-
-        for i in (0..=self.degree() - divisor.degree()).rev() {
-            let pos = i + divisor.degree();
-            let coeff = remainder.coeffs[pos] * divisor_leading_inv;
-            quotient[i] = coeff;
-
-            for (j, &div_coeff) in divisor.coeffs.iter().enumerate() {
-                remainder.coeffs[i + j] = remainder.coeffs[i + j] - coeff * div_coeff;
-            }
-        }
-
-        DensePolynomialGeneric::from_coefficients_vec(quotient)
+        self.div_rem(divisor).0
     }
 }
 
@@ -533,6 +571,8 @@ pub type DensePolynomial = DensePolynomialGeneric<Fr>;
 pub type Radix2EvaluationDomain = Radix2EvaluationDomainGeneric<Fr>;
 /// Polynomial evaluations for the active scalar field.
 pub type Evaluations = EvaluationsGeneric<Fr>;
+/// Mixed-radix (`2^a · 3^b`) evaluation domain for the active scalar field.
+pub type MixedRadixEvaluationDomain = MixedRadixEvaluationDomainGeneric<Fr>;
 
 #[cfg(test)]
 mod tests {
@@ -557,6 +597,36 @@ mod tests {
         assert_eq!(sum.coeffs, vec![Fr::from_u64(4), Fr::from_u64(6)]);
     }
 
+    #[test]
+    fn trait_operations_match_inherent() {
+        fn check<P: Polynomial<Fr>>(poly: &P, point: Fr, domain: &Radix2EvaluationDomain) {
+            let (quotient, remainder) = poly.divide_by_linear(point);
+            assert_eq!(remainder, poly.evaluate(&point));
+            let x = Fr::from_u64(11);
+            assert_eq!(
+                quotient.evaluate(&x) * (x - point) + remainder,
+                poly.evaluate(&x)
+            );
+
+            let vanishing = P::from_roots(&domain.elements());
+            for element in domain.elements() {
+                assert_eq!(vanishing.evaluate(&element), Fr::zero());
+            }
+
+            let evals = poly.evaluate_over_domain(domain);
+            for (element, eval) in domain.elements().iter().zip(&evals) {
+                assert_eq!(poly.evaluate(element), *eval);
+            }
+        }
+
+        let poly = DensePolynomial::from_coefficients_vec((1..=6).map(Fr::from_u64).collect());
+        check(
+            &poly,
+            Fr::from_u64(3),
+            &Radix2EvaluationDomain::new(8).unwrap(),
+        );
+    }
+
     #[test]
     fn polynomial_fft_domain() {
         let domain = Radix2EvaluationDomain::new(4).unwrap();
@@ -598,6 +668,29 @@ mod tests {
         assert_eq!(naive, optimized);
     }
 
+    #[test]
+    fn interpolate_recovers_polynomial() {
+        let poly = DensePolynomial::from_coefficients_vec((1..=5).map(Fr::from_u64).collect());
+        let points: Vec<Fr> = (10..15).map(Fr::from_u64).collect();
+        let values: Vec<Fr> = points.iter().map(|p| poly.evaluate(p)).collect();
+        assert_eq!(DensePolynomial::interpolate(&points, &values), Some(poly));
+
+        let duplicate = [Fr::one(), Fr::one()];
+        assert_eq!(
+            DensePolynomial::interpolate(&duplicate, &[Fr::one(), Fr::zero()]),
+            None
+        );
+        assert_eq!(DensePolynomial::interpolate(&points, &values[1..]), None);
+    }
+
+    #[test]
+    fn divide_by_linear_of_constant_returns_constant() {
+        let constant = DensePolynomial::from_coefficients_vec(vec![Fr::from_u64(9)]);
+        let (quotient, remainder) = constant.divide_by_linear(Fr::from_u64(4));
+        assert_eq!(quotient, DensePolynomial::zero());
+        assert_eq!(remainder, Fr::from_u64(9));
+    }
+
     #[test]
     fn divide_by_linear_test() {
         let poly = DensePolynomial::from_coefficients_vec(vec![-Fr::one(), Fr::zero(), Fr::one()]);
@@ -0,0 +1,127 @@
+//! Per-backend namespaces for the enabled pairing backends.
+//!
+//! Every backend feature adds a module here exposing its scalar field `Fr`,
+//! its groups `G1`, `G2` and `Gt`, and its [`PairingBackend`] implementation
+//! `PairingEngine`, so a binary can enable several backends and name each one
+//! unambiguously:
+//!
+//! | Feature | Module |
+//! |---------|--------|
+//! | `blst` | `backends::blst` |
+//! | `ark_bls12381` | `backends::ark_bls12` |
+//! | `ark_bn254` | `backends::ark_bn254` |
+//! | `mock` only | `backends::mock` |
+//!
+//! The crate root re-exports one of them as [`crate::Fr`],
+//! [`crate::PairingEngine`] and friends, which the protocol types are built
+//! on. When several features are enabled the first in the table above wins.
+//! The other backends remain usable through the arithmetic traits here.
+//!
+//! `backends::mock` exists only when `mock` is the sole backend feature. It
+//! pairs [`MockBackend`](crate::MockBackend) with the 64-bit
+//! [`MockFr`](crate::MockFr) field so the crate builds and tests without any
+//! curve library. It offers no security.
+//!
+//! [`PairingBackend`]: crate::PairingBackend
+
+/// BLS12-381 through `blstrs`.
+#[cfg(feature = "blst")]
+pub mod blst {
+    pub use crate::arith::field::blst_bls12_381::Fr;
+    pub use crate::arith::group::blst_bls12_381::{G1, G2, Gt};
+    pub use crate::arith::pairing::blst_bls12_381::PairingEngine;
+}
+
+/// BLS12-381 through arkworks.
+#[cfg(feature = "ark_bls12381")]
+pub mod ark_bls12 {
+    pub use crate::arith::field::ark_bls12_381::Fr;
+    pub use crate::arith::group::ark_bls12_381::{G1, G2, Gt};
+    pub use crate::arith::pairing::ark_bls12_381::PairingEngine;
+}
+
+/// BN254 through arkworks.
+#[cfg(feature = "ark_bn254")]
+pub mod ark_bn254 {
+    pub use crate::arith::field::ark_bn254::Fr;
+    pub use crate::arith::group::ark_bn254::{G1, G2, Gt};
+    pub use crate::arith::pairing::ark_bn254::PairingEngine;
+}
+
+/// Insecure mock pairing over a 64-bit field, for builds without a real backend.
+#[cfg(all(
+    feature = "mock",
+    not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))
+))]
+pub mod mock {
+    pub use crate::arith::{
+        MockBackend as PairingEngine, MockFr as Fr, MockG1 as G1, MockG2 as G2, MockGt as Gt,
+    };
+}
+
+#[cfg(all(feature = "ark_bls12381", not(feature = "blst")))]
+pub(crate) use self::ark_bls12 as primary;
+#[cfg(all(
+    feature = "ark_bn254",
+    not(any(feature = "blst", feature = "ark_bls12381"))
+))]
+pub(crate) use self::ark_bn254 as primary;
+/// Backend re-exported at the crate root.
+#[cfg(feature = "blst")]
+pub(crate) use self::blst as primary;
+#[cfg(all(
+    feature = "mock",
+    not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))
+))]
+pub(crate) use self::mock as primary;
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{CurvePoint, FieldElement, PairingBackend};
+
+    fn check_pairing<B: PairingBackend>(rng: &mut StdRng) {
+        let a = B::Scalar::random(rng);
+        let lhs = B::pairing(&B::G1::mul_generator(&a), &B::G2::generator());
+        let rhs = B::pairing(&B::G1::generator(), &B::G2::mul_generator(&a));
+        assert_eq!(lhs, rhs);
+        assert_ne!(lhs, B::pairing(&B::G1::generator(), &B::G2::generator()));
+    }
+
+    #[test]
+    fn enabled_backends_are_independently_usable() {
+        let mut rng = StdRng::seed_from_u64(1465);
+        #[cfg(feature = "blst")]
+        check_pairing::<super::blst::PairingEngine>(&mut rng);
+        #[cfg(feature = "ark_bls12381")]
+        check_pairing::<super::ark_bls12::PairingEngine>(&mut rng);
+        #[cfg(feature = "ark_bn254")]
+        check_pairing::<super::ark_bn254::PairingEngine>(&mut rng);
+        #[cfg(all(
+            feature = "mock",
+            not(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))
+        ))]
+        check_pairing::<super::mock::PairingEngine>(&mut rng);
+    }
+
+    #[cfg(all(feature = "blst", feature = "ark_bls12381"))]
+    #[test]
+    fn bls12_381_backends_share_point_encodings() {
+        use crate::FieldElement;
+        type Blst = super::blst::PairingEngine;
+        type Ark = super::ark_bls12::PairingEngine;
+
+        let mut rng = StdRng::seed_from_u64(1484);
+        for _ in 0..4 {
+            let scalar = <Blst as PairingBackend>::Scalar::random(&mut rng);
+            let g1 = <Blst as PairingBackend>::G1::mul_generator(&scalar);
+            let g2 = <Blst as PairingBackend>::G2::mul_generator(&scalar);
+
+            let ark_g1 = Ark::deserialize_g1(&Blst::serialize_g1(&g1)).unwrap();
+            let ark_g2 = Ark::deserialize_g2(&Blst::serialize_g2(&g2)).unwrap();
+            assert_eq!(Ark::serialize_g1(&ark_g1), Blst::serialize_g1(&g1));
+            assert_eq!(Ark::serialize_g2(&ark_g2), Blst::serialize_g2(&g2));
+        }
+    }
+}
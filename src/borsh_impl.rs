@@ -0,0 +1,467 @@
+//! Borsh serialization for the types on-chain programs store.
+//!
+//! Solana programs, including Anchor accounts, persist state with Borsh, so
+//! a program and an off-chain TESS client agree on an account's bytes when
+//! both go through these implementations. Every field uses Borsh's own rules:
+//! integers are little-endian, `bool` is one byte, and `Vec<u8>` carries a
+//! `u32` length prefix. Group elements are written as raw fixed-size arrays
+//! in the backend's compressed encoding, without a length prefix.
+//!
+//! The `borsh_len` function of each type documents its field layout and
+//! gives the exact encoded size for sizing accounts; Anchor adds its 8-byte
+//! discriminator on top. On BLS12-381, `FR`, `G1`, `G2` and `GT` in those
+//! layouts are 32, 48, 96 and 576 bytes. Decoding checks every group element
+//! and scalar as the backend's `deserialize_*` functions do, and rejects any
+//! length prefix above the [`DeserializeLimits`] in effect before allocating
+//! for it.
+
+use alloc::vec::Vec;
+
+use borsh::{
+    BorshDeserialize, BorshSerialize,
+    io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write},
+};
+use smallvec::SmallVec;
+
+use crate::{
+    COMMITTEE_FINGERPRINT_LEN, Ciphertext, CiphertextProof, DEK_LEN, DeserializeLimits,
+    FieldElement, KdfId, MAX_LABEL_LEN, PROOF_G1_LEN, PROOF_G2_LEN, PairingBackend,
+    PartialDecryption, PublicKey, SenderSignature,
+    tess::{g1_size, g2_size, target_size},
+};
+
+impl<B: PairingBackend> PartialDecryption<B> {
+    /// Size in bytes of the Borsh encoding, which is laid out as:
+    ///
+    /// | Field | Borsh type |
+    /// |-------|------------|
+    /// | `participant_id` | `u32` |
+    /// | `response` | `[u8; G2]` |
+    pub fn borsh_len() -> usize {
+        4 + g2_size::<B>()
+    }
+}
+
+impl<B: PairingBackend> PublicKey<B> {
+    /// Size in bytes of the Borsh encoding of a key for a committee of
+    /// `parties`, which is laid out as:
+    ///
+    /// | Field | Borsh type |
+    /// |-------|------------|
+    /// | `participant_id` | `u32` |
+    /// | `bls_key`, `lagrange_li`, `lagrange_li_minus0`, `lagrange_li_x` | `[u8; G1]` each |
+    /// | `lagrange_li_lj_z` | `u32` count, then `[u8; G1]` each |
+    pub fn borsh_len(parties: usize) -> usize {
+        4 + 4 + (4 + parties) * g1_size::<B>()
+    }
+}
+
+impl<B: PairingBackend> Ciphertext<B> {
+    /// Size in bytes of this ciphertext's Borsh encoding, which is laid out
+    /// as:
+    ///
+    /// | Field | Borsh type |
+    /// |-------|------------|
+    /// | `gamma_g2` | `[u8; G2]` |
+    /// | `proof_g1` | [`PROOF_G1_LEN`] × `[u8; G1]` |
+    /// | `proof_g2` | [`PROOF_G2_LEN`] × `[u8; G2]` |
+    /// | `shared_secret` | `[u8; GT]` |
+    /// | `threshold` | `u32` |
+    /// | `epoch` | `u64` |
+    /// | `committee` | `[u8; 32]` |
+    /// | `label`, `payload` | `Vec<u8>` |
+    /// | `compressed`, `padded`, `deterministic` | `bool` |
+    /// | `kdf` | `u8`, [`KdfId::to_byte`] |
+    /// | `validity_proof` | `Option`: `challenge` as `[u8; FR]`, then `responses` as a `u32` count and `[u8; FR]` each |
+    /// | `sender_signature` | `Option`: `sender` as `[u8; G1]`, then `signature` as `[u8; G2]` |
+    /// | `broadcast_keys` | `Vec<[u8; 32]>` |
+    pub fn borsh_len(&self) -> usize {
+        let scalar = scalar_size::<B>();
+        (1 + PROOF_G2_LEN) * g2_size::<B>()
+            + PROOF_G1_LEN * g1_size::<B>()
+            + target_size::<B>()
+            + 4
+            + 8
+            + COMMITTEE_FINGERPRINT_LEN
+            + 4
+            + self.label.len()
+            + 4
+            + self.payload.len()
+            + 4
+            + 1
+            + self
+                .validity_proof
+                .as_ref()
+                .map_or(0, |proof| scalar + 4 + proof.responses.len() * scalar)
+            + 1
+            + self
+                .sender_signature
+                .as_ref()
+                .map_or(0, |_| g1_size::<B>() + g2_size::<B>())
+            + 4
+            + self.broadcast_keys.len() * DEK_LEN
+    }
+}
+
+impl<B: PairingBackend> BorshSerialize for PartialDecryption<B> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        write_u32(writer, self.participant_id)?;
+        writer.write_all(&B::serialize_g2(&self.response))
+    }
+}
+
+impl<B: PairingBackend> BorshDeserialize for PartialDecryption<B> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        Ok(Self {
+            participant_id: u32::deserialize_reader(reader)? as usize,
+            response: read_g2::<B, R>(reader)?,
+        })
+    }
+}
+
+impl<B: PairingBackend> BorshSerialize for PublicKey<B> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        write_u32(writer, self.participant_id)?;
+        for point in [
+            &self.bls_key,
+            &self.lagrange_li,
+            &self.lagrange_li_minus0,
+            &self.lagrange_li_x,
+        ] {
+            writer.write_all(&B::serialize_g1(point))?;
+        }
+        write_u32(writer, self.lagrange_li_lj_z.len())?;
+        for point in self.lagrange_li_lj_z.iter() {
+            writer.write_all(&B::serialize_g1(point))?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: PairingBackend> BorshDeserialize for PublicKey<B> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        let participant_id = u32::deserialize_reader(reader)? as usize;
+        let bls_key = read_g1::<B, R>(reader)?;
+        let lagrange_li = read_g1::<B, R>(reader)?;
+        let lagrange_li_minus0 = read_g1::<B, R>(reader)?;
+        let lagrange_li_x = read_g1::<B, R>(reader)?;
+        let count = read_len(
+            reader,
+            DeserializeLimits::in_effect().max_public_key_hints,
+            "public key hints exceed limit",
+        )?;
+        // Grow with the input rather than trusting the count up front.
+        let mut encoded = Vec::new();
+        for _ in 0..count {
+            encoded.push(read_bytes(reader, g1_size::<B>())?);
+        }
+        let lagrange_li_lj_z = B::deserialize_g1_batch(&encoded)
+            .map_err(|_| invalid_data("invalid G1 point in public key"))?;
+        Ok(Self {
+            participant_id,
+            bls_key,
+            lagrange_li,
+            lagrange_li_minus0,
+            lagrange_li_x,
+            lagrange_li_lj_z: lagrange_li_lj_z.into(),
+        })
+    }
+}
+
+impl<B: PairingBackend> BorshSerialize for Ciphertext<B> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        if self.proof_g1.len() != PROOF_G1_LEN || self.proof_g2.len() != PROOF_G2_LEN {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "ciphertext proof sizes are invalid",
+            ));
+        }
+        writer.write_all(&B::serialize_g2(&self.gamma_g2))?;
+        for point in &self.proof_g1 {
+            writer.write_all(&B::serialize_g1(point))?;
+        }
+        for point in &self.proof_g2 {
+            writer.write_all(&B::serialize_g2(point))?;
+        }
+        writer.write_all(&B::serialize_target(&self.shared_secret))?;
+        write_u32(writer, self.threshold)?;
+        self.epoch.serialize(writer)?;
+        self.committee.serialize(writer)?;
+        self.label.serialize(writer)?;
+        self.payload.serialize(writer)?;
+        self.compressed.serialize(writer)?;
+        self.padded.serialize(writer)?;
+        self.deterministic.serialize(writer)?;
+        self.kdf.to_byte().serialize(writer)?;
+        self.validity_proof.serialize(writer)?;
+        self.sender_signature.serialize(writer)?;
+        self.broadcast_keys.serialize(writer)
+    }
+}
+
+impl<B: PairingBackend> BorshDeserialize for Ciphertext<B> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        let gamma_g2 = read_g2::<B, R>(reader)?;
+        let mut proof_g1 = SmallVec::new();
+        for _ in 0..PROOF_G1_LEN {
+            proof_g1.push(read_g1::<B, R>(reader)?);
+        }
+        let mut proof_g2 = SmallVec::new();
+        for _ in 0..PROOF_G2_LEN {
+            proof_g2.push(read_g2::<B, R>(reader)?);
+        }
+        let shared_secret = B::deserialize_target(&read_bytes(reader, target_size::<B>())?)
+            .map_err(|_| invalid_data("invalid target group element"))?;
+        let threshold = u32::deserialize_reader(reader)? as usize;
+        let epoch = u64::deserialize_reader(reader)?;
+        let committee = <[u8; COMMITTEE_FINGERPRINT_LEN]>::deserialize_reader(reader)?;
+        let limits = DeserializeLimits::in_effect();
+        let label_len = read_len(reader, MAX_LABEL_LEN, "ciphertext label is too long")?;
+        let label = read_bytes(reader, label_len)?;
+        let payload_len = read_len(
+            reader,
+            limits.max_payload_len,
+            "ciphertext payload exceeds limit",
+        )?;
+        let payload = read_bytes(reader, payload_len)?;
+        let compressed = bool::deserialize_reader(reader)?;
+        let padded = bool::deserialize_reader(reader)?;
+        let deterministic = bool::deserialize_reader(reader)?;
+        let kdf = KdfId::from_byte(u8::deserialize_reader(reader)?)
+            .map_err(|_| invalid_data("unknown key derivation function"))?;
+        Ok(Self {
+            gamma_g2,
+            proof_g1,
+            proof_g2,
+            shared_secret,
+            threshold,
+            epoch,
+            committee,
+            label,
+            payload,
+            compressed,
+            padded,
+            deterministic,
+            kdf,
+            validity_proof: Option::deserialize_reader(reader)?,
+            sender_signature: Option::deserialize_reader(reader)?,
+            broadcast_keys: {
+                let count = read_len(
+                    reader,
+                    limits.max_public_key_hints,
+                    "broadcast keys exceed limit",
+                )?;
+                let mut keys = Vec::new();
+                for _ in 0..count {
+                    keys.push(<[u8; DEK_LEN]>::deserialize_reader(reader)?);
+                }
+                keys
+            },
+        })
+    }
+}
+
+impl<B: PairingBackend> BorshSerialize for CiphertextProof<B> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        writer.write_all(&B::serialize_scalar(&self.challenge))?;
+        write_u32(writer, self.responses.len())?;
+        for response in &self.responses {
+            writer.write_all(&B::serialize_scalar(response))?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: PairingBackend> BorshDeserialize for CiphertextProof<B> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        let challenge = read_scalar::<B, R>(reader)?;
+        let count = read_len(
+            reader,
+            DeserializeLimits::in_effect().max_proof_elements,
+            "validity proof exceeds limit",
+        )?;
+        let mut responses = Vec::new();
+        for _ in 0..count {
+            responses.push(read_scalar::<B, R>(reader)?);
+        }
+        Ok(Self {
+            challenge,
+            responses,
+        })
+    }
+}
+
+impl<B: PairingBackend> BorshSerialize for SenderSignature<B> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> IoResult<()> {
+        writer.write_all(&B::serialize_g1(&self.sender))?;
+        writer.write_all(&B::serialize_g2(&self.signature))
+    }
+}
+
+impl<B: PairingBackend> BorshDeserialize for SenderSignature<B> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> IoResult<Self> {
+        Ok(Self {
+            sender: read_g1::<B, R>(reader)?,
+            signature: read_g2::<B, R>(reader)?,
+        })
+    }
+}
+
+fn scalar_size<B: PairingBackend>() -> usize {
+    B::serialize_scalar(&B::Scalar::zero()).len()
+}
+
+fn write_u32<W: Write>(writer: &mut W, value: usize) -> IoResult<()> {
+    u32::try_from(value)
+        .map_err(|_| IoError::new(ErrorKind::InvalidInput, "value does not fit in u32"))?
+        .serialize(writer)
+}
+
+/// Reads a `u32` length prefix, rejecting it before anything is allocated if
+/// it exceeds `max`.
+fn read_len<R: Read>(reader: &mut R, max: usize, reason: &'static str) -> IoResult<usize> {
+    let len = u32::deserialize_reader(reader)? as usize;
+    if len > max {
+        return Err(invalid_data(reason));
+    }
+    Ok(len)
+}
+
+fn read_bytes<R: Read>(reader: &mut R, len: usize) -> IoResult<Vec<u8>> {
+    let mut bytes = vec![0u8; len];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_scalar<B: PairingBackend, R: Read>(reader: &mut R) -> IoResult<B::Scalar> {
+    B::deserialize_scalar(&read_bytes(reader, scalar_size::<B>())?)
+        .map_err(|_| invalid_data("invalid scalar"))
+}
+
+fn read_g1<B: PairingBackend, R: Read>(reader: &mut R) -> IoResult<B::G1> {
+    B::deserialize_g1(&read_bytes(reader, g1_size::<B>())?)
+        .map_err(|_| invalid_data("invalid G1 point"))
+}
+
+fn read_g2<B: PairingBackend, R: Read>(reader: &mut R) -> IoResult<B::G2> {
+    B::deserialize_g2(&read_bytes(reader, g2_size::<B>())?)
+        .map_err(|_| invalid_data("invalid G2 point"))
+}
+
+fn invalid_data(reason: &'static str) -> IoError {
+    IoError::new(ErrorKind::InvalidData, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{
+        AggregateKeyDigest, CurvePoint, PairingEngine, SenderKey, SilentThresholdScheme,
+        ThresholdEncryption,
+    };
+
+    type B = PairingEngine;
+
+    #[test]
+    fn borsh_layouts_roundtrip_at_their_documented_sizes() {
+        let mut rng = StdRng::seed_from_u64(1483);
+        let scheme = SilentThresholdScheme::<B>::new().with_label(b"slot-7");
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"on-chain")
+            .unwrap();
+
+        let bytes = borsh::to_vec(&ct).unwrap();
+        assert_eq!(bytes.len(), ct.borsh_len());
+        let decoded = borsh::from_slice::<Ciphertext<B>>(&bytes).unwrap();
+        assert_eq!(borsh::to_vec(&decoded).unwrap(), bytes);
+        let digest = AggregateKeyDigest::new(&keys.aggregate_key, &params, 2).unwrap();
+        digest.verify_ciphertext(&decoded).unwrap();
+
+        let partial = scheme.partial_decrypt(&keys.secret_keys[1], &ct).unwrap();
+        let bytes = borsh::to_vec(&partial).unwrap();
+        assert_eq!(bytes.len(), PartialDecryption::<B>::borsh_len());
+        let decoded = borsh::from_slice::<PartialDecryption<B>>(&bytes).unwrap();
+        assert_eq!(decoded.participant_id, 1);
+        assert!(decoded.response.sub(&partial.response).is_identity());
+
+        let bytes = borsh::to_vec(&keys.public_keys[2]).unwrap();
+        assert_eq!(bytes.len(), PublicKey::<B>::borsh_len(4));
+        let decoded = borsh::from_slice::<PublicKey<B>>(&bytes).unwrap();
+        assert_eq!(
+            decoded.lagrange_li_lj_z,
+            keys.public_keys[2].lagrange_li_lj_z
+        );
+
+        // A corrupted point or trailing garbage is rejected.
+        let mut corrupted = bytes.clone();
+        corrupted[4] ^= 0x01;
+        assert!(borsh::from_slice::<PublicKey<B>>(&corrupted).is_err());
+        let mut extended = bytes;
+        extended.push(0);
+        assert!(borsh::from_slice::<PublicKey<B>>(&extended).is_err());
+
+        let sender = SenderKey::<B>::generate(&mut rng);
+        let mut signed = ct.clone();
+        signed.sender_signature = Some(sender.sign(&ct));
+        let bytes = borsh::to_vec(&signed).unwrap();
+        assert_eq!(bytes.len(), signed.borsh_len());
+        let decoded = borsh::from_slice::<Ciphertext<B>>(&bytes).unwrap();
+        assert!(
+            decoded
+                .sender_signature
+                .as_ref()
+                .unwrap()
+                .verify(&decoded)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn oversized_length_prefixes_are_rejected_before_reading() {
+        let mut rng = StdRng::seed_from_u64(1483);
+        let scheme = SilentThresholdScheme::<B>::new().with_label(b"slot-7");
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, &[7; 64])
+            .unwrap();
+        let bytes = borsh::to_vec(&ct).unwrap();
+
+        // Truncate right after each prefix: a bounded prefix would fail with
+        // `UnexpectedEof`, an oversized one with `InvalidData`.
+        let label_at = (1 + PROOF_G2_LEN) * g2_size::<B>()
+            + PROOF_G1_LEN * g1_size::<B>()
+            + target_size::<B>()
+            + 4
+            + 8
+            + COMMITTEE_FINGERPRINT_LEN;
+        let payload_at = label_at + 4 + ct.label.len();
+        for at in [label_at, payload_at] {
+            let mut forged = bytes[..at].to_vec();
+            forged.extend_from_slice(&u32::MAX.to_le_bytes());
+            let err = borsh::from_slice::<Ciphertext<B>>(&forged).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let strict = DeserializeLimits {
+                max_payload_len: 32,
+                ..DeserializeLimits::default()
+            };
+            assert!(
+                strict
+                    .deserialize_borsh::<Ciphertext<B>, _>(&mut bytes.as_slice())
+                    .is_err()
+            );
+            let decoded = DeserializeLimits::default()
+                .deserialize_borsh::<Ciphertext<B>, _>(&mut bytes.as_slice())
+                .unwrap();
+            assert_eq!(decoded.payload, ct.payload);
+        }
+    }
+}
@@ -0,0 +1,503 @@
+//! Checkpointing for long-running setup operations.
+//!
+//! SRS generation and key generation for committees of 2^16 parties or more
+//! take long enough that losing the work to a crash hurts. The checkpointed
+//! variants compute their output in chunks of [`Checkpoint::every`] items and
+//! write the progress so far to [`Checkpoint::path`] after each chunk. After a
+//! crash, the matching `resume` entry point picks up from the last chunk
+//! written:
+//!
+//! | Operation  | Checkpointed                                       | Resume                                        |
+//! |------------|----------------------------------------------------|-----------------------------------------------|
+//! | KZG setup  | [`KZG::setup_with_checkpoint`]                     | [`KZG::resume_from`]                          |
+//! | Keygen     | [`SilentThresholdScheme::keygen_with_checkpoint`]  | [`SilentThresholdScheme::resume_keygen_from`] |
+//!
+//! The checkpoint file is removed once the operation completes.
+//!
+//! # Security
+//!
+//! Checkpoints hold the setup seed (from which `τ` is derived) or every
+//! participant's secret key. They are written like secret key records of
+//! [`FsKeyStore`](crate::FsKeyStore): through a fresh temporary file that only
+//! the owner can read on Unix, synced before it replaces the checkpoint, with
+//! the encoded bytes wiped from memory afterwards. Store them only where the
+//! final secrets could be stored, and delete stale checkpoints of abandoned
+//! runs.
+//!
+//! ```rust
+//! use tess::{Checkpoint, KZG, PairingEngine, PolynomialCommitment};
+//!
+//! let path = std::env::temp_dir().join(format!("tess-doc-{}.ckpt", std::process::id()));
+//! let checkpoint = Checkpoint::new(&path).every(8);
+//! let srs = KZG::setup_with_checkpoint::<PairingEngine>(32, &[7u8; 32], &checkpoint).unwrap();
+//! let direct = <KZG as PolynomialCommitment<PairingEngine>>::setup(32, &[7u8; 32]).unwrap();
+//! assert_eq!(srs.powers_of_g, direct.powers_of_g);
+//! assert!(!path.exists());
+//! ```
+
+use alloc::vec::Vec;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use tracing::{debug, instrument};
+use zeroize::Zeroize;
+
+use crate::{
+    AggregateKey, BackendError, CurvePoint, Fr, KZG, PairingBackend, Params, PublicKey, SRS,
+    SecretKey, SilentThresholdScheme, UnsafeKeyMaterial, Xof,
+    codec::{Reader, put_g1_vec, put_g2_vec, put_len, put_public_key, put_secret_key},
+    errors::Error,
+    keystore::{io_error, write_file},
+    kzg::setup_trapdoor,
+};
+
+/// Items computed between two checkpoint writes unless configured otherwise.
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 4096;
+
+const MAGIC: &[u8; 8] = b"TESSCKPT";
+const VERSION: u8 = 1;
+const KIND_SRS: u8 = 1;
+const KIND_KEYGEN: u8 = 2;
+
+/// Where and how often a setup operation saves its progress.
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    path: PathBuf,
+    interval: usize,
+}
+
+impl Checkpoint {
+    /// Checkpoints to `path` every [`DEFAULT_CHECKPOINT_INTERVAL`] items.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            interval: DEFAULT_CHECKPOINT_INTERVAL,
+        }
+    }
+
+    /// Writes a checkpoint after every `items` SRS powers or public keys.
+    pub fn every(mut self, items: usize) -> Self {
+        self.interval = items.max(1);
+        self
+    }
+
+    /// The checkpoint file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Replaces the checkpoint with `bytes`, then wipes them.
+    fn save(&self, mut bytes: Vec<u8>) -> Result<(), Error> {
+        let written = write_file(&self.path, &bytes, true);
+        bytes.zeroize();
+        written
+    }
+
+    /// Reads a checkpoint of `kind` and decodes its body with `decode`,
+    /// wiping the file contents afterwards.
+    fn load<T>(
+        &self,
+        kind: u8,
+        decode: impl FnOnce(&[u8]) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let mut bytes = fs::read(&self.path).map_err(|e| io_error("read", &self.path, e))?;
+        let decoded = if bytes.len() < MAGIC.len() + 2
+            || &bytes[..MAGIC.len()] != MAGIC
+            || bytes[MAGIC.len()] != VERSION
+            || bytes[MAGIC.len() + 1] != kind
+        {
+            Err(Error::MalformedInput(
+                "not a checkpoint of this operation".into(),
+            ))
+        } else {
+            decode(&bytes[MAGIC.len() + 2..])
+        };
+        bytes.zeroize();
+        decoded
+    }
+
+    fn finish(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+impl KZG {
+    /// Runs [`PolynomialCommitment::setup`](crate::PolynomialCommitment::setup),
+    /// saving the powers computed so far to `checkpoint`.
+    ///
+    /// The result is identical to the unchecked setup with the same seed.
+    #[instrument(level = "info", skip_all, fields(max_degree))]
+    pub fn setup_with_checkpoint<B: PairingBackend<Scalar = Fr>>(
+        max_degree: usize,
+        seed: &[u8; 32],
+        checkpoint: &Checkpoint,
+    ) -> Result<SRS<B>, Error> {
+        if max_degree < 1 {
+            return Err(Error::InvalidConfig("SRS degree must be at least 1".into()));
+        }
+        Self::continue_setup(
+            SetupProgress {
+                seed: *seed,
+                max_degree,
+                powers_of_g: Vec::new(),
+                powers_of_h: Vec::new(),
+            },
+            checkpoint,
+        )
+    }
+
+    /// Resumes an interrupted [`KZG::setup_with_checkpoint`] from `checkpoint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the file is not an SRS checkpoint.
+    #[instrument(level = "info", skip_all)]
+    pub fn resume_from<B: PairingBackend<Scalar = Fr>>(
+        checkpoint: &Checkpoint,
+    ) -> Result<SRS<B>, Error> {
+        let progress = checkpoint.load(KIND_SRS, SetupProgress::decode)?;
+        debug!(done = progress.powers_of_g.len(), "resuming SRS setup");
+        Self::continue_setup(progress, checkpoint)
+    }
+
+    fn continue_setup<B: PairingBackend<Scalar = Fr>>(
+        mut progress: SetupProgress<B>,
+        checkpoint: &Checkpoint,
+    ) -> Result<SRS<B>, Error> {
+        let tau = setup_trapdoor(&progress.seed);
+        let total = progress.max_degree + 1;
+        while progress.powers_of_g.len() < total {
+            let start = progress.powers_of_g.len();
+            let end = (start + checkpoint.interval).min(total);
+            let (g, h) = SRS::<B>::powers_in_range(&tau, start, end);
+            progress.powers_of_g.extend(g);
+            progress.powers_of_h.extend(h);
+            if end < total {
+                checkpoint.save(progress.encode())?;
+            }
+        }
+        checkpoint.finish();
+
+        Ok(SRS {
+            powers_of_g: progress.powers_of_g,
+            powers_of_h: progress.powers_of_h,
+            e_gh: B::pairing(&B::G1::generator(), &B::G2::generator()),
+            lagrange_basis_g: Vec::new(),
+        })
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Runs [`ThresholdEncryption::keygen_unsafe`](crate::ThresholdEncryption::keygen_unsafe),
+    /// saving the secret keys and the public keys derived so far to
+    /// `checkpoint`.
+    #[instrument(level = "info", skip_all, fields(parties))]
+    pub fn keygen_with_checkpoint<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        parties: usize,
+        params: &Params<B>,
+        checkpoint: &Checkpoint,
+    ) -> Result<UnsafeKeyMaterial<B>, Error> {
+        check_parties(parties, params)?;
+        let progress = KeygenProgress {
+            secret_keys: Self::generate_secret_keys(rng, parties),
+            public_keys: Vec::new(),
+        };
+        checkpoint.save(progress.encode())?;
+        self.continue_keygen(progress, params, checkpoint)
+    }
+
+    /// Resumes an interrupted [`SilentThresholdScheme::keygen_with_checkpoint`]
+    /// from `checkpoint`, using the same `params`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the file is not a keygen checkpoint,
+    /// and [`Error::InvalidConfig`] if `params` has a different party count.
+    #[instrument(level = "info", skip_all)]
+    pub fn resume_keygen_from(
+        &self,
+        checkpoint: &Checkpoint,
+        params: &Params<B>,
+    ) -> Result<UnsafeKeyMaterial<B>, Error> {
+        let progress = checkpoint.load(KIND_KEYGEN, KeygenProgress::decode)?;
+        debug!(done = progress.public_keys.len(), "resuming keygen");
+        self.continue_keygen(progress, params, checkpoint)
+    }
+
+    fn continue_keygen(
+        &self,
+        mut progress: KeygenProgress<B>,
+        params: &Params<B>,
+        checkpoint: &Checkpoint,
+    ) -> Result<UnsafeKeyMaterial<B>, Error> {
+        let parties = progress.secret_keys.len();
+        check_parties(parties, params)?;
+
+        while progress.public_keys.len() < parties {
+            let start = progress.public_keys.len();
+            let end = (start + checkpoint.interval).min(parties);
+            let chunk = &progress.secret_keys[start..end];
+            let public_keys = {
+                #[cfg(feature = "parallel")]
+                {
+                    chunk
+                        .par_iter()
+                        .map(|sk| sk.derive_public_key(params))
+                        .collect::<Result<Vec<_>, BackendError>>()?
+                }
+                #[cfg(not(feature = "parallel"))]
+                {
+                    chunk
+                        .iter()
+                        .map(|sk| sk.derive_public_key(params))
+                        .collect::<Result<Vec<_>, BackendError>>()?
+                }
+            };
+            progress.public_keys.extend(public_keys);
+            if end < parties {
+                checkpoint.save(progress.encode())?;
+            }
+        }
+
+        let aggregate_key = AggregateKey::aggregate_keys(&progress.public_keys, params, parties)?;
+        checkpoint.finish();
+        Ok(UnsafeKeyMaterial {
+            secret_keys: progress.secret_keys,
+            public_keys: progress.public_keys,
+            aggregate_key,
+            kzg_params: params.srs.clone(),
+        })
+    }
+}
+
+struct SetupProgress<B: PairingBackend<Scalar = Fr>> {
+    seed: [u8; 32],
+    max_degree: usize,
+    powers_of_g: Vec<B::G1>,
+    powers_of_h: Vec<B::G2>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> SetupProgress<B> {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = header(KIND_SRS);
+        out.extend_from_slice(&self.seed);
+        put_len(&mut out, self.max_degree);
+        put_g1_vec::<B>(&mut out, &self.powers_of_g);
+        put_g2_vec::<B>(&mut out, &self.powers_of_h);
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader(bytes);
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(reader.take(32)?);
+        let max_degree = reader.len()?;
+        let powers_of_g = reader.g1_vec::<B>()?;
+        let powers_of_h = reader.g2_vec::<B>()?;
+        reader.finish()?;
+        if powers_of_g.len() != powers_of_h.len() || powers_of_g.len() > max_degree + 1 {
+            return Err(Error::MalformedInput("inconsistent SRS checkpoint".into()));
+        }
+        Ok(Self {
+            seed,
+            max_degree,
+            powers_of_g,
+            powers_of_h,
+        })
+    }
+}
+
+struct KeygenProgress<B: PairingBackend<Scalar = Fr>> {
+    secret_keys: Vec<SecretKey<B>>,
+    public_keys: Vec<PublicKey<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> KeygenProgress<B> {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = header(KIND_KEYGEN);
+        put_len(&mut out, self.secret_keys.len());
+        for secret_key in &self.secret_keys {
+            put_secret_key(&mut out, secret_key);
+        }
+        put_len(&mut out, self.public_keys.len());
+        for public_key in &self.public_keys {
+            put_public_key(&mut out, public_key);
+        }
+        out
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader(bytes);
+        let parties = reader.count(8)?;
+        let secret_keys = (0..parties)
+            .map(|_| reader.secret_key::<B>())
+            .collect::<Result<Vec<_>, _>>()?;
+        let done = reader.count(8)?;
+        let public_keys = (0..done)
+            .map(|_| reader.public_key::<B>())
+            .collect::<Result<Vec<_>, _>>()?;
+        reader.finish()?;
+        let in_order = secret_keys
+            .iter()
+            .enumerate()
+            .all(|(i, sk)| sk.participant_id == i)
+            && public_keys
+                .iter()
+                .enumerate()
+                .all(|(i, pk)| pk.participant_id == i);
+        if done > parties || !in_order {
+            return Err(Error::MalformedInput(
+                "inconsistent keygen checkpoint".into(),
+            ));
+        }
+        Ok(Self {
+            secret_keys,
+            public_keys,
+        })
+    }
+}
+
+fn check_parties<B: PairingBackend<Scalar = Fr>>(
+    parties: usize,
+    params: &Params<B>,
+) -> Result<(), Error> {
+    if parties != params.parties() {
+        return Err(Error::InvalidConfig(format!(
+            "keygen for {parties} parties but params have {}",
+            params.parties()
+        )));
+    }
+    Ok(())
+}
+
+fn header(kind: u8) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+    out.push(VERSION);
+    out.push(kind);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, PolynomialCommitment, ThresholdEncryption};
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("tess-{name}-{}.ckpt", std::process::id()))
+    }
+
+    #[test]
+    fn setup_resumes_from_checkpoint() {
+        let seed = [40u8; 32];
+        let checkpoint = Checkpoint::new(checkpoint_path("srs")).every(5);
+
+        // Simulate a crash after the first chunk.
+        let tau = setup_trapdoor(&seed);
+        let (powers_of_g, powers_of_h) = SRS::<PairingEngine>::powers_in_range(&tau, 0, 5);
+        let partial = SetupProgress::<PairingEngine> {
+            seed,
+            max_degree: 16,
+            powers_of_g,
+            powers_of_h,
+        };
+        checkpoint.save(partial.encode()).unwrap();
+
+        let resumed = KZG::resume_from::<PairingEngine>(&checkpoint).unwrap();
+        let direct = <KZG as PolynomialCommitment<PairingEngine>>::setup(16, &seed).unwrap();
+        assert_eq!(resumed.powers_of_g, direct.powers_of_g);
+        assert_eq!(resumed.powers_of_h, direct.powers_of_h);
+        assert!(!checkpoint.path().exists());
+        assert!(KZG::resume_from::<PairingEngine>(&checkpoint).is_err());
+    }
+
+    #[test]
+    fn keygen_resumes_from_checkpoint() {
+        let mut rng = StdRng::seed_from_u64(1440);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 3).unwrap();
+        let checkpoint = Checkpoint::new(checkpoint_path("keygen")).every(3);
+
+        // Simulate a crash after the first chunk of public keys.
+        let secret_keys = SilentThresholdScheme::<PairingEngine>::generate_secret_keys(&mut rng, 8);
+        let public_keys = secret_keys[..3]
+            .iter()
+            .map(|sk| sk.derive_public_key(&params).unwrap())
+            .collect();
+        let expected: Vec<_> = secret_keys.iter().map(|sk| sk.scalar).collect();
+        let partial = KeygenProgress {
+            secret_keys,
+            public_keys,
+        };
+        checkpoint.save(partial.encode()).unwrap();
+
+        let other = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        assert!(scheme.resume_keygen_from(&checkpoint, &other).is_err());
+
+        let keys = scheme.resume_keygen_from(&checkpoint, &params).unwrap();
+        let scalars: Vec<_> = keys.secret_keys.iter().map(|sk| sk.scalar).collect();
+        assert_eq!(scalars, expected);
+        assert!(!checkpoint.path().exists());
+
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 3, b"resumed")
+            .unwrap();
+        let partials: Vec<_> = keys.secret_keys[..4]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let selector = [true, true, true, true, false, false, false, false];
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"resumed");
+
+        assert!(
+            scheme
+                .keygen_with_checkpoint(&mut rng, 4, &params, &checkpoint)
+                .is_err()
+        );
+        assert!(!checkpoint.path().exists());
+        let fresh = scheme
+            .keygen_with_checkpoint(&mut rng, 8, &params, &checkpoint)
+            .unwrap();
+        assert_eq!(fresh.public_keys.len(), 8);
+        assert!(!checkpoint.path().exists());
+    }
+
+    #[test]
+    fn checkpoints_are_private_and_keep_io_errors() {
+        let checkpoint = Checkpoint::new(checkpoint_path("private"));
+        let err = KZG::resume_from::<PairingEngine>(&checkpoint).unwrap_err();
+        assert!(
+            matches!(&err, Error::Io { source, .. } if source.kind() == std::io::ErrorKind::NotFound)
+        );
+
+        checkpoint.save(header(KIND_SRS)).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(checkpoint.path())
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        checkpoint.finish();
+        assert!(!checkpoint.path().exists());
+
+        let unwritable = Checkpoint::new(checkpoint_path("missing").join("nested.ckpt"));
+        assert!(matches!(
+            unwritable.save(header(KIND_SRS)),
+            Err(Error::Io { .. })
+        ));
+    }
+}
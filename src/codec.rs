@@ -0,0 +1,216 @@
+//! Compact binary encoding of key material for on-disk formats.
+//!
+//! Lengths and indices are little-endian `u64`; group elements and scalars use
+//! the backend's canonical encoding, each prefixed with its length. Callers add
+//! their own header (magic, version, record kind) in front.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{AggregateKey, Fr, PairingBackend, PublicKey, SRS, SecretKey, errors::Error};
+
+pub(crate) fn put_len(out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+pub(crate) fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_len(out, bytes.len());
+    out.extend_from_slice(bytes);
+}
+
+pub(crate) fn put_g1_vec<B: PairingBackend>(out: &mut Vec<u8>, points: &[B::G1]) {
+    put_len(out, points.len());
+    for point in points {
+        put_bytes(out, &B::serialize_g1(point));
+    }
+}
+
+pub(crate) fn put_g2_vec<B: PairingBackend>(out: &mut Vec<u8>, points: &[B::G2]) {
+    put_len(out, points.len());
+    for point in points {
+        put_bytes(out, &B::serialize_g2(point));
+    }
+}
+
+pub(crate) fn put_secret_key<B: PairingBackend>(out: &mut Vec<u8>, secret_key: &SecretKey<B>) {
+    put_len(out, secret_key.participant_id);
+    put_bytes(out, &B::serialize_scalar(&secret_key.scalar));
+}
+
+pub(crate) fn put_public_key<B: PairingBackend>(out: &mut Vec<u8>, public_key: &PublicKey<B>) {
+    put_len(out, public_key.participant_id);
+    for point in [
+        &public_key.bls_key,
+        &public_key.lagrange_li,
+        &public_key.lagrange_li_minus0,
+        &public_key.lagrange_li_x,
+    ] {
+        put_bytes(out, &B::serialize_g1(point));
+    }
+    put_g1_vec::<B>(out, &public_key.lagrange_li_lj_z);
+}
+
+pub(crate) fn put_srs<B: PairingBackend<Scalar = Fr>>(out: &mut Vec<u8>, srs: &SRS<B>) {
+    put_g1_vec::<B>(out, &srs.powers_of_g);
+    put_g2_vec::<B>(out, &srs.powers_of_h);
+    put_bytes(out, &B::serialize_target(&srs.e_gh));
+    put_g1_vec::<B>(out, &srs.lagrange_basis_g);
+}
+
+pub(crate) fn put_aggregate_key<B: PairingBackend<Scalar = Fr>>(
+    out: &mut Vec<u8>,
+    aggregate_key: &AggregateKey<B>,
+) {
+    put_len(out, aggregate_key.public_keys.len());
+    for public_key in &aggregate_key.public_keys {
+        put_public_key(out, public_key);
+    }
+    put_bytes(out, &B::serialize_g1(&aggregate_key.ask));
+    put_bytes(out, &B::serialize_g2(&aggregate_key.z_g2));
+    put_g1_vec::<B>(out, &aggregate_key.lagrange_row_sums);
+    put_bytes(
+        out,
+        &B::serialize_target(&aggregate_key.precomputed_pairing),
+    );
+    put_srs(out, &aggregate_key.kzg_params);
+}
+
+/// Writes the revoked participant ids that [`put_aggregate_key`] leaves out.
+pub(crate) fn put_revoked(out: &mut Vec<u8>, revoked: &[usize]) {
+    put_len(out, revoked.len());
+    for &id in revoked {
+        put_len(out, id);
+    }
+}
+
+/// Cursor over an encoded body.
+pub(crate) struct Reader<'a>(pub(crate) &'a [u8]);
+
+impl<'a> Reader<'a> {
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(truncated());
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    pub(crate) fn len(&mut self) -> Result<usize, Error> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.take(8)?);
+        usize::try_from(u64::from_le_bytes(bytes)).map_err(|_| truncated())
+    }
+
+    /// Reads a count of items that each take at least `min_item_len` bytes,
+    /// rejecting counts the remaining input cannot hold.
+    pub(crate) fn count(&mut self, min_item_len: usize) -> Result<usize, Error> {
+        let count = self.len()?;
+        if count.saturating_mul(min_item_len) > self.0.len() {
+            return Err(truncated());
+        }
+        Ok(count)
+    }
+
+    pub(crate) fn bytes(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    pub(crate) fn g1<B: PairingBackend>(&mut self) -> Result<B::G1, Error> {
+        B::deserialize_g1(self.bytes()?).map_err(Error::Backend)
+    }
+
+    pub(crate) fn g2<B: PairingBackend>(&mut self) -> Result<B::G2, Error> {
+        B::deserialize_g2(self.bytes()?).map_err(Error::Backend)
+    }
+
+    pub(crate) fn g1_vec<B: PairingBackend>(&mut self) -> Result<Vec<B::G1>, Error> {
+        let count = self.count(8)?;
+        let encoded = (0..count)
+            .map(|_| self.bytes())
+            .collect::<Result<Vec<_>, _>>()?;
+        B::deserialize_g1_batch(&encoded).map_err(Error::Backend)
+    }
+
+    pub(crate) fn g2_vec<B: PairingBackend>(&mut self) -> Result<Vec<B::G2>, Error> {
+        let count = self.count(8)?;
+        let encoded = (0..count)
+            .map(|_| self.bytes())
+            .collect::<Result<Vec<_>, _>>()?;
+        B::deserialize_g2_batch(&encoded).map_err(Error::Backend)
+    }
+
+    pub(crate) fn secret_key<B: PairingBackend>(&mut self) -> Result<SecretKey<B>, Error> {
+        let participant_id = self.len()?;
+        let scalar = B::deserialize_scalar(self.bytes()?).map_err(Error::Backend)?;
+        Ok(SecretKey {
+            participant_id,
+            scalar,
+        })
+    }
+
+    pub(crate) fn public_key<B: PairingBackend>(&mut self) -> Result<PublicKey<B>, Error> {
+        Ok(PublicKey {
+            participant_id: self.len()?,
+            bls_key: self.g1::<B>()?,
+            lagrange_li: self.g1::<B>()?,
+            lagrange_li_minus0: self.g1::<B>()?,
+            lagrange_li_x: self.g1::<B>()?,
+            lagrange_li_lj_z: self.g1_vec::<B>()?.into_boxed_slice(),
+        })
+    }
+
+    pub(crate) fn srs<B: PairingBackend<Scalar = Fr>>(&mut self) -> Result<SRS<B>, Error> {
+        Ok(SRS {
+            powers_of_g: self.g1_vec::<B>()?,
+            powers_of_h: self.g2_vec::<B>()?,
+            e_gh: B::deserialize_target(self.bytes()?).map_err(Error::Backend)?,
+            lagrange_basis_g: self.g1_vec::<B>()?,
+        })
+    }
+
+    pub(crate) fn aggregate_key<B: PairingBackend<Scalar = Fr>>(
+        &mut self,
+    ) -> Result<AggregateKey<B>, Error> {
+        let count = self.count(8)?;
+        let public_keys = (0..count)
+            .map(|_| self.public_key::<B>())
+            .collect::<Result<_, _>>()?;
+        Ok(AggregateKey {
+            public_keys,
+            ask: self.g1::<B>()?,
+            z_g2: self.g2::<B>()?,
+            lagrange_row_sums: self.g1_vec::<B>()?,
+            precomputed_pairing: B::deserialize_target(self.bytes()?).map_err(Error::Backend)?,
+            kzg_params: self.srs::<B>()?,
+            revoked: Vec::new(),
+        })
+    }
+
+    /// Reads revoked participant ids written by [`put_revoked`], sorted and
+    /// deduplicated.
+    pub(crate) fn revoked(&mut self) -> Result<Vec<usize>, Error> {
+        let count = self.count(8)?;
+        let mut revoked = (0..count)
+            .map(|_| self.len())
+            .collect::<Result<Vec<_>, _>>()?;
+        revoked.sort_unstable();
+        revoked.dedup();
+        Ok(revoked)
+    }
+
+    /// Fails unless the whole input has been consumed.
+    pub(crate) fn finish(&self) -> Result<(), Error> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::MalformedInput(String::from(
+                "trailing bytes after encoded value",
+            )))
+        }
+    }
+}
+
+fn truncated() -> Error {
+    Error::MalformedInput(String::from("truncated or corrupt encoding"))
+}
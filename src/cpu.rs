@@ -0,0 +1,309 @@
+//! Runtime CPU feature detection and dispatched kernels.
+//!
+//! Release binaries are usually built for a baseline target and then run on
+//! machines with very different vector units. [`CpuFeatures::active`] reports
+//! what the current CPU supports, detected once at first use, and the kernels
+//! the crate implements itself pick the widest implementation available:
+//!
+//! | Kernel | x86_64 | aarch64 | Fallback |
+//! |--------|--------|---------|----------|
+//! | Payload keystream XOR | AVX-512F, AVX2 | NEON | 64-bit words |
+//!
+//! Field arithmetic and MSM bucket accumulation run inside the pairing
+//! backends rather than in this crate. `blst` selects its ADX/BMI2 assembly
+//! at runtime on its own, which [`CpuFeatures::adx`] reports. The arkworks
+//! backends use whatever the binary was compiled for, so they only benefit
+//! from a `-C target-cpu` or `-C target-feature` build.
+//!
+//! Without the `std` feature nothing is detected at runtime and only the
+//! features enabled at compile time are used.
+//!
+//! [`CpuFeatures::install`] narrows the active set, for example to pin a
+//! heterogeneous fleet to one code path or to benchmark the fallbacks.
+//! Features the CPU lacks are never enabled.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+const AVX2: u8 = 1 << 0;
+const AVX512F: u8 = 1 << 1;
+const NEON: u8 = 1 << 2;
+const ADX: u8 = 1 << 3;
+/// Set once detection has run; the remaining bits are meaningless before.
+const DETECTED: u8 = 1 << 7;
+
+static ACTIVE: AtomicU8 = AtomicU8::new(0);
+
+/// Vector and arithmetic extensions used by the dispatched kernels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+    /// x86_64 AVX2.
+    pub avx2: bool,
+    /// x86_64 AVX-512 Foundation.
+    pub avx512f: bool,
+    /// aarch64 Advanced SIMD.
+    pub neon: bool,
+    /// x86_64 ADX carry chains, used by `blst`'s field assembly.
+    pub adx: bool,
+}
+
+impl CpuFeatures {
+    /// No extensions: every kernel uses its portable fallback.
+    pub const PORTABLE: Self = Self {
+        avx2: false,
+        avx512f: false,
+        neon: false,
+        adx: false,
+    };
+
+    /// Features of the current CPU, ignoring any [`install`](Self::install)ed
+    /// restriction.
+    pub fn detect() -> Self {
+        Self::from_bits(detect_bits())
+    }
+
+    /// Features the dispatched kernels currently use.
+    pub fn active() -> Self {
+        Self::from_bits(active_bits())
+    }
+
+    /// Restricts the kernels to these features, intersected with
+    /// [`detect`](Self::detect).
+    pub fn install(self) {
+        ACTIVE.store((self.bits() & detect_bits()) | DETECTED, Ordering::Relaxed);
+    }
+
+    fn bits(self) -> u8 {
+        (if self.avx2 { AVX2 } else { 0 })
+            | (if self.avx512f { AVX512F } else { 0 })
+            | (if self.neon { NEON } else { 0 })
+            | (if self.adx { ADX } else { 0 })
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        Self {
+            avx2: bits & AVX2 != 0,
+            avx512f: bits & AVX512F != 0,
+            neon: bits & NEON != 0,
+            adx: bits & ADX != 0,
+        }
+    }
+}
+
+fn active_bits() -> u8 {
+    let bits = ACTIVE.load(Ordering::Relaxed);
+    if bits & DETECTED != 0 {
+        return bits;
+    }
+    let bits = detect_bits() | DETECTED;
+    ACTIVE.store(bits, Ordering::Relaxed);
+    bits
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+fn detect_bits() -> u8 {
+    (if std::arch::is_x86_feature_detected!("avx2") {
+        AVX2
+    } else {
+        0
+    }) | (if std::arch::is_x86_feature_detected!("avx512f") {
+        AVX512F
+    } else {
+        0
+    }) | (if std::arch::is_x86_feature_detected!("adx") {
+        ADX
+    } else {
+        0
+    })
+}
+
+#[cfg(all(feature = "std", target_arch = "aarch64"))]
+fn detect_bits() -> u8 {
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        NEON
+    } else {
+        0
+    }
+}
+
+#[cfg(not(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64"))))]
+fn detect_bits() -> u8 {
+    (if cfg!(all(target_arch = "x86_64", target_feature = "avx2")) {
+        AVX2
+    } else {
+        0
+    }) | (if cfg!(all(target_arch = "x86_64", target_feature = "avx512f")) {
+        AVX512F
+    } else {
+        0
+    }) | (if cfg!(all(target_arch = "x86_64", target_feature = "adx")) {
+        ADX
+    } else {
+        0
+    }) | (if cfg!(all(target_arch = "aarch64", target_feature = "neon")) {
+        NEON
+    } else {
+        0
+    })
+}
+
+/// XORs `src` into `dst` with the widest kernel the CPU supports.
+pub(crate) fn xor_in_place(dst: &mut [u8], src: &[u8]) {
+    debug_assert_eq!(dst.len(), src.len());
+    let bits = active_bits();
+    #[cfg(target_arch = "x86_64")]
+    {
+        if bits & AVX512F != 0 {
+            // SAFETY: AVX-512F was detected on this CPU.
+            unsafe { x86::xor_avx512(dst, src) };
+            return;
+        }
+        if bits & AVX2 != 0 {
+            // SAFETY: AVX2 was detected on this CPU.
+            unsafe { x86::xor_avx2(dst, src) };
+            return;
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if bits & NEON != 0 {
+            // SAFETY: NEON was detected on this CPU.
+            unsafe { arm::xor_neon(dst, src) };
+            return;
+        }
+    }
+    let _ = bits;
+    xor_portable(dst, src);
+}
+
+/// XORs `src` into `dst`, eight bytes at a time with a byte-wise tail.
+fn xor_portable(dst: &mut [u8], src: &[u8]) {
+    let mut dst_words = dst.chunks_exact_mut(8);
+    let mut src_words = src.chunks_exact(8);
+    for (d, s) in (&mut dst_words).zip(&mut src_words) {
+        let word = u64::from_ne_bytes((&*d).try_into().unwrap())
+            ^ u64::from_ne_bytes(s.try_into().unwrap());
+        d.copy_from_slice(&word.to_ne_bytes());
+    }
+    for (d, s) in dst_words
+        .into_remainder()
+        .iter_mut()
+        .zip(src_words.remainder())
+    {
+        *d ^= s;
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use core::arch::x86_64::*;
+
+    #[target_feature(enable = "avx2")]
+    pub(super) fn xor_avx2(dst: &mut [u8], src: &[u8]) {
+        let len = dst.len().min(src.len());
+        let mut i = 0;
+        while i + 32 <= len {
+            // SAFETY: `i + 32 <= len` keeps both unaligned accesses in bounds.
+            unsafe {
+                let d = dst.as_mut_ptr().add(i).cast::<__m256i>();
+                let s = src.as_ptr().add(i).cast::<__m256i>();
+                _mm256_storeu_si256(
+                    d,
+                    _mm256_xor_si256(_mm256_loadu_si256(d), _mm256_loadu_si256(s)),
+                );
+            }
+            i += 32;
+        }
+        super::xor_portable(&mut dst[i..len], &src[i..len]);
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) fn xor_avx512(dst: &mut [u8], src: &[u8]) {
+        let len = dst.len().min(src.len());
+        let mut i = 0;
+        while i + 64 <= len {
+            // SAFETY: `i + 64 <= len` keeps both unaligned accesses in bounds.
+            unsafe {
+                let d = dst.as_mut_ptr().add(i).cast::<__m512i>();
+                let s = src.as_ptr().add(i).cast::<__m512i>();
+                _mm512_storeu_si512(
+                    d,
+                    _mm512_xor_si512(_mm512_loadu_si512(d), _mm512_loadu_si512(s)),
+                );
+            }
+            i += 64;
+        }
+        super::xor_portable(&mut dst[i..len], &src[i..len]);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arm {
+    use core::arch::aarch64::*;
+
+    #[target_feature(enable = "neon")]
+    pub(super) fn xor_neon(dst: &mut [u8], src: &[u8]) {
+        let len = dst.len().min(src.len());
+        let mut i = 0;
+        while i + 16 <= len {
+            // SAFETY: `i + 16 <= len` keeps both accesses in bounds.
+            unsafe {
+                let d = dst.as_mut_ptr().add(i);
+                let s = src.as_ptr().add(i);
+                vst1q_u8(d, veorq_u8(vld1q_u8(d), vld1q_u8(s)));
+            }
+            i += 16;
+        }
+        super::xor_portable(&mut dst[i..len], &src[i..len]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn dispatched_xor_matches_bytewise_on_every_path() {
+        let a: Vec<u8> = (0..=255u8).chain(0..=200).collect();
+        let b: Vec<u8> = a
+            .iter()
+            .map(|x| x.wrapping_mul(31).wrapping_add(7))
+            .collect();
+        let detected = CpuFeatures::detect();
+        let mut paths = vec![CpuFeatures::PORTABLE, detected];
+        if detected.avx512f {
+            paths.push(CpuFeatures {
+                avx512f: false,
+                ..detected
+            });
+        }
+        for features in paths {
+            features.install();
+            assert_eq!(CpuFeatures::active(), features);
+            for start in [0, 1, 7, 33] {
+                for len in [0, 5, 31, 32, 63, 64, 65, 130, a.len() - start] {
+                    let mut dst = a[start..start + len].to_vec();
+                    xor_in_place(&mut dst, &b[start..start + len]);
+                    let expected: Vec<u8> = a[start..start + len]
+                        .iter()
+                        .zip(&b[start..start + len])
+                        .map(|(x, y)| x ^ y)
+                        .collect();
+                    assert_eq!(dst, expected, "{features:?} start {start} len {len}");
+                }
+            }
+        }
+        detected.install();
+
+        // Features the CPU lacks cannot be switched on.
+        CpuFeatures {
+            avx2: true,
+            avx512f: true,
+            neon: true,
+            adx: true,
+        }
+        .install();
+        assert_eq!(CpuFeatures::active(), detected);
+    }
+}
@@ -7,15 +7,27 @@
 //! The errors implement `core::fmt::Display` and `core::error::Error` so they are
 //! easy to convert and debug in higher-level code.
 //!
+//! Failures reported by the underlying curve libraries are kept as
+//! [`BackendError::Library`], so walking `source()` from an [`Error`] reaches
+//! the arkworks or blstrs error itself. With `std`, these also capture a
+//! backtrace (see [`Error::backtrace`]), subject to `RUST_BACKTRACE`.
+//!
+//! [`Error::code`] maps every error to a stable number for FFI bindings.
+//!
 //! # Examples
 //!
 //! ```rust
 //! use tess::Error;
+//!
+//! let err = Error::InsufficientShares { required: 3, provided: 2 };
+//! assert_eq!(err.code(), 400);
 //! ```
 
-use alloc::string::String;
+use alloc::{boxed::Box, string::String};
 use core::error::Error as CoreError;
 use core::fmt;
+#[cfg(feature = "std")]
+use std::backtrace::Backtrace;
 
 /// Errors bubbled up from backend implementations (Arkworks, blstrs, etc.).
 #[derive(Debug)]
@@ -30,6 +42,17 @@ pub enum BackendError {
     Math(&'static str),
     /// A backend error not covered by more specific variants.
     Other(String),
+    /// The underlying curve library reported an error, available through
+    /// `source()`. Construct with [`BackendError::library`].
+    Library {
+        /// What the backend was doing when the library failed.
+        context: &'static str,
+        /// The library's own error.
+        source: Box<dyn CoreError + Send + Sync>,
+        /// Where the error was wrapped, if backtraces are enabled.
+        #[cfg(feature = "std")]
+        backtrace: Backtrace,
+    },
 }
 
 /// High-level errors returned by the threshold encryption API.
@@ -42,7 +65,7 @@ pub enum Error {
     /// Input data is malformed or fails validation.
     MalformedInput(String),
     /// Not enough shares were provided to meet the threshold.
-    NotEnoughShares {
+    InsufficientShares {
         /// Minimum number of shares required.
         required: usize,
         /// Number of shares provided.
@@ -55,6 +78,20 @@ pub enum Error {
         /// Actual selector length.
         actual: usize,
     },
+    /// A partial decryption came from a participant revoked from the aggregate key.
+    RevokedParticipant {
+        /// Id of the revoked participant.
+        participant_id: usize,
+    },
+    /// Reading or writing persistent storage failed; the I/O error is
+    /// available through `source()`.
+    #[cfg(feature = "std")]
+    Io {
+        /// What was being done, including the path involved.
+        context: String,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
 }
 
 impl fmt::Display for BackendError {
@@ -67,6 +104,90 @@ impl fmt::Display for BackendError {
             BackendError::Serialization(msg) => write!(f, "serialization failure: {msg}"),
             BackendError::Math(msg) => write!(f, "math error: {msg}"),
             BackendError::Other(msg) => write!(f, "{msg}"),
+            BackendError::Library { context, .. } => write!(f, "{context}"),
+        }
+    }
+}
+
+impl BackendError {
+    /// Wraps an error from the underlying curve library, capturing a
+    /// backtrace when `std` is enabled.
+    pub fn library(context: &'static str, source: impl CoreError + Send + Sync + 'static) -> Self {
+        BackendError::Library {
+            context,
+            source: Box::new(source),
+            #[cfg(feature = "std")]
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Backtrace captured when a library error was wrapped.
+    ///
+    /// Only [`BackendError::Library`] carries one, and it is empty unless
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` enables capturing.
+    #[cfg(feature = "std")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            BackendError::Library { backtrace, .. } => Some(backtrace),
+            _ => None,
+        }
+    }
+
+    /// Stable numeric code for this error, as listed under [`Error::code`].
+    pub fn code(&self) -> u32 {
+        match self {
+            BackendError::Other(_) => 200,
+            BackendError::UnsupportedCurve(_) => 201,
+            BackendError::UnsupportedFeature(_) => 202,
+            BackendError::Serialization(_) => 203,
+            BackendError::Math(_) => 204,
+            BackendError::Library { .. } => 205,
+        }
+    }
+}
+
+impl Error {
+    /// Stable numeric code for this error.
+    ///
+    /// FFI bindings should branch on the code and treat the message as
+    /// diagnostic text only. Codes are never reused or renumbered; new
+    /// variants get new codes. Code 0 is reserved for success and never
+    /// returned.
+    ///
+    /// | Code | Error |
+    /// |------|-------|
+    /// | 100 | [`Error::InvalidConfig`] |
+    /// | 200 | [`Error::Backend`] with [`BackendError::Other`] |
+    /// | 201 | [`Error::Backend`] with [`BackendError::UnsupportedCurve`] |
+    /// | 202 | [`Error::Backend`] with [`BackendError::UnsupportedFeature`] |
+    /// | 203 | [`Error::Backend`] with [`BackendError::Serialization`] |
+    /// | 204 | [`Error::Backend`] with [`BackendError::Math`] |
+    /// | 205 | [`Error::Backend`] with [`BackendError::Library`] |
+    /// | 300 | [`Error::MalformedInput`] |
+    /// | 400 | [`Error::InsufficientShares`] |
+    /// | 500 | [`Error::SelectorMismatch`] |
+    /// | 600 | [`Error::RevokedParticipant`] |
+    /// | 700 | `Error::Io` (with `std`) |
+    pub fn code(&self) -> u32 {
+        match self {
+            Error::InvalidConfig(_) => 100,
+            Error::Backend(err) => err.code(),
+            Error::MalformedInput(_) => 300,
+            Error::InsufficientShares { .. } => 400,
+            Error::SelectorMismatch { .. } => 500,
+            Error::RevokedParticipant { .. } => 600,
+            #[cfg(feature = "std")]
+            Error::Io { .. } => 700,
+        }
+    }
+
+    /// Backtrace of the wrapped library error, if there is one; see
+    /// [`BackendError::backtrace`].
+    #[cfg(feature = "std")]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        match self {
+            Error::Backend(err) => err.backtrace(),
+            _ => None,
         }
     }
 }
@@ -77,7 +198,14 @@ impl From<BackendError> for Error {
     }
 }
 
-impl CoreError for BackendError {}
+impl CoreError for BackendError {
+    fn source(&self) -> Option<&(dyn CoreError + 'static)> {
+        match self {
+            BackendError::Library { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -85,7 +213,7 @@ impl fmt::Display for Error {
             Error::InvalidConfig(msg) => write!(f, "invalid configuration: {msg}"),
             Error::Backend(err) => write!(f, "backend error: {err}"),
             Error::MalformedInput(msg) => write!(f, "malformed input: {msg}"),
-            Error::NotEnoughShares { required, provided } => write!(
+            Error::InsufficientShares { required, provided } => write!(
                 f,
                 "insufficient shares: required {required}, provided {provided}"
             ),
@@ -95,6 +223,11 @@ impl fmt::Display for Error {
                     "selector length mismatch: expected {expected}, got {actual}"
                 )
             }
+            Error::RevokedParticipant { participant_id } => {
+                write!(f, "participant {participant_id} has been revoked")
+            }
+            #[cfg(feature = "std")]
+            Error::Io { context, source } => write!(f, "{context}: {source}"),
         }
     }
 }
@@ -103,7 +236,90 @@ impl CoreError for Error {
     fn source(&self) -> Option<&(dyn CoreError + 'static)> {
         match self {
             Error::Backend(err) => Some(err),
+            #[cfg(feature = "std")]
+            Error::Io { source, .. } => Some(source),
             _ => None,
         }
     }
 }
+
+/// Adapts arkworks serialization errors, which implement `ark_std`'s own
+/// error trait, to `core::error::Error` for [`BackendError::library`].
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+pub(crate) struct ArkError(pub(crate) ark_serialize::SerializationError);
+
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+impl fmt::Debug for ArkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+impl fmt::Display for ArkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(any(feature = "ark_bls12381", feature = "ark_bn254"))]
+impl CoreError for ArkError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    // The codes are part of the public contract; changing one breaks bindings.
+    #[test]
+    fn error_codes_are_stable() {
+        let cases = [
+            (Error::InvalidConfig(String::new()), 100),
+            (Error::Backend(BackendError::Other(String::new())), 200),
+            (Error::Backend(BackendError::UnsupportedCurve("")), 201),
+            (Error::Backend(BackendError::UnsupportedFeature("")), 202),
+            (Error::Backend(BackendError::Serialization("")), 203),
+            (Error::Backend(BackendError::Math("")), 204),
+            (Error::Backend(BackendError::library("", fmt::Error)), 205),
+            (Error::MalformedInput(String::new()), 300),
+            (
+                Error::InsufficientShares {
+                    required: 2,
+                    provided: 1,
+                },
+                400,
+            ),
+            (
+                Error::SelectorMismatch {
+                    expected: 2,
+                    actual: 1,
+                },
+                500,
+            ),
+            (Error::RevokedParticipant { participant_id: 1 }, 600),
+            #[cfg(feature = "std")]
+            (
+                Error::Io {
+                    context: String::new(),
+                    source: std::io::ErrorKind::Other.into(),
+                },
+                700,
+            ),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.code(), code, "{err}");
+        }
+    }
+
+    #[test]
+    fn library_errors_are_reachable_through_source() {
+        let err = Error::from(BackendError::library("invalid G1 bytes", fmt::Error));
+        let backend = err.source().expect("backend error");
+        assert_eq!(backend.to_string(), "invalid G1 bytes");
+        let library = backend.source().expect("library error");
+        assert!(library.is::<fmt::Error>());
+        assert!(library.source().is_none());
+        #[cfg(feature = "std")]
+        assert!(err.backtrace().is_some());
+    }
+}
@@ -0,0 +1,660 @@
+//! REST API for a decryption coordinator.
+//!
+//! A [`Coordinator`] accepts ciphertexts, collects partial decryptions from
+//! committee members, and releases the plaintext once enough partials have
+//! arrived. [`router`] exposes it as an [axum](https://docs.rs/axum) service
+//! with JSON bodies, and [`serve`] runs that service on a Tokio listener:
+//!
+//! | Method   | Path                             | Body                         | Response                 |
+//! |----------|----------------------------------|------------------------------|--------------------------|
+//! | `POST`   | `/ciphertexts`                   | [`SubmitCiphertextRequest`]  | [`DecryptionStatus`]     |
+//! | `GET`    | `/ciphertexts/{id}`              |                              | [`DecryptionStatus`]     |
+//! | `DELETE` | `/ciphertexts/{id}`              |                              | [`DecryptionStatus`]     |
+//! | `POST`   | `/ciphertexts/{id}/partials`     | [`SubmitPartialRequest`]     | [`DecryptionStatus`]     |
+//! | `GET`    | `/ciphertexts/{id}/plaintext`    |                              | [`PlaintextResponse`]    |
+//!
+//! Failures are answered with an [`ErrorResponse`] and the status of
+//! [`CoordinatorError::status`]. The request and response types are plain
+//! serde types, so clients can reuse them with any HTTP stack.
+//!
+//! Every partial decryption is checked against the submitting member's public
+//! key before it is stored, and the first valid partial from a member is kept,
+//! so clients cannot displace honest shares. The coordinator tracks at most
+//! [`Coordinator::with_max_pending`] ciphertexts until they are deleted, and
+//! each request must complete within [`Coordinator::with_request_timeout`].
+//! Pairing work runs on Tokio's blocking thread pool.
+
+use alloc::{collections::BTreeMap, format, string::String, sync::Arc, vec::Vec};
+use core::{fmt, time::Duration};
+use std::{io, sync::Mutex};
+
+use axum::{
+    Json, Router,
+    extract::{
+        DefaultBodyLimit, Path, Request, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+
+use crate::{
+    AggregateKey, Ciphertext, Fr, PairingBackend, Params, PartialDecryption, SilentThresholdScheme,
+    ThresholdEncryption, errors::Error, verify_partial_decryption,
+};
+
+/// Largest request body the server accepts (16 MiB).
+const MAX_BODY_LEN: usize = 16 << 20;
+
+/// Default limit on ciphertexts tracked at once.
+pub const DEFAULT_MAX_PENDING: usize = 1024;
+
+/// Default time allowed for reading a request and producing its response.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Body of `POST /ciphertexts`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SubmitCiphertextRequest<B: PairingBackend<Scalar = Fr>> {
+    /// Ciphertext to collect partial decryptions for.
+    pub ciphertext: Ciphertext<B>,
+}
+
+/// Body of `POST /ciphertexts/{id}/partials`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SubmitPartialRequest<B: PairingBackend<Scalar = Fr>> {
+    /// A committee member's partial decryption of the ciphertext.
+    pub partial: PartialDecryption<B>,
+}
+
+/// Progress of a submitted ciphertext.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecryptionStatus {
+    /// Identifier assigned by the coordinator.
+    pub id: u64,
+    /// Number of partials required to decrypt.
+    pub threshold: usize,
+    /// Participants whose partials have been accepted.
+    pub received: Vec<usize>,
+    /// Whether enough partials, including participant 0's, have arrived to
+    /// fetch the plaintext.
+    pub ready: bool,
+}
+
+/// Body of a successful `GET /ciphertexts/{id}/plaintext`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaintextResponse {
+    /// Identifier of the decrypted ciphertext.
+    pub id: u64,
+    /// The recovered plaintext.
+    pub plaintext: Vec<u8>,
+}
+
+/// Body of every failed request.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    /// Human-readable description of the failure.
+    pub error: String,
+}
+
+/// Reasons a [`Coordinator`] operation fails.
+#[derive(Debug)]
+pub enum CoordinatorError {
+    /// No ciphertext with this id is pending.
+    UnknownCiphertext(u64),
+    /// A partial decryption from this participant was already accepted.
+    DuplicatePartial(usize),
+    /// The coordinator already tracks its maximum number of ciphertexts.
+    TooManyPending(usize),
+    /// The scheme rejected the ciphertext or a partial decryption.
+    Scheme(Error),
+}
+
+impl CoordinatorError {
+    /// HTTP status the REST API answers this error with.
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::UnknownCiphertext(_) => StatusCode::NOT_FOUND,
+            Self::DuplicatePartial(_) | Self::Scheme(Error::InsufficientShares { .. }) => {
+                StatusCode::CONFLICT
+            }
+            Self::TooManyPending(_) => StatusCode::SERVICE_UNAVAILABLE,
+            Self::Scheme(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl fmt::Display for CoordinatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCiphertext(id) => write!(f, "unknown ciphertext {id}"),
+            Self::DuplicatePartial(participant_id) => write!(
+                f,
+                "a partial decryption from participant {participant_id} was already accepted"
+            ),
+            Self::TooManyPending(max) => {
+                write!(f, "coordinator already tracks {max} ciphertexts")
+            }
+            Self::Scheme(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl core::error::Error for CoordinatorError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Scheme(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for CoordinatorError {
+    fn from(e: Error) -> Self {
+        Self::Scheme(e)
+    }
+}
+
+impl IntoResponse for CoordinatorError {
+    fn into_response(self) -> Response {
+        error_response(self.status(), format!("{self}"))
+    }
+}
+
+struct PendingDecryption<B: PairingBackend<Scalar = Fr>> {
+    // Shared so partials can be verified without holding the lock.
+    ciphertext: Arc<Ciphertext<B>>,
+    partials: BTreeMap<usize, PartialDecryption<B>>,
+}
+
+struct Pending<B: PairingBackend<Scalar = Fr>> {
+    next_id: u64,
+    entries: BTreeMap<u64, PendingDecryption<B>>,
+}
+
+/// Collects partial decryptions for ciphertexts encrypted to one committee.
+pub struct Coordinator<B: PairingBackend<Scalar = Fr>> {
+    scheme: SilentThresholdScheme<B>,
+    params: Params<B>,
+    aggregate_key: AggregateKey<B>,
+    max_pending: usize,
+    request_timeout: Duration,
+    pending: Mutex<Pending<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Coordinator<B> {
+    /// Creates a coordinator for the committee described by `aggregate_key`.
+    pub fn new(
+        scheme: SilentThresholdScheme<B>,
+        params: Params<B>,
+        aggregate_key: AggregateKey<B>,
+    ) -> Self {
+        Self {
+            scheme,
+            params,
+            aggregate_key,
+            max_pending: DEFAULT_MAX_PENDING,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            pending: Mutex::new(Pending {
+                next_id: 0,
+                entries: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// Sets how many ciphertexts may be pending at once; further submissions
+    /// fail until one is removed.
+    pub fn with_max_pending(mut self, max_pending: usize) -> Self {
+        self.max_pending = max_pending;
+        self
+    }
+
+    /// Sets the time [`router`] allows each request, including reading its
+    /// body.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Verifies and stores `ciphertext`, returning its initial status.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinatorError::TooManyPending`] if the coordinator is
+    /// full, and the error of [`ThresholdEncryption::verify_ciphertext`] if
+    /// the ciphertext is not well formed for this committee.
+    pub fn submit_ciphertext(
+        &self,
+        ciphertext: Ciphertext<B>,
+    ) -> Result<DecryptionStatus, CoordinatorError> {
+        if self.lock().entries.len() >= self.max_pending {
+            return Err(CoordinatorError::TooManyPending(self.max_pending));
+        }
+        self.scheme
+            .verify_ciphertext(&self.aggregate_key, &self.params, &ciphertext)?;
+        let mut pending = self.lock();
+        if pending.entries.len() >= self.max_pending {
+            return Err(CoordinatorError::TooManyPending(self.max_pending));
+        }
+        let id = pending.next_id;
+        pending.next_id += 1;
+        let entry = PendingDecryption {
+            ciphertext: Arc::new(ciphertext),
+            partials: BTreeMap::new(),
+        };
+        let status = self.status_of(id, &entry);
+        pending.entries.insert(id, entry);
+        Ok(status)
+    }
+
+    /// Returns the status of ciphertext `id`.
+    pub fn status(&self, id: u64) -> Result<DecryptionStatus, CoordinatorError> {
+        let pending = self.lock();
+        let entry = pending
+            .entries
+            .get(&id)
+            .ok_or(CoordinatorError::UnknownCiphertext(id))?;
+        Ok(self.status_of(id, entry))
+    }
+
+    /// Stops tracking ciphertext `id`, returning its final status.
+    pub fn remove(&self, id: u64) -> Result<DecryptionStatus, CoordinatorError> {
+        let entry = self
+            .lock()
+            .entries
+            .remove(&id)
+            .ok_or(CoordinatorError::UnknownCiphertext(id))?;
+        Ok(self.status_of(id, &entry))
+    }
+
+    /// Verifies and records a committee member's partial decryption of
+    /// ciphertext `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinatorError::UnknownCiphertext`] if `id` is not pending,
+    /// [`CoordinatorError::DuplicatePartial`] if the participant already
+    /// submitted a valid partial, and [`Error::RevokedParticipant`] or
+    /// [`Error::MalformedInput`] if the participant is revoked, not a member,
+    /// or the partial does not verify against its public key.
+    pub fn submit_partial(
+        &self,
+        id: u64,
+        partial: PartialDecryption<B>,
+    ) -> Result<DecryptionStatus, CoordinatorError> {
+        let participant_id = partial.participant_id;
+        let public_key = self
+            .aggregate_key
+            .public_keys
+            .get(participant_id)
+            .ok_or_else(|| {
+                Error::MalformedInput(format!(
+                    "participant {participant_id} is not a committee member"
+                ))
+            })?;
+        if self.is_revoked(participant_id) {
+            return Err(Error::RevokedParticipant { participant_id }.into());
+        }
+        let ciphertext = {
+            let pending = self.lock();
+            let entry = pending
+                .entries
+                .get(&id)
+                .ok_or(CoordinatorError::UnknownCiphertext(id))?;
+            if entry.partials.contains_key(&participant_id) {
+                return Err(CoordinatorError::DuplicatePartial(participant_id));
+            }
+            Arc::clone(&entry.ciphertext)
+        };
+        // The pairing check runs unlocked so other requests are not held up.
+        if !verify_partial_decryption(public_key, &ciphertext, &partial) {
+            return Err(Error::MalformedInput(format!(
+                "partial decryption from participant {participant_id} does not verify"
+            ))
+            .into());
+        }
+
+        // The entry may have been removed, or the participant may have raced
+        // another submission, while the lock was released.
+        let mut pending = self.lock();
+        let entry = pending
+            .entries
+            .get_mut(&id)
+            .ok_or(CoordinatorError::UnknownCiphertext(id))?;
+        if entry.partials.contains_key(&participant_id) {
+            return Err(CoordinatorError::DuplicatePartial(participant_id));
+        }
+        entry.partials.insert(participant_id, partial);
+        Ok(self.status_of(id, entry))
+    }
+
+    /// Combines the collected partials and returns the plaintext of `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoordinatorError::UnknownCiphertext`] if `id` is not pending,
+    /// [`Error::InsufficientShares`] until enough partials have arrived, and
+    /// any error of [`ThresholdEncryption::aggregate_decrypt`].
+    pub fn plaintext(&self, id: u64) -> Result<Vec<u8>, CoordinatorError> {
+        let (ciphertext, partials) = {
+            let pending = self.lock();
+            let entry = pending
+                .entries
+                .get(&id)
+                .ok_or(CoordinatorError::UnknownCiphertext(id))?;
+            let partials: Vec<_> = entry.partials.values().cloned().collect();
+            (Arc::clone(&entry.ciphertext), partials)
+        };
+        // Revoked slots contribute public zero shares without a partial.
+        let mut selector = vec![false; self.aggregate_key.public_keys.len()];
+        for &participant_id in &self.aggregate_key.revoked {
+            selector[participant_id] = true;
+        }
+        for partial in &partials {
+            selector[partial.participant_id] = true;
+        }
+        let result = self.scheme.aggregate_decrypt(
+            &ciphertext,
+            &partials,
+            &selector,
+            &self.aggregate_key,
+        )?;
+        result
+            .plaintext
+            .ok_or_else(|| Error::MalformedInput("decryption produced no plaintext".into()).into())
+    }
+
+    fn status_of(&self, id: u64, entry: &PendingDecryption<B>) -> DecryptionStatus {
+        let anchored = entry.partials.contains_key(&0) || self.is_revoked(0);
+        DecryptionStatus {
+            id,
+            threshold: entry.ciphertext.threshold,
+            received: entry.partials.keys().copied().collect(),
+            ready: anchored
+                && entry.partials.len() + self.aggregate_key.revoked.len()
+                    >= entry.ciphertext.threshold,
+        }
+    }
+
+    fn is_revoked(&self, participant_id: usize) -> bool {
+        self.aggregate_key
+            .revoked
+            .binary_search(&participant_id)
+            .is_ok()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Pending<B>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Builds the axum service exposing `coordinator`'s REST API.
+pub fn router<B: PairingBackend<Scalar = Fr>>(coordinator: Arc<Coordinator<B>>) -> Router {
+    let timeout = coordinator.request_timeout;
+    Router::new()
+        .route("/ciphertexts", post(submit_ciphertext::<B>))
+        .route("/ciphertexts/{id}", get(status::<B>).delete(remove::<B>))
+        .route("/ciphertexts/{id}/partials", post(submit_partial::<B>))
+        .route("/ciphertexts/{id}/plaintext", get(plaintext::<B>))
+        .fallback(|| async { error_response(StatusCode::NOT_FOUND, "no such endpoint".into()) })
+        .layer(DefaultBodyLimit::max(MAX_BODY_LEN))
+        .layer(middleware::from_fn(move |request: Request, next: Next| {
+            request_with_timeout(timeout, request, next)
+        }))
+        .with_state(coordinator)
+}
+
+/// Serves [`router`] on `listener` until the server fails.
+pub async fn serve<B: PairingBackend<Scalar = Fr>>(
+    coordinator: Arc<Coordinator<B>>,
+    listener: TcpListener,
+) -> io::Result<()> {
+    axum::serve(listener, router(coordinator)).await
+}
+
+async fn request_with_timeout(timeout: Duration, request: Request, next: Next) -> Response {
+    match tokio::time::timeout(timeout, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => error_response(StatusCode::REQUEST_TIMEOUT, "request timed out".into()),
+    }
+}
+
+/// Runs a coordinator call on the blocking pool, since it may do pairings.
+async fn run<B, T, F>(coordinator: Arc<Coordinator<B>>, call: F) -> Response
+where
+    B: PairingBackend<Scalar = Fr>,
+    T: Serialize,
+    F: FnOnce(&Coordinator<B>) -> Result<T, CoordinatorError> + Send + 'static,
+    T: Send + 'static,
+{
+    match tokio::task::spawn_blocking(move || call(&coordinator)).await {
+        Ok(Ok(value)) => Json(value).into_response(),
+        Ok(Err(e)) => e.into_response(),
+        Err(e) => error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("coordinator task failed: {e}"),
+        ),
+    }
+}
+
+async fn submit_ciphertext<B: PairingBackend<Scalar = Fr>>(
+    State(coordinator): State<Arc<Coordinator<B>>>,
+    body: Result<Json<SubmitCiphertextRequest<B>>, JsonRejection>,
+) -> Response {
+    match body {
+        Ok(Json(request)) => {
+            run(coordinator, move |c| {
+                c.submit_ciphertext(request.ciphertext)
+            })
+            .await
+        }
+        Err(rejection) => rejection_response(&rejection),
+    }
+}
+
+async fn status<B: PairingBackend<Scalar = Fr>>(
+    State(coordinator): State<Arc<Coordinator<B>>>,
+    id: Result<Path<u64>, PathRejection>,
+) -> Response {
+    match id {
+        Ok(Path(id)) => run(coordinator, move |c| c.status(id)).await,
+        Err(_) => unknown_path(),
+    }
+}
+
+async fn remove<B: PairingBackend<Scalar = Fr>>(
+    State(coordinator): State<Arc<Coordinator<B>>>,
+    id: Result<Path<u64>, PathRejection>,
+) -> Response {
+    match id {
+        Ok(Path(id)) => run(coordinator, move |c| c.remove(id)).await,
+        Err(_) => unknown_path(),
+    }
+}
+
+async fn submit_partial<B: PairingBackend<Scalar = Fr>>(
+    State(coordinator): State<Arc<Coordinator<B>>>,
+    id: Result<Path<u64>, PathRejection>,
+    body: Result<Json<SubmitPartialRequest<B>>, JsonRejection>,
+) -> Response {
+    match (id, body) {
+        (Err(_), _) => unknown_path(),
+        (_, Err(rejection)) => rejection_response(&rejection),
+        (Ok(Path(id)), Ok(Json(request))) => {
+            run(coordinator, move |c| c.submit_partial(id, request.partial)).await
+        }
+    }
+}
+
+async fn plaintext<B: PairingBackend<Scalar = Fr>>(
+    State(coordinator): State<Arc<Coordinator<B>>>,
+    id: Result<Path<u64>, PathRejection>,
+) -> Response {
+    match id {
+        Ok(Path(id)) => {
+            run(coordinator, move |c| {
+                c.plaintext(id)
+                    .map(|plaintext| PlaintextResponse { id, plaintext })
+            })
+            .await
+        }
+        Err(_) => unknown_path(),
+    }
+}
+
+fn unknown_path() -> Response {
+    error_response(StatusCode::NOT_FOUND, "no such ciphertext".into())
+}
+
+fn rejection_response(rejection: &JsonRejection) -> Response {
+    error_response(
+        rejection.status(),
+        format!("invalid request body: {}", rejection.body_text()),
+    )
+}
+
+fn error_response(status: StatusCode, error: String) -> Response {
+    (status, Json(ErrorResponse { error })).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{Body, to_bytes};
+    use rand::{SeedableRng, rngs::StdRng};
+    use tower::ServiceExt;
+
+    use crate::{CurvePoint, PairingEngine};
+
+    /// Sends one request through the router and returns status and body.
+    fn call(app: &Router, method: &str, uri: &str, body: Vec<u8>) -> (StatusCode, Vec<u8>) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), MAX_BODY_LEN).await.unwrap();
+            (status, body.to_vec())
+        })
+    }
+
+    #[test]
+    fn coordinator_routes_rest_requests() {
+        let mut rng = StdRng::seed_from_u64(1439);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"over http")
+            .unwrap();
+        let partials: Vec<_> = keys
+            .secret_keys
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let coordinator = Coordinator::new(scheme, params, keys.aggregate_key.clone());
+        let app = router(Arc::new(coordinator.with_max_pending(1)));
+        let partial_body = |partial: &PartialDecryption<PairingEngine>| {
+            serde_json::to_vec(&SubmitPartialRequest {
+                partial: partial.clone(),
+            })
+            .unwrap()
+        };
+
+        let body = serde_json::to_vec(&SubmitCiphertextRequest { ciphertext: ct }).unwrap();
+        let (status, response) = call(&app, "POST", "/ciphertexts", body.clone());
+        assert_eq!(status, StatusCode::OK);
+        let submitted: DecryptionStatus = serde_json::from_slice(&response).unwrap();
+        assert!(!submitted.ready);
+        // The pending cap rejects a second ciphertext.
+        let (status, _) = call(&app, "POST", "/ciphertexts", body);
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+
+        let (status, _) = call(&app, "GET", "/ciphertexts/0/plaintext", Vec::new());
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        // A share relabelled with another member's id fails verification.
+        let mut forged = partials[2].clone();
+        forged.participant_id = 1;
+        let (status, _) = call(
+            &app,
+            "POST",
+            "/ciphertexts/0/partials",
+            partial_body(&forged),
+        );
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        for partial in &partials[1..] {
+            let (status, _) = call(
+                &app,
+                "POST",
+                "/ciphertexts/0/partials",
+                partial_body(partial),
+            );
+            assert_eq!(status, StatusCode::OK);
+        }
+        // A resubmission cannot replace an accepted share.
+        let mut replaced = partials[1].clone();
+        replaced.response = replaced.response.add(&replaced.response);
+        let (status, _) = call(
+            &app,
+            "POST",
+            "/ciphertexts/0/partials",
+            partial_body(&replaced),
+        );
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (_, response) = call(&app, "GET", "/ciphertexts/0", Vec::new());
+        let current: DecryptionStatus = serde_json::from_slice(&response).unwrap();
+        assert_eq!(current.received, vec![1, 2, 3]);
+        // Participant 0 anchors interpolation and is always required.
+        assert!(!current.ready);
+
+        let (_, response) = call(
+            &app,
+            "POST",
+            "/ciphertexts/0/partials",
+            partial_body(&partials[0]),
+        );
+        let current: DecryptionStatus = serde_json::from_slice(&response).unwrap();
+        assert!(current.ready);
+
+        let (status, response) = call(&app, "GET", "/ciphertexts/0/plaintext", Vec::new());
+        assert_eq!(status, StatusCode::OK);
+        let opened: PlaintextResponse = serde_json::from_slice(&response).unwrap();
+        assert_eq!(opened.plaintext, b"over http");
+
+        assert_eq!(
+            call(&app, "GET", "/ciphertexts/7", Vec::new()).0,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            call(&app, "POST", "/ciphertexts", b"{}".to_vec()).0,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            call(&app, "DELETE", "/ciphertexts/0", Vec::new()).0,
+            StatusCode::OK
+        );
+        assert_eq!(
+            call(&app, "GET", "/ciphertexts/0", Vec::new()).0,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            call(&app, "PUT", "/nowhere", Vec::new()).0,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
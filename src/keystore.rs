@@ -0,0 +1,560 @@
+//! Persistent storage for key material.
+//!
+//! [`KeyStore`] stores secret keys, public keys, and aggregate keys under
+//! caller-chosen names. Implementors only provide raw record storage
+//! ([`KeyStore::write_record`], [`KeyStore::read_record`],
+//! [`KeyStore::delete_record`]); the typed accessors encode every value as a
+//! versioned record so that future format changes can be detected on load.
+//! Versions are bumped per record kind, and older versions of a kind are still
+//! read.
+//!
+//! [`FsKeyStore`] keeps one file per record under a root directory:
+//!
+//! ```text
+//! <root>/secret/<name>.key
+//! <root>/public/<name>.key
+//! <root>/aggregate/<name>.key
+//! ```
+//!
+//! Records are written to a temporary file, flushed with `fsync`, and renamed
+//! over the destination, so a crash leaves either the old or the new record
+//! and never a torn one. Secret key files are created with mode `0600` on Unix.
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{FsKeyStore, KeyStore, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//!
+//! let root = std::env::temp_dir().join(format!("tess-keystore-doc-{}", std::process::id()));
+//! let store = FsKeyStore::open(&root).unwrap();
+//! store.put_secret_key("node-0", &keys.secret_keys[0]).unwrap();
+//! let loaded = store.get_secret_key::<PairingEngine>("node-0").unwrap().unwrap();
+//! assert_eq!(loaded.scalar, keys.secret_keys[0].scalar);
+//! # std::fs::remove_dir_all(&root).unwrap();
+//! ```
+
+use alloc::{format, vec::Vec};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use zeroize::Zeroize;
+
+use crate::{
+    AggregateKey, Fr, PairingBackend, PublicKey, SecretKey,
+    codec::{Reader, put_aggregate_key, put_public_key, put_revoked, put_secret_key},
+    errors::Error,
+};
+
+const RECORD_MAGIC: &[u8; 7] = b"TESSKEY";
+
+/// Newest record encoding version written by this release.
+///
+/// Aggregate key records gained the list of revoked participants in version 2
+/// and version 1 records load with none revoked. Secret and public key
+/// records are unchanged and still written as version 1.
+pub const RECORD_VERSION: u8 = 2;
+
+/// Kind of key material held by a record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RecordKind {
+    /// A participant's [`SecretKey`].
+    SecretKey,
+    /// A participant's [`PublicKey`].
+    PublicKey,
+    /// A committee's [`AggregateKey`].
+    AggregateKey,
+}
+
+impl RecordKind {
+    fn tag(self) -> u8 {
+        match self {
+            Self::SecretKey => 1,
+            Self::PublicKey => 2,
+            Self::AggregateKey => 3,
+        }
+    }
+
+    /// Version written for this kind; every version from 1 up to it is read.
+    fn version(self) -> u8 {
+        match self {
+            Self::SecretKey | Self::PublicKey => 1,
+            Self::AggregateKey => RECORD_VERSION,
+        }
+    }
+
+    fn dir(self) -> &'static str {
+        match self {
+            Self::SecretKey => "secret",
+            Self::PublicKey => "public",
+            Self::AggregateKey => "aggregate",
+        }
+    }
+}
+
+/// Named, versioned storage for key material.
+pub trait KeyStore {
+    /// Durably stores `record` under `name`, replacing any previous record.
+    fn write_record(&self, kind: RecordKind, name: &str, record: &[u8]) -> Result<(), Error>;
+
+    /// Returns the record stored under `name`, if any.
+    fn read_record(&self, kind: RecordKind, name: &str) -> Result<Option<Vec<u8>>, Error>;
+
+    /// Removes the record stored under `name`; removing a missing record is
+    /// not an error.
+    fn delete_record(&self, kind: RecordKind, name: &str) -> Result<(), Error>;
+
+    /// Stores a participant's secret key.
+    fn put_secret_key<B: PairingBackend>(
+        &self,
+        name: &str,
+        secret_key: &SecretKey<B>,
+    ) -> Result<(), Error> {
+        let mut record = record_header(RecordKind::SecretKey);
+        put_secret_key(&mut record, secret_key);
+        let written = self.write_record(RecordKind::SecretKey, name, &record);
+        record.zeroize();
+        written
+    }
+
+    /// Loads a participant's secret key.
+    fn get_secret_key<B: PairingBackend>(&self, name: &str) -> Result<Option<SecretKey<B>>, Error> {
+        let Some(mut record) = self.read_record(RecordKind::SecretKey, name)? else {
+            return Ok(None);
+        };
+        let decoded = open_record(RecordKind::SecretKey, &record).and_then(|(_, mut reader)| {
+            let secret_key = reader.secret_key::<B>()?;
+            reader.finish()?;
+            Ok(secret_key)
+        });
+        record.zeroize();
+        decoded.map(Some)
+    }
+
+    /// Stores a participant's public key.
+    fn put_public_key<B: PairingBackend>(
+        &self,
+        name: &str,
+        public_key: &PublicKey<B>,
+    ) -> Result<(), Error> {
+        let mut record = record_header(RecordKind::PublicKey);
+        put_public_key(&mut record, public_key);
+        self.write_record(RecordKind::PublicKey, name, &record)
+    }
+
+    /// Loads a participant's public key.
+    fn get_public_key<B: PairingBackend>(&self, name: &str) -> Result<Option<PublicKey<B>>, Error> {
+        let Some(record) = self.read_record(RecordKind::PublicKey, name)? else {
+            return Ok(None);
+        };
+        let (_, mut reader) = open_record(RecordKind::PublicKey, &record)?;
+        let public_key = reader.public_key::<B>()?;
+        reader.finish()?;
+        Ok(Some(public_key))
+    }
+
+    /// Stores a committee's aggregate key.
+    fn put_aggregate_key<B: PairingBackend<Scalar = Fr>>(
+        &self,
+        name: &str,
+        aggregate_key: &AggregateKey<B>,
+    ) -> Result<(), Error> {
+        let mut record = record_header(RecordKind::AggregateKey);
+        put_aggregate_key(&mut record, aggregate_key);
+        put_revoked(&mut record, &aggregate_key.revoked);
+        self.write_record(RecordKind::AggregateKey, name, &record)
+    }
+
+    /// Loads a committee's aggregate key.
+    fn get_aggregate_key<B: PairingBackend<Scalar = Fr>>(
+        &self,
+        name: &str,
+    ) -> Result<Option<AggregateKey<B>>, Error> {
+        let Some(record) = self.read_record(RecordKind::AggregateKey, name)? else {
+            return Ok(None);
+        };
+        let (version, mut reader) = open_record(RecordKind::AggregateKey, &record)?;
+        let mut aggregate_key = reader.aggregate_key::<B>()?;
+        if version >= 2 {
+            aggregate_key.revoked = reader.revoked()?;
+        }
+        reader.finish()?;
+        Ok(Some(aggregate_key))
+    }
+}
+
+fn record_header(kind: RecordKind) -> Vec<u8> {
+    let mut record = RECORD_MAGIC.to_vec();
+    record.push(kind.version());
+    record.push(kind.tag());
+    record
+}
+
+/// Checks the record header and returns its version and a reader over the
+/// body.
+fn open_record(kind: RecordKind, record: &[u8]) -> Result<(u8, Reader<'_>), Error> {
+    let body = record
+        .strip_prefix(RECORD_MAGIC.as_slice())
+        .ok_or_else(|| Error::MalformedInput("not a key store record".into()))?;
+    match body {
+        [version, ..] if !(1..=kind.version()).contains(version) => Err(Error::MalformedInput(
+            format!("unsupported {kind:?} record version {version}"),
+        )),
+        [version, tag, rest @ ..] if *tag == kind.tag() => Ok((*version, Reader(rest))),
+        [_, _, ..] => Err(Error::MalformedInput(format!(
+            "record does not hold a {kind:?}"
+        ))),
+        _ => Err(Error::MalformedInput("truncated key store record".into())),
+    }
+}
+
+/// [`KeyStore`] backed by a directory on the local filesystem.
+#[derive(Clone, Debug)]
+pub struct FsKeyStore {
+    root: PathBuf,
+}
+
+impl FsKeyStore {
+    /// Opens the store rooted at `root`, creating the directory if needed.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, Error> {
+        let root = root.into();
+        fs::create_dir_all(&root).map_err(|e| io_error("create key store", &root, e))?;
+        Ok(Self { root })
+    }
+
+    /// The store's root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn record_path(&self, kind: RecordKind, name: &str) -> Result<PathBuf, Error> {
+        let valid = !name.is_empty()
+            && !name.starts_with('.')
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'));
+        if !valid {
+            return Err(Error::InvalidConfig(format!(
+                "invalid key store name {name:?}: use ASCII letters, digits, '-', '_' and '.'"
+            )));
+        }
+        Ok(self.root.join(kind.dir()).join(format!("{name}.key")))
+    }
+}
+
+impl KeyStore for FsKeyStore {
+    fn write_record(&self, kind: RecordKind, name: &str, record: &[u8]) -> Result<(), Error> {
+        let path = self.record_path(kind, name)?;
+        let dir = self.root.join(kind.dir());
+        fs::create_dir_all(&dir).map_err(|e| io_error("create", &dir, e))?;
+        write_file(&path, record, kind == RecordKind::SecretKey)
+    }
+
+    fn read_record(&self, kind: RecordKind, name: &str) -> Result<Option<Vec<u8>>, Error> {
+        let path = self.record_path(kind, name)?;
+        match fs::read(&path) {
+            Ok(record) => Ok(Some(record)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error("read", &path, e)),
+        }
+    }
+
+    fn delete_record(&self, kind: RecordKind, name: &str) -> Result<(), Error> {
+        let path = self.record_path(kind, name)?;
+        match fs::remove_file(&path) {
+            Ok(()) => sync_dir(&self.root.join(kind.dir())).map_err(|e| io_error("sync", &path, e)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(io_error("delete", &path, e)),
+        }
+    }
+}
+
+/// Atomically replaces `path` with `bytes`, readable only by the owner if
+/// `secret` is set.
+///
+/// The bytes go to a temporary sibling first, synced and then renamed over
+/// `path`, and the directory is synced, so a crash leaves either the old or
+/// the new contents.
+pub(crate) fn write_file(path: &Path, bytes: &[u8], secret: bool) -> Result<(), Error> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let name = path
+        .file_name()
+        .ok_or_else(|| Error::InvalidConfig(format!("{} is not a file path", path.display())))?;
+
+    // A fresh random name per write, opened with `create_new`, so concurrent
+    // writers never share a temporary file and the mode below always applies.
+    let tmp = dir.join(format!(
+        ".{}.{:016x}.tmp",
+        name.to_string_lossy(),
+        rand::random::<u64>()
+    ));
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        if secret {
+            options.mode(0o600);
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = secret;
+
+    let mut file = options
+        .open(&tmp)
+        .map_err(|e| io_error("create", &tmp, e))?;
+    let written = file
+        .write_all(bytes)
+        .and_then(|()| file.sync_all())
+        .and_then(|()| fs::rename(&tmp, path));
+    drop(file);
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp);
+        return Err(io_error("write", path, e));
+    }
+    sync_dir(dir).map_err(|e| io_error("sync", dir, e))
+}
+
+/// Flushes directory entries so a completed rename survives a crash.
+fn sync_dir(dir: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        File::open(dir)?.sync_all()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+        Ok(())
+    }
+}
+
+pub(crate) fn io_error(action: &str, path: &Path, source: std::io::Error) -> Error {
+    Error::Io {
+        context: format!("failed to {action} {}", path.display()),
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn fs_key_store_roundtrips_versioned_records() {
+        let mut rng = StdRng::seed_from_u64(1441);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+
+        let root = std::env::temp_dir().join(format!("tess-keystore-{}", std::process::id()));
+        let store = FsKeyStore::open(&root).unwrap();
+        store
+            .put_secret_key("node-1", &keys.secret_keys[1])
+            .unwrap();
+        store
+            .put_public_key("node-1", &keys.public_keys[1])
+            .unwrap();
+        store
+            .put_aggregate_key("committee", &keys.aggregate_key)
+            .unwrap();
+
+        let secret_key = store
+            .get_secret_key::<PairingEngine>("node-1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(secret_key.participant_id, 1);
+        assert_eq!(secret_key.scalar, keys.secret_keys[1].scalar);
+        let public_key = store
+            .get_public_key::<PairingEngine>("node-1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(public_key.bls_key, keys.public_keys[1].bls_key);
+        let aggregate_key = store
+            .get_aggregate_key::<PairingEngine>("committee")
+            .unwrap()
+            .unwrap();
+
+        // The reloaded aggregate key still encrypts to the committee.
+        let ct = scheme
+            .encrypt(&mut rng, &aggregate_key, &params, 2, b"stored")
+            .unwrap();
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &[true, true, true, false], &aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"stored");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(root.join("secret/node-1.key"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // Wrong kind, unknown version, bad names, and missing records.
+        let record = store
+            .read_record(RecordKind::PublicKey, "node-1")
+            .unwrap()
+            .unwrap();
+        store
+            .write_record(RecordKind::SecretKey, "swapped", &record)
+            .unwrap();
+        assert!(store.get_secret_key::<PairingEngine>("swapped").is_err());
+        let mut future = record.clone();
+        future[RECORD_MAGIC.len()] = RECORD_VERSION + 1;
+        store
+            .write_record(RecordKind::PublicKey, "future", &future)
+            .unwrap();
+        assert!(store.get_public_key::<PairingEngine>("future").is_err());
+        assert!(
+            store
+                .put_secret_key("../escape", &keys.secret_keys[0])
+                .is_err()
+        );
+        assert!(
+            store
+                .get_public_key::<PairingEngine>("missing")
+                .unwrap()
+                .is_none()
+        );
+
+        store
+            .delete_record(RecordKind::SecretKey, "node-1")
+            .unwrap();
+        store
+            .delete_record(RecordKind::SecretKey, "node-1")
+            .unwrap();
+        assert!(
+            store
+                .get_secret_key::<PairingEngine>("node-1")
+                .unwrap()
+                .is_none()
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn version_1_records_still_load() {
+        let mut rng = StdRng::seed_from_u64(1464);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let root = std::env::temp_dir().join(format!("tess-keystore-v1-{}", std::process::id()));
+        let store = FsKeyStore::open(&root).unwrap();
+
+        // Secret key records keep the version 1 encoding.
+        store
+            .put_secret_key("node-0", &keys.secret_keys[0])
+            .unwrap();
+        let record = store
+            .read_record(RecordKind::SecretKey, "node-0")
+            .unwrap()
+            .unwrap();
+        assert_eq!(record[RECORD_MAGIC.len()], 1);
+
+        // A version 1 aggregate key record has no revoked list.
+        let mut v1 = RECORD_MAGIC.to_vec();
+        v1.extend_from_slice(&[1, RecordKind::AggregateKey.tag()]);
+        put_aggregate_key(&mut v1, &keys.aggregate_key);
+        store
+            .write_record(RecordKind::AggregateKey, "v1", &v1)
+            .unwrap();
+        let loaded = store
+            .get_aggregate_key::<PairingEngine>("v1")
+            .unwrap()
+            .unwrap();
+        assert!(loaded.revoked.is_empty());
+
+        let revoked =
+            AggregateKey::aggregate_keys_with_revocations(&keys.public_keys, &params, 4, &[2])
+                .unwrap();
+        store.put_aggregate_key("v2", &revoked).unwrap();
+        let loaded = store
+            .get_aggregate_key::<PairingEngine>("v2")
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.revoked, [2]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn concurrent_writers_use_private_temp_files() {
+        let root = std::env::temp_dir().join(format!("tess-keystore-tmp-{}", std::process::id()));
+        let store = FsKeyStore::open(&root).unwrap();
+        let dir = root.join(RecordKind::SecretKey.dir());
+        fs::create_dir_all(&dir).unwrap();
+
+        // A world-readable leftover with the old fixed temp name is ignored.
+        fs::write(
+            dir.join(format!(".shared.{}.tmp", std::process::id())),
+            b"stale",
+        )
+        .unwrap();
+
+        std::thread::scope(|scope| {
+            for writer in 0..8u8 {
+                let store = &store;
+                scope.spawn(move || {
+                    for _ in 0..16 {
+                        store
+                            .write_record(RecordKind::SecretKey, "shared", &[writer; 64])
+                            .unwrap();
+                    }
+                });
+            }
+        });
+        let record = store
+            .read_record(RecordKind::SecretKey, "shared")
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.len(), 64);
+        assert!(record.iter().all(|byte| *byte == record[0]));
+        let leftovers = fs::read_dir(&dir)
+            .unwrap()
+            .filter(|entry| {
+                let name = entry.as_ref().unwrap().file_name();
+                name.to_string_lossy().ends_with(".tmp")
+            })
+            .count();
+        assert_eq!(leftovers, 1);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dir.join("shared.key"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        // I/O failures keep the underlying error.
+        fs::remove_dir_all(&root).unwrap();
+        fs::write(&root, b"not a directory").unwrap();
+        let err = store
+            .write_record(RecordKind::SecretKey, "shared", &[0; 64])
+            .unwrap_err();
+        assert_eq!(err.code(), 700);
+        let source = core::error::Error::source(&err).expect("I/O source");
+        assert!(source.is::<std::io::Error>());
+        fs::remove_file(&root).unwrap();
+    }
+}
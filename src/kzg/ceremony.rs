@@ -0,0 +1,552 @@
+//! Powers of Tau ceremony run by the committee itself.
+//!
+//! Instead of importing a public ceremony output or trusting one dealer, the
+//! committee can produce the [`SRS`] with a sequential MPC: every member in
+//! turn raises the current powers to a fresh secret `s`, so the final `τ` is
+//! the product of all contributions and stays unknown as long as a single
+//! contributor discards their `s`.
+//!
+//! [`KeyCeremony`] is the coordinator. Each round it hands out a
+//! [`CeremonyChallenge`] to the next participant, who answers with
+//! [`CeremonyChallenge::contribute`]. [`KeyCeremony::submit`] checks the
+//! contribution before accepting it:
+//!
+//! - the new powers are consecutive powers of one `τ'` (see [`SRS::verify`]);
+//! - `τ'·g = s·(τ·g)` for the published `s·g`, via
+//!   `e(τ'·g, h) = e(s·g, τ·h)`;
+//! - the contributor knows `s`: a BLS signature `s·H(d)` on the challenge
+//!   digest `d` verifies against `s·g`.
+//!
+//! Accepted contributions are appended to a [`CeremonyTranscript`] recording
+//! `s·g`, the proof of knowledge and the resulting `τ'·g` and `τ'·h`. Anyone
+//! holding the final SRS can replay the transcript with
+//! [`CeremonyTranscript::verify`] to confirm who contributed and that the SRS
+//! is the result of exactly those updates.
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{KeyCeremony, PairingEngine};
+//!
+//! let mut rng = thread_rng();
+//! let mut ceremony = KeyCeremony::<PairingEngine>::new(16, 3).unwrap();
+//! while let Some(challenge) = ceremony.challenge() {
+//!     // Sent to participant `challenge.participant_id`, who answers with:
+//!     let contribution = challenge.contribute(&mut rng).unwrap();
+//!     ceremony.submit(contribution).unwrap();
+//! }
+//! let (srs, transcript) = ceremony.finish().unwrap();
+//! transcript.verify(&srs).unwrap();
+//! ```
+
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::scheme::wipe_scalars;
+use crate::{CurvePoint, FieldElement, Fr, PairingBackend, SRS, TargetGroup, errors::Error};
+
+/// Domain separation tag for contributors' proofs of knowledge.
+const CEREMONY_POK_DST: &[u8] = b"TESS-CEREMONY-V01-POK-G2";
+
+/// State of a [`KeyCeremony`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CeremonyPhase {
+    /// Waiting for the contribution of `next`.
+    Contributing {
+        /// Participant whose turn it is.
+        next: usize,
+    },
+    /// Every participant has contributed or been skipped.
+    Complete,
+}
+
+/// What the coordinator sends to the participant whose turn it is.
+#[derive(Clone, Debug)]
+pub struct CeremonyChallenge<B: PairingBackend<Scalar = Fr>> {
+    /// Participant expected to answer.
+    pub participant_id: usize,
+    /// Digest of the transcript so far, signed by the contributor.
+    pub digest: [u8; 32],
+    /// Current powers to update.
+    pub srs: SRS<B>,
+}
+
+/// A participant's update of the powers.
+#[derive(Clone, Debug)]
+pub struct CeremonyContribution<B: PairingBackend<Scalar = Fr>> {
+    /// Contributing participant.
+    pub participant_id: usize,
+    /// Updated powers `s^i·τ^i·g`.
+    pub powers_of_g: Vec<B::G1>,
+    /// Updated powers `s^i·τ^i·h`.
+    pub powers_of_h: Vec<B::G2>,
+    /// `s·g`.
+    pub public_key: B::G1,
+    /// Proof of knowledge of `s`: `s·H(digest)` in G2.
+    pub proof: B::G2,
+}
+
+/// A contribution as recorded in the transcript.
+#[derive(Clone, Debug)]
+pub struct ContributionRecord<B: PairingBackend> {
+    /// Contributing participant.
+    pub participant_id: usize,
+    /// `s·g`.
+    pub public_key: B::G1,
+    /// Proof of knowledge of `s`.
+    pub proof: B::G2,
+    /// `τ·g` after this contribution.
+    pub tau_g1: B::G1,
+    /// `τ·h` after this contribution.
+    pub tau_g2: B::G2,
+}
+
+/// Verifiable record of a ceremony, published alongside its [`SRS`].
+#[derive(Clone, Debug)]
+pub struct CeremonyTranscript<B: PairingBackend> {
+    /// Degree of the SRS the ceremony produced.
+    pub max_degree: usize,
+    /// `τ·g` of the SRS the ceremony started from.
+    pub initial_tau_g1: B::G1,
+    /// `τ·h` of the SRS the ceremony started from.
+    pub initial_tau_g2: B::G2,
+    /// Accepted contributions in order.
+    pub contributions: Vec<ContributionRecord<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> CeremonyChallenge<B> {
+    /// Updates the powers with a fresh secret drawn from `rng`.
+    ///
+    /// The secret is wiped before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the challenge holds no powers.
+    pub fn contribute<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<CeremonyContribution<B>, Error> {
+        if self.srs.max_degree() == 0 {
+            return Err(Error::MalformedInput("ceremony challenge is empty".into()));
+        }
+        let mut secret = Fr::random(rng);
+        while secret == Fr::zero() {
+            secret = Fr::random(rng);
+        }
+        let mut powers = Vec::with_capacity(self.srs.powers_of_g.len());
+        let mut cur = Fr::one();
+        for _ in 0..self.srs.powers_of_g.len() {
+            powers.push(cur);
+            cur *= &secret;
+        }
+
+        let (powers_of_g, powers_of_h) = {
+            #[cfg(feature = "parallel")]
+            {
+                (
+                    self.srs
+                        .powers_of_g
+                        .par_iter()
+                        .zip(&powers)
+                        .map(|(p, s)| p.mul_scalar(s))
+                        .collect(),
+                    self.srs
+                        .powers_of_h
+                        .par_iter()
+                        .zip(&powers)
+                        .map(|(p, s)| p.mul_scalar(s))
+                        .collect(),
+                )
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                (
+                    self.srs
+                        .powers_of_g
+                        .iter()
+                        .zip(&powers)
+                        .map(|(p, s)| p.mul_scalar(s))
+                        .collect(),
+                    self.srs
+                        .powers_of_h
+                        .iter()
+                        .zip(&powers)
+                        .map(|(p, s)| p.mul_scalar(s))
+                        .collect(),
+                )
+            }
+        };
+        let contribution = CeremonyContribution {
+            participant_id: self.participant_id,
+            powers_of_g,
+            powers_of_h,
+            public_key: B::G1::mul_generator(&secret),
+            proof: pok_point::<B>(&self.digest, self.participant_id).mul_scalar(&secret),
+        };
+        wipe_scalars(&mut powers);
+        wipe_scalars(core::slice::from_mut(&mut secret));
+        wipe_scalars(core::slice::from_mut(&mut cur));
+        Ok(contribution)
+    }
+}
+
+impl<B: PairingBackend> CeremonyTranscript<B> {
+    fn new(max_degree: usize, tau_g1: B::G1, tau_g2: B::G2) -> Self {
+        Self {
+            max_degree,
+            initial_tau_g1: tau_g1,
+            initial_tau_g2: tau_g2,
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Digest of the transcript, which the next contributor signs.
+    ///
+    /// Chains the degree, the starting powers and every record, so a proof
+    /// of knowledge cannot be replayed in another ceremony or position.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new_derive_key("tess::ceremony-transcript");
+        hasher.update(&(self.max_degree as u64).to_le_bytes());
+        hasher.update(&B::serialize_g1(&self.initial_tau_g1));
+        hasher.update(&B::serialize_g2(&self.initial_tau_g2));
+        let mut digest = *hasher.finalize().as_bytes();
+        for record in &self.contributions {
+            digest = chain(&digest, record);
+        }
+        digest
+    }
+
+    /// Current `τ·g` and `τ·h`.
+    fn tau(&self) -> (B::G1, B::G2) {
+        self.contributions
+            .last()
+            .map_or((self.initial_tau_g1, self.initial_tau_g2), |record| {
+                (record.tau_g1, record.tau_g2)
+            })
+    }
+
+    /// Replays the transcript and checks that `srs` is its result.
+    ///
+    /// Checks every record's proof of knowledge and update equation, that
+    /// `srs` has the transcript's degree and ends at the last record's powers,
+    /// and that `srs` passes [`SRS::verify`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] naming the first check that fails.
+    pub fn verify(&self, srs: &SRS<B>) -> Result<(), Error>
+    where
+        B: PairingBackend<Scalar = Fr>,
+    {
+        if self.contributions.is_empty() {
+            return Err(Error::MalformedInput(
+                "ceremony transcript has no contributions".into(),
+            ));
+        }
+        let mut replay = Self::new(self.max_degree, self.initial_tau_g1, self.initial_tau_g2);
+        let mut digest = replay.digest();
+        for record in &self.contributions {
+            check_record(record, &digest, replay.tau().1)?;
+            digest = chain(&digest, record);
+            replay.contributions.push(record.clone());
+        }
+
+        let (tau_g1, tau_g2) = self.tau();
+        if srs.max_degree() != self.max_degree
+            || srs.powers_of_h.len() != srs.powers_of_g.len()
+            || !srs.powers_of_g[1].sub(&tau_g1).is_identity()
+            || !srs.powers_of_h[1].sub(&tau_g2).is_identity()
+        {
+            return Err(Error::MalformedInput(
+                "SRS is not the result of the ceremony transcript".into(),
+            ));
+        }
+        srs.verify()
+    }
+}
+
+/// Coordinator of a committee-run Powers of Tau ceremony.
+///
+/// Participants `0..parties` contribute in id order; a participant who does
+/// not answer can be [skipped](Self::skip). The ceremony completes once every
+/// participant has had a turn, and [`finish`](Self::finish) returns the SRS
+/// together with its transcript.
+#[derive(Clone, Debug)]
+pub struct KeyCeremony<B: PairingBackend<Scalar = Fr>> {
+    srs: SRS<B>,
+    transcript: CeremonyTranscript<B>,
+    parties: usize,
+    next: usize,
+    skipped: Vec<usize>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> KeyCeremony<B> {
+    /// Starts a ceremony for an SRS of `max_degree` from `τ = 1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `max_degree` or `parties` is zero.
+    pub fn new(max_degree: usize, parties: usize) -> Result<Self, Error> {
+        let srs = SRS::new_unsafe(&Fr::one(), max_degree)
+            .map_err(|_| Error::InvalidConfig(format!("invalid ceremony degree {max_degree}")))?;
+        Self::from_srs(srs, parties)
+    }
+
+    /// Continues from an existing SRS, such as a public ceremony output.
+    ///
+    /// Any Lagrange basis on `srs` is dropped, as it does not survive updates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `parties` is zero, and the errors of
+    /// [`SRS::verify`] for an invalid `srs`.
+    pub fn from_srs(mut srs: SRS<B>, parties: usize) -> Result<Self, Error> {
+        if parties == 0 {
+            return Err(Error::InvalidConfig(
+                "ceremony needs at least one participant".into(),
+            ));
+        }
+        srs.lagrange_basis_g.clear();
+        srs.verify()?;
+        let transcript =
+            CeremonyTranscript::new(srs.max_degree(), srs.powers_of_g[1], srs.powers_of_h[1]);
+        Ok(Self {
+            srs,
+            transcript,
+            parties,
+            next: 0,
+            skipped: Vec::new(),
+        })
+    }
+
+    /// Current phase.
+    pub fn phase(&self) -> CeremonyPhase {
+        if self.next < self.parties {
+            CeremonyPhase::Contributing { next: self.next }
+        } else {
+            CeremonyPhase::Complete
+        }
+    }
+
+    /// Challenge for the participant whose turn it is, or `None` once the
+    /// ceremony is complete.
+    pub fn challenge(&self) -> Option<CeremonyChallenge<B>> {
+        (self.next < self.parties).then(|| CeremonyChallenge {
+            participant_id: self.next,
+            digest: self.transcript.digest(),
+            srs: self.srs.clone(),
+        })
+    }
+
+    /// Verifies a contribution and makes it the current state.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if it is not the contributor's turn.
+    /// - [`Error::MalformedInput`] if the powers have the wrong degree, are not
+    ///   powers of one `τ`, do not extend the current powers by the published
+    ///   key, or the proof of knowledge does not verify. The state is left
+    ///   unchanged.
+    pub fn submit(&mut self, contribution: CeremonyContribution<B>) -> Result<(), Error> {
+        let id = contribution.participant_id;
+        if self.phase() != (CeremonyPhase::Contributing { next: id }) {
+            return Err(Error::InvalidConfig(format!(
+                "it is not participant {id}'s turn to contribute"
+            )));
+        }
+        let degree = self.srs.max_degree();
+        if contribution.powers_of_g.len() != degree + 1
+            || contribution.powers_of_h.len() != degree + 1
+        {
+            return Err(Error::MalformedInput(format!(
+                "contribution of participant {id} does not have degree {degree}"
+            )));
+        }
+        let record = ContributionRecord {
+            participant_id: id,
+            public_key: contribution.public_key,
+            proof: contribution.proof,
+            tau_g1: contribution.powers_of_g[1],
+            tau_g2: contribution.powers_of_h[1],
+        };
+        check_record(&record, &self.transcript.digest(), self.transcript.tau().1)?;
+        let srs = SRS {
+            powers_of_g: contribution.powers_of_g,
+            powers_of_h: contribution.powers_of_h,
+            e_gh: self.srs.e_gh.clone(),
+            lagrange_basis_g: Vec::new(),
+        };
+        srs.verify()
+            .map_err(|e| Error::MalformedInput(format!("contribution of participant {id}: {e}")))?;
+
+        self.srs = srs;
+        self.transcript.contributions.push(record);
+        self.next += 1;
+        Ok(())
+    }
+
+    /// Passes over the participant whose turn it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if it is not `participant_id`'s turn.
+    pub fn skip(&mut self, participant_id: usize) -> Result<(), Error> {
+        if self.phase()
+            != (CeremonyPhase::Contributing {
+                next: participant_id,
+            })
+        {
+            return Err(Error::InvalidConfig(format!(
+                "it is not participant {participant_id}'s turn to contribute"
+            )));
+        }
+        self.skipped.push(participant_id);
+        self.next += 1;
+        Ok(())
+    }
+
+    /// Participants skipped so far.
+    pub fn skipped(&self) -> &[usize] {
+        &self.skipped
+    }
+
+    /// Transcript of the contributions accepted so far.
+    pub fn transcript(&self) -> &CeremonyTranscript<B> {
+        &self.transcript
+    }
+
+    /// Returns the final SRS and its transcript.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if the ceremony is not complete or no
+    /// participant contributed.
+    pub fn finish(self) -> Result<(SRS<B>, CeremonyTranscript<B>), Error> {
+        if let CeremonyPhase::Contributing { next } = self.phase() {
+            return Err(Error::InvalidConfig(format!(
+                "ceremony is waiting for participant {next}"
+            )));
+        }
+        if self.transcript.contributions.is_empty() {
+            return Err(Error::InvalidConfig(
+                "ceremony finished without contributions".into(),
+            ));
+        }
+        Ok((self.srs, self.transcript))
+    }
+}
+
+/// Checks one record against the digest and powers it updates.
+fn check_record<B: PairingBackend>(
+    record: &ContributionRecord<B>,
+    digest: &[u8; 32],
+    prev_g2: B::G2,
+) -> Result<(), Error> {
+    let id = record.participant_id;
+    let invalid =
+        |what: &str| Error::MalformedInput(format!("contribution of participant {id}: {what}"));
+    if record.public_key.is_identity() {
+        return Err(invalid("key is the identity"));
+    }
+    let (g, h) = (B::G1::generator(), B::G2::generator());
+    let pok = B::multi_pairing(
+        &[g, record.public_key.negate()],
+        &[record.proof, pok_point::<B>(digest, id)],
+    )
+    .map_err(Error::Backend)?;
+    if pok != B::Target::identity() {
+        return Err(invalid("proof of knowledge does not verify"));
+    }
+    let update = B::multi_pairing(&[record.tau_g1, record.public_key.negate()], &[h, prev_g2])
+        .map_err(Error::Backend)?;
+    let link = B::multi_pairing(&[g, record.tau_g1.negate()], &[record.tau_g2, h])
+        .map_err(Error::Backend)?;
+    if update != B::Target::identity() || link != B::Target::identity() {
+        return Err(invalid("powers do not extend the previous powers"));
+    }
+    Ok(())
+}
+
+fn chain<B: PairingBackend>(digest: &[u8; 32], record: &ContributionRecord<B>) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new_derive_key("tess::ceremony-transcript");
+    hasher.update(digest);
+    hasher.update(&(record.participant_id as u64).to_le_bytes());
+    hasher.update(&B::serialize_g1(&record.public_key));
+    hasher.update(&B::serialize_g2(&record.proof));
+    hasher.update(&B::serialize_g1(&record.tau_g1));
+    hasher.update(&B::serialize_g2(&record.tau_g2));
+    *hasher.finalize().as_bytes()
+}
+
+fn pok_point<B: PairingBackend>(digest: &[u8; 32], participant_id: usize) -> B::G2 {
+    let mut message = [0u8; 40];
+    message[..32].copy_from_slice(digest);
+    message[32..].copy_from_slice(&(participant_id as u64).to_le_bytes());
+    B::G2::hash_to_curve(&message, CEREMONY_POK_DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, Params, SilentThresholdScheme, ThresholdEncryption};
+
+    type B = PairingEngine;
+
+    #[test]
+    fn committee_ceremony_produces_verifiable_srs() {
+        let mut rng = StdRng::seed_from_u64(1473);
+        let mut ceremony = KeyCeremony::<B>::new(8, 4).unwrap();
+
+        // Out-of-turn and forged contributions are rejected.
+        let challenge = ceremony.challenge().unwrap();
+        let mut early = challenge.contribute(&mut rng).unwrap();
+        early.participant_id = 1;
+        assert!(ceremony.submit(early).is_err());
+        let mut forged = challenge.contribute(&mut rng).unwrap();
+        forged.powers_of_g[2] = forged.powers_of_g[2].add(&forged.powers_of_g[0]);
+        assert!(ceremony.submit(forged).is_err());
+        let mut stolen = challenge.contribute(&mut rng).unwrap();
+        stolen.proof = stolen.proof.add(&stolen.proof);
+        assert!(ceremony.submit(stolen).is_err());
+
+        ceremony
+            .submit(challenge.contribute(&mut rng).unwrap())
+            .unwrap();
+        ceremony.skip(1).unwrap();
+        // A stale challenge no longer matches the transcript.
+        assert!(
+            ceremony
+                .submit(challenge.contribute(&mut rng).unwrap())
+                .is_err()
+        );
+        while let Some(challenge) = ceremony.challenge() {
+            ceremony
+                .submit(challenge.contribute(&mut rng).unwrap())
+                .unwrap();
+        }
+        assert_eq!(ceremony.phase(), CeremonyPhase::Complete);
+        assert_eq!(ceremony.skipped(), [1]);
+
+        let (srs, transcript) = ceremony.finish().unwrap();
+        assert_eq!(transcript.contributions.len(), 3);
+        transcript.verify(&srs).unwrap();
+        let json = serde_json::to_string(&transcript).unwrap();
+        let decoded: CeremonyTranscript<B> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.digest(), transcript.digest());
+
+        let mut dropped = transcript.clone();
+        dropped.contributions.remove(1);
+        assert!(dropped.verify(&srs).is_err());
+        let other = SRS::<B>::new_unsafe(&Fr::random(&mut rng), 8).unwrap();
+        assert!(transcript.verify(&other).is_err());
+
+        // The result is usable for threshold parameters.
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = Params::from_srs(&srs, 4).unwrap();
+        scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+    }
+}
@@ -0,0 +1,656 @@
+//! Transparent polynomial commitments from the inner product argument.
+//!
+//! [`IPA`] commits to a coefficient vector as a Pedersen vector commitment
+//! `∑ c_i * G_i` over generators hashed to the curve, so setup needs no secret
+//! and no ceremony. An opening at `z` proves `⟨c, (1, z, z², …)⟩ = v` with the
+//! logarithmic-round argument of Bootle et al. and Bulletproofs, made
+//! non-interactive with a BLAKE3 transcript.
+//!
+//! Compared to [`KZG`](super::KZG):
+//!
+//! - proofs hold `2 * log2(n)` group elements and a scalar instead of one point
+//! - verification is linear in the degree rather than constant
+//! - several points or commitments cannot share a proof the way KZG quotients do
+//!
+//! The threshold encryption scheme itself pairs against KZG powers of tau and
+//! keeps using [`KZG`](super::KZG); code that is generic over
+//! [`PolynomialCommitment`] selects this backend through its type parameter.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tess::{DensePolynomial, FieldElement, Fr, IPA, PairingEngine, Polynomial, PolynomialCommitment};
+//!
+//! let params = <IPA as PolynomialCommitment<PairingEngine>>::setup(7, b"public ipa seed 0000000000000000").unwrap();
+//! let poly = DensePolynomial::from_coefficients_vec(vec![Fr::one(), Fr::from_u64(2)]);
+//! let commitment = <IPA as PolynomialCommitment<PairingEngine>>::commit_g1(&params, &poly).unwrap();
+//! let point = Fr::from_u64(5);
+//! let (value, proof) = <IPA as PolynomialCommitment<PairingEngine>>::open_g1(&params, &poly, &point).unwrap();
+//! assert!(<IPA as PolynomialCommitment<PairingEngine>>::verify_g1(&params, &commitment, &point, &value, &proof).unwrap());
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use blake3::Hasher;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+use super::scheme::chunked_msm;
+use crate::{
+    BackendError, CurvePoint, DensePolynomial, FieldElement, Fr, MixedRadixEvaluationDomain,
+    MsmProvider, PairingBackend, Polynomial, PolynomialCommitment,
+};
+
+/// Domain separation tag for the G1 commitment generators.
+const IPA_G1_DST: &[u8] = b"TESS-IPA-G1";
+/// Domain separation tag for the G2 commitment generators.
+const IPA_G2_DST: &[u8] = b"TESS-IPA-G2";
+/// Domain separation tag for the inner product generator.
+const IPA_U_DST: &[u8] = b"TESS-IPA-U";
+
+/// Inner product argument commitment scheme with a transparent setup.
+///
+/// This is a zero-sized type that implements the [`PolynomialCommitment`]
+/// trait. See the [module documentation](self) for the trade-offs against KZG.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IPA;
+
+/// Public generators for [`IPA`].
+///
+/// Every element is hashed to the curve from the setup seed, so anyone can
+/// regenerate and audit the parameters.
+#[derive(Debug)]
+pub struct IpaParams<B: PairingBackend<Scalar = Fr>> {
+    /// Seed the generators were derived from.
+    pub seed: [u8; 32],
+    /// G1 generators, one per coefficient up to the maximum degree.
+    pub generators_g: Vec<B::G1>,
+    /// G2 generators, one per coefficient up to the maximum degree.
+    pub generators_h: Vec<B::G2>,
+    /// Generator binding the claimed inner product.
+    pub u: B::G1,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Clone for IpaParams<B>
+where
+    B::G1: Clone,
+    B::G2: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            seed: self.seed,
+            generators_g: self.generators_g.clone(),
+            generators_h: self.generators_h.clone(),
+            u: self.u,
+        }
+    }
+}
+
+/// Inner product argument proof.
+///
+/// Holds one `(L, R)` pair per halving round and the final folded coefficient.
+#[derive(Debug)]
+pub struct IpaProof<B: PairingBackend<Scalar = Fr>> {
+    /// Left cross terms, one per round.
+    pub l: Vec<B::G1>,
+    /// Right cross terms, one per round.
+    pub r: Vec<B::G1>,
+    /// Coefficient vector folded down to a single scalar.
+    pub a: Fr,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Clone for IpaProof<B>
+where
+    B::G1: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            l: self.l.clone(),
+            r: self.r.clone(),
+            a: self.a,
+        }
+    }
+}
+
+/// Fiat-Shamir transcript chaining a BLAKE3 state across rounds.
+struct Transcript {
+    state: [u8; 32],
+}
+
+impl Transcript {
+    fn new(label: &[u8]) -> Self {
+        let mut hasher = Hasher::new();
+        hasher.update(b"tess::ipa");
+        hasher.update(label);
+        Self {
+            state: *hasher.finalize().as_bytes(),
+        }
+    }
+
+    fn absorb(&mut self, bytes: &[u8]) {
+        let mut hasher = Hasher::new();
+        hasher.update(&self.state);
+        hasher.update(&(bytes.len() as u64).to_le_bytes());
+        hasher.update(bytes);
+        self.state = *hasher.finalize().as_bytes();
+    }
+
+    fn challenge(&mut self) -> Result<(Fr, Fr), BackendError> {
+        let mut rng = ChaCha20Rng::from_seed(self.state);
+        let challenge = Fr::random(&mut rng);
+        self.absorb(challenge.to_repr().as_ref());
+        let inverse = challenge
+            .invert()
+            .ok_or(BackendError::Math("zero transcript challenge"))?;
+        Ok((challenge, inverse))
+    }
+}
+
+impl IPA {
+    fn powers(point: &Fr, len: usize) -> Vec<Fr> {
+        let mut powers = Vec::with_capacity(len);
+        let mut current = Fr::one();
+        for _ in 0..len {
+            powers.push(current);
+            current *= *point;
+        }
+        powers
+    }
+
+    fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+        a.iter()
+            .zip(b)
+            .fold(Fr::zero(), |acc, (x, y)| acc + *x * *y)
+    }
+
+    fn coefficients(
+        generators: usize,
+        polynomial: &DensePolynomial,
+    ) -> Result<Vec<Fr>, BackendError> {
+        let degree = polynomial.degree();
+        if degree + 1 > generators {
+            return Err(BackendError::Math("polynomial degree too large"));
+        }
+        let mut coeffs = polynomial.coeffs()[..=degree].to_vec();
+        coeffs.resize(generators, Fr::zero());
+        Ok(coeffs)
+    }
+
+    /// Binds the claimed inner product to `u` with a transcript challenge.
+    fn bind_value<C: CurvePoint<Fr>>(
+        transcript: &mut Transcript,
+        commitment: &C,
+        value: &Fr,
+        u: &C,
+    ) -> Result<C, BackendError> {
+        transcript.absorb(commitment.to_repr().as_ref());
+        transcript.absorb(value.to_repr().as_ref());
+        let (xi, _) = transcript.challenge()?;
+        Ok(u.mul_scalar(&xi))
+    }
+
+    /// Proves knowledge of `a` with `⟨a, G⟩ = C` and `⟨a, b⟩ = v`.
+    ///
+    /// Vectors are padded to a power of two with zero coefficients on identity
+    /// generators, which contribute to neither side of the relation.
+    fn prove<C: CurvePoint<Fr>>(
+        transcript: &mut Transcript,
+        generators: &[C],
+        u: &C,
+        mut a: Vec<Fr>,
+        mut b: Vec<Fr>,
+        msm: fn(&[C], &[Fr]) -> Result<C, BackendError>,
+    ) -> Result<(Vec<C>, Vec<C>, Fr), BackendError> {
+        let size = a.len().next_power_of_two();
+        let mut g = generators.to_vec();
+        g.resize(size, C::identity());
+        a.resize(size, Fr::zero());
+        b.resize(size, Fr::zero());
+
+        let rounds = size.trailing_zeros() as usize;
+        let mut ls = Vec::with_capacity(rounds);
+        let mut rs = Vec::with_capacity(rounds);
+        let mut n = size;
+        while n > 1 {
+            let half = n / 2;
+            let (a_lo, a_hi) = a.split_at(half);
+            let (b_lo, b_hi) = b.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+
+            let l =
+                chunked_msm(g_hi, a_lo, msm)?.add(&u.mul_scalar(&Self::inner_product(a_lo, b_hi)));
+            let r =
+                chunked_msm(g_lo, a_hi, msm)?.add(&u.mul_scalar(&Self::inner_product(a_hi, b_lo)));
+            transcript.absorb(l.to_repr().as_ref());
+            transcript.absorb(r.to_repr().as_ref());
+            let (x, x_inv) = transcript.challenge()?;
+
+            // a' = a_lo x + a_hi x⁻¹, b' = b_lo x⁻¹ + b_hi x, G' = G_lo x⁻¹ + G_hi x
+            a = (0..half).map(|i| a_lo[i] * x + a_hi[i] * x_inv).collect();
+            b = (0..half).map(|i| b_lo[i] * x_inv + b_hi[i] * x).collect();
+            g = (0..half)
+                .map(|i| g_lo[i].mul_scalar(&x_inv).add(&g_hi[i].mul_scalar(&x)))
+                .collect();
+            ls.push(l);
+            rs.push(r);
+            n = half;
+        }
+
+        Ok((ls, rs, a[0]))
+    }
+
+    /// Checks a proof from [`IPA::prove`] against `P = C + U·v`.
+    fn verify<C: CurvePoint<Fr>>(
+        transcript: &mut Transcript,
+        generators: &[C],
+        u: &C,
+        target: C,
+        b: &[Fr],
+        proof: (&[C], &[C], &Fr),
+        msm: fn(&[C], &[Fr]) -> Result<C, BackendError>,
+    ) -> Result<bool, BackendError> {
+        let (ls, rs, a) = proof;
+        let size = generators.len().next_power_of_two();
+        let rounds = size.trailing_zeros() as usize;
+        if ls.len() != rounds || rs.len() != rounds {
+            return Ok(false);
+        }
+
+        let mut challenges = Vec::with_capacity(rounds);
+        let mut folded = target;
+        for (l, r) in ls.iter().zip(rs) {
+            transcript.absorb(l.to_repr().as_ref());
+            transcript.absorb(r.to_repr().as_ref());
+            let (x, x_inv) = transcript.challenge()?;
+            let x_sq = x * x;
+            let x_inv_sq = x_inv * x_inv;
+            folded = folded
+                .add(&l.mul_scalar(&x_sq))
+                .add(&r.mul_scalar(&x_inv_sq));
+            challenges.push((x, x_inv));
+        }
+
+        // s_i is the product of x_j or x_j⁻¹ over rounds, by whether index i
+        // sat in the upper or lower half at round j; G_final = ⟨s, G⟩.
+        let mut s = vec![Fr::one()];
+        for (x, x_inv) in challenges.iter().rev() {
+            let lower = s.iter().map(|v| *v * *x_inv);
+            let upper = s.iter().map(|v| *v * *x).collect::<Vec<_>>();
+            s = lower.chain(upper).collect();
+        }
+
+        let b_final = Self::inner_product(&s, b);
+        let g_final = chunked_msm(generators, &s[..generators.len()], msm)?;
+        let expected = g_final.mul_scalar(a).add(&u.mul_scalar(&(*a * b_final)));
+        Ok(expected.sub(&folded).is_identity())
+    }
+
+    fn batch_challenge(
+        commitment: &[u8],
+        points: &[Fr],
+        values: &[Fr],
+    ) -> Result<Fr, BackendError> {
+        let mut transcript = Transcript::new(b"batch");
+        transcript.absorb(commitment);
+        for (point, value) in points.iter().zip(values) {
+            transcript.absorb(point.to_repr().as_ref());
+            transcript.absorb(value.to_repr().as_ref());
+        }
+        Ok(transcript.challenge()?.0)
+    }
+
+    /// Folds openings at several points into `⟨a, ∑ ρ^j z_j^i⟩ = ∑ ρ^j v_j`.
+    fn batch_statement(len: usize, points: &[Fr], values: &[Fr], rho: &Fr) -> (Vec<Fr>, Fr) {
+        let mut b = vec![Fr::zero(); len];
+        let mut value = Fr::zero();
+        let mut weight = Fr::one();
+        for (point, v) in points.iter().zip(values) {
+            for (acc, power) in b.iter_mut().zip(Self::powers(point, len)) {
+                *acc += weight * power;
+            }
+            value += weight * *v;
+            weight *= *rho;
+        }
+        (b, value)
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> PolynomialCommitment<B> for IPA {
+    type Parameters = IpaParams<B>;
+    type Polynomial = DensePolynomial;
+    type Proof = IpaProof<B>;
+
+    fn setup(max_degree: usize, seed: &[u8; 32]) -> Result<Self::Parameters, BackendError> {
+        if max_degree < 1 {
+            return Err(BackendError::Math("IPA setup requires degree at least 1"));
+        }
+
+        let generator_message = |index: usize| {
+            let mut msg = Vec::with_capacity(40);
+            msg.extend_from_slice(seed);
+            msg.extend_from_slice(&(index as u64).to_le_bytes());
+            msg
+        };
+        let generators_g = (0..=max_degree)
+            .map(|i| B::G1::hash_to_curve(&generator_message(i), IPA_G1_DST))
+            .collect();
+        let generators_h = (0..=max_degree)
+            .map(|i| B::G2::hash_to_curve(&generator_message(i), IPA_G2_DST))
+            .collect();
+        let u = B::G1::hash_to_curve(seed, IPA_U_DST);
+
+        Ok(IpaParams {
+            seed: *seed,
+            generators_g,
+            generators_h,
+            u,
+        })
+    }
+
+    fn commit_g1(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+    ) -> Result<B::G1, BackendError> {
+        let degree = polynomial.degree();
+        if degree + 1 > params.generators_g.len() {
+            return Err(BackendError::Math("polynomial degree too large"));
+        }
+        let scalars = &polynomial.coeffs()[..=degree];
+        chunked_msm(&params.generators_g[..=degree], scalars, B::Msm::msm_g1)
+    }
+
+    fn commit_g2(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+    ) -> Result<B::G2, BackendError> {
+        let degree = polynomial.degree();
+        if degree + 1 > params.generators_h.len() {
+            return Err(BackendError::Math("polynomial degree too large"));
+        }
+        let scalars = &polynomial.coeffs()[..=degree];
+        chunked_msm(&params.generators_h[..=degree], scalars, B::Msm::msm_g2)
+    }
+
+    fn commit_from_evals(
+        params: &Self::Parameters,
+        evals: &[B::Scalar],
+    ) -> Result<B::G1, BackendError> {
+        let domain = MixedRadixEvaluationDomain::new(evals.len())
+            .ok_or(BackendError::Math("unsupported evaluation domain size"))?;
+        let polynomial = DensePolynomial::from_coefficients_vec(domain.ifft(evals));
+        <Self as PolynomialCommitment<B>>::commit_g1(params, &polynomial)
+    }
+
+    fn open_g1(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+        point: &B::Scalar,
+    ) -> Result<(B::Scalar, Self::Proof), BackendError> {
+        let a = Self::coefficients(params.generators_g.len(), polynomial)?;
+        let b = Self::powers(point, a.len());
+        let value = Self::inner_product(&a, &b);
+        let commitment = <Self as PolynomialCommitment<B>>::commit_g1(params, polynomial)?;
+
+        let mut transcript = Transcript::new(b"open");
+        transcript.absorb(point.to_repr().as_ref());
+        let u = Self::bind_value(&mut transcript, &commitment, &value, &params.u)?;
+        let (l, r, a) = Self::prove(
+            &mut transcript,
+            &params.generators_g,
+            &u,
+            a,
+            b,
+            B::Msm::msm_g1,
+        )?;
+        Ok((value, IpaProof { l, r, a }))
+    }
+
+    fn verify_g1(
+        params: &Self::Parameters,
+        commitment: &B::G1,
+        point: &B::Scalar,
+        value: &B::Scalar,
+        proof: &Self::Proof,
+    ) -> Result<bool, BackendError> {
+        let b = Self::powers(point, params.generators_g.len());
+
+        let mut transcript = Transcript::new(b"open");
+        transcript.absorb(point.to_repr().as_ref());
+        let u = Self::bind_value(&mut transcript, commitment, value, &params.u)?;
+        let target = commitment.add(&u.mul_scalar(value));
+        Self::verify(
+            &mut transcript,
+            &params.generators_g,
+            &u,
+            target,
+            &b,
+            (&proof.l, &proof.r, &proof.a),
+            B::Msm::msm_g1,
+        )
+    }
+
+    fn batch_open_g1(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+        points: &[B::Scalar],
+    ) -> Result<(Vec<B::Scalar>, Self::Proof), BackendError> {
+        let a = Self::coefficients(params.generators_g.len(), polynomial)?;
+        let values = points
+            .iter()
+            .map(|point| polynomial.evaluate(point))
+            .collect::<Vec<_>>();
+        let commitment = <Self as PolynomialCommitment<B>>::commit_g1(params, polynomial)?;
+
+        let rho = Self::batch_challenge(commitment.to_repr().as_ref(), points, &values)?;
+        let (b, value) = Self::batch_statement(a.len(), points, &values, &rho);
+        let mut transcript = Transcript::new(b"batch-open");
+        let u = Self::bind_value(&mut transcript, &commitment, &value, &params.u)?;
+        let (l, r, a) = Self::prove(
+            &mut transcript,
+            &params.generators_g,
+            &u,
+            a,
+            b,
+            B::Msm::msm_g1,
+        )?;
+        Ok((values, IpaProof { l, r, a }))
+    }
+
+    fn batch_verify_g1(
+        params: &Self::Parameters,
+        commitment: &B::G1,
+        points: &[B::Scalar],
+        values: &[B::Scalar],
+        proof: &Self::Proof,
+    ) -> Result<bool, BackendError> {
+        if points.len() != values.len() {
+            return Err(BackendError::Math(
+                "batch verify: mismatched points and values",
+            ));
+        }
+
+        let rho = Self::batch_challenge(commitment.to_repr().as_ref(), points, values)?;
+        let (b, value) = Self::batch_statement(params.generators_g.len(), points, values, &rho);
+        let mut transcript = Transcript::new(b"batch-open");
+        let u = Self::bind_value(&mut transcript, commitment, &value, &params.u)?;
+        let target = commitment.add(&u.mul_scalar(&value));
+        Self::verify(
+            &mut transcript,
+            &params.generators_g,
+            &u,
+            target,
+            &b,
+            (&proof.l, &proof.r, &proof.a),
+            B::Msm::msm_g1,
+        )
+    }
+
+    fn max_degree(params: &Self::Parameters) -> usize {
+        params.generators_g.len().saturating_sub(1)
+    }
+
+    fn batch_verify_multi_g1(
+        params: &Self::Parameters,
+        commitments: &[B::G1],
+        points: &[B::Scalar],
+        values: &[B::Scalar],
+        proofs: &[Self::Proof],
+    ) -> Result<bool, BackendError> {
+        let count = commitments.len();
+        if points.len() != count || values.len() != count || proofs.len() != count {
+            return Err(BackendError::Math(
+                "batch verify: mismatched commitments, points, values and proofs",
+            ));
+        }
+
+        // Each proof folds its own challenges, so there is no shared check.
+        for i in 0..count {
+            let ok = <Self as PolynomialCommitment<B>>::verify_g1(
+                params,
+                &commitments[i],
+                &points[i],
+                &values[i],
+                &proofs[i],
+            )?;
+            if !ok {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn open_degree_bound_g1(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+        bound: usize,
+    ) -> Result<Self::Proof, BackendError> {
+        if bound + 1 > params.generators_g.len() {
+            return Err(BackendError::Math("degree bound exceeds SRS size"));
+        }
+        if polynomial.degree() > bound {
+            return Err(BackendError::Math("polynomial exceeds degree bound"));
+        }
+
+        // Opening the commitment over the first bound + 1 generators alone
+        // shows it has no component on the higher ones.
+        let a = Self::coefficients(bound + 1, polynomial)?;
+        let commitment = <Self as PolynomialCommitment<B>>::commit_g1(params, polynomial)?;
+        let mut transcript = Transcript::new(b"degree-bound");
+        transcript.absorb(&(bound as u64).to_le_bytes());
+        let u = Self::bind_value(&mut transcript, &commitment, &Fr::zero(), &params.u)?;
+        let b = vec![Fr::zero(); a.len()];
+        let (l, r, a) = Self::prove(
+            &mut transcript,
+            &params.generators_g[..=bound],
+            &u,
+            a,
+            b,
+            B::Msm::msm_g1,
+        )?;
+        Ok(IpaProof { l, r, a })
+    }
+
+    fn verify_degree_bound_g1(
+        params: &Self::Parameters,
+        commitment: &B::G1,
+        bound: usize,
+        proof: &Self::Proof,
+    ) -> Result<bool, BackendError> {
+        if bound + 1 > params.generators_g.len() {
+            return Err(BackendError::Math("degree bound exceeds SRS size"));
+        }
+
+        let mut transcript = Transcript::new(b"degree-bound");
+        transcript.absorb(&(bound as u64).to_le_bytes());
+        let u = Self::bind_value(&mut transcript, commitment, &Fr::zero(), &params.u)?;
+        let b = vec![Fr::zero(); bound + 1];
+        Self::verify(
+            &mut transcript,
+            &params.generators_g[..=bound],
+            &u,
+            *commitment,
+            &b,
+            (&proof.l, &proof.r, &proof.a),
+            B::Msm::msm_g1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PairingEngine;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    type Scheme = IPA;
+
+    fn random_polynomial(rng: &mut StdRng, len: usize) -> DensePolynomial {
+        DensePolynomial::from_coefficients_vec((0..len).map(|_| Fr::random(&mut *rng)).collect())
+    }
+
+    #[test]
+    fn ipa_open_verify() {
+        let mut rng = StdRng::seed_from_u64(1423);
+        // 10 generators exercises the identity padding up to 16.
+        let params: IpaParams<PairingEngine> = Scheme::setup(9, &[3u8; 32]).expect("setup");
+        let poly = random_polynomial(&mut rng, 7);
+        let commitment = Scheme::commit_g1(&params, &poly).expect("commit");
+        let point = Fr::random(&mut rng);
+
+        let (value, proof) = Scheme::open_g1(&params, &poly, &point).expect("open");
+        assert_eq!(value, poly.evaluate(&point));
+        assert_eq!(proof.l.len(), 4);
+        assert!(Scheme::verify_g1(&params, &commitment, &point, &value, &proof).expect("verify"));
+
+        let wrong = value + Fr::one();
+        assert!(!Scheme::verify_g1(&params, &commitment, &point, &wrong, &proof).expect("verify"));
+        let other = Fr::random(&mut rng);
+        assert!(!Scheme::verify_g1(&params, &commitment, &other, &value, &proof).expect("verify"));
+    }
+
+    #[test]
+    fn ipa_batch_and_multi_verify() {
+        let mut rng = StdRng::seed_from_u64(1424);
+        let params: IpaParams<PairingEngine> = Scheme::setup(8, &[5u8; 32]).expect("setup");
+        let poly = random_polynomial(&mut rng, 5);
+        let commitment = Scheme::commit_g1(&params, &poly).expect("commit");
+
+        let points = vec![Fr::from_u64(1), Fr::from_u64(2), Fr::from_u64(3)];
+        let (mut values, proof) = Scheme::batch_open_g1(&params, &poly, &points).expect("open");
+        assert!(
+            Scheme::batch_verify_g1(&params, &commitment, &points, &values, &proof)
+                .expect("verify")
+        );
+        values[1] += Fr::one();
+        assert!(
+            !Scheme::batch_verify_g1(&params, &commitment, &points, &values, &proof)
+                .expect("verify")
+        );
+
+        let other = random_polynomial(&mut rng, 3);
+        let commitments = vec![
+            commitment,
+            Scheme::commit_g1(&params, &other).expect("commit"),
+        ];
+        let points = vec![Fr::from_u64(7), Fr::from_u64(11)];
+        let (v0, p0) = Scheme::open_g1(&params, &poly, &points[0]).expect("open");
+        let (v1, p1) = Scheme::open_g1(&params, &other, &points[1]).expect("open");
+        let ok =
+            Scheme::batch_verify_multi_g1(&params, &commitments, &points, &[v0, v1], &[p0, p1])
+                .expect("verify");
+        assert!(ok);
+    }
+
+    #[test]
+    fn ipa_degree_bound() {
+        let mut rng = StdRng::seed_from_u64(1425);
+        let params: IpaParams<PairingEngine> = Scheme::setup(8, &[7u8; 32]).expect("setup");
+        let poly = random_polynomial(&mut rng, 4);
+        let commitment = Scheme::commit_g1(&params, &poly).expect("commit");
+
+        let proof = Scheme::open_degree_bound_g1(&params, &poly, 3).expect("prove");
+        assert!(Scheme::verify_degree_bound_g1(&params, &commitment, 3, &proof).expect("verify"));
+        assert!(!Scheme::verify_degree_bound_g1(&params, &commitment, 2, &proof).expect("verify"));
+        assert!(Scheme::open_degree_bound_g1(&params, &poly, 2).is_err());
+    }
+}
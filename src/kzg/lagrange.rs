@@ -0,0 +1,178 @@
+//! Structured reference string in Lagrange basis.
+//!
+//! Ceremonies such as Ethereum's KZG setup publish G1 powers as Lagrange basis
+//! commitments `g · L_i(τ)` over a fixed domain together with a few monomial G2
+//! powers. In that form a commitment to evaluations is one MSM, and the hint
+//! for participant `i` is read off directly as `g · L_i(τ)`.
+//!
+//! [`LagrangeSRS::from_monomial`] and [`LagrangeSRS::to_monomial`] convert to
+//! and from [`SRS`] with an FFT over G1, so neither direction needs τ. Points
+//! are kept in natural domain order (`ω^0, ω^1, …`); files that store them
+//! bit-reversed must be permuted first.
+
+use alloc::vec::Vec;
+
+use super::scheme::chunked_msm;
+use crate::{
+    BackendError, CurvePoint, FieldElement, Fr, MixedRadixEvaluationDomain, MsmProvider,
+    PairingBackend, SRS,
+};
+
+/// SRS whose G1 elements are Lagrange basis commitments over a domain.
+#[derive(Clone, Debug)]
+pub struct LagrangeSRS<B: PairingBackend<Scalar = Fr>> {
+    /// `g * L_i(tau)` for each element `ω^i` of the domain.
+    pub lagrange_g: Vec<B::G1>,
+    /// Powers of tau in G2: h * tau^i.
+    pub powers_of_h: Vec<B::G2>,
+    /// Precomputed pairing e(g, h) for verification.
+    pub e_gh: B::Target,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> LagrangeSRS<B> {
+    /// Converts the first `domain_size` monomial G1 powers to Lagrange basis.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` if `domain_size` is not a supported domain
+    /// size or exceeds the number of G1 powers.
+    pub fn from_monomial(srs: &SRS<B>, domain_size: usize) -> Result<Self, BackendError> {
+        if domain_size > srs.powers_of_g.len() {
+            return Err(BackendError::Math("domain exceeds SRS powers"));
+        }
+        let omega_inv = domain_generator(domain_size)?
+            .invert()
+            .ok_or(BackendError::Math("invalid generator inversion"))?;
+        let n_inv = Fr::from_u64(domain_size as u64)
+            .invert()
+            .ok_or(BackendError::Math("domain size is not invertible"))?;
+
+        // g·L_i(τ) = (1/n) Σ_k ω^{-ik} g·τ^k
+        let lagrange_g = group_fft(&srs.powers_of_g[..domain_size], omega_inv)
+            .into_iter()
+            .map(|point| point.mul_scalar(&n_inv))
+            .collect();
+        Ok(Self {
+            lagrange_g,
+            powers_of_h: srs.powers_of_h.clone(),
+            e_gh: srs.e_gh.clone(),
+        })
+    }
+
+    /// Converts back to monomial basis with `domain_size` G1 powers.
+    ///
+    /// The returned SRS keeps this basis as its [`SRS::lagrange_basis_g`].
+    pub fn to_monomial(&self) -> Result<SRS<B>, BackendError> {
+        let omega = domain_generator(self.domain_size())?;
+
+        // g·τ^k = Σ_i ω^{ik} g·L_i(τ), since x^k interpolates to Σ_i ω^{ik} L_i(x).
+        Ok(SRS {
+            powers_of_g: group_fft(&self.lagrange_g, omega),
+            powers_of_h: self.powers_of_h.clone(),
+            e_gh: self.e_gh.clone(),
+            lagrange_basis_g: self.lagrange_g.clone(),
+        })
+    }
+
+    /// Size of the evaluation domain.
+    pub fn domain_size(&self) -> usize {
+        self.lagrange_g.len()
+    }
+
+    /// Hint base `g * L_i(tau)` for participant `i`.
+    pub fn lagrange_commitment(&self, i: usize) -> Option<&B::G1> {
+        self.lagrange_g.get(i)
+    }
+
+    /// Commits to the polynomial taking `evals` on the domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` unless there is one evaluation per domain element.
+    pub fn commit(&self, evals: &[Fr]) -> Result<B::G1, BackendError> {
+        if evals.len() != self.domain_size() {
+            return Err(BackendError::Math("evaluations do not match domain size"));
+        }
+        chunked_msm(&self.lagrange_g, evals, B::Msm::msm_g1)
+    }
+}
+
+pub(crate) fn domain_generator(domain_size: usize) -> Result<Fr, BackendError> {
+    let domain = MixedRadixEvaluationDomain::new(domain_size)
+        .ok_or(BackendError::Math("unsupported evaluation domain size"))?;
+    Ok(domain.elements().get(1).copied().unwrap_or_else(Fr::one))
+}
+
+/// DFT over curve points: `out[k] = Σ_j ω^{jk} · points[j]`.
+///
+/// Recursive decimation in time over radices 2 and 3, matching
+/// [`MixedRadixEvaluationDomain`].
+pub(crate) fn group_fft<C: CurvePoint<Fr>>(points: &[C], omega: Fr) -> Vec<C> {
+    let n = points.len();
+    if n <= 1 {
+        return points.to_vec();
+    }
+    let radix = if n.is_multiple_of(2) { 2 } else { 3 };
+    let m = n / radix;
+
+    let omega_radix = (1..radix).fold(omega, |acc, _| acc * omega);
+    let subs: Vec<Vec<C>> = (0..radix)
+        .map(|r| {
+            let strided: Vec<C> = points.iter().skip(r).step_by(radix).copied().collect();
+            group_fft(&strided, omega_radix)
+        })
+        .collect();
+
+    let mut out = Vec::with_capacity(n);
+    let mut omega_k = Fr::one();
+    for k in 0..n {
+        let mut acc = subs[0][k % m];
+        let mut twiddle = omega_k;
+        for sub in &subs[1..] {
+            acc = acc.add(&sub[k % m].mul_scalar(&twiddle));
+            twiddle *= omega_k;
+        }
+        out.push(acc);
+        omega_k *= omega;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DensePolynomial, KZG, PairingEngine, Polynomial, PolynomialCommitment};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[test]
+    fn lagrange_srs_roundtrips_and_commits() {
+        let mut rng = StdRng::seed_from_u64(47);
+        for domain_size in [8, 12] {
+            let srs: SRS<PairingEngine> = KZG::setup(domain_size, &[9u8; 32]).expect("setup");
+            let lagrange = LagrangeSRS::from_monomial(&srs, domain_size).unwrap();
+
+            let monomial = lagrange.to_monomial().unwrap();
+            for (a, b) in monomial.powers_of_g.iter().zip(&srs.powers_of_g) {
+                assert!(a.sub(b).is_identity());
+            }
+
+            let poly = DensePolynomial::from_coefficients_vec(
+                (0..domain_size).map(|_| Fr::random(&mut rng)).collect(),
+            );
+            let domain = MixedRadixEvaluationDomain::new(domain_size).unwrap();
+            let evals = domain.fft(poly.coeffs());
+            let expected = KZG::commit_g1(&srs, &poly).unwrap();
+            assert!(
+                lagrange
+                    .commit(&evals)
+                    .unwrap()
+                    .sub(&expected)
+                    .is_identity()
+            );
+            assert!(lagrange.commit(&evals[1..]).is_err());
+        }
+
+        let srs: SRS<PairingEngine> = KZG::setup(4, &[9u8; 32]).expect("setup");
+        assert!(LagrangeSRS::from_monomial(&srs, 8).is_err());
+    }
+}
@@ -1,6 +1,31 @@
 mod scheme;
+#[cfg(feature = "std")]
+pub(crate) use scheme::setup_trapdoor;
 pub use scheme::{KZG, SRS};
 
+mod lagrange;
+pub use lagrange::LagrangeSRS;
+pub(crate) use lagrange::{domain_generator, group_fft};
+
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use file::{SRS_FILE_VERSION, SrsCheck};
+
+#[cfg(feature = "std")]
+mod ceremony;
+#[cfg(feature = "std")]
+pub use ceremony::{
+    CeremonyChallenge, CeremonyContribution, CeremonyPhase, CeremonyTranscript, ContributionRecord,
+    KeyCeremony,
+};
+
+mod ppot;
+pub use ppot::*;
+
+mod ipa;
+pub use ipa::{IPA, IpaParams, IpaProof};
+
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
@@ -37,6 +62,9 @@ pub trait PolynomialCommitment<B: PairingBackend>: Send + Sync + Debug + 'static
     /// Polynomial type used by this commitment scheme.
     type Polynomial: Polynomial<B::Scalar>;
 
+    /// Evaluation and degree-bound proof.
+    type Proof: Clone + Send + Sync + Debug + 'static;
+
     /// Performs trusted setup to generate commitment parameters.
     ///
     /// This generates powers of tau: `[τ^0, τ^1, ..., τ^max_degree]` in both G1 and G2.
@@ -59,12 +87,22 @@ pub trait PolynomialCommitment<B: PairingBackend>: Send + Sync + Debug + 'static
         polynomial: &Self::Polynomial,
     ) -> Result<B::G2, BackendError>;
 
+    /// Commits in G1 to the polynomial whose evaluations on the size-`evals.len()`
+    /// domain are `evals`.
+    ///
+    /// Uses precomputed Lagrange basis commitments when the parameters carry them
+    /// for that domain, and interpolates otherwise.
+    fn commit_from_evals(
+        params: &Self::Parameters,
+        evals: &[B::Scalar],
+    ) -> Result<B::G1, BackendError>;
+
     /// Opens a commitment at a point in G1, returning the evaluation and proof.
     fn open_g1(
         params: &Self::Parameters,
         polynomial: &Self::Polynomial,
         point: &B::Scalar,
-    ) -> Result<(B::Scalar, B::G1), BackendError>;
+    ) -> Result<(B::Scalar, Self::Proof), BackendError>;
 
     /// Verifies a commitment opening in G1.
     fn verify_g1(
@@ -72,7 +110,7 @@ pub trait PolynomialCommitment<B: PairingBackend>: Send + Sync + Debug + 'static
         commitment: &B::G1,
         point: &B::Scalar,
         value: &B::Scalar,
-        proof: &B::G1,
+        proof: &Self::Proof,
     ) -> Result<bool, BackendError>;
 
     /// Opens a commitment at multiple points in G1, returning evaluations and a proof.
@@ -83,7 +121,7 @@ pub trait PolynomialCommitment<B: PairingBackend>: Send + Sync + Debug + 'static
         params: &Self::Parameters,
         polynomial: &Self::Polynomial,
         points: &[B::Scalar],
-    ) -> Result<(Vec<B::Scalar>, B::G1), BackendError>;
+    ) -> Result<(Vec<B::Scalar>, Self::Proof), BackendError>;
 
     /// Verifies multiple commitment openings in G1 for the same commitment.
     ///
@@ -93,7 +131,43 @@ pub trait PolynomialCommitment<B: PairingBackend>: Send + Sync + Debug + 'static
         commitment: &B::G1,
         points: &[B::Scalar],
         values: &[B::Scalar],
-        proof: &B::G1,
+        proof: &Self::Proof,
+    ) -> Result<bool, BackendError>;
+
+    /// Returns the maximum polynomial degree the parameters can commit to.
+    fn max_degree(params: &Self::Parameters) -> usize;
+
+    /// Verifies many single-point openings, possibly of different commitments,
+    /// with one pairing check.
+    ///
+    /// Entry `i` claims that the polynomial behind `commitments[i]` evaluates to
+    /// `values[i]` at `points[i]`, witnessed by `proofs[i]` as produced by
+    /// [`open_g1`](Self::open_g1). The claims are folded with challenge weights
+    /// derived from all inputs, so a single bad opening fails the whole batch.
+    fn batch_verify_multi_g1(
+        params: &Self::Parameters,
+        commitments: &[B::G1],
+        points: &[B::Scalar],
+        values: &[B::Scalar],
+        proofs: &[Self::Proof],
+    ) -> Result<bool, BackendError>;
+
+    /// Proves that a committed polynomial has degree at most `bound`.
+    ///
+    /// Fails if the polynomial exceeds `bound` or `bound` exceeds
+    /// [`max_degree`](Self::max_degree).
+    fn open_degree_bound_g1(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+        bound: usize,
+    ) -> Result<Self::Proof, BackendError>;
+
+    /// Verifies a proof from [`open_degree_bound_g1`](Self::open_degree_bound_g1).
+    fn verify_degree_bound_g1(
+        params: &Self::Parameters,
+        commitment: &B::G1,
+        bound: usize,
+        proof: &Self::Proof,
     ) -> Result<bool, BackendError>;
 }
 
@@ -183,6 +257,57 @@ mod tests {
         assert!(!ok, "batch proof should not verify with tampered value");
     }
 
+    #[test]
+    fn kzg_large_commitment_matches_single_msm() {
+        let mut rng = StdRng::seed_from_u64(41);
+        let params: SRS<crate::PairingEngine> = KZG::setup(1500, &[3u8; 32]).expect("setup");
+        let coeffs: Vec<Fr> = (0..1500).map(|_| Fr::random(&mut rng)).collect();
+        let poly = DensePolynomial::from_coefficients_vec(coeffs.clone());
+
+        let commitment = KZG::commit_g1(&params, &poly).expect("commit");
+        let expected = <crate::PairingEngine as PairingBackend>::G1::multi_scalar_multiplication(
+            &params.powers_of_g[..coeffs.len()],
+            &coeffs,
+        );
+        assert!(commitment.sub(&expected).is_identity());
+
+        let commitment = KZG::commit_g2(&params, &poly).expect("commit");
+        let expected = <crate::PairingEngine as PairingBackend>::G2::multi_scalar_multiplication(
+            &params.powers_of_h[..coeffs.len()],
+            &coeffs,
+        );
+        assert!(commitment.sub(&expected).is_identity());
+    }
+
+    #[test]
+    fn kzg_commit_from_evals_matches_commit() {
+        let mut rng = StdRng::seed_from_u64(43);
+        let mut params: SRS<crate::PairingEngine> = KZG::setup(8, &[5u8; 32]).expect("setup");
+        let coeffs: Vec<Fr> = (0..8).map(|_| Fr::random(&mut rng)).collect();
+        let poly = DensePolynomial::from_coefficients_vec(coeffs);
+        let domain = crate::Radix2EvaluationDomain::new(8).unwrap();
+        let evals = domain.fft(&poly.coeffs);
+        let expected = KZG::commit_g1(&params, &poly).expect("commit");
+
+        // Interpolating fallback.
+        let commitment = KZG::commit_from_evals(&params, &evals).expect("commit");
+        assert!(commitment.sub(&expected).is_identity());
+
+        // Lagrange basis fast path.
+        params.lagrange_basis_g = (0..8)
+            .map(|i| {
+                let mut unit = vec![Fr::zero(); 8];
+                unit[i] = Fr::one();
+                let basis = DensePolynomial::from_coefficients_vec(domain.ifft(&unit));
+                KZG::commit_g1(&params, &basis).expect("commit")
+            })
+            .collect();
+        let commitment = KZG::commit_from_evals(&params, &evals).expect("commit");
+        assert!(commitment.sub(&expected).is_identity());
+
+        assert!(KZG::commit_from_evals(&params, &evals[..5]).is_err());
+    }
+
     #[test]
     fn kzg_zero_degree_polynomial() {
         let mut rng = StdRng::from_entropy();
@@ -408,4 +533,67 @@ mod tests {
             "batch proof should not verify with multiple tampered values"
         );
     }
+
+    #[test]
+    fn kzg_batch_verify_multi_commitments() {
+        let mut rng = StdRng::seed_from_u64(1422);
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed);
+        let params: SRS<crate::PairingEngine> = KZG::setup(8, &seed).expect("setup");
+
+        let mut commitments = Vec::new();
+        let mut points = Vec::new();
+        let mut values = Vec::new();
+        let mut proofs = Vec::new();
+        for i in 0..4u64 {
+            let coeffs: Vec<Fr> = (0..=i as usize + 2).map(|_| Fr::random(&mut rng)).collect();
+            let poly = DensePolynomial::from_coefficients_vec(coeffs);
+            let point = Fr::from_u64(i + 5);
+            let (value, proof) = KZG::open_g1(&params, &poly, &point).expect("open");
+            commitments.push(KZG::commit_g1(&params, &poly).expect("commit"));
+            points.push(point);
+            values.push(value);
+            proofs.push(proof);
+        }
+
+        let ok = KZG::batch_verify_multi_g1(&params, &commitments, &points, &values, &proofs)
+            .expect("batch verify");
+        assert!(ok, "batched openings should verify");
+
+        values[3] += Fr::one();
+        let ok = KZG::batch_verify_multi_g1(&params, &commitments, &points, &values, &proofs)
+            .expect("batch verify");
+        assert!(!ok, "one tampered opening should fail the batch");
+
+        let err = KZG::batch_verify_multi_g1(&params, &commitments, &points[..3], &values, &proofs);
+        assert!(err.is_err(), "mismatched lengths should be rejected");
+    }
+
+    #[test]
+    fn kzg_degree_bound() {
+        let mut rng = StdRng::seed_from_u64(1423);
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed);
+        let params: SRS<crate::PairingEngine> = KZG::setup(8, &seed).expect("setup");
+        assert_eq!(
+            <KZG as PolynomialCommitment<crate::PairingEngine>>::max_degree(&params),
+            8
+        );
+
+        let coeffs: Vec<Fr> = (0..4).map(|_| Fr::random(&mut rng)).collect();
+        let poly = DensePolynomial::from_coefficients_vec(coeffs);
+        let commitment = KZG::commit_g1(&params, &poly).expect("commit");
+
+        let proof = KZG::open_degree_bound_g1(&params, &poly, 3).expect("prove bound");
+        assert!(
+            KZG::verify_degree_bound_g1(&params, &commitment, 3, &proof).expect("verify bound"),
+            "degree bound proof should verify"
+        );
+        assert!(
+            !KZG::verify_degree_bound_g1(&params, &commitment, 2, &proof).expect("verify bound"),
+            "proof should not verify against a tighter bound"
+        );
+        assert!(KZG::open_degree_bound_g1(&params, &poly, 2).is_err());
+        assert!(KZG::open_degree_bound_g1(&params, &poly, 9).is_err());
+    }
 }
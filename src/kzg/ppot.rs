@@ -0,0 +1,347 @@
+//! Import of Powers of Tau ceremony transcripts.
+//!
+//! The Zcash Powers of Tau ceremony (BLS12-381) and the Perpetual Powers of
+//! Tau ceremony (BN254) publish their accumulators as `challenge` and
+//! `response` files with the same layout:
+//!
+//! ```text
+//! 64-byte BLAKE2b hash of the previous file
+//! τ^i·g   for i in 0..2^(power+1) - 1     (G1)
+//! τ^i·h   for i in 0..2^power             (G2)
+//! α·τ^i·g for i in 0..2^power             (G1)
+//! β·τ^i·g for i in 0..2^power             (G1)
+//! β·h                                     (G2)
+//! ```
+//!
+//! Challenge files store points uncompressed, response files compressed and
+//! followed by the contributor's public key. Only the τ powers are needed for
+//! an [`SRS`]; [`PowersOfTauReader`] reads the requested prefix of each of
+//! the first two sections point by point and skips the rest, so memory use is
+//! bounded by the degree requested rather than the size of the transcript.
+//!
+//! The reader checks that both sections start at the generators and that
+//! `e(τ·g, h) = e(g, τ·h)`; [`SRS::extend_with`] checks every further power
+//! when that is required.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+use crate::{BackendError, PairingBackend};
+#[cfg(feature = "std")]
+use crate::{CurvePoint, Fr, SRS, TargetGroup, errors::Error};
+
+/// Length of the hash that starts every transcript file.
+pub const POWERS_OF_TAU_HASH_LEN: usize = 64;
+
+/// Point encodings used by Powers of Tau transcripts on a backend's curve.
+///
+/// BLS12-381 uses the Zcash encoding; BN254 uses big-endian coordinates with
+/// the infinity flag in bit 6 and, for compressed points, the "larger y" flag
+/// in bit 7 of the first byte. Extension field elements are written `c1 || c0`.
+pub trait PowersOfTauCurve: PairingBackend {
+    /// Encoded length of a G1 point.
+    fn g1_len(compressed: bool) -> usize;
+
+    /// Encoded length of a G2 point.
+    fn g2_len(compressed: bool) -> usize;
+
+    /// Decodes a G1 point, checking that it lies in the prime-order subgroup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackendError::Serialization`] for invalid encodings.
+    fn decode_g1(bytes: &[u8], compressed: bool) -> Result<Self::G1, BackendError>;
+
+    /// Decodes a G2 point, checking that it lies in the prime-order subgroup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BackendError::Serialization`] for invalid encodings.
+    fn decode_g2(bytes: &[u8], compressed: bool) -> Result<Self::G2, BackendError>;
+
+    /// Encodes a G1 point.
+    fn encode_g1(point: &Self::G1, compressed: bool) -> Vec<u8>;
+
+    /// Encodes a G2 point.
+    fn encode_g2(point: &Self::G2, compressed: bool) -> Vec<u8>;
+}
+
+/// Streaming reader for a Powers of Tau `challenge` or `response` file.
+///
+/// ```rust,no_run
+/// use std::{fs::File, io::BufReader};
+/// use tess::{PairingEngine, Params, PowersOfTauReader};
+///
+/// let file = BufReader::new(File::open("challenge_0072").unwrap());
+/// let (srs, _hash) = PowersOfTauReader::<PairingEngine, _>::challenge(file, 28)
+///     .read_srs(1024)
+///     .unwrap();
+/// let params = Params::from_srs(&srs, 1024).unwrap();
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct PowersOfTauReader<B, R> {
+    reader: R,
+    power: u32,
+    compressed: bool,
+    _backend: PhantomData<B>,
+}
+
+#[cfg(feature = "std")]
+impl<B: PowersOfTauCurve<Scalar = Fr>, R: Read> PowersOfTauReader<B, R> {
+    /// Reads a `challenge` file (uncompressed points) of a ceremony with
+    /// `2^power` G2 powers.
+    pub fn challenge(reader: R, power: u32) -> Self {
+        Self::new(reader, power, false)
+    }
+
+    /// Reads a `response` file (compressed points) of a ceremony with
+    /// `2^power` G2 powers.
+    pub fn response(reader: R, power: u32) -> Self {
+        Self::new(reader, power, true)
+    }
+
+    fn new(reader: R, power: u32, compressed: bool) -> Self {
+        Self {
+            reader,
+            power,
+            compressed,
+            _backend: PhantomData,
+        }
+    }
+
+    /// Reads the powers up to `max_degree` into an [`SRS`], consuming the
+    /// transcript only up to the last point needed.
+    ///
+    /// Returns the SRS and the hash at the start of the file.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if `max_degree` is zero or exceeds the
+    ///   ceremony size.
+    /// - [`Error::Io`] if the transcript cannot be read, keeping the
+    ///   underlying error, e.g. [`UnexpectedEof`](io::ErrorKind::UnexpectedEof)
+    ///   for a truncated file.
+    /// - [`Error::MalformedInput`] if a point fails to decode or the powers do
+    ///   not start at the generators of a consistent τ.
+    pub fn read_srs(
+        mut self,
+        max_degree: usize,
+    ) -> Result<(SRS<B>, [u8; POWERS_OF_TAU_HASH_LEN]), Error> {
+        let g2_count = 1usize
+            .checked_shl(self.power)
+            .filter(|&n| n <= usize::MAX / 2)
+            .ok_or_else(|| Error::InvalidConfig(format!("unsupported power {}", self.power)))?;
+        let g1_count = 2 * g2_count - 1;
+        if max_degree == 0 || max_degree >= g2_count {
+            return Err(Error::InvalidConfig(format!(
+                "degree {max_degree} is outside the ceremony's 1..{g2_count}"
+            )));
+        }
+
+        let mut hash = [0u8; POWERS_OF_TAU_HASH_LEN];
+        self.read_exact(&mut hash)?;
+
+        let g1_len = B::g1_len(self.compressed);
+        let powers_of_g = self.read_points(max_degree + 1, g1_len, |bytes, compressed| {
+            B::decode_g1(bytes, compressed)
+        })?;
+        self.skip((g1_count - max_degree - 1) as u64 * g1_len as u64)?;
+        let powers_of_h = self.read_points(
+            max_degree + 1,
+            B::g2_len(self.compressed),
+            |bytes, compressed| B::decode_g2(bytes, compressed),
+        )?;
+
+        let (g, h) = (B::G1::generator(), B::G2::generator());
+        if !powers_of_g[0].sub(&g).is_identity() || !powers_of_h[0].sub(&h).is_identity() {
+            return Err(Error::MalformedInput(
+                "ceremony powers do not start at the generators".into(),
+            ));
+        }
+        let link = B::multi_pairing(&[powers_of_g[1], g.negate()], &[h, powers_of_h[1]])
+            .map_err(Error::Backend)?;
+        if link != B::Target::identity() {
+            return Err(Error::MalformedInput(
+                "ceremony G1 and G2 powers use different tau".into(),
+            ));
+        }
+
+        let srs = SRS {
+            powers_of_g,
+            powers_of_h,
+            e_gh: B::pairing(&g, &h),
+            lagrange_basis_g: Vec::new(),
+        };
+        Ok((srs, hash))
+    }
+
+    fn read_points<P>(
+        &mut self,
+        count: usize,
+        len: usize,
+        decode: impl Fn(&[u8], bool) -> Result<P, BackendError>,
+    ) -> Result<Vec<P>, Error> {
+        let mut buf = vec![0u8; len];
+        let mut points = Vec::with_capacity(count);
+        for i in 0..count {
+            self.read_exact(&mut buf)?;
+            let point = decode(&buf, self.compressed)
+                .map_err(|e| Error::MalformedInput(format!("invalid ceremony point {i}: {e}")))?;
+            points.push(point);
+        }
+        Ok(points)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        self.reader.read_exact(buf).map_err(read_error)
+    }
+
+    fn skip(&mut self, len: u64) -> Result<(), Error> {
+        let skipped =
+            io::copy(&mut (&mut self.reader).take(len), &mut io::sink()).map_err(read_error)?;
+        if skipped != len {
+            return Err(read_error(io::ErrorKind::UnexpectedEof.into()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+fn read_error(source: io::Error) -> Error {
+    Error::Io {
+        context: "failed to read ceremony transcript".into(),
+        source,
+    }
+}
+
+// The mock backend has no ceremony encodings.
+#[cfg(all(
+    test,
+    any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254")
+))]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use rand::{SeedableRng, rngs::StdRng};
+
+    #[cfg(feature = "std")]
+    use crate::FieldElement;
+    use crate::{CurvePoint, PairingEngine};
+
+    type B = PairingEngine;
+
+    #[cfg(feature = "std")]
+    /// Writes a transcript for `tau` with zeroed α and β sections.
+    fn transcript(tau: &Fr, power: u32, compressed: bool) -> Vec<u8> {
+        let n = 1usize << power;
+        let srs = SRS::<B>::new_unsafe(tau, 2 * n - 2).unwrap();
+        let mut out = vec![0xa5; POWERS_OF_TAU_HASH_LEN];
+        for point in &srs.powers_of_g {
+            out.extend(B::encode_g1(point, compressed));
+        }
+        for point in &srs.powers_of_h[..n] {
+            out.extend(B::encode_g2(point, compressed));
+        }
+        let tail = 2 * n * B::g1_len(compressed) + B::g2_len(compressed);
+        out.resize(out.len() + tail, 0);
+        out
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ceremony_files_import_as_srs() {
+        let mut rng = StdRng::seed_from_u64(1459);
+        let tau = Fr::random(&mut rng);
+        let expected = SRS::<B>::new_unsafe(&tau, 5).unwrap();
+
+        for compressed in [false, true] {
+            let file = transcript(&tau, 3, compressed);
+            let reader = if compressed {
+                PowersOfTauReader::<B, _>::response(file.as_slice(), 3)
+            } else {
+                PowersOfTauReader::<B, _>::challenge(file.as_slice(), 3)
+            };
+            let (srs, hash) = reader.read_srs(5).unwrap();
+            assert_eq!(hash, [0xa5; POWERS_OF_TAU_HASH_LEN]);
+            assert_eq!(srs.powers_of_g, expected.powers_of_g);
+            assert_eq!(srs.powers_of_h, expected.powers_of_h);
+            assert_eq!(srs.e_gh, expected.e_gh);
+
+            // Only the prefix up to the last G2 power needed is consumed.
+            let needed =
+                POWERS_OF_TAU_HASH_LEN + 15 * B::g1_len(compressed) + 6 * B::g2_len(compressed);
+            assert!(
+                PowersOfTauReader::<B, _>::new(&file[..needed], 3, compressed)
+                    .read_srs(5)
+                    .is_ok()
+            );
+            let truncated = PowersOfTauReader::<B, _>::new(&file[..needed - 1], 3, compressed)
+                .read_srs(5)
+                .unwrap_err();
+            assert!(matches!(
+                truncated,
+                Error::Io { ref source, .. } if source.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        let file = transcript(&tau, 3, false);
+        assert!(
+            PowersOfTauReader::<B, _>::challenge(file.as_slice(), 3)
+                .read_srs(8)
+                .is_err()
+        );
+        let mut corrupt = file.clone();
+        corrupt[POWERS_OF_TAU_HASH_LEN + B::g1_len(false) + 5] ^= 1;
+        assert!(
+            PowersOfTauReader::<B, _>::challenge(corrupt.as_slice(), 3)
+                .read_srs(5)
+                .is_err()
+        );
+
+        // G2 powers from another ceremony are rejected.
+        let other = transcript(&Fr::random(&mut rng), 3, false);
+        let mut mixed = file.clone();
+        let g2_start = POWERS_OF_TAU_HASH_LEN + 15 * B::g1_len(false);
+        let g2_end = g2_start + 8 * B::g2_len(false);
+        mixed[g2_start..g2_end].copy_from_slice(&other[g2_start..g2_end]);
+        assert!(
+            PowersOfTauReader::<B, _>::challenge(mixed.as_slice(), 3)
+                .read_srs(5)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn generator_encodings_match_ceremony_format() {
+        let g1 = <B as PairingBackend>::G1::generator();
+        for compressed in [false, true] {
+            let bytes = B::encode_g1(&g1, compressed);
+            assert_eq!(bytes.len(), B::g1_len(compressed));
+            assert_eq!(B::decode_g1(&bytes, compressed).unwrap(), g1);
+            let bytes = B::encode_g2(&<B as PairingBackend>::G2::generator(), compressed);
+            assert_eq!(bytes.len(), B::g2_len(compressed));
+            assert_eq!(
+                B::decode_g2(&bytes, compressed).unwrap(),
+                <B as PairingBackend>::G2::generator()
+            );
+        }
+
+        #[cfg(any(feature = "blst", feature = "ark_bls12381"))]
+        let expected = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        // (1, 2) with the "larger y" flag clear: 2 < p - 2.
+        #[cfg(all(
+            feature = "ark_bn254",
+            not(any(feature = "blst", feature = "ark_bls12381"))
+        ))]
+        let expected = "0000000000000000000000000000000000000000000000000000000000000001";
+        let expected: Vec<u8> = (0..expected.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&expected[i..i + 2], 16).unwrap())
+            .collect();
+        assert_eq!(B::encode_g1(&g1, true), expected);
+    }
+}
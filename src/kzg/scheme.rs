@@ -50,16 +50,20 @@
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use blake3::Hasher;
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 #[cfg(feature = "parallel")]
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::{
+    iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator},
+    slice::ParallelSlice,
+};
 
 use crate::CurvePoint;
 use crate::TargetGroup;
 use crate::{
-    BackendError, DensePolynomial, FieldElement, Fr, PairingBackend, Polynomial,
-    PolynomialCommitment,
+    BackendError, DensePolynomial, FieldElement, Fr, MixedRadixEvaluationDomain, MsmProvider,
+    PairingBackend, Polynomial, PolynomialCommitment,
 };
 
 /// KZG polynomial commitment scheme implementation.
@@ -109,11 +113,7 @@ impl KZG {
     }
 
     fn vanishing_polynomial(points: &[Fr]) -> DensePolynomial {
-        let mut poly = DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
-        for point in points {
-            poly = poly.mul_by_linear(*point);
-        }
-        poly
+        DensePolynomial::from_roots(points)
     }
 
     fn interpolate_remainder(
@@ -128,25 +128,8 @@ impl KZG {
 
         Self::ensure_unique_points(points)?;
 
-        let vanishing = Self::vanishing_polynomial(points);
-        let mut remainder = DensePolynomial::from_coefficients_vec(vec![Fr::zero()]);
-
-        for (idx, point) in points.iter().enumerate() {
-            let (numerator, rem) = vanishing.divide_by_linear(*point);
-            if rem != Fr::zero() {
-                return Err(BackendError::Math(
-                    "non-zero remainder in vanishing division",
-                ));
-            }
-            let denom = numerator.evaluate(point);
-            let denom_inv = denom
-                .invert()
-                .ok_or(BackendError::Math("duplicate points in batch opening"))?;
-            let scaled = &numerator * (values[idx] * denom_inv);
-            remainder = remainder + scaled;
-        }
-
-        Ok(remainder)
+        DensePolynomial::interpolate(points, values)
+            .ok_or(BackendError::Math("duplicate points in batch opening"))
     }
 }
 
@@ -188,6 +171,12 @@ pub struct SRS<B: PairingBackend<Scalar = Fr>> {
     pub powers_of_h: Vec<B::G2>,
     /// Precomputed pairing e(g, h) for verification.
     pub e_gh: B::Target,
+    /// Lagrange basis commitments `g * L_i(tau)` over an evaluation domain.
+    ///
+    /// Empty unless filled by the caller; threshold parameter generation sets it
+    /// to the committee domain. Lets [`PolynomialCommitment::commit_from_evals`]
+    /// commit without interpolating.
+    pub lagrange_basis_g: Vec<B::G1>,
 }
 
 impl<B: PairingBackend<Scalar = Fr>> Clone for SRS<B>
@@ -201,6 +190,7 @@ where
             powers_of_g: self.powers_of_g.clone(),
             powers_of_h: self.powers_of_h.clone(),
             e_gh: self.e_gh.clone(),
+            lagrange_basis_g: self.lagrange_basis_g.clone(),
         }
     }
 }
@@ -247,70 +237,209 @@ impl<B: PairingBackend<Scalar = Fr>> SRS<B> {
             return Err(String::from("SRS setup failed"));
         }
 
-        let g = B::G1::generator();
-        let h = B::G2::generator();
+        let (powers_of_g, powers_of_h) = Self::powers_in_range(tau, 0, max_degree + 1);
+        let e_gh = B::pairing(&B::G1::generator(), &B::G2::generator());
+
+        Ok(SRS {
+            powers_of_g,
+            powers_of_h,
+            e_gh,
+            lagrange_basis_g: Vec::new(),
+        })
+    }
+
+    /// Highest polynomial degree this SRS can commit to.
+    pub fn max_degree(&self) -> usize {
+        self.powers_of_g.len().saturating_sub(1)
+    }
+
+    /// Drops the powers above `new_degree`.
+    ///
+    /// One large ceremony output can be cut down to the degree a smaller
+    /// committee needs. The Lagrange basis, if any, is kept: it does not depend
+    /// on the number of powers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` if `new_degree` is zero or exceeds the
+    /// current degree.
+    pub fn truncate(&mut self, new_degree: usize) -> Result<(), BackendError> {
+        if new_degree == 0 || new_degree > self.max_degree() {
+            return Err(BackendError::Math("invalid SRS truncation degree"));
+        }
+        self.powers_of_g.truncate(new_degree + 1);
+        self.powers_of_h.truncate(new_degree + 1);
+        Ok(())
+    }
+
+    /// Appends the next powers `g * τ^i` and `h * τ^i` from a ceremony output.
+    ///
+    /// Each appended pair is checked against its predecessor with
+    /// `e(g·τ^i, h) = e(g·τ^(i-1), h·τ)` and `e(g, h·τ^i) = e(g·τ, h·τ^(i-1))`,
+    /// so powers of a different τ are rejected. Nothing is appended on error.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` if the slices differ in length or a power
+    /// does not continue the sequence.
+    pub fn extend_with(
+        &mut self,
+        powers_of_g: &[B::G1],
+        powers_of_h: &[B::G2],
+    ) -> Result<(), BackendError> {
+        if powers_of_g.len() != powers_of_h.len() {
+            return Err(BackendError::Math("G1 and G2 extensions differ in length"));
+        }
+        let (g, g_tau) = (self.powers_of_g[0], self.powers_of_g[1]);
+        let (h, h_tau) = (self.powers_of_h[0], self.powers_of_h[1]);
+        let mut prev_g = self.powers_of_g[self.max_degree()];
+        let mut prev_h = self.powers_of_h[self.max_degree()];
+        for (next_g, next_h) in powers_of_g.iter().zip(powers_of_h) {
+            let g_link = B::multi_pairing(&[*next_g, prev_g.negate()], &[h, h_tau])?;
+            let h_link = B::multi_pairing(&[g, g_tau.negate()], &[*next_h, prev_h])?;
+            if g_link != B::Target::identity() || h_link != B::Target::identity() {
+                return Err(BackendError::Math(
+                    "SRS extension does not continue the powers",
+                ));
+            }
+            prev_g = *next_g;
+            prev_h = *next_h;
+        }
+        self.powers_of_g.extend_from_slice(powers_of_g);
+        self.powers_of_h.extend_from_slice(powers_of_h);
+        Ok(())
+    }
 
-        let mut powers_of_tau = Vec::with_capacity(max_degree + 1);
-        powers_of_tau.push(<B::Scalar as FieldElement>::one());
-        let mut cur = *tau;
-        for _ in 0..max_degree {
+    /// Extends the SRS to `max_degree` by computing the missing powers from `tau`.
+    ///
+    /// # Security Warning
+    ///
+    /// As with [`SRS::new_unsafe`], `tau` must be the trapdoor of this SRS and
+    /// must be discarded afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` if `max_degree` is below the current degree.
+    pub fn extend_unsafe(
+        &mut self,
+        tau: &B::Scalar,
+        max_degree: usize,
+    ) -> Result<(), BackendError> {
+        if max_degree < self.max_degree() {
+            return Err(BackendError::Math(
+                "SRS extension degree is below current degree",
+            ));
+        }
+        let (powers_of_g, powers_of_h) =
+            Self::powers_in_range(tau, self.powers_of_g.len(), max_degree + 1);
+        self.powers_of_g.extend(powers_of_g);
+        self.powers_of_h.extend(powers_of_h);
+        Ok(())
+    }
+
+    /// Commitment `h·(τ^n - 1)` to the vanishing polynomial of the size-`n`
+    /// evaluation domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackendError::Math` if the SRS has fewer than `n + 1` G2 powers.
+    pub fn vanishing_commitment_g2(&self, n: usize) -> Result<B::G2, BackendError> {
+        let h_tau_n = self
+            .powers_of_h
+            .get(n)
+            .ok_or(BackendError::Math("SRS degree is below the domain size"))?;
+        Ok(h_tau_n.sub(&self.powers_of_h[0]))
+    }
+
+    /// Computes `g * τ^i` and `h * τ^i` for `i` in `start..end`.
+    pub(crate) fn powers_in_range(
+        tau: &B::Scalar,
+        start: usize,
+        end: usize,
+    ) -> (Vec<B::G1>, Vec<B::G2>) {
+        let mut cur = <B::Scalar as FieldElement>::one();
+        for _ in 0..start {
+            cur *= tau;
+        }
+        let mut powers_of_tau = Vec::with_capacity(end.saturating_sub(start));
+        for _ in start..end {
             powers_of_tau.push(cur);
             cur *= tau;
         }
+        wipe_scalars(core::slice::from_mut(&mut cur));
 
         let powers_of_g: Vec<B::G1> = {
             #[cfg(feature = "parallel")]
             {
-                powers_of_tau
-                    .par_iter()
-                    .map(|power| g.mul_scalar(power))
-                    .collect()
+                powers_of_tau.par_iter().map(B::G1::mul_generator).collect()
             }
             #[cfg(not(feature = "parallel"))]
             {
-                powers_of_tau
-                    .iter()
-                    .map(|power| g.mul_scalar(power))
-                    .collect()
+                powers_of_tau.iter().map(B::G1::mul_generator).collect()
             }
         };
 
         let powers_of_h: Vec<B::G2> = {
             #[cfg(feature = "parallel")]
             {
-                powers_of_tau
-                    .par_iter()
-                    .map(|power| h.mul_scalar(power))
-                    .collect()
+                powers_of_tau.par_iter().map(B::G2::mul_generator).collect()
             }
             #[cfg(not(feature = "parallel"))]
             {
-                powers_of_tau
-                    .iter()
-                    .map(|power| h.mul_scalar(power))
-                    .collect()
+                powers_of_tau.iter().map(B::G2::mul_generator).collect()
             }
         };
 
-        let e_gh = B::pairing(&g, &h);
         wipe_scalars(&mut powers_of_tau);
+        (powers_of_g, powers_of_h)
+    }
+}
 
-        Ok(SRS {
-            powers_of_g,
-            powers_of_h,
-            e_gh,
-        })
+/// Smallest chunk a commitment MSM is split into across threads.
+///
+/// Below this size a single MSM beats the cost of spawning and combining.
+#[cfg(feature = "parallel")]
+const MIN_COMMIT_CHUNK: usize = 256;
+
+/// Commitment MSM, split across rayon when the `parallel` feature is enabled.
+///
+/// Large inputs are cut into roughly one chunk per thread (never smaller than
+/// [`MIN_COMMIT_CHUNK`]); each chunk runs through the backend MSM and the
+/// partial sums are added.
+pub(super) fn chunked_msm<C: CurvePoint<Fr>>(
+    bases: &[C],
+    scalars: &[Fr],
+    msm: fn(&[C], &[Fr]) -> Result<C, BackendError>,
+) -> Result<C, BackendError> {
+    #[cfg(feature = "parallel")]
+    {
+        let chunk = bases
+            .len()
+            .div_ceil(rayon::current_num_threads())
+            .max(MIN_COMMIT_CHUNK);
+        if bases.len() <= chunk {
+            return msm(bases, scalars);
+        }
+        bases
+            .par_chunks(chunk)
+            .zip(scalars.par_chunks(chunk))
+            .map(|(bases, scalars)| msm(bases, scalars))
+            .try_reduce(C::identity, |acc, part| Ok(acc.add(&part)))
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        msm(bases, scalars)
     }
 }
 
 impl<B: PairingBackend<Scalar = Fr>> PolynomialCommitment<B> for KZG {
     type Parameters = SRS<B>;
     type Polynomial = DensePolynomial;
+    type Proof = B::G1;
 
     fn setup(max_degree: usize, seed: &[u8; 32]) -> Result<Self::Parameters, BackendError> {
-        let mut rng = ChaCha20Rng::from_seed(*seed);
-        let tau = Fr::random(&mut rng);
-        SRS::new_unsafe(&tau, max_degree).map_err(BackendError::Other)
+        SRS::new_unsafe(&setup_trapdoor(seed), max_degree).map_err(BackendError::Other)
     }
 
     fn commit_g1(
@@ -322,9 +451,7 @@ impl<B: PairingBackend<Scalar = Fr>> PolynomialCommitment<B> for KZG {
             return Err(BackendError::Math("polynomial degree too large"));
         }
         let scalars = &polynomial.coeffs()[..=degree];
-        let commitment =
-            B::G1::multi_scalar_multiplication(&params.powers_of_g[..=degree], scalars);
-        Ok(commitment)
+        chunked_msm(&params.powers_of_g[..=degree], scalars, B::Msm::msm_g1)
     }
 
     fn commit_g2(
@@ -336,9 +463,21 @@ impl<B: PairingBackend<Scalar = Fr>> PolynomialCommitment<B> for KZG {
             return Err(BackendError::Math("polynomial degree too large"));
         }
         let scalars = &polynomial.coeffs()[..=degree];
-        let commitment =
-            B::G2::multi_scalar_multiplication(&params.powers_of_h[..=degree], scalars);
-        Ok(commitment)
+        chunked_msm(&params.powers_of_h[..=degree], scalars, B::Msm::msm_g2)
+    }
+
+    fn commit_from_evals(
+        params: &Self::Parameters,
+        evals: &[B::Scalar],
+    ) -> Result<B::G1, BackendError> {
+        if !evals.is_empty() && evals.len() == params.lagrange_basis_g.len() {
+            return chunked_msm(&params.lagrange_basis_g, evals, B::Msm::msm_g1);
+        }
+
+        let domain = MixedRadixEvaluationDomain::new(evals.len())
+            .ok_or(BackendError::Math("unsupported evaluation domain size"))?;
+        let polynomial = DensePolynomial::from_coefficients_vec(domain.ifft(evals));
+        Self::commit_g1(params, &polynomial)
     }
 
     fn open_g1(
@@ -449,9 +588,118 @@ impl<B: PairingBackend<Scalar = Fr>> PolynomialCommitment<B> for KZG {
         let result = B::multi_pairing(&[lhs, neg_proof], &[h, vanishing_commitment])?;
         Ok(result == <B::Target as TargetGroup>::identity())
     }
+
+    fn max_degree(params: &Self::Parameters) -> usize {
+        params.powers_of_g.len().saturating_sub(1)
+    }
+
+    fn batch_verify_multi_g1(
+        params: &Self::Parameters,
+        commitments: &[B::G1],
+        points: &[B::Scalar],
+        values: &[B::Scalar],
+        proofs: &[B::G1],
+    ) -> Result<bool, BackendError> {
+        let count = commitments.len();
+        if points.len() != count || values.len() != count || proofs.len() != count {
+            return Err(BackendError::Math(
+                "batch verify: mismatched commitments, points, values and proofs",
+            ));
+        }
+
+        if count == 0 {
+            return Ok(true);
+        }
+
+        if params.powers_of_h.len() < 2 {
+            return Err(BackendError::Math("insufficient SRS powers"));
+        }
+
+        let mut hasher = Hasher::new();
+        hasher.update(b"tess::kzg-batch-verify");
+        hasher.update(&(count as u64).to_le_bytes());
+        for i in 0..count {
+            hasher.update(commitments[i].to_repr().as_ref());
+            hasher.update(points[i].to_repr().as_ref());
+            hasher.update(values[i].to_repr().as_ref());
+            hasher.update(proofs[i].to_repr().as_ref());
+        }
+        let mut rng = ChaCha20Rng::from_seed(*hasher.finalize().as_bytes());
+        let weights = (0..count).map(|_| Fr::random(&mut rng)).collect::<Vec<_>>();
+
+        // Each claim satisfies e(C_i - g*v_i + π_i*z_i, h) == e(π_i, h*τ); fold
+        // them as e(∑ r_i (C_i - g*v_i + π_i*z_i), h) * e(-∑ r_i π_i, h*τ) == 1.
+        let mut weighted_value = Fr::zero();
+        let mut point_weights = Vec::with_capacity(count);
+        for i in 0..count {
+            weighted_value += weights[i] * values[i];
+            point_weights.push(weights[i] * points[i]);
+        }
+        let lhs = B::Msm::msm_g1(commitments, &weights)?
+            .add(&B::Msm::msm_g1(proofs, &point_weights)?)
+            .sub(&B::G1::mul_generator(&weighted_value));
+        let neg_proof = B::Msm::msm_g1(proofs, &weights)?.negate();
+
+        let h = params.powers_of_h[0];
+        let h_tau = params.powers_of_h[1];
+        let result = B::multi_pairing(&[lhs, neg_proof], &[h, h_tau])?;
+        Ok(result == <B::Target as TargetGroup>::identity())
+    }
+
+    fn open_degree_bound_g1(
+        params: &Self::Parameters,
+        polynomial: &Self::Polynomial,
+        bound: usize,
+    ) -> Result<B::G1, BackendError> {
+        let max_degree = <Self as PolynomialCommitment<B>>::max_degree(params);
+        if bound > max_degree {
+            return Err(BackendError::Math("degree bound exceeds SRS size"));
+        }
+        let degree = polynomial.degree();
+        if degree > bound {
+            return Err(BackendError::Math("polynomial exceeds degree bound"));
+        }
+
+        // Commit to x^(D - bound) * p(x), which only fits in the SRS when
+        // deg p <= bound.
+        let shift = max_degree - bound;
+        let scalars = &polynomial.coeffs()[..=degree];
+        chunked_msm(
+            &params.powers_of_g[shift..=shift + degree],
+            scalars,
+            B::Msm::msm_g1,
+        )
+    }
+
+    fn verify_degree_bound_g1(
+        params: &Self::Parameters,
+        commitment: &B::G1,
+        bound: usize,
+        proof: &B::G1,
+    ) -> Result<bool, BackendError> {
+        let max_degree = <Self as PolynomialCommitment<B>>::max_degree(params);
+        if bound > max_degree {
+            return Err(BackendError::Math("degree bound exceeds SRS size"));
+        }
+        let shifted_h = params
+            .powers_of_h
+            .get(max_degree - bound)
+            .ok_or(BackendError::Math("insufficient SRS powers"))?;
+
+        // Verify: e(C, h*τ^(D - bound)) == e(π, h)
+        let h = params.powers_of_h[0];
+        let result = B::multi_pairing(&[*commitment, proof.negate()], &[*shifted_h, h])?;
+        Ok(result == <B::Target as TargetGroup>::identity())
+    }
+}
+
+/// Derives the trapdoor `τ` that [`KZG`] setup uses for `seed`.
+pub(crate) fn setup_trapdoor(seed: &[u8; 32]) -> Fr {
+    let mut rng = ChaCha20Rng::from_seed(*seed);
+    Fr::random(&mut rng)
 }
 
-fn wipe_scalars<F: FieldElement + Copy>(scalars: &mut [F]) {
+pub(super) fn wipe_scalars<F: FieldElement + Copy>(scalars: &mut [F]) {
     let zero = F::zero();
     for scalar in scalars {
         unsafe { core::ptr::write_volatile(scalar, zero) };
@@ -23,16 +23,44 @@
 //!   along with key structures like [`SecretKey`], [`PublicKey`], [`Ciphertext`], etc.
 //!
 //! - **`kzg`**: KZG polynomial commitment scheme with [`SRS`] and [`PolynomialCommitment`] trait.
+//!   [`PowersOfTauReader`] imports an SRS from Zcash or Perpetual Powers of Tau
+//!   ceremony transcripts, and [`SRS::to_bytes`] writes a hashed binary file that
+//!   [`SRS::from_bytes`] can fully verify on load.
 //!
 //! - **`sym_enc`**: Symmetric encryption using BLAKE3 for payload encapsulation.
 //!
+//! - **`cpu`**: Runtime [`CpuFeatures`] detection that selects the AVX2,
+//!   AVX-512 or NEON implementation of the crate's own hot kernels.
+//!
+//! - **`xof`**: The [`Xof`] trait that lets deployments replace BLAKE3 in the
+//!   payload keystream and key derivation.
+//!
+//! - **`pvss`**: Publicly verifiable secret sharing over the backend groups.
+//!
+//! - **`rng`**: Fork-safe, reseeding [`TessRng`] for long-running processes.
+//!
+//! - **`protocol`**: Sans-IO state machines for silent setup, decryption
+//!   sessions and resharing that integrators drive over any transport.
+//!
+//! - **`sim`**: In-memory committee [`Simulation`] with fault-injection hooks for
+//!   integration tests and demos.
+//!
+//! - **`backends`**: Per-backend namespaces so several backends can be enabled
+//!   in one build.
+//!
 //! - **`errors`**: Error types for backend and protocol operations.
 //!
+//! - **[`prelude`]**: The high-level API without the arithmetic internals;
+//!   prefer `use tess::prelude::*` over globbing the crate root.
+//!
+//! - **`adversarial`** (`test-utils` feature): Deliberately invalid artifacts for
+//!   checking that verification paths reject them.
+//!
 //! ## Quick Example
 //!
 //! ```rust,no_run
 //! use rand::thread_rng;
-//! use tess::{PairingEngine, SilentThresholdScheme};
+//! use tess::prelude::*;
 //!
 //! let mut rng = thread_rng();
 //! let scheme = SilentThresholdScheme::<PairingEngine>::new();
@@ -46,6 +74,46 @@
 //! - **`blst`** (default): blstrs backend for BLS12-381
 //! - **`ark_bls12381`**: Arkworks backend for BLS12-381
 //! - **`ark_bn254`**: Arkworks backend for BN254
+//! - **`mock`**: insecure `MockBackend` for fast protocol tests. Enabled on
+//!   its own it becomes the crate root backend over the 64-bit `MockFr`
+//!   field, so protocol code builds without any curve library
+//! - **`parallel`** (default): spread MSMs, FFTs, key generation and payload
+//!   encryption across threads with rayon. Without it every operation runs on
+//!   the calling thread, for targets such as WASM or embedded systems where
+//!   rayon is unavailable
+//! - **`verify-only`**: the dependency set for on-chain and embedded
+//!   verifiers, used with `default-features = false`; see
+//!   [Verifier Builds](#verifier-builds)
+//! - **`borsh`**: Borsh encodings of ciphertexts, partial decryptions and
+//!   public keys for Solana and Anchor programs, with the byte layouts
+//!   documented on `Ciphertext::borsh_len` and friends
+//! - **`js`**: draw OS entropy from the browser's `crypto.getRandomValues` on
+//!   `wasm32-unknown-unknown`, which `getrandom` cannot reach otherwise
+//!
+//! Backends can be enabled together. Each is reachable under [`backends`], and
+//! the crate root re-exports the first enabled one in the order above.
+//!
+//! ## Verifier Builds
+//!
+//! Programs that only check what off-chain parties produce, such as Solana
+//! BPF programs, build the crate with
+//! `default-features = false, features = ["verify-only"]`. This selects the
+//! arkworks BLS12-381 backend, which is pure Rust, and leaves out `std`,
+//! rayon and the `blst` C library. The checks available there are:
+//!
+//! - [`verify_partial_decryption`] and [`verify_partial_decryptions`] for
+//!   decryption shares
+//! - [`AggregateKeyDigest::verify_ciphertext`] for ciphertext validity proofs
+//! - [`FixedCiphertext::verify_partial`] for heap-free share checks
+//! - [`SenderSignature::verify`] for signcrypted ciphertexts
+//!
+//! Key generation, encryption and aggregation still compile, since Cargo
+//! features can only add code, but they are generic over the backend and
+//! therefore add nothing to a binary that never calls them. Public keys and
+//! partial decryptions from `blst` nodes decode under this backend
+//! unchanged, but target-group elements and scalars are encoded differently,
+//! so ciphertexts for an arkworks verifier must be serialized by an arkworks
+//! build.
 //!
 //! ## Protocol Workflow
 //!
@@ -69,7 +137,7 @@
 //!
 //! ## Performance
 //!
-//! TESS leverages parallel processing using Rayon for performance-critical operations:
+//! With the `parallel` feature, TESS uses Rayon for performance-critical operations:
 //! - Multi-scalar multiplication (MSM) operations
 //! - Parallel key generation
 //! - FFT operations in polynomial arithmetic
@@ -82,26 +150,95 @@
 //!   are compromised.
 //! - **Payload Encryption**: Uses BLAKE3 as a KDF to derive symmetric keys from the shared
 //!   secret for payload encapsulation.
+//! - **Randomness**: Long-running encryptors, especially in runtimes that fork worker
+//!   processes, should draw randomness from [`TessRng`], which reseeds from the OS after
+//!   a fork, on clone, and after a fixed output budget.
+//! - **Post-Quantum**: None. Security rests on discrete logarithms in the pairing group,
+//!   and the crate ships no lattice-based or hybrid mode; see the README for why.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 
 #[macro_use]
 extern crate alloc;
+
+/// Adds to an operation counter in [`metrics`] when the `metrics` feature is
+/// enabled; expands to nothing otherwise.
+macro_rules! metric {
+    ($counter:ident += $n:expr) => {
+        #[cfg(feature = "metrics")]
+        $crate::metrics::COUNTERS
+            .$counter
+            .fetch_add(($n) as u64, core::sync::atomic::Ordering::Relaxed);
+    };
+}
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod adversarial;
 mod arith;
+pub mod backends;
+#[cfg(feature = "std")]
+mod checkpoint;
+#[cfg(feature = "std")]
+mod codec;
+mod cpu;
 mod errors;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "std")]
+mod keystore;
 mod kzg;
+mod limits;
+#[cfg(feature = "metrics")]
+mod metrics;
+pub mod prelude;
+mod protocol;
+mod pvss;
+#[cfg(feature = "std")]
+mod rng;
+#[cfg(feature = "std")]
+mod sim;
 mod sym_enc;
 mod tess;
+mod xof;
 
+#[cfg(feature = "borsh")]
+mod borsh_impl;
 #[cfg(feature = "std")]
 mod serde_impl;
+#[cfg(feature = "std")]
+pub use serde_impl::LimitedSeed;
+
+#[cfg(not(any(
+    feature = "blst",
+    feature = "ark_bls12381",
+    feature = "ark_bn254",
+    feature = "mock"
+)))]
+compile_error!("enable one of the `blst`, `ark_bls12381`, `ark_bn254` or `mock` features");
 
 pub use arith::*;
+pub use backends::primary::{Fr, G1, G2, Gt, PairingEngine};
+#[cfg(feature = "std")]
+pub use checkpoint::*;
+pub use cpu::CpuFeatures;
 pub use errors::*;
+#[cfg(feature = "http")]
+pub use http::*;
+#[cfg(feature = "std")]
+pub use keystore::*;
 pub use kzg::*;
+pub use limits::DeserializeLimits;
+#[cfg(feature = "metrics")]
+pub use metrics::*;
+pub use protocol::*;
+pub use pvss::*;
+#[cfg(feature = "std")]
+pub use rng::*;
+#[cfg(feature = "std")]
+pub use sim::*;
 pub use sym_enc::*;
 pub use tess::*;
+pub use xof::*;
@@ -0,0 +1,109 @@
+//! Bounds on attacker-controlled lengths, shared by the Serde and Borsh
+//! decoders.
+
+#[cfg(all(feature = "borsh", feature = "std"))]
+use borsh::{BorshDeserialize, io::Read};
+
+/// Upper bounds on attacker-controlled lengths accepted while deserializing.
+///
+/// Ciphertexts and public keys arrive from the network, so every length that
+/// drives an allocation is checked against these limits as elements are
+/// read, and oversized inputs are rejected before they are buffered.
+///
+/// Plain Serde `Deserialize` and Borsh `BorshDeserialize` calls use
+/// [`DeserializeLimits::default`]. With `std`, [`deserialize`](Self::deserialize),
+/// [`seed`](Self::seed) and [`deserialize_borsh`](Self::deserialize_borsh)
+/// apply other limits to a single call, including every value nested inside
+/// it; other calls, concurrent or not, keep their own limits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// Maximum number of elements in a ciphertext's `proof_g1`, `proof_g2`,
+    /// and validity proof.
+    pub max_proof_elements: usize,
+    /// Maximum ciphertext payload length in bytes.
+    pub max_payload_len: usize,
+    /// Maximum length of a public key's `lagrange_li_lj_z` hints and of a
+    /// ciphertext's broadcast key list, i.e. the largest committee accepted.
+    pub max_public_key_hints: usize,
+}
+
+/// Limits of calls that do not choose their own.
+const DEFAULT_LIMITS: DeserializeLimits = DeserializeLimits {
+    max_proof_elements: 64,
+    max_payload_len: 64 << 20,
+    max_public_key_hints: 1 << 20,
+};
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Limits of the innermost scoped deserialization running on this thread.
+    static CALL_LIMITS: core::cell::Cell<DeserializeLimits> =
+        const { core::cell::Cell::new(DEFAULT_LIMITS) };
+}
+
+impl Default for DeserializeLimits {
+    /// 64 proof elements, 64 MiB payloads, and committees of up to 2^20 parties.
+    fn default() -> Self {
+        DEFAULT_LIMITS
+    }
+}
+
+impl DeserializeLimits {
+    /// Decodes a `T` from Borsh bytes in `reader` under these limits.
+    ///
+    /// ```rust
+    /// use rand::thread_rng;
+    /// use tess::{Ciphertext, DeserializeLimits, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+    ///
+    /// let mut rng = thread_rng();
+    /// let scheme = SilentThresholdScheme::<PairingEngine>::new();
+    /// let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+    /// let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+    /// let ct = scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 2, &[0; 64]).unwrap();
+    /// let bytes = borsh::to_vec(&ct).unwrap();
+    ///
+    /// let strict = DeserializeLimits {
+    ///     max_payload_len: 32,
+    ///     ..DeserializeLimits::default()
+    /// };
+    /// let res = strict.deserialize_borsh::<Ciphertext<PairingEngine>, _>(&mut bytes.as_slice());
+    /// assert!(res.is_err());
+    /// assert!(borsh::from_slice::<Ciphertext<PairingEngine>>(&bytes).is_ok());
+    /// ```
+    #[cfg(all(feature = "borsh", feature = "std"))]
+    pub fn deserialize_borsh<T: BorshDeserialize, R: Read>(
+        self,
+        reader: &mut R,
+    ) -> borsh::io::Result<T> {
+        self.scoped(|| T::deserialize_reader(reader))
+    }
+
+    /// Runs `decode` with these limits in effect on this thread.
+    #[cfg(feature = "std")]
+    pub(crate) fn scoped<T>(self, decode: impl FnOnce() -> T) -> T {
+        /// Restores the caller's limits when the call returns or unwinds.
+        struct Restore(DeserializeLimits);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CALL_LIMITS.with(|limits| limits.set(self.0));
+            }
+        }
+
+        let _restore = Restore(CALL_LIMITS.with(|limits| limits.replace(self)));
+        decode()
+    }
+
+    /// Limits in effect for the value being deserialized.
+    #[cfg(any(feature = "std", feature = "borsh"))]
+    pub(crate) fn in_effect() -> Self {
+        #[cfg(feature = "std")]
+        {
+            CALL_LIMITS.with(core::cell::Cell::get)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            DEFAULT_LIMITS
+        }
+    }
+}
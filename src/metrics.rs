@@ -0,0 +1,362 @@
+//! Operation counters for performance work.
+//!
+//! With the `metrics` feature, the backends count every pairing, MSM, scalar
+//! multiplication and serialized byte in process-wide atomic counters.
+//! [`Metrics::snapshot`] reads them, and [`Metrics::measure`] reports what a
+//! single high-level operation cost:
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{Metrics, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+//!
+//! let (ct, cost) = Metrics::measure(|| {
+//!     scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 4, b"payload")
+//! });
+//! ct.unwrap();
+//! println!("encrypt: {} pairings, {} G1 MSM terms", cost.pairings, cost.msm_g1.terms);
+//! ```
+//!
+//! Counters are shared by all threads, which lets them see work that rayon
+//! spreads across its pool, but it also means a measurement includes whatever
+//! else the process did at the same time. Measure on an otherwise idle process.
+//!
+//! Counting is a relaxed atomic increment per operation. Without the feature
+//! the instrumentation compiles to nothing.
+//!
+//! # Allocations
+//!
+//! The hint structures grow quadratically with the committee, so memory is
+//! usually what limits scale. Installing [`CountingAllocator`] as the global
+//! allocator lets [`AllocationMetrics::measure`] report the allocations and
+//! peak heap growth of an operation:
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use std::alloc::System;
+//! use tess::{
+//!     AllocationMetrics, CountingAllocator, PairingEngine, SilentThresholdScheme,
+//!     ThresholdEncryption,
+//! };
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator<System> = CountingAllocator::new(System);
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+//!
+//! let (keys, cost) = AllocationMetrics::measure(|| scheme.keygen_unsafe(&mut rng, 8, &params));
+//! keys.unwrap();
+//! println!("keygen: {} allocations, peak {} bytes", cost.allocations, cost.peak_bytes);
+//! ```
+//!
+//! The same caveat about concurrent work applies, and nested measurements
+//! disturb each other's peak.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Calls and total terms of the MSMs in one group.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MsmMetrics {
+    /// Number of MSMs computed.
+    pub calls: u64,
+    /// Sum of the MSM lengths, i.e. the number of (base, scalar) pairs.
+    pub terms: u64,
+}
+
+/// Snapshot of the operation counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// Pairings evaluated; a multi-pairing counts one per term.
+    pub pairings: u64,
+    /// MSMs in G1.
+    pub msm_g1: MsmMetrics,
+    /// MSMs in G2.
+    pub msm_g2: MsmMetrics,
+    /// Multi-exponentiations in the target group.
+    pub msm_gt: MsmMetrics,
+    /// Single scalar multiplications in G1, G2 and the target group, outside
+    /// of MSMs.
+    pub scalar_muls: u64,
+    /// Bytes produced by encoding scalars and group elements.
+    pub bytes_serialized: u64,
+}
+
+pub(crate) struct Counters {
+    pub(crate) pairings: AtomicU64,
+    pub(crate) msm_g1_calls: AtomicU64,
+    pub(crate) msm_g1_terms: AtomicU64,
+    pub(crate) msm_g2_calls: AtomicU64,
+    pub(crate) msm_g2_terms: AtomicU64,
+    pub(crate) msm_gt_calls: AtomicU64,
+    pub(crate) msm_gt_terms: AtomicU64,
+    pub(crate) scalar_muls: AtomicU64,
+    pub(crate) bytes_serialized: AtomicU64,
+}
+
+pub(crate) static COUNTERS: Counters = Counters {
+    pairings: AtomicU64::new(0),
+    msm_g1_calls: AtomicU64::new(0),
+    msm_g1_terms: AtomicU64::new(0),
+    msm_g2_calls: AtomicU64::new(0),
+    msm_g2_terms: AtomicU64::new(0),
+    msm_gt_calls: AtomicU64::new(0),
+    msm_gt_terms: AtomicU64::new(0),
+    scalar_muls: AtomicU64::new(0),
+    bytes_serialized: AtomicU64::new(0),
+};
+
+impl Counters {
+    fn all(&self) -> [&AtomicU64; 9] {
+        [
+            &self.pairings,
+            &self.msm_g1_calls,
+            &self.msm_g1_terms,
+            &self.msm_g2_calls,
+            &self.msm_g2_terms,
+            &self.msm_gt_calls,
+            &self.msm_gt_terms,
+            &self.scalar_muls,
+            &self.bytes_serialized,
+        ]
+    }
+}
+
+impl Metrics {
+    /// Reads the current counter values.
+    pub fn snapshot() -> Self {
+        let read = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        let c = &COUNTERS;
+        Self {
+            pairings: read(&c.pairings),
+            msm_g1: MsmMetrics {
+                calls: read(&c.msm_g1_calls),
+                terms: read(&c.msm_g1_terms),
+            },
+            msm_g2: MsmMetrics {
+                calls: read(&c.msm_g2_calls),
+                terms: read(&c.msm_g2_terms),
+            },
+            msm_gt: MsmMetrics {
+                calls: read(&c.msm_gt_calls),
+                terms: read(&c.msm_gt_terms),
+            },
+            scalar_muls: read(&c.scalar_muls),
+            bytes_serialized: read(&c.bytes_serialized),
+        }
+    }
+
+    /// Resets every counter to zero.
+    pub fn reset() {
+        for counter in COUNTERS.all() {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Counts accumulated between `earlier` and this snapshot.
+    pub fn since(&self, earlier: &Metrics) -> Metrics {
+        let msm = |now: MsmMetrics, then: MsmMetrics| MsmMetrics {
+            calls: now.calls.saturating_sub(then.calls),
+            terms: now.terms.saturating_sub(then.terms),
+        };
+        Metrics {
+            pairings: self.pairings.saturating_sub(earlier.pairings),
+            msm_g1: msm(self.msm_g1, earlier.msm_g1),
+            msm_g2: msm(self.msm_g2, earlier.msm_g2),
+            msm_gt: msm(self.msm_gt, earlier.msm_gt),
+            scalar_muls: self.scalar_muls.saturating_sub(earlier.scalar_muls),
+            bytes_serialized: self
+                .bytes_serialized
+                .saturating_sub(earlier.bytes_serialized),
+        }
+    }
+
+    /// Runs `operation` and returns its result with the counts it added.
+    pub fn measure<T>(operation: impl FnOnce() -> T) -> (T, Metrics) {
+        let before = Self::snapshot();
+        let result = operation();
+        (result, Self::snapshot().since(&before))
+    }
+}
+
+/// Global allocator wrapper that feeds [`AllocationMetrics`].
+///
+/// Without it installed as the `#[global_allocator]`, every allocation
+/// measurement reads zero.
+#[derive(Debug, Default)]
+pub struct CountingAllocator<A> {
+    inner: A,
+}
+
+impl<A> CountingAllocator<A> {
+    /// Wraps `inner`, typically `std::alloc::System`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES_ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn record_alloc(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES_ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_dealloc(size: usize) {
+    LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+// SAFETY: every call is forwarded unchanged to `inner`; the wrapper only
+// updates counters.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: the caller upholds `GlobalAlloc::alloc`'s contract.
+        let ptr = unsafe { self.inner.alloc(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: the caller upholds `GlobalAlloc::alloc_zeroed`'s contract.
+        let ptr = unsafe { self.inner.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: the caller upholds `GlobalAlloc::dealloc`'s contract.
+        unsafe { self.inner.dealloc(ptr, layout) };
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: the caller upholds `GlobalAlloc::realloc`'s contract.
+        let new_ptr = unsafe { self.inner.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of an operation, recorded by [`CountingAllocator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocationMetrics {
+    /// Allocations made, counting each reallocation as one.
+    pub allocations: u64,
+    /// Total bytes requested by those allocations.
+    pub bytes_allocated: u64,
+    /// Highest heap usage reached above the starting level.
+    pub peak_bytes: usize,
+}
+
+impl AllocationMetrics {
+    /// Runs `operation` and returns its result with the allocations it made.
+    pub fn measure<T>(operation: impl FnOnce() -> T) -> (T, AllocationMetrics) {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let bytes_allocated = BYTES_ALLOCATED.load(Ordering::Relaxed);
+        let live = LIVE_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(live, Ordering::Relaxed);
+
+        let result = operation();
+
+        let metrics = AllocationMetrics {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed) - bytes_allocated,
+            peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(live),
+        };
+        (result, metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{
+        PairingBackend, PairingEngine, SilentThresholdScheme, ThresholdEncryption,
+        verify_partial_decryption,
+    };
+
+    // Other tests run concurrently and share the counters, so only lower
+    // bounds are meaningful here.
+    #[test]
+    fn operations_are_counted() {
+        let mut rng = StdRng::seed_from_u64(1450);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"metrics")
+            .unwrap();
+        let partial = scheme.partial_decrypt(&keys.secret_keys[0], &ct).unwrap();
+
+        let (_, cost) =
+            Metrics::measure(|| verify_partial_decryption(&keys.public_keys[0], &ct, &partial));
+        assert!(cost.pairings >= 2);
+
+        let (_, cost) = Metrics::measure(|| scheme.partial_decrypt(&keys.secret_keys[1], &ct));
+        assert!(cost.scalar_muls >= 1);
+
+        let (bytes, cost) = Metrics::measure(|| PairingEngine::serialize_g2(&partial.response));
+        assert!(cost.bytes_serialized >= bytes.len() as u64);
+
+        let selector = [true, true, true, false];
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let (result, cost) = Metrics::measure(|| {
+            scheme.aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+        });
+        assert_eq!(result.unwrap().plaintext.unwrap(), b"metrics");
+        assert!(cost.msm_g1.calls >= 1 && cost.msm_g1.terms >= 3);
+        assert!(cost.msm_g2.calls >= 1);
+
+        let now = Metrics::snapshot();
+        assert_eq!(now.since(&now), Metrics::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator<std::alloc::System> =
+        CountingAllocator::new(std::alloc::System);
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn allocations_are_counted() {
+        let mut rng = StdRng::seed_from_u64(1453);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+
+        let (keys, cost) =
+            AllocationMetrics::measure(|| scheme.keygen_unsafe(&mut rng, 8, &params));
+        let keys = keys.unwrap();
+        assert!(cost.allocations > 0);
+        assert!(cost.peak_bytes > 0);
+        assert!(cost.bytes_allocated >= cost.peak_bytes as u64);
+
+        let (buffer, cost) = AllocationMetrics::measure(|| Vec::<u8>::with_capacity(1 << 20));
+        assert!(cost.allocations >= 1);
+        assert!(cost.peak_bytes >= buffer.capacity());
+        drop(keys);
+    }
+}
@@ -0,0 +1,41 @@
+//! The high-level API in one import.
+//!
+//! ```rust
+//! use tess::prelude::*;
+//!
+//! let mut rng = rand::thread_rng();
+//! let scheme =
+//!     SilentThreshold::<PairingEngine>::new().with_padding(PaddingScheme::FixedBucket(256));
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let ct = scheme
+//!     .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"prelude")
+//!     .unwrap();
+//! let partials: Vec<_> = keys.secret_keys[..2]
+//!     .iter()
+//!     .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+//!     .collect();
+//! let result = scheme
+//!     .aggregate_decrypt(&ct, &partials, &[true, true, false, false], &keys.aggregate_key)
+//!     .unwrap();
+//! assert_eq!(result.plaintext.unwrap(), b"prelude");
+//! ```
+//!
+//! The prelude covers the scheme, its builder options, the key, parameter and
+//! ciphertext types, errors, and the serde traits their wire formats are built
+//! on. It deliberately leaves out the arithmetic traits, MSM providers and
+//! per-backend modules: those stay reachable from the crate root for code
+//! that needs them, but may change between releases more freely than what is
+//! listed here. [`PairingEngine`] and [`Fr`] are the crate's primary backend,
+//! needed to name the generic types.
+
+pub use crate::{
+    AggregateKey, Ciphertext, CiphertextHeader, Committee, DecryptionResult, Error, Fr, KdfId,
+    PaddingScheme, PairingEngine, Params, PartialDecryption, PublicKey, PublicKeyRegistry, SRS,
+    SecretKey, SilentThreshold, SilentThresholdScheme, ThresholdEncryption, UnsafeKeyMaterial,
+};
+
+#[cfg(feature = "std")]
+pub use crate::{DeserializeLimits, SrsCheck};
+#[cfg(feature = "std")]
+pub use serde::{Deserialize, Serialize};
@@ -0,0 +1,676 @@
+//! Sans-IO state machines for the committee protocols.
+//!
+//! Each protocol role is a [`Protocol`] that consumes inbound messages and
+//! returns the messages it wants sent, without touching a network, clock or
+//! thread. Integrators move [`OutgoingMessage`]s over whatever transport they
+//! use (gRPC, libp2p, a blockchain) and feed what arrives back into
+//! [`handle_message`](Protocol::handle_message), tagged with the sender's
+//! [`Node`] as authenticated by that transport. Timeouts and retries stay
+//! with the integrator: a machine never waits, it only reacts.
+//!
+//! | Protocol | Participant | Coordinator | Output |
+//! |----------|-------------|-------------|--------|
+//! | Silent setup | [`KeygenParticipant`] | [`KeygenCoordinator`] | [`AggregateKey`] |
+//! | Decryption | [`DecryptionParticipant`] | [`DecryptionSession`] | [`DecryptionResult`] |
+//! | Resharing | [`ReshareDealer`] | [`ReshareCoordinator`] | [`PvssTranscript`] |
+//!
+//! Machines that need randomness draw it when they are constructed, so
+//! [`handle_message`](Protocol::handle_message) is deterministic. A message
+//! that is invalid, unexpected or from the wrong sender is answered with an
+//! error and leaves the machine unchanged, so one faulty peer cannot stall a
+//! session.
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{
+//!     DecryptionParticipant, DecryptionSession, Node, PairingEngine, Protocol,
+//!     SilentThresholdScheme, ThresholdEncryption,
+//! };
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let ct = scheme
+//!     .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"sans-io")
+//!     .unwrap();
+//!
+//! let mut session = DecryptionSession::new(scheme.clone(), ct, keys.aggregate_key.clone());
+//! let mut participants: Vec<_> = keys
+//!     .secret_keys
+//!     .into_iter()
+//!     .map(|sk| DecryptionParticipant::new(scheme.clone(), sk))
+//!     .collect();
+//!
+//! let mut queue = session.start();
+//! while let Some(out) = queue.pop() {
+//!     let Node::Participant(id) = out.to else { unreachable!() };
+//!     for reply in participants[id].handle_message(Node::Coordinator, out.message).unwrap() {
+//!         session.handle_message(Node::Participant(id), reply.message).unwrap();
+//!     }
+//! }
+//! let result = session.take_output().unwrap();
+//! assert_eq!(result.plaintext.unwrap(), b"sans-io");
+//! ```
+
+use alloc::{boxed::Box, collections::BTreeMap, vec, vec::Vec};
+
+use rand_core::RngCore;
+
+use crate::{
+    AggregateKey, Ciphertext, DecryptionResult, Fr, PairingBackend, Params, PartialDecryption,
+    PublicKey, PublicKeyRegistry, PvssTranscript, Reshare, SecretKey, ThresholdEncryption,
+    errors::Error, verify_partial_decryption,
+};
+
+/// Address of a protocol node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Node {
+    /// Committee member with the given participant id.
+    Participant(usize),
+    /// The party that aggregates keys, shares and re-dealings.
+    Coordinator,
+}
+
+/// Protocol message carried between nodes.
+#[derive(Clone, Debug)]
+pub enum Message<B: PairingBackend<Scalar = Fr>> {
+    /// A participant's public key, sent to the coordinator during keygen.
+    PublicKey(PublicKey<B>),
+    /// A ciphertext the coordinator asks participants to decrypt.
+    Ciphertext(Box<Ciphertext<B>>),
+    /// A participant's decryption share, sent to the coordinator.
+    Partial(PartialDecryption<B>),
+    /// An old committee member's re-dealing of its share.
+    Reshare(Box<Reshare<B>>),
+}
+
+/// A message a [`Protocol`] asks its driver to send.
+#[derive(Clone, Debug)]
+pub struct OutgoingMessage<B: PairingBackend<Scalar = Fr>> {
+    /// Receiving node.
+    pub to: Node,
+    /// Payload.
+    pub message: Message<B>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> OutgoingMessage<B> {
+    fn to_coordinator(message: Message<B>) -> Vec<Self> {
+        vec![Self {
+            to: Node::Coordinator,
+            message,
+        }]
+    }
+}
+
+/// One role in a committee protocol, driven by its caller.
+pub trait Protocol<B: PairingBackend<Scalar = Fr>> {
+    /// What the role produces once the protocol completes.
+    type Output;
+
+    /// Messages to send when the protocol begins.
+    fn start(&mut self) -> Vec<OutgoingMessage<B>>;
+
+    /// Processes `message` from `from` and returns the messages to send in
+    /// response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] for a message this role does not
+    /// expect from `from` or that fails verification, and otherwise the
+    /// errors of the underlying scheme call. The state is unchanged on error.
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error>;
+
+    /// Takes the output once the protocol has completed.
+    fn take_output(&mut self) -> Option<Self::Output>;
+}
+
+fn unexpected<B: PairingBackend<Scalar = Fr>>(
+    role: &str,
+    from: Node,
+    message: &Message<B>,
+) -> Error {
+    let kind = match message {
+        Message::PublicKey(_) => "public key",
+        Message::Ciphertext(_) => "ciphertext",
+        Message::Partial(_) => "partial decryption",
+        Message::Reshare(_) => "reshare",
+    };
+    Error::MalformedInput(format!("{role} does not accept a {kind} from {from:?}"))
+}
+
+/// Committee member in silent setup: generates its key pair and publishes the
+/// public half.
+///
+/// The output is the member's [`SecretKey`], available right after
+/// [`start`](Protocol::start).
+pub struct KeygenParticipant<B: PairingBackend<Scalar = Fr>> {
+    secret_key: Option<SecretKey<B>>,
+    public_key: Option<PublicKey<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> KeygenParticipant<B> {
+    /// Generates the key pair of participant `id`.
+    pub fn new<S: ThresholdEncryption<B>, R: RngCore + ?Sized>(
+        rng: &mut R,
+        scheme: &S,
+        id: usize,
+        params: &Params<B>,
+    ) -> Result<Self, Error> {
+        let (secret_key, public_key) = scheme.keygen_single_validator(rng, id, params)?;
+        Ok(Self {
+            secret_key: Some(secret_key),
+            public_key: Some(public_key),
+        })
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Protocol<B> for KeygenParticipant<B> {
+    type Output = SecretKey<B>;
+
+    fn start(&mut self) -> Vec<OutgoingMessage<B>> {
+        self.public_key
+            .take()
+            .map(|pk| OutgoingMessage::to_coordinator(Message::PublicKey(pk)))
+            .unwrap_or_default()
+    }
+
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error> {
+        Err(unexpected("keygen participant", from, &message))
+    }
+
+    fn take_output(&mut self) -> Option<SecretKey<B>> {
+        if self.public_key.is_some() {
+            return None;
+        }
+        self.secret_key.take()
+    }
+}
+
+/// Coordinator of silent setup: collects every member's public key and
+/// aggregates them.
+pub struct KeygenCoordinator<B: PairingBackend<Scalar = Fr>, S> {
+    scheme: S,
+    params: Params<B>,
+    registry: PublicKeyRegistry<B>,
+    output: Option<AggregateKey<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>, S: ThresholdEncryption<B>> KeygenCoordinator<B, S> {
+    /// Waits for a key from every participant of `params`' committee.
+    pub fn new(scheme: S, params: Params<B>) -> Self {
+        Self {
+            scheme,
+            registry: PublicKeyRegistry::new(&params),
+            params,
+            output: None,
+        }
+    }
+
+    /// Participants whose key has not arrived yet.
+    pub fn missing(&self) -> Vec<usize> {
+        self.registry.missing()
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, S: ThresholdEncryption<B>> Protocol<B>
+    for KeygenCoordinator<B, S>
+{
+    type Output = AggregateKey<B>;
+
+    fn start(&mut self) -> Vec<OutgoingMessage<B>> {
+        Vec::new()
+    }
+
+    /// Registers a public key sent by the participant it belongs to; see
+    /// [`PublicKeyRegistry::insert`] for the checks. Aggregates once every
+    /// participant has registered.
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error> {
+        let public_key = match (from, message) {
+            (Node::Participant(id), Message::PublicKey(pk)) if pk.participant_id == id => pk,
+            (from, message) => return Err(unexpected("keygen coordinator", from, &message)),
+        };
+        let id = public_key.participant_id;
+        self.registry.insert(public_key)?;
+        if self.registry.is_complete() {
+            match self.registry.aggregate(&self.scheme, &self.params) {
+                Ok(aggregate_key) => self.output = Some(aggregate_key),
+                Err(e) => {
+                    self.registry.remove(id);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(Vec::new())
+    }
+
+    fn take_output(&mut self) -> Option<AggregateKey<B>> {
+        self.output.take()
+    }
+}
+
+/// Committee member in decryption sessions: answers every ciphertext from the
+/// coordinator with its partial decryption.
+///
+/// Serves any number of sessions and has no output.
+pub struct DecryptionParticipant<B: PairingBackend<Scalar = Fr>, S> {
+    scheme: S,
+    secret_key: SecretKey<B>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>, S: ThresholdEncryption<B>> DecryptionParticipant<B, S> {
+    /// Decrypts with `secret_key`.
+    pub fn new(scheme: S, secret_key: SecretKey<B>) -> Self {
+        Self { scheme, secret_key }
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, S: ThresholdEncryption<B>> Protocol<B>
+    for DecryptionParticipant<B, S>
+{
+    type Output = ();
+
+    fn start(&mut self) -> Vec<OutgoingMessage<B>> {
+        Vec::new()
+    }
+
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error> {
+        let Message::Ciphertext(ciphertext) = message else {
+            return Err(unexpected("decryption participant", from, &message));
+        };
+        if from != Node::Coordinator {
+            return Err(unexpected(
+                "decryption participant",
+                from,
+                &Message::Ciphertext(ciphertext),
+            ));
+        }
+        let partial = self.scheme.partial_decrypt(&self.secret_key, &ciphertext)?;
+        Ok(OutgoingMessage::to_coordinator(Message::Partial(partial)))
+    }
+
+    fn take_output(&mut self) -> Option<()> {
+        None
+    }
+}
+
+/// Coordinator of one decryption: broadcasts the ciphertext, verifies the
+/// returned shares and aggregates as soon as enough are valid.
+///
+/// As with [`ThresholdEncryption::aggregate_decrypt`], participant 0 must be
+/// among the shares, so the session keeps waiting until it has answered.
+pub struct DecryptionSession<B: PairingBackend<Scalar = Fr>, S> {
+    scheme: S,
+    ciphertext: Ciphertext<B>,
+    aggregate_key: AggregateKey<B>,
+    partials: BTreeMap<usize, PartialDecryption<B>>,
+    output: Option<DecryptionResult<B>>,
+    done: bool,
+}
+
+impl<B: PairingBackend<Scalar = Fr>, S: ThresholdEncryption<B>> DecryptionSession<B, S> {
+    /// Starts a session decrypting `ciphertext` under `aggregate_key`.
+    pub fn new(scheme: S, ciphertext: Ciphertext<B>, aggregate_key: AggregateKey<B>) -> Self {
+        Self {
+            scheme,
+            ciphertext,
+            aggregate_key,
+            partials: BTreeMap::new(),
+            output: None,
+            done: false,
+        }
+    }
+
+    /// Participants whose valid share has been received.
+    pub fn received(&self) -> Vec<usize> {
+        self.partials.keys().copied().collect()
+    }
+
+    fn try_aggregate(&mut self) -> Result<(), Error> {
+        // Participant 0's share anchors the interpolation.
+        if !self.partials.contains_key(&0)
+            || self.partials.len() + self.aggregate_key.revoked.len() < self.ciphertext.threshold
+        {
+            return Ok(());
+        }
+        let mut selector = vec![false; self.aggregate_key.public_keys.len()];
+        for &id in self.partials.keys() {
+            selector[id] = true;
+        }
+        let partials: Vec<_> = self.partials.values().cloned().collect();
+        match self.scheme.aggregate_decrypt(
+            &self.ciphertext,
+            &partials,
+            &selector,
+            &self.aggregate_key,
+        ) {
+            Ok(result) => {
+                self.output = Some(result);
+                self.done = true;
+                Ok(())
+            }
+            Err(Error::InsufficientShares { .. }) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, S: ThresholdEncryption<B>> Protocol<B>
+    for DecryptionSession<B, S>
+{
+    type Output = DecryptionResult<B>;
+
+    /// Sends the ciphertext to every participant.
+    fn start(&mut self) -> Vec<OutgoingMessage<B>> {
+        (0..self.aggregate_key.public_keys.len())
+            .map(|id| OutgoingMessage {
+                to: Node::Participant(id),
+                message: Message::Ciphertext(Box::new(self.ciphertext.clone())),
+            })
+            .collect()
+    }
+
+    /// Accepts a share that passes [`verify_partial_decryption`] for its
+    /// sender. Shares arriving after the session completed are ignored.
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error> {
+        let (id, partial) = match (from, message) {
+            (Node::Participant(id), Message::Partial(p)) if p.participant_id == id => (id, p),
+            (from, message) => return Err(unexpected("decryption session", from, &message)),
+        };
+        if self.done || self.partials.contains_key(&id) {
+            return Ok(Vec::new());
+        }
+        match self.aggregate_key.public_keys.get(id) {
+            Some(public_key)
+                if verify_partial_decryption(public_key, &self.ciphertext, &partial) => {}
+            _ => {
+                return Err(Error::MalformedInput(format!(
+                    "invalid partial decryption from participant {id}"
+                )));
+            }
+        }
+        self.partials.insert(id, partial);
+        if let Err(e) = self.try_aggregate() {
+            self.partials.remove(&id);
+            return Err(e);
+        }
+        Ok(Vec::new())
+    }
+
+    fn take_output(&mut self) -> Option<DecryptionResult<B>> {
+        self.output.take()
+    }
+}
+
+/// Old committee member in resharing: re-deals its share of a PVSS secret to
+/// the new committee.
+pub struct ReshareDealer<B: PairingBackend<Scalar = Fr>> {
+    reshare: Option<Reshare<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> ReshareDealer<B> {
+    /// Re-deals `share`, held as participant `dealer`; see [`Reshare::deal`].
+    pub fn new<R: RngCore + ?Sized>(
+        rng: &mut R,
+        dealer: usize,
+        share: &Fr,
+        new_threshold: usize,
+        new_public_keys: &[B::G1],
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            reshare: Some(Reshare::deal(
+                rng,
+                dealer,
+                share,
+                new_threshold,
+                new_public_keys,
+            )?),
+        })
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Protocol<B> for ReshareDealer<B> {
+    type Output = ();
+
+    fn start(&mut self) -> Vec<OutgoingMessage<B>> {
+        self.reshare
+            .take()
+            .map(|r| OutgoingMessage::to_coordinator(Message::Reshare(Box::new(r))))
+            .unwrap_or_default()
+    }
+
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error> {
+        Err(unexpected("reshare dealer", from, &message))
+    }
+
+    fn take_output(&mut self) -> Option<()> {
+        None
+    }
+}
+
+/// Coordinator of resharing: verifies re-dealings from the old committee and
+/// combines the first `threshold` valid ones into the new sharing.
+pub struct ReshareCoordinator<B: PairingBackend<Scalar = Fr>> {
+    original: PvssTranscript<B>,
+    new_public_keys: Vec<B::G1>,
+    reshares: BTreeMap<usize, Reshare<B>>,
+    output: Option<PvssTranscript<B>>,
+    done: bool,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> ReshareCoordinator<B> {
+    /// Reshares the secret of `original` to the holders of `new_public_keys`.
+    pub fn new(original: PvssTranscript<B>, new_public_keys: Vec<B::G1>) -> Self {
+        Self {
+            original,
+            new_public_keys,
+            reshares: BTreeMap::new(),
+            output: None,
+            done: false,
+        }
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Protocol<B> for ReshareCoordinator<B> {
+    type Output = PvssTranscript<B>;
+
+    fn start(&mut self) -> Vec<OutgoingMessage<B>> {
+        Vec::new()
+    }
+
+    /// Accepts a re-dealing sent by its dealer that passes
+    /// [`Reshare::verify`].
+    fn handle_message(
+        &mut self,
+        from: Node,
+        message: Message<B>,
+    ) -> Result<Vec<OutgoingMessage<B>>, Error> {
+        let reshare = match (from, message) {
+            (Node::Participant(id), Message::Reshare(r)) if r.dealer == id => r,
+            (from, message) => return Err(unexpected("reshare coordinator", from, &message)),
+        };
+        if self.done || self.reshares.contains_key(&reshare.dealer) {
+            return Ok(Vec::new());
+        }
+        reshare.verify(&self.original, &self.new_public_keys)?;
+        self.reshares.insert(reshare.dealer, *reshare);
+        if self.reshares.len() >= self.original.threshold {
+            let reshares: Vec<_> = self.reshares.values().cloned().collect();
+            self.output = Some(
+                self.original
+                    .combine_reshares(&reshares, &self.new_public_keys)?,
+            );
+            self.done = true;
+        }
+        Ok(Vec::new())
+    }
+
+    fn take_output(&mut self) -> Option<PvssTranscript<B>> {
+        self.output.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{CurvePoint, FieldElement, PairingEngine, SilentThresholdScheme};
+
+    type B = PairingEngine;
+
+    #[test]
+    fn keygen_and_decryption_without_io() {
+        let mut rng = StdRng::seed_from_u64(1474);
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+
+        let mut members: Vec<_> = (0..4)
+            .map(|id| KeygenParticipant::new(&mut rng, &scheme, id, &params).unwrap())
+            .collect();
+        let mut keygen = KeygenCoordinator::new(scheme.clone(), params.clone());
+        for (id, member) in members.iter_mut().enumerate() {
+            let mut outgoing = member.start();
+            let message = outgoing.pop().unwrap().message;
+            // Keys claimed by another sender are refused.
+            let forged = (id + 1) % 4;
+            assert!(
+                keygen
+                    .handle_message(Node::Participant(forged), message.clone())
+                    .is_err()
+            );
+            keygen
+                .handle_message(Node::Participant(id), message)
+                .unwrap();
+        }
+        let aggregate_key = keygen.take_output().unwrap();
+        let secret_keys: Vec<_> = members
+            .iter_mut()
+            .map(|m| m.take_output().unwrap())
+            .collect();
+
+        let ct = scheme
+            .encrypt(&mut rng, &aggregate_key, &params, 2, b"sans-io")
+            .unwrap();
+        let mut session = DecryptionSession::new(scheme.clone(), ct, aggregate_key);
+        let mut participants: Vec<_> = secret_keys
+            .into_iter()
+            .map(|sk| DecryptionParticipant::new(scheme.clone(), sk))
+            .collect();
+
+        let mut replies = Vec::new();
+        for out in session.start() {
+            let Node::Participant(id) = out.to else {
+                unreachable!()
+            };
+            for reply in participants[id]
+                .handle_message(Node::Coordinator, out.message)
+                .unwrap()
+            {
+                replies.push((id, reply.message));
+            }
+        }
+        assert_eq!(replies.len(), 4);
+
+        // A corrupted share is rejected without disturbing the session.
+        let (id, Message::Partial(mut bad)) = replies[0].clone() else {
+            unreachable!()
+        };
+        bad.response = bad.response.add(&bad.response);
+        assert!(
+            session
+                .handle_message(Node::Participant(id), Message::Partial(bad))
+                .is_err()
+        );
+
+        for (id, message) in replies {
+            session
+                .handle_message(Node::Participant(id), message)
+                .unwrap();
+        }
+        assert_eq!(session.received(), vec![0, 1]);
+        assert_eq!(
+            session.take_output().unwrap().plaintext.unwrap(),
+            b"sans-io"
+        );
+    }
+
+    #[test]
+    fn resharing_without_io() {
+        let mut rng = StdRng::seed_from_u64(1474);
+        let g = <B as PairingBackend>::G1::generator();
+        let keys = |rng: &mut StdRng, n| -> (Vec<Fr>, Vec<_>) {
+            let sks: Vec<Fr> = (0..n).map(|_| Fr::random(&mut *rng)).collect();
+            let pks = sks.iter().map(|sk| g.mul_scalar(sk)).collect();
+            (sks, pks)
+        };
+        let (_, old_keys) = keys(&mut rng, 4);
+        let (new_secret_keys, new_keys) = keys(&mut rng, 3);
+
+        let secret = Fr::random(&mut rng);
+        let coeffs = [secret, Fr::random(&mut rng)];
+        let shares: Vec<Fr> = (0..4u64)
+            .map(|i| coeffs[0] + coeffs[1] * Fr::from_u64(i + 1))
+            .collect();
+        let original = PvssTranscript::<B> {
+            threshold: 2,
+            commitments: shares
+                .iter()
+                .map(<B as PairingBackend>::G2::mul_generator)
+                .collect(),
+            encrypted_shares: old_keys
+                .iter()
+                .zip(&shares)
+                .map(|(pk, s)| pk.mul_scalar(s))
+                .collect(),
+        };
+
+        let mut coordinator = ReshareCoordinator::new(original, new_keys.clone());
+        for dealer in [3, 1] {
+            let mut machine =
+                ReshareDealer::<B>::new(&mut rng, dealer, &shares[dealer], 2, &new_keys).unwrap();
+            let message = machine.start().pop().unwrap().message;
+            assert!(
+                coordinator
+                    .handle_message(Node::Participant(0), message.clone())
+                    .is_err()
+            );
+            coordinator
+                .handle_message(Node::Participant(dealer), message)
+                .unwrap();
+        }
+        let transcript = coordinator.take_output().unwrap();
+        let recovered = transcript
+            .reconstruct(
+                &(0..2)
+                    .map(|i| transcript.decrypt_share(i, &new_secret_keys[i]).unwrap())
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap();
+        assert!(recovered.sub(&g.mul_scalar(&secret)).is_identity());
+    }
+}
@@ -0,0 +1,431 @@
+//! Publicly verifiable secret sharing (PVSS).
+//!
+//! This module implements the pairing-based SCRAPE PVSS scheme (Cascudo and
+//! David, "SCRAPE: Scalable Randomness Attested by Public Entities"). A dealer
+//! shares a secret `s` among `n` participants with threshold `t` by sampling a
+//! polynomial `p` of degree `t - 1` with `p(0) = s` and publishing, for each
+//! participant `i` with evaluation point `x_i = i + 1`:
+//!
+//! - a commitment `F_i = p(x_i)·h` in G2, and
+//! - an encrypted share `Ŝ_i = p(x_i)·pk_i` in G1, where `pk_i = sk_i·g` is the
+//!   participant's BLS public key.
+//!
+//! Anyone can check a [`PvssTranscript`] without secrets:
+//!
+//! - **Share consistency**: `e(Ŝ_i, h) = e(pk_i, F_i)` proves each encrypted share
+//!   matches its commitment. The pairing equation plays the role of the NIZK of
+//!   correct encryption, so no separate proof object is needed.
+//! - **Degree check**: `Σ v_i·c(x_i)·F_i = 0` for a random dual codeword `c`
+//!   proves that the commitments lie on a polynomial of degree below `t`.
+//!
+//! Participant `i` decrypts its share as `S_i = sk_i⁻¹·Ŝ_i = p(x_i)·g`, which is
+//! itself checkable via `e(S_i, h) = e(g, F_i)`. Any `t` valid shares reconstruct
+//! the shared secret `s·g` by Lagrange interpolation in the exponent.
+//!
+//! Both checks are batched with Fiat-Shamir randomness, so verifying a
+//! transcript costs one `n`-term multi-pairing and two MSMs.
+//!
+//! Holders of scalar shares can hand a shared secret to a new committee with
+//! verifiable [`Reshare`]s; see [`PvssTranscript::combine_reshares`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{CurvePoint, FieldElement, Fr, PairingBackend, PairingEngine, PvssTranscript};
+//!
+//! let mut rng = thread_rng();
+//! let g = <PairingEngine as PairingBackend>::G1::generator();
+//! let secret_keys: Vec<Fr> = (0..4).map(|_| Fr::random(&mut rng)).collect();
+//! let public_keys: Vec<_> = secret_keys.iter().map(|sk| g.mul_scalar(sk)).collect();
+//!
+//! let secret = Fr::random(&mut rng);
+//! let transcript =
+//!     PvssTranscript::<PairingEngine>::deal(&mut rng, &secret, 3, &public_keys).unwrap();
+//! transcript.verify(&public_keys).unwrap();
+//!
+//! let shares: Vec<_> = (0..3)
+//!     .map(|i| transcript.decrypt_share(i, &secret_keys[i]).unwrap())
+//!     .collect();
+//! let recovered = transcript.reconstruct(&shares).unwrap();
+//! assert!(recovered.sub(&g.mul_scalar(&secret)).is_identity());
+//! ```
+
+use alloc::vec::Vec;
+
+use blake3::Hasher;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+use tracing::instrument;
+
+use crate::{
+    CurvePoint, FieldElement, Fr, MsmProvider, PairingBackend, TargetGroup,
+    errors::{BackendError, Error},
+};
+
+mod reshare;
+
+pub use reshare::*;
+
+/// Public output of a PVSS dealing.
+#[derive(Clone, Debug)]
+pub struct PvssTranscript<B: PairingBackend> {
+    /// Number of shares required to reconstruct the secret.
+    pub threshold: usize,
+    /// Commitments `F_i = p(x_i)·h` to each participant's share.
+    pub commitments: Vec<B::G2>,
+    /// Encrypted shares `Ŝ_i = p(x_i)·pk_i`.
+    pub encrypted_shares: Vec<B::G1>,
+}
+
+/// A participant's decrypted PVSS share `S_i = p(x_i)·g`.
+#[derive(Clone, Debug)]
+pub struct PvssShare<B: PairingBackend> {
+    /// Participant index (0-indexed); the evaluation point is `index + 1`.
+    pub index: usize,
+    /// The decrypted share.
+    pub value: B::G1,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> PvssTranscript<B> {
+    /// Shares `secret` among the holders of `public_keys` with threshold `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] unless `1 <= threshold <= public_keys.len()`.
+    #[instrument(level = "info", skip_all, fields(threshold, parties = public_keys.len()))]
+    pub fn deal<R: RngCore + ?Sized>(
+        rng: &mut R,
+        secret: &Fr,
+        threshold: usize,
+        public_keys: &[B::G1],
+    ) -> Result<Self, Error> {
+        check_threshold(threshold, public_keys.len())?;
+
+        let mut coeffs = Vec::with_capacity(threshold);
+        coeffs.push(*secret);
+        coeffs.extend((1..threshold).map(|_| Fr::random(rng)));
+
+        let h = B::G2::generator();
+        let (commitments, encrypted_shares) = public_keys
+            .iter()
+            .enumerate()
+            .map(|(i, pk)| {
+                let eval = evaluate(&coeffs, &evaluation_point(i));
+                (h.mul_scalar(&eval), pk.mul_scalar(&eval))
+            })
+            .unzip();
+
+        Ok(Self {
+            threshold,
+            commitments,
+            encrypted_shares,
+        })
+    }
+
+    /// Verifies the transcript against the participants' public keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the transcript has the wrong shape,
+    /// its commitments are not of degree below the threshold, or any encrypted
+    /// share is inconsistent with its commitment.
+    #[instrument(level = "info", skip_all, fields(threshold = self.threshold, parties = public_keys.len()))]
+    pub fn verify(&self, public_keys: &[B::G1]) -> Result<(), Error> {
+        let n = public_keys.len();
+        check_threshold(self.threshold, n)?;
+        if self.commitments.len() != n || self.encrypted_shares.len() != n {
+            return Err(Error::MalformedInput(
+                "pvss transcript size does not match public keys".into(),
+            ));
+        }
+
+        let mut rng = ChaCha20Rng::from_seed(self.challenge_seed(public_keys));
+
+        // Degree check against a random codeword of the dual code.
+        if self.threshold < n {
+            let dual: Vec<Fr> = (0..n - self.threshold)
+                .map(|_| Fr::random(&mut rng))
+                .collect();
+            let weights = dual_code_weights(n)?
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| v * evaluate(&dual, &evaluation_point(i)))
+                .collect::<Vec<_>>();
+            let combined = B::Msm::msm_g2(&self.commitments, &weights).map_err(Error::Backend)?;
+            if !combined.is_identity() {
+                return Err(Error::MalformedInput(
+                    "pvss commitments exceed the threshold degree".into(),
+                ));
+            }
+        }
+
+        // e(Σ ρ_i·Ŝ_i, h) · Π e(-ρ_i·pk_i, F_i) = 1
+        let rho: Vec<Fr> = (0..n).map(|_| Fr::random(&mut rng)).collect();
+        let mut g1 = Vec::with_capacity(n + 1);
+        g1.push(B::Msm::msm_g1(&self.encrypted_shares, &rho).map_err(Error::Backend)?);
+        g1.extend(
+            public_keys
+                .iter()
+                .zip(&rho)
+                .map(|(pk, r)| pk.mul_scalar(r).negate()),
+        );
+        let mut g2 = Vec::with_capacity(n + 1);
+        g2.push(B::G2::generator());
+        g2.extend(self.commitments.iter().copied());
+        let product = B::multi_pairing(&g1, &g2).map_err(Error::Backend)?;
+        if product != B::Target::identity() {
+            return Err(Error::MalformedInput(
+                "pvss encrypted share does not match its commitment".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Decrypts participant `index`'s share with its secret key.
+    pub fn decrypt_share(&self, index: usize, secret_key: &Fr) -> Result<PvssShare<B>, Error> {
+        let encrypted = self
+            .encrypted_shares
+            .get(index)
+            .ok_or_else(|| Error::MalformedInput("pvss share index out of range".into()))?;
+        let inverse = secret_key
+            .invert()
+            .ok_or(Error::Backend(BackendError::Math("zero secret key")))?;
+        Ok(PvssShare {
+            index,
+            value: encrypted.mul_scalar(&inverse),
+        })
+    }
+
+    /// Checks a decrypted share against its commitment: `e(S_i, h) = e(g, F_i)`.
+    pub fn verify_share(&self, share: &PvssShare<B>) -> Result<(), Error> {
+        let commitment = self
+            .commitments
+            .get(share.index)
+            .ok_or_else(|| Error::MalformedInput("pvss share index out of range".into()))?;
+        if B::pairing(&share.value, &B::G2::generator())
+            != B::pairing(&B::G1::generator(), commitment)
+        {
+            return Err(Error::MalformedInput(
+                "pvss share does not match its commitment".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Verifies the shares and reconstructs the shared secret `s·g`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InsufficientShares`] if fewer than `threshold` distinct shares
+    /// are given, or [`Error::MalformedInput`] if any share is invalid.
+    pub fn reconstruct(&self, shares: &[PvssShare<B>]) -> Result<B::G1, Error> {
+        let mut selected: Vec<&PvssShare<B>> = Vec::with_capacity(self.threshold);
+        for share in shares {
+            if selected.iter().all(|s| s.index != share.index) {
+                self.verify_share(share)?;
+                selected.push(share);
+                if selected.len() == self.threshold {
+                    break;
+                }
+            }
+        }
+        if selected.len() < self.threshold {
+            return Err(Error::InsufficientShares {
+                required: self.threshold,
+                provided: selected.len(),
+            });
+        }
+
+        let points: Vec<Fr> = selected
+            .iter()
+            .map(|share| evaluation_point(share.index))
+            .collect();
+        let lagrange = lagrange_at_zero(&points)?;
+        let values: Vec<B::G1> = selected.iter().map(|share| share.value).collect();
+        B::Msm::msm_g1(&values, &lagrange).map_err(Error::Backend)
+    }
+
+    /// Returns the commitment `s·h` to the shared secret, interpolated from the
+    /// first `threshold` share commitments.
+    pub fn secret_commitment(&self) -> Result<B::G2, Error> {
+        if self.commitments.len() < self.threshold {
+            return Err(Error::MalformedInput(
+                "pvss transcript has too few commitments".into(),
+            ));
+        }
+        let points: Vec<Fr> = (0..self.threshold).map(evaluation_point).collect();
+        let lagrange = lagrange_at_zero(&points)?;
+        B::Msm::msm_g2(&self.commitments[..self.threshold], &lagrange).map_err(Error::Backend)
+    }
+
+    /// Fiat-Shamir seed binding the transcript and the participants' keys.
+    fn challenge_seed(&self, public_keys: &[B::G1]) -> [u8; 32] {
+        let mut hasher = Hasher::new();
+        hasher.update(b"tess::pvss");
+        hasher.update(&(self.threshold as u64).to_le_bytes());
+        hasher.update(&(public_keys.len() as u64).to_le_bytes());
+        for pk in public_keys {
+            hasher.update(pk.to_repr().as_ref());
+        }
+        for commitment in &self.commitments {
+            hasher.update(commitment.to_repr().as_ref());
+        }
+        for share in &self.encrypted_shares {
+            hasher.update(share.to_repr().as_ref());
+        }
+        *hasher.finalize().as_bytes()
+    }
+}
+
+fn check_threshold(threshold: usize, parties: usize) -> Result<(), Error> {
+    if threshold == 0 || threshold > parties {
+        return Err(Error::InvalidConfig(
+            "pvss threshold must be between 1 and the number of parties".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Evaluation point of participant `index`.
+fn evaluation_point(index: usize) -> Fr {
+    Fr::from_u64(index as u64 + 1)
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `x` (Horner's rule).
+fn evaluate(coeffs: &[Fr], x: &Fr) -> Fr {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Fr::zero(), |acc, coeff| acc * *x + *coeff)
+}
+
+/// Returns `v_i = 1 / Π_{j≠i} (x_i - x_j)` for the points `x_i = 1..=n`.
+///
+/// For consecutive integers this is `(-1)^{n-i} / ((i-1)! (n-i)!)`.
+fn dual_code_weights(n: usize) -> Result<Vec<Fr>, Error> {
+    let mut factorials = Vec::with_capacity(n);
+    factorials.push(Fr::one());
+    for k in 1..n {
+        factorials.push(factorials[k - 1] * Fr::from_u64(k as u64));
+    }
+    let mut weights: Vec<Fr> = (0..n)
+        .map(|i| factorials[i] * factorials[n - 1 - i])
+        .collect();
+    Fr::batch_inversion(&mut weights).map_err(Error::Backend)?;
+    for (i, weight) in weights.iter_mut().enumerate() {
+        if (n - 1 - i) % 2 == 1 {
+            *weight = -*weight;
+        }
+    }
+    Ok(weights)
+}
+
+/// Lagrange coefficients for interpolating at zero from distinct `points`.
+fn lagrange_at_zero(points: &[Fr]) -> Result<Vec<Fr>, Error> {
+    let mut denominators = Vec::with_capacity(points.len());
+    let mut numerators = Vec::with_capacity(points.len());
+    for (i, xi) in points.iter().enumerate() {
+        let mut num = Fr::one();
+        let mut den = Fr::one();
+        for (j, xj) in points.iter().enumerate() {
+            if i != j {
+                num *= *xj;
+                den *= *xj - *xi;
+            }
+        }
+        numerators.push(num);
+        denominators.push(den);
+    }
+    Fr::batch_inversion(&mut denominators).map_err(Error::Backend)?;
+    Ok(numerators
+        .into_iter()
+        .zip(denominators)
+        .map(|(num, inv)| num * inv)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::PairingEngine;
+
+    type G1 = <PairingEngine as PairingBackend>::G1;
+
+    fn keys(rng: &mut StdRng, n: usize) -> (Vec<Fr>, Vec<G1>) {
+        let g = G1::generator();
+        let secret_keys: Vec<Fr> = (0..n).map(|_| Fr::random(&mut *rng)).collect();
+        let public_keys = secret_keys.iter().map(|sk| g.mul_scalar(sk)).collect();
+        (secret_keys, public_keys)
+    }
+
+    #[test]
+    fn deal_verify_reconstruct() {
+        let mut rng = StdRng::seed_from_u64(47);
+        let (secret_keys, public_keys) = keys(&mut rng, 7);
+        let secret = Fr::random(&mut rng);
+
+        let transcript =
+            PvssTranscript::<PairingEngine>::deal(&mut rng, &secret, 4, &public_keys).unwrap();
+        transcript.verify(&public_keys).unwrap();
+
+        let shares: Vec<_> = [6, 1, 3, 4]
+            .iter()
+            .map(|&i| transcript.decrypt_share(i, &secret_keys[i]).unwrap())
+            .collect();
+        let recovered = transcript.reconstruct(&shares).unwrap();
+        assert!(recovered.sub(&G1::mul_generator(&secret)).is_identity());
+
+        let expected = <PairingEngine as PairingBackend>::G2::mul_generator(&secret);
+        assert!(
+            transcript
+                .secret_commitment()
+                .unwrap()
+                .sub(&expected)
+                .is_identity()
+        );
+
+        let res = transcript.reconstruct(&shares[..3]);
+        assert!(matches!(res, Err(Error::InsufficientShares { .. })));
+    }
+
+    #[test]
+    fn verify_rejects_bad_transcripts() {
+        let mut rng = StdRng::seed_from_u64(53);
+        let (_, public_keys) = keys(&mut rng, 6);
+        let secret = Fr::random(&mut rng);
+
+        // Dealing with a higher-degree polynomial fails the degree check.
+        let high_degree =
+            PvssTranscript::<PairingEngine>::deal(&mut rng, &secret, 5, &public_keys).unwrap();
+        let mut relabeled = high_degree.clone();
+        relabeled.threshold = 3;
+        assert!(relabeled.verify(&public_keys).is_err());
+
+        let transcript =
+            PvssTranscript::<PairingEngine>::deal(&mut rng, &secret, 3, &public_keys).unwrap();
+        let mut swapped = transcript.clone();
+        swapped.encrypted_shares.swap(0, 1);
+        assert!(swapped.verify(&public_keys).is_err());
+    }
+
+    #[test]
+    fn reconstruct_rejects_invalid_share() {
+        let mut rng = StdRng::seed_from_u64(59);
+        let (secret_keys, public_keys) = keys(&mut rng, 5);
+        let secret = Fr::random(&mut rng);
+        let transcript =
+            PvssTranscript::<PairingEngine>::deal(&mut rng, &secret, 3, &public_keys).unwrap();
+
+        let mut shares: Vec<_> = (0..3)
+            .map(|i| transcript.decrypt_share(i, &secret_keys[i]).unwrap())
+            .collect();
+        shares[2].value = shares[2].value.add(&G1::generator());
+        assert!(matches!(
+            transcript.reconstruct(&shares),
+            Err(Error::MalformedInput(_))
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+//! Verifiable resharing of a PVSS-shared secret.
+//!
+//! Holders of scalar shares `σ_i = p(x_i)`, whose commitments `F_i = σ_i·h` are
+//! published in an existing [`PvssTranscript`], can hand the secret to a new
+//! committee (or refresh it in place) without reconstructing it. Each dealer
+//! re-deals its own share with a fresh PVSS transcript; the new committee then
+//! interpolates `t` such transcripts into a sharing of the original secret.
+//!
+//! Receivers do not trust the dealers. A [`Reshare`] is accepted only if its
+//! transcript verifies and its secret commitment equals the dealer's original
+//! commitment `F_i`, so a dealer cannot substitute a different share. The
+//! combined transcript is checked to commit to the same `s·h` as the original.
+
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+use tracing::instrument;
+
+use super::{evaluation_point, lagrange_at_zero};
+use crate::{CurvePoint, Fr, MsmProvider, PairingBackend, PvssTranscript, errors::Error};
+
+/// One old participant's re-dealing of its share to the new committee.
+#[derive(Clone, Debug)]
+pub struct Reshare<B: PairingBackend> {
+    /// Index of the dealer in the original transcript.
+    pub dealer: usize,
+    /// PVSS transcript sharing the dealer's share among the new committee.
+    pub transcript: PvssTranscript<B>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Reshare<B> {
+    /// Re-deals the scalar share held by participant `dealer` to `new_public_keys`.
+    pub fn deal<R: RngCore + ?Sized>(
+        rng: &mut R,
+        dealer: usize,
+        share: &Fr,
+        new_threshold: usize,
+        new_public_keys: &[B::G1],
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            dealer,
+            transcript: PvssTranscript::deal(rng, share, new_threshold, new_public_keys)?,
+        })
+    }
+
+    /// Verifies the re-dealing against the original transcript.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the transcript is invalid or does not
+    /// share the value the dealer committed to in `original`.
+    pub fn verify(
+        &self,
+        original: &PvssTranscript<B>,
+        new_public_keys: &[B::G1],
+    ) -> Result<(), Error> {
+        let expected = original
+            .commitments
+            .get(self.dealer)
+            .ok_or_else(|| Error::MalformedInput("reshare dealer index out of range".into()))?;
+        self.transcript.verify(new_public_keys)?;
+        if !self
+            .transcript
+            .secret_commitment()?
+            .sub(expected)
+            .is_identity()
+        {
+            return Err(Error::MalformedInput(
+                "reshare does not match the dealer's original commitment".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> PvssTranscript<B> {
+    /// Combines verified re-dealings into a sharing of this transcript's secret
+    /// among the new committee.
+    ///
+    /// Invalid or duplicate re-dealings are rejected; the first `threshold` valid
+    /// ones from distinct dealers are interpolated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if a re-dealing fails verification or the
+    /// re-dealings disagree on the new threshold, and [`Error::InsufficientShares`]
+    /// if fewer than `threshold` distinct dealers contributed.
+    #[instrument(level = "info", skip_all, fields(threshold = self.threshold, reshares = reshares.len()))]
+    pub fn combine_reshares(
+        &self,
+        reshares: &[Reshare<B>],
+        new_public_keys: &[B::G1],
+    ) -> Result<Self, Error> {
+        let mut selected: Vec<&Reshare<B>> = Vec::with_capacity(self.threshold);
+        for reshare in reshares {
+            if selected.iter().all(|r| r.dealer != reshare.dealer) {
+                reshare.verify(self, new_public_keys)?;
+                selected.push(reshare);
+                if selected.len() == self.threshold {
+                    break;
+                }
+            }
+        }
+        if selected.len() < self.threshold {
+            return Err(Error::InsufficientShares {
+                required: self.threshold,
+                provided: selected.len(),
+            });
+        }
+        let new_threshold = selected[0].transcript.threshold;
+        if selected
+            .iter()
+            .any(|r| r.transcript.threshold != new_threshold)
+        {
+            return Err(Error::MalformedInput(
+                "reshares use different thresholds".into(),
+            ));
+        }
+
+        let points: Vec<Fr> = selected
+            .iter()
+            .map(|r| evaluation_point(r.dealer))
+            .collect();
+        let lagrange = lagrange_at_zero(&points)?;
+        let mut commitments = Vec::with_capacity(new_public_keys.len());
+        let mut encrypted_shares = Vec::with_capacity(new_public_keys.len());
+        for j in 0..new_public_keys.len() {
+            let column_g2: Vec<B::G2> = selected
+                .iter()
+                .map(|r| r.transcript.commitments[j])
+                .collect();
+            let column_g1: Vec<B::G1> = selected
+                .iter()
+                .map(|r| r.transcript.encrypted_shares[j])
+                .collect();
+            commitments.push(B::Msm::msm_g2(&column_g2, &lagrange).map_err(Error::Backend)?);
+            encrypted_shares.push(B::Msm::msm_g1(&column_g1, &lagrange).map_err(Error::Backend)?);
+        }
+
+        let combined = Self {
+            threshold: new_threshold,
+            commitments,
+            encrypted_shares,
+        };
+        if !combined
+            .secret_commitment()?
+            .sub(&self.secret_commitment()?)
+            .is_identity()
+        {
+            return Err(Error::MalformedInput(
+                "reshared secret does not match the original".into(),
+            ));
+        }
+        Ok(combined)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{FieldElement, PairingEngine, pvss::evaluate};
+
+    type G1 = <PairingEngine as PairingBackend>::G1;
+    type G2 = <PairingEngine as PairingBackend>::G2;
+
+    fn keys(rng: &mut StdRng, n: usize) -> (Vec<Fr>, Vec<G1>) {
+        let g = G1::generator();
+        let secret_keys: Vec<Fr> = (0..n).map(|_| Fr::random(&mut *rng)).collect();
+        let public_keys = secret_keys.iter().map(|sk| g.mul_scalar(sk)).collect();
+        (secret_keys, public_keys)
+    }
+
+    #[test]
+    fn reshare_preserves_secret_and_rejects_substitution() {
+        let mut rng = StdRng::seed_from_u64(61);
+        let (_, old_keys) = keys(&mut rng, 6);
+        let (new_secret_keys, new_keys) = keys(&mut rng, 5);
+
+        // Original sharing with scalar shares known to the old committee.
+        let secret = Fr::random(&mut rng);
+        let coeffs = [secret, Fr::random(&mut rng), Fr::random(&mut rng)];
+        let shares: Vec<Fr> = (0..6)
+            .map(|i| evaluate(&coeffs, &evaluation_point(i)))
+            .collect();
+        let original = PvssTranscript::<PairingEngine> {
+            threshold: 3,
+            commitments: shares.iter().map(G2::mul_generator).collect(),
+            encrypted_shares: old_keys
+                .iter()
+                .zip(&shares)
+                .map(|(pk, s)| pk.mul_scalar(s))
+                .collect(),
+        };
+        original.verify(&old_keys).unwrap();
+
+        let mut reshares: Vec<_> = [5, 0, 2]
+            .iter()
+            .map(|&i| Reshare::deal(&mut rng, i, &shares[i], 4, &new_keys).unwrap())
+            .collect();
+        let combined = original.combine_reshares(&reshares, &new_keys).unwrap();
+        combined.verify(&new_keys).unwrap();
+
+        let new_shares: Vec<_> = [4, 1, 0, 3]
+            .iter()
+            .map(|&i| combined.decrypt_share(i, &new_secret_keys[i]).unwrap())
+            .collect();
+        let recovered = combined.reconstruct(&new_shares).unwrap();
+        assert!(recovered.sub(&G1::mul_generator(&secret)).is_identity());
+
+        // A dealer re-sharing a different value is caught.
+        let forged = Fr::random(&mut rng);
+        reshares[1] = Reshare::deal(&mut rng, 0, &forged, 4, &new_keys).unwrap();
+        assert!(reshares[1].verify(&original, &new_keys).is_err());
+        assert!(original.combine_reshares(&reshares, &new_keys).is_err());
+
+        assert!(matches!(
+            original.combine_reshares(&reshares[..1], &new_keys),
+            Err(Error::InsufficientShares { .. })
+        ));
+    }
+}
@@ -0,0 +1,205 @@
+//! Reseeding, fork-safe randomness for long-running processes.
+//!
+//! [`TessRng`] is a ChaCha20 generator seeded from the operating system that
+//! reseeds itself:
+//!
+//! - when the process id changes, so a forked child never replays the
+//!   parent's stream,
+//! - when it is cloned, so copies handed to worker threads diverge, and
+//! - after emitting a configurable number of bytes.
+//!
+//! It implements [`RngCore`] and [`CryptoRng`] and can be passed anywhere the
+//! crate accepts an RNG.
+//!
+//! ```rust
+//! use tess::{PairingEngine, SilentThresholdScheme, TessRng, ThresholdEncryption};
+//!
+//! let mut rng = TessRng::new();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! ```
+//!
+//! # WebAssembly
+//!
+//! On `wasm32-unknown-unknown` the OS entropy source is the browser's
+//! `crypto.getRandomValues`, which `getrandom` only reaches with its `js`
+//! feature; enable this crate's `js` feature to turn it on. Processes cannot
+//! fork there, so only the clone and budget triggers apply. Use
+//! [`TessRng::try_new`] to surface missing entropy as an [`Error`] instead of
+//! a panic.
+
+use core::fmt;
+
+use rand::rngs::OsRng;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::errors::{BackendError, Error};
+
+/// Number of bytes [`TessRng::new`] emits before reseeding (1 GiB).
+pub const DEFAULT_RESEED_BYTES: u64 = 1 << 30;
+
+/// ChaCha20 generator that reseeds from the OS after fork, clone, or a fixed
+/// output budget.
+pub struct TessRng {
+    inner: ChaCha20Rng,
+    pid: u32,
+    budget: u64,
+    remaining: u64,
+}
+
+impl TessRng {
+    /// Creates a generator that reseeds every [`DEFAULT_RESEED_BYTES`] bytes.
+    pub fn new() -> Self {
+        Self::with_reseed_budget(DEFAULT_RESEED_BYTES)
+    }
+
+    /// Creates a generator that reseeds after emitting `bytes` bytes.
+    ///
+    /// A budget of zero reseeds before every request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS entropy source is unavailable; see
+    /// [`TessRng::try_with_reseed_budget`].
+    pub fn with_reseed_budget(bytes: u64) -> Self {
+        Self::try_with_reseed_budget(bytes).expect("OS randomness is unavailable")
+    }
+
+    /// Fallible [`TessRng::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Backend`] wrapping the entropy source's error if it is
+    /// unavailable, for example in a browser build without the `js` feature.
+    pub fn try_new() -> Result<Self, Error> {
+        Self::try_with_reseed_budget(DEFAULT_RESEED_BYTES)
+    }
+
+    /// Fallible [`TessRng::with_reseed_budget`].
+    ///
+    /// Later reseeds draw from the same source and panic if it stops working.
+    pub fn try_with_reseed_budget(bytes: u64) -> Result<Self, Error> {
+        let inner = ChaCha20Rng::from_rng(OsRng)
+            .map_err(|err| BackendError::library("OS randomness is unavailable", err))?;
+        Ok(Self {
+            inner,
+            pid: process_id(),
+            budget: bytes,
+            remaining: bytes,
+        })
+    }
+
+    /// Reseeds from the operating system immediately.
+    pub fn reseed(&mut self) {
+        *self = Self::with_reseed_budget(self.budget);
+    }
+
+    /// Reseeds if the process forked or `len` bytes would exceed the budget.
+    fn prepare(&mut self, len: usize) {
+        let len = len as u64;
+        if self.pid != process_id() || self.remaining < len {
+            self.reseed();
+        }
+        self.remaining = self.remaining.saturating_sub(len);
+    }
+}
+
+/// Current process id; WebAssembly has no processes to fork, and its `std`
+/// panics when asked for one.
+fn process_id() -> u32 {
+    #[cfg(target_family = "wasm")]
+    {
+        0
+    }
+    #[cfg(not(target_family = "wasm"))]
+    {
+        std::process::id()
+    }
+}
+
+impl Default for TessRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for TessRng {
+    /// Returns an independently seeded generator with the same budget.
+    fn clone(&self) -> Self {
+        Self::with_reseed_budget(self.budget)
+    }
+}
+
+impl fmt::Debug for TessRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TessRng")
+            .field("budget", &self.budget)
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RngCore for TessRng {
+    fn next_u32(&mut self) -> u32 {
+        self.prepare(4);
+        self.inner.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.prepare(8);
+        self.inner.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.prepare(dest.len());
+        self.inner.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.prepare(dest.len());
+        self.inner.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for TessRng {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tess_rng_reseeds_after_budget() {
+        let mut rng = TessRng::with_reseed_budget(16);
+        let stream = rng.inner.clone();
+        rng.next_u64();
+        rng.next_u64();
+        assert_eq!(rng.remaining, 0);
+        assert_eq!(rng.inner.get_seed(), stream.get_seed());
+
+        rng.next_u32();
+        assert_eq!(rng.remaining, 12);
+        assert_ne!(rng.inner.get_seed(), stream.get_seed());
+    }
+
+    #[test]
+    fn try_new_seeds_independent_generators() {
+        let a = TessRng::try_new().unwrap();
+        let b = TessRng::try_new().unwrap();
+        assert_eq!(a.budget, DEFAULT_RESEED_BYTES);
+        assert_ne!(a.inner.get_seed(), b.inner.get_seed());
+    }
+
+    #[test]
+    fn tess_rng_reseeds_after_fork_and_clone() {
+        let mut rng = TessRng::new();
+        let seed = rng.inner.get_seed();
+        assert_ne!(rng.clone().inner.get_seed(), seed);
+
+        // Simulate running in a child process.
+        rng.pid = rng.pid.wrapping_add(1);
+        rng.next_u32();
+        assert_eq!(rng.pid, std::process::id());
+        assert_ne!(rng.inner.get_seed(), seed);
+    }
+}
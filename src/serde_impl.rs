@@ -29,55 +29,255 @@
 //! ```
 
 use alloc::vec::Vec;
-use serde::de;
+use core::{fmt, marker::PhantomData};
+use serde::de::{self, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    AggregateKey, Ciphertext, DecryptionResult, Fr, LagrangePowers, PairingBackend, Params,
-    PartialDecryption, PublicKey, SRS, SecretKey, UnsafeKeyMaterial,
+    AggregateKey, COMMITTEE_FINGERPRINT_LEN, CeremonyContribution, CeremonyTranscript, Ciphertext,
+    CiphertextHeader, CiphertextProof, Committee, ContributionRecord, DEK_LEN, DecryptionProof,
+    DecryptionResult, DeserializeLimits, Fr, IdentityKey, IpaProof, KdfId, LagrangePowers,
+    LagrangeSRS, MAX_LABEL_LEN, MultiCommitteeCiphertext, PairingBackend, Params,
+    PartialDecryption, ParticipantId, PublicKey, SRS, SchemeId, SecretKey, SenderSignature,
+    SignedPartialDecryption, UnsafeKeyMaterial,
     arith::{CurvePoint, FieldElement, TargetGroup},
+    tess::DecryptionTerms,
 };
 
-fn repr_from_bytes<R, E>(bytes: &[u8]) -> Result<R, E>
+fn scalar_from_bytes<B, E>(bytes: &[u8]) -> Result<B::Scalar, E>
 where
-    R: Default + AsRef<[u8]> + AsMut<[u8]>,
+    B: PairingBackend,
     E: de::Error,
 {
-    let mut repr = R::default();
-    let dst = repr.as_mut();
-    if bytes.len() > dst.len() {
-        return Err(E::custom("byte representation too long"));
-    }
-    dst[..bytes.len()].copy_from_slice(bytes);
-    Ok(repr)
+    B::deserialize_scalar(bytes).map_err(E::custom)
 }
 
-fn field_from_bytes<F, E>(bytes: &[u8]) -> Result<F, E>
+fn g1_from_bytes<B, E>(bytes: &[u8]) -> Result<B::G1, E>
 where
-    F: FieldElement,
+    B: PairingBackend,
     E: de::Error,
 {
-    let repr = repr_from_bytes::<F::Repr, E>(bytes)?;
-    F::from_repr(&repr).map_err(E::custom)
+    B::deserialize_g1(bytes).map_err(E::custom)
 }
 
-fn curve_point_from_bytes<C, F, E>(bytes: &[u8]) -> Result<C, E>
+fn g2_from_bytes<B, E>(bytes: &[u8]) -> Result<B::G2, E>
 where
-    C: CurvePoint<F>,
-    F: FieldElement,
+    B: PairingBackend,
     E: de::Error,
 {
-    let repr = repr_from_bytes::<C::Repr, E>(bytes)?;
-    C::from_repr(&repr).map_err(E::custom)
+    B::deserialize_g2(bytes).map_err(E::custom)
 }
 
-fn target_group_from_bytes<T, E>(bytes: &[u8]) -> Result<T, E>
+fn g1s_from_bytes<B, E, T>(encoded: &[T]) -> Result<Vec<B::G1>, E>
 where
-    T: TargetGroup,
+    B: PairingBackend,
     E: de::Error,
+    T: AsRef<[u8]> + Sync,
 {
-    let repr = repr_from_bytes::<T::Repr, E>(bytes)?;
-    T::from_repr(&repr).map_err(E::custom)
+    B::deserialize_g1_batch(encoded).map_err(E::custom)
+}
+
+fn g2s_from_bytes<B, E, T>(encoded: &[T]) -> Result<Vec<B::G2>, E>
+where
+    B: PairingBackend,
+    E: de::Error,
+    T: AsRef<[u8]> + Sync,
+{
+    B::deserialize_g2_batch(encoded).map_err(E::custom)
+}
+
+fn target_from_bytes<B, E>(bytes: &[u8]) -> Result<B::Target, E>
+where
+    B: PairingBackend,
+    E: de::Error,
+{
+    B::deserialize_target(bytes).map_err(E::custom)
+}
+
+/// Longest encoding of a single group element or scalar accepted on the wire.
+const MAX_ELEMENT_LEN: usize = 1024;
+
+impl DeserializeLimits {
+    /// Deserializes a `T` from `deserializer` under these limits.
+    ///
+    /// ```rust
+    /// use rand::thread_rng;
+    /// use tess::{Ciphertext, DeserializeLimits, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+    ///
+    /// let mut rng = thread_rng();
+    /// let scheme = SilentThresholdScheme::<PairingEngine>::new();
+    /// let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+    /// let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+    /// let ct = scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 2, &[0; 64]).unwrap();
+    /// let json = serde_json::to_string(&ct).unwrap();
+    ///
+    /// let strict = DeserializeLimits {
+    ///     max_payload_len: 32,
+    ///     ..DeserializeLimits::default()
+    /// };
+    /// let mut de = serde_json::Deserializer::from_str(&json);
+    /// assert!(strict.deserialize::<Ciphertext<PairingEngine>, _>(&mut de).is_err());
+    /// assert!(serde_json::from_str::<Ciphertext<PairingEngine>>(&json).is_ok());
+    /// ```
+    pub fn deserialize<'de, T, D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        self.scoped(|| T::deserialize(deserializer))
+    }
+
+    /// Returns a [`DeserializeSeed`](de::DeserializeSeed) that deserializes a
+    /// `T` under these limits.
+    pub fn seed<T>(self) -> LimitedSeed<T> {
+        LimitedSeed {
+            limits: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Deserializes a `T` under the limits it was created with; see
+/// [`DeserializeLimits::seed`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug)]
+pub struct LimitedSeed<T> {
+    limits: DeserializeLimits,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "std")]
+impl<'de, T: Deserialize<'de>> de::DeserializeSeed<'de> for LimitedSeed<T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        self.limits.deserialize(deserializer)
+    }
+}
+
+/// Which limit a [`Bounded`] sequence is checked against.
+trait Limit {
+    const FIELD: &'static str;
+    fn max(limits: &DeserializeLimits) -> usize;
+}
+
+struct ProofLimit;
+struct PayloadLimit;
+struct HintLimit;
+struct ElementLimit;
+struct LabelLimit;
+
+impl Limit for ProofLimit {
+    const FIELD: &'static str = "proof";
+    fn max(limits: &DeserializeLimits) -> usize {
+        limits.max_proof_elements
+    }
+}
+
+impl Limit for PayloadLimit {
+    const FIELD: &'static str = "payload";
+    fn max(limits: &DeserializeLimits) -> usize {
+        limits.max_payload_len
+    }
+}
+
+impl Limit for HintLimit {
+    const FIELD: &'static str = "public key hints";
+    fn max(limits: &DeserializeLimits) -> usize {
+        limits.max_public_key_hints
+    }
+}
+
+impl Limit for ElementLimit {
+    const FIELD: &'static str = "group element";
+    fn max(_: &DeserializeLimits) -> usize {
+        MAX_ELEMENT_LEN
+    }
+}
+
+impl Limit for LabelLimit {
+    const FIELD: &'static str = "label";
+    fn max(_: &DeserializeLimits) -> usize {
+        MAX_LABEL_LEN
+    }
+}
+
+/// A sequence that fails to deserialize once it exceeds `L::max` elements
+/// under the limits in effect.
+struct Bounded<T, L>(Vec<T>, PhantomData<L>);
+
+/// Group element encodings bounded by [`MAX_ELEMENT_LEN`].
+type ElementBytes = Bounded<u8, ElementLimit>;
+
+impl<T, L> Bounded<T, L> {
+    fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T, L> AsRef<[T]> for Bounded<T, L> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<'de, T: Deserialize<'de>, L: Limit> Deserialize<'de> for Bounded<T, L> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BoundedVisitor<T, L> {
+            max: usize,
+            _marker: PhantomData<(T, L)>,
+        }
+
+        fn too_long<E: de::Error, L: Limit>(max: usize) -> E {
+            E::custom(format_args!(
+                "{} exceeds the limit of {max} elements",
+                L::FIELD
+            ))
+        }
+
+        impl<'de, T: Deserialize<'de>, L: Limit> Visitor<'de> for BoundedVisitor<T, L> {
+            type Value = Bounded<T, L>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {} elements", self.max)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let max = self.max;
+                let hint = seq.size_hint().unwrap_or(0);
+                if hint > max {
+                    return Err(too_long::<A::Error, L>(max));
+                }
+                let mut items = Vec::with_capacity(hint.min(4096));
+                while let Some(item) = seq.next_element()? {
+                    if items.len() == max {
+                        return Err(too_long::<A::Error, L>(max));
+                    }
+                    items.push(item);
+                }
+                Ok(Bounded(items, PhantomData))
+            }
+
+            fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+                if bytes.len() > self.max {
+                    return Err(too_long::<E, L>(self.max));
+                }
+                let items = bytes
+                    .iter()
+                    .map(|&b| T::deserialize(de::value::U8Deserializer::<E>::new(b)))
+                    .collect::<Result<_, _>>()?;
+                Ok(Bounded(items, PhantomData))
+            }
+        }
+
+        deserializer.deserialize_seq(BoundedVisitor {
+            max: L::max(&DeserializeLimits::in_effect()),
+            _marker: PhantomData,
+        })
+    }
 }
 
 // Implement Serialize and Deserialize for SecretKey
@@ -107,7 +307,7 @@ impl<'de, B: PairingBackend> Deserialize<'de> for SecretKey<B> {
         }
 
         let helper = SecretKeyHelper::deserialize(deserializer)?;
-        let scalar = field_from_bytes::<B::Scalar, D::Error>(&helper.scalar)?;
+        let scalar = scalar_from_bytes::<B, D::Error>(&helper.scalar)?;
 
         Ok(SecretKey {
             participant_id: helper.participant_id,
@@ -152,30 +352,27 @@ impl<'de, B: PairingBackend> Deserialize<'de> for PublicKey<B> {
         #[derive(Deserialize)]
         struct PublicKeyHelper {
             participant_id: usize,
-            bls_key: Vec<u8>,
-            lagrange_li: Vec<u8>,
-            lagrange_li_minus0: Vec<u8>,
-            lagrange_li_x: Vec<u8>,
-            lagrange_li_lj_z: Vec<Vec<u8>>,
+            bls_key: ElementBytes,
+            lagrange_li: ElementBytes,
+            lagrange_li_minus0: ElementBytes,
+            lagrange_li_x: ElementBytes,
+            lagrange_li_lj_z: Bounded<ElementBytes, HintLimit>,
         }
 
         let helper = PublicKeyHelper::deserialize(deserializer)?;
 
         Ok(PublicKey {
             participant_id: helper.participant_id,
-            bls_key: curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(&helper.bls_key)?,
-            lagrange_li: curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(&helper.lagrange_li)?,
-            lagrange_li_minus0: curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(
-                &helper.lagrange_li_minus0,
-            )?,
-            lagrange_li_x: curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(
-                &helper.lagrange_li_x,
+            bls_key: g1_from_bytes::<B, D::Error>(&helper.bls_key.into_inner())?,
+            lagrange_li: g1_from_bytes::<B, D::Error>(&helper.lagrange_li.into_inner())?,
+            lagrange_li_minus0: g1_from_bytes::<B, D::Error>(
+                &helper.lagrange_li_minus0.into_inner(),
             )?,
-            lagrange_li_lj_z: helper
-                .lagrange_li_lj_z
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
+            lagrange_li_x: g1_from_bytes::<B, D::Error>(&helper.lagrange_li_x.into_inner())?,
+            lagrange_li_lj_z: g1s_from_bytes::<B, D::Error, _>(
+                &helper.lagrange_li_lj_z.into_inner(),
+            )?
+            .into(),
         })
     }
 }
@@ -187,7 +384,7 @@ impl<B: PairingBackend<Scalar = Fr>> Serialize for AggregateKey<B> {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("AggregateKey", 6)?;
+        let mut state = serializer.serialize_struct("AggregateKey", 7)?;
         state.serialize_field("public_keys", &self.public_keys)?;
         state.serialize_field("ask", &self.ask.to_repr().as_ref())?;
         state.serialize_field("z_g2", &self.z_g2.to_repr().as_ref())?;
@@ -204,6 +401,7 @@ impl<B: PairingBackend<Scalar = Fr>> Serialize for AggregateKey<B> {
             &self.precomputed_pairing.to_repr().as_ref(),
         )?;
         state.serialize_field("kzg_params", &self.kzg_params)?;
+        state.serialize_field("revoked", &self.revoked)?;
         state.end()
     }
 }
@@ -222,35 +420,86 @@ impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for AggregateKey<B> {
             lagrange_row_sums: Vec<Vec<u8>>,
             precomputed_pairing: Vec<u8>,
             kzg_params: SRS<B>,
+            #[serde(default)]
+            revoked: Vec<usize>,
         }
 
-        let helper = AggregateKeyHelper::deserialize(deserializer)?;
+        let mut helper = AggregateKeyHelper::deserialize(deserializer)?;
+        helper.revoked.sort_unstable();
+        helper.revoked.dedup();
 
         Ok(AggregateKey {
             public_keys: helper.public_keys,
-            ask: curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(&helper.ask)?,
-            z_g2: curve_point_from_bytes::<B::G2, B::Scalar, D::Error>(&helper.z_g2)?,
-            lagrange_row_sums: helper
-                .lagrange_row_sums
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
-            precomputed_pairing: target_group_from_bytes::<B::Target, D::Error>(
-                &helper.precomputed_pairing,
-            )?,
+            ask: g1_from_bytes::<B, D::Error>(&helper.ask)?,
+            z_g2: g2_from_bytes::<B, D::Error>(&helper.z_g2)?,
+            lagrange_row_sums: g1s_from_bytes::<B, D::Error, _>(&helper.lagrange_row_sums)?,
+            precomputed_pairing: target_from_bytes::<B, D::Error>(&helper.precomputed_pairing)?,
             kzg_params: helper.kzg_params,
+            revoked: helper.revoked,
+        })
+    }
+}
+
+// Implement Serialize and Deserialize for CiphertextHeader
+impl Serialize for CiphertextHeader {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CiphertextHeader", 7)?;
+        state.serialize_field("scheme", &self.scheme.to_byte())?;
+        state.serialize_field("epoch", &self.epoch)?;
+        state.serialize_field("committee", &self.committee)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("threshold", &self.threshold)?;
+        state.serialize_field("payload_len", &self.payload_len)?;
+        state.serialize_field("cipher_suite", &self.cipher_suite.to_byte())?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CiphertextHeader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct CiphertextHeaderHelper {
+            scheme: u8,
+            epoch: u64,
+            committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+            label: Bounded<u8, LabelLimit>,
+            threshold: usize,
+            payload_len: usize,
+            cipher_suite: u8,
+        }
+
+        let helper = CiphertextHeaderHelper::deserialize(deserializer)?;
+        Ok(CiphertextHeader {
+            scheme: SchemeId::from_byte(helper.scheme).map_err(de::Error::custom)?,
+            epoch: helper.epoch,
+            committee: helper.committee,
+            label: helper.label.into_inner(),
+            threshold: helper.threshold,
+            payload_len: helper.payload_len,
+            cipher_suite: KdfId::from_byte(helper.cipher_suite).map_err(de::Error::custom)?,
         })
     }
 }
 
 // Implement Serialize and Deserialize for Ciphertext
+//
+// The header comes first so readers can route on it before the proofs. Older
+// encodings without a header carry `threshold` and `kdf` at the top level.
 impl<B: PairingBackend> Serialize for Ciphertext<B> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("Ciphertext", 6)?;
+        let mut state = serializer.serialize_struct("Ciphertext", 12)?;
+        state.serialize_field("header", &self.header())?;
         state.serialize_field("gamma_g2", &self.gamma_g2.to_repr().as_ref())?;
         state.serialize_field(
             "proof_g1",
@@ -269,8 +518,29 @@ impl<B: PairingBackend> Serialize for Ciphertext<B> {
                 .collect::<Vec<Vec<u8>>>(),
         )?;
         state.serialize_field("shared_secret", &self.shared_secret.to_repr().as_ref())?;
-        state.serialize_field("threshold", &self.threshold)?;
         state.serialize_field("payload", &self.payload)?;
+        state.serialize_field("compressed", &self.compressed)?;
+        state.serialize_field("padded", &self.padded)?;
+        state.serialize_field("deterministic", &self.deterministic)?;
+        state.serialize_field(
+            "validity_proof",
+            &self.validity_proof.as_ref().map(|proof| {
+                core::iter::once(&proof.challenge)
+                    .chain(&proof.responses)
+                    .map(|scalar| scalar.to_repr().as_ref().to_vec())
+                    .collect::<Vec<Vec<u8>>>()
+            }),
+        )?;
+        state.serialize_field(
+            "sender_signature",
+            &self.sender_signature.as_ref().map(|signature| {
+                (
+                    signature.sender.to_repr().as_ref().to_vec(),
+                    signature.signature.to_repr().as_ref().to_vec(),
+                )
+            }),
+        )?;
+        state.serialize_field("broadcast_keys", &self.broadcast_keys)?;
         state.end()
     }
 }
@@ -282,31 +552,112 @@ impl<'de, B: PairingBackend> Deserialize<'de> for Ciphertext<B> {
     {
         #[derive(Deserialize)]
         struct CiphertextHelper {
-            gamma_g2: Vec<u8>,
-            proof_g1: Vec<Vec<u8>>,
-            proof_g2: Vec<Vec<u8>>,
-            shared_secret: Vec<u8>,
-            threshold: usize,
-            payload: Vec<u8>,
+            #[serde(default)]
+            header: Option<CiphertextHeader>,
+            gamma_g2: ElementBytes,
+            proof_g1: Bounded<ElementBytes, ProofLimit>,
+            proof_g2: Bounded<ElementBytes, ProofLimit>,
+            shared_secret: ElementBytes,
+            #[serde(default)]
+            threshold: Option<usize>,
+            payload: Bounded<u8, PayloadLimit>,
+            #[serde(default)]
+            compressed: bool,
+            #[serde(default)]
+            padded: bool,
+            #[serde(default)]
+            deterministic: bool,
+            #[serde(default)]
+            kdf: u8,
+            #[serde(default)]
+            validity_proof: Option<Bounded<ElementBytes, ProofLimit>>,
+            #[serde(default)]
+            sender_signature: Option<(ElementBytes, ElementBytes)>,
+            #[serde(default)]
+            broadcast_keys: Option<Bounded<[u8; DEK_LEN], HintLimit>>,
         }
 
         let helper = CiphertextHelper::deserialize(deserializer)?;
+        let broadcast_keys = helper
+            .broadcast_keys
+            .map(Bounded::into_inner)
+            .unwrap_or_default();
+        let payload = helper.payload.into_inner();
+        let header = match helper.header {
+            Some(header) => {
+                let scheme = if broadcast_keys.is_empty() {
+                    SchemeId::Threshold
+                } else {
+                    SchemeId::Broadcast
+                };
+                if header.scheme != scheme || header.payload_len != payload.len() {
+                    return Err(de::Error::custom(
+                        "ciphertext header does not match its body",
+                    ));
+                }
+                header
+            }
+            None => CiphertextHeader {
+                threshold: helper
+                    .threshold
+                    .ok_or_else(|| de::Error::missing_field("header"))?,
+                cipher_suite: KdfId::from_byte(helper.kdf).map_err(de::Error::custom)?,
+                ..CiphertextHeader::default()
+            },
+        };
 
         Ok(Ciphertext {
-            gamma_g2: curve_point_from_bytes::<B::G2, B::Scalar, D::Error>(&helper.gamma_g2)?,
+            gamma_g2: g2_from_bytes::<B, D::Error>(&helper.gamma_g2.into_inner())?,
             proof_g1: helper
                 .proof_g1
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
+                .into_inner()
+                .into_iter()
+                .map(|bytes| g1_from_bytes::<B, D::Error>(&bytes.into_inner()))
+                .collect::<Result<_, _>>()?,
             proof_g2: helper
                 .proof_g2
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G2, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
-            shared_secret: target_group_from_bytes::<B::Target, D::Error>(&helper.shared_secret)?,
-            threshold: helper.threshold,
-            payload: helper.payload,
+                .into_inner()
+                .into_iter()
+                .map(|bytes| g2_from_bytes::<B, D::Error>(&bytes.into_inner()))
+                .collect::<Result<_, _>>()?,
+            shared_secret: target_from_bytes::<B, D::Error>(&helper.shared_secret.into_inner())?,
+            threshold: header.threshold,
+            epoch: header.epoch,
+            committee: header.committee,
+            label: header.label,
+            payload,
+            compressed: helper.compressed,
+            padded: helper.padded,
+            deterministic: helper.deterministic,
+            kdf: header.cipher_suite,
+            validity_proof: helper
+                .validity_proof
+                .map(|scalars| {
+                    let mut scalars = scalars
+                        .into_inner()
+                        .into_iter()
+                        .map(|bytes| scalar_from_bytes::<B, D::Error>(&bytes.into_inner()))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    if scalars.is_empty() {
+                        return Err(de::Error::custom("empty validity proof"));
+                    }
+                    let challenge = scalars.remove(0);
+                    Ok(CiphertextProof {
+                        challenge,
+                        responses: scalars,
+                    })
+                })
+                .transpose()?,
+            sender_signature: helper
+                .sender_signature
+                .map(|(sender, signature)| {
+                    Ok::<_, D::Error>(SenderSignature {
+                        sender: g1_from_bytes::<B, D::Error>(&sender.into_inner())?,
+                        signature: g2_from_bytes::<B, D::Error>(&signature.into_inner())?,
+                    })
+                })
+                .transpose()?,
+            broadcast_keys,
         })
     }
 }
@@ -340,37 +691,148 @@ impl<'de, B: PairingBackend> Deserialize<'de> for PartialDecryption<B> {
 
         Ok(PartialDecryption {
             participant_id: helper.participant_id,
-            response: curve_point_from_bytes::<B::G2, B::Scalar, D::Error>(&helper.response)?,
+            response: g2_from_bytes::<B, D::Error>(&helper.response)?,
+        })
+    }
+}
+
+// Implement Serialize and Deserialize for SignedPartialDecryption
+impl<B: PairingBackend> Serialize for SignedPartialDecryption<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SignedPartialDecryption", 4)?;
+        state.serialize_field("partial", &self.partial)?;
+        state.serialize_field("ciphertext_digest", &self.ciphertext_digest)?;
+        state.serialize_field("epoch", &self.epoch)?;
+        state.serialize_field("signature", &B::serialize_g2(&self.signature))?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend> Deserialize<'de> for SignedPartialDecryption<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(bound = "")]
+        struct SignedPartialDecryptionHelper<B: PairingBackend> {
+            partial: PartialDecryption<B>,
+            ciphertext_digest: [u8; 32],
+            epoch: u64,
+            signature: Vec<u8>,
+        }
+
+        let helper = SignedPartialDecryptionHelper::<B>::deserialize(deserializer)?;
+
+        Ok(SignedPartialDecryption {
+            partial: helper.partial,
+            ciphertext_digest: helper.ciphertext_digest,
+            epoch: helper.epoch,
+            signature: g2_from_bytes::<B, D::Error>(&helper.signature)?,
+        })
+    }
+}
+
+// Implement Serialize and Deserialize for IdentityKey
+impl<B: PairingBackend> Serialize for IdentityKey<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IdentityKey", 5)?;
+        state.serialize_field("identity", &self.identity)?;
+        state.serialize_field("threshold", &self.threshold)?;
+        state.serialize_field("committee", &self.committee)?;
+        state.serialize_field("g1_terms", &self.terms.g1.each_ref().map(B::serialize_g1))?;
+        state.serialize_field("g2_terms", &self.terms.g2.each_ref().map(B::serialize_g2))?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend> Deserialize<'de> for IdentityKey<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct IdentityKeyHelper {
+            identity: Bounded<u8, PayloadLimit>,
+            threshold: usize,
+            committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+            g1_terms: [ElementBytes; 6],
+            g2_terms: [ElementBytes; 2],
+        }
+
+        let helper = IdentityKeyHelper::deserialize(deserializer)?;
+        let g1 = g1s_from_bytes::<B, D::Error, _>(&helper.g1_terms)?;
+        let g2 = g2s_from_bytes::<B, D::Error, _>(&helper.g2_terms)?;
+
+        Ok(IdentityKey {
+            identity: helper.identity.into_inner(),
+            threshold: helper.threshold,
+            committee: helper.committee,
+            terms: DecryptionTerms {
+                g1: g1
+                    .try_into()
+                    .map_err(|_| de::Error::custom("expected 6 G1 terms"))?,
+                g2: g2
+                    .try_into()
+                    .map_err(|_| de::Error::custom("expected 2 G2 terms"))?,
+            },
         })
     }
 }
 
 // Implement Serialize and Deserialize for DecryptionResult
-impl Serialize for DecryptionResult {
+impl<B: PairingBackend> Serialize for DecryptionResult<B> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("DecryptionResult", 1)?;
+        let mut state = serializer.serialize_struct("DecryptionResult", 3)?;
         state.serialize_field("plaintext", &self.plaintext)?;
+        state.serialize_field(
+            "proof_selector",
+            &self.proof.as_ref().map(|proof| &proof.selector),
+        )?;
+        state.serialize_field(
+            "proof_partials",
+            &self.proof.as_ref().map(|proof| &proof.partials),
+        )?;
         state.end()
     }
 }
 
-impl<'de> Deserialize<'de> for DecryptionResult {
+impl<'de, B: PairingBackend> Deserialize<'de> for DecryptionResult<B> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         #[derive(Deserialize)]
-        struct DecryptionResultHelper {
+        #[serde(bound(deserialize = ""))]
+        struct DecryptionResultHelper<B: PairingBackend> {
             plaintext: Option<Vec<u8>>,
+            #[serde(default)]
+            proof_selector: Option<Vec<bool>>,
+            #[serde(default)]
+            proof_partials: Option<Vec<PartialDecryption<B>>>,
         }
 
-        let helper = DecryptionResultHelper::deserialize(deserializer)?;
+        let helper = DecryptionResultHelper::<B>::deserialize(deserializer)?;
+        let proof = match (helper.proof_selector, helper.proof_partials) {
+            (Some(selector), Some(partials)) => Some(DecryptionProof { selector, partials }),
+            (None, None) => None,
+            _ => return Err(de::Error::custom("incomplete decryption proof")),
+        };
         Ok(DecryptionResult {
             plaintext: helper.plaintext,
+            proof,
         })
     }
 }
@@ -382,7 +844,7 @@ impl<B: PairingBackend<Scalar = Fr>> Serialize for SRS<B> {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SRS", 3)?;
+        let mut state = serializer.serialize_struct("SRS", 4)?;
         state.serialize_field(
             "powers_of_g",
             &self
@@ -400,6 +862,14 @@ impl<B: PairingBackend<Scalar = Fr>> Serialize for SRS<B> {
                 .collect::<Vec<Vec<u8>>>(),
         )?;
         state.serialize_field("e_gh", &self.e_gh.to_repr().as_ref())?;
+        state.serialize_field(
+            "lagrange_basis_g",
+            &self
+                .lagrange_basis_g
+                .iter()
+                .map(|p| p.to_repr().as_ref().to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+        )?;
         state.end()
     }
 }
@@ -414,22 +884,254 @@ impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for SRS<B> {
             powers_of_g: Vec<Vec<u8>>,
             powers_of_h: Vec<Vec<u8>>,
             e_gh: Vec<u8>,
+            #[serde(default)]
+            lagrange_basis_g: Vec<Vec<u8>>,
         }
 
         let helper = SRSHelper::deserialize(deserializer)?;
 
         Ok(SRS {
-            powers_of_g: helper
+            powers_of_g: g1s_from_bytes::<B, D::Error, _>(&helper.powers_of_g)?,
+            powers_of_h: g2s_from_bytes::<B, D::Error, _>(&helper.powers_of_h)?,
+            e_gh: target_from_bytes::<B, D::Error>(&helper.e_gh)?,
+            lagrange_basis_g: g1s_from_bytes::<B, D::Error, _>(&helper.lagrange_basis_g)?,
+        })
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Serialize for LagrangeSRS<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("LagrangeSRS", 3)?;
+        state.serialize_field(
+            "lagrange_g",
+            &self
+                .lagrange_g
+                .iter()
+                .map(|p| p.to_repr().as_ref().to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+        )?;
+        state.serialize_field(
+            "powers_of_h",
+            &self
+                .powers_of_h
+                .iter()
+                .map(|p| p.to_repr().as_ref().to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+        )?;
+        state.serialize_field("e_gh", &self.e_gh.to_repr().as_ref())?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for LagrangeSRS<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct LagrangeSRSHelper {
+            lagrange_g: Vec<Vec<u8>>,
+            powers_of_h: Vec<Vec<u8>>,
+            e_gh: Vec<u8>,
+        }
+
+        let helper = LagrangeSRSHelper::deserialize(deserializer)?;
+
+        Ok(LagrangeSRS {
+            lagrange_g: g1s_from_bytes::<B, D::Error, _>(&helper.lagrange_g)?,
+            powers_of_h: g2s_from_bytes::<B, D::Error, _>(&helper.powers_of_h)?,
+            e_gh: target_from_bytes::<B, D::Error>(&helper.e_gh)?,
+        })
+    }
+}
+
+// Implement Serialize and Deserialize for CeremonyContribution
+impl<B: PairingBackend<Scalar = Fr>> Serialize for CeremonyContribution<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CeremonyContribution", 5)?;
+        state.serialize_field("participant_id", &self.participant_id)?;
+        state.serialize_field(
+            "powers_of_g",
+            &self
                 .powers_of_g
                 .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
-            powers_of_h: helper
+                .map(|p| B::serialize_g1(p))
+                .collect::<Vec<_>>(),
+        )?;
+        state.serialize_field(
+            "powers_of_h",
+            &self
                 .powers_of_h
                 .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G2, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
-            e_gh: target_group_from_bytes::<B::Target, D::Error>(&helper.e_gh)?,
+                .map(|p| B::serialize_g2(p))
+                .collect::<Vec<_>>(),
+        )?;
+        state.serialize_field("public_key", &B::serialize_g1(&self.public_key))?;
+        state.serialize_field("proof", &B::serialize_g2(&self.proof))?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for CeremonyContribution<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct CeremonyContributionHelper {
+            participant_id: usize,
+            powers_of_g: Vec<ElementBytes>,
+            powers_of_h: Vec<ElementBytes>,
+            public_key: ElementBytes,
+            proof: ElementBytes,
+        }
+
+        let helper = CeremonyContributionHelper::deserialize(deserializer)?;
+
+        Ok(CeremonyContribution {
+            participant_id: helper.participant_id,
+            powers_of_g: g1s_from_bytes::<B, D::Error, _>(&helper.powers_of_g)?,
+            powers_of_h: g2s_from_bytes::<B, D::Error, _>(&helper.powers_of_h)?,
+            public_key: g1_from_bytes::<B, D::Error>(&helper.public_key.into_inner())?,
+            proof: g2_from_bytes::<B, D::Error>(&helper.proof.into_inner())?,
+        })
+    }
+}
+
+// Implement Serialize and Deserialize for CeremonyTranscript
+impl<B: PairingBackend> Serialize for CeremonyTranscript<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("CeremonyTranscript", 4)?;
+        state.serialize_field("max_degree", &self.max_degree)?;
+        state.serialize_field("initial_tau_g1", &B::serialize_g1(&self.initial_tau_g1))?;
+        state.serialize_field("initial_tau_g2", &B::serialize_g2(&self.initial_tau_g2))?;
+        state.serialize_field(
+            "contributions",
+            &self
+                .contributions
+                .iter()
+                .map(|record| {
+                    (
+                        record.participant_id,
+                        B::serialize_g1(&record.public_key),
+                        B::serialize_g2(&record.proof),
+                        B::serialize_g1(&record.tau_g1),
+                        B::serialize_g2(&record.tau_g2),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend> Deserialize<'de> for CeremonyTranscript<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        type RecordHelper = (
+            usize,
+            ElementBytes,
+            ElementBytes,
+            ElementBytes,
+            ElementBytes,
+        );
+
+        #[derive(Deserialize)]
+        struct CeremonyTranscriptHelper {
+            max_degree: usize,
+            initial_tau_g1: ElementBytes,
+            initial_tau_g2: ElementBytes,
+            contributions: Vec<RecordHelper>,
+        }
+
+        let helper = CeremonyTranscriptHelper::deserialize(deserializer)?;
+
+        Ok(CeremonyTranscript {
+            max_degree: helper.max_degree,
+            initial_tau_g1: g1_from_bytes::<B, D::Error>(&helper.initial_tau_g1.into_inner())?,
+            initial_tau_g2: g2_from_bytes::<B, D::Error>(&helper.initial_tau_g2.into_inner())?,
+            contributions: helper
+                .contributions
+                .into_iter()
+                .map(|(participant_id, public_key, proof, tau_g1, tau_g2)| {
+                    Ok(ContributionRecord {
+                        participant_id,
+                        public_key: g1_from_bytes::<B, D::Error>(&public_key.into_inner())?,
+                        proof: g2_from_bytes::<B, D::Error>(&proof.into_inner())?,
+                        tau_g1: g1_from_bytes::<B, D::Error>(&tau_g1.into_inner())?,
+                        tau_g2: g2_from_bytes::<B, D::Error>(&tau_g2.into_inner())?,
+                    })
+                })
+                .collect::<Result<_, D::Error>>()?,
+        })
+    }
+}
+
+// Implement Serialize and Deserialize for IpaProof
+impl<B: PairingBackend<Scalar = Fr>> Serialize for IpaProof<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("IpaProof", 3)?;
+        state.serialize_field(
+            "l",
+            &self
+                .l
+                .iter()
+                .map(|p| p.to_repr().as_ref().to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+        )?;
+        state.serialize_field(
+            "r",
+            &self
+                .r
+                .iter()
+                .map(|p| p.to_repr().as_ref().to_vec())
+                .collect::<Vec<Vec<u8>>>(),
+        )?;
+        let a_bytes = self.a.to_repr();
+        state.serialize_field("a", &a_bytes[..])?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for IpaProof<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct IpaProofHelper {
+            l: Vec<Vec<u8>>,
+            r: Vec<Vec<u8>>,
+            a: Vec<u8>,
+        }
+
+        let helper = IpaProofHelper::deserialize(deserializer)?;
+        if helper.l.len() != helper.r.len() {
+            return Err(de::Error::custom("IPA proof has unequal L and R rounds"));
+        }
+
+        Ok(IpaProof {
+            l: g1s_from_bytes::<B, D::Error, _>(&helper.l)?,
+            r: g1s_from_bytes::<B, D::Error, _>(&helper.r)?,
+            a: scalar_from_bytes::<B, D::Error>(&helper.a)?,
         })
     }
 }
@@ -498,29 +1200,13 @@ impl<'de, B: PairingBackend> Deserialize<'de> for LagrangePowers<B> {
         let helper = LagrangePowersHelper::deserialize(deserializer)?;
 
         Ok(LagrangePowers {
-            li: helper
-                .li
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
-            li_minus0: helper
-                .li_minus0
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
-            li_x: helper
-                .li_x
-                .iter()
-                .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                .collect::<Result<Vec<_>, _>>()?,
+            li: g1s_from_bytes::<B, D::Error, _>(&helper.li)?,
+            li_minus0: g1s_from_bytes::<B, D::Error, _>(&helper.li_minus0)?,
+            li_x: g1s_from_bytes::<B, D::Error, _>(&helper.li_x)?,
             li_lj_z: helper
                 .li_lj_z
                 .iter()
-                .map(|row| {
-                    row.iter()
-                        .map(|bytes| curve_point_from_bytes::<B::G1, B::Scalar, D::Error>(bytes))
-                        .collect::<Result<Vec<_>, _>>()
-                })
+                .map(|row| g1s_from_bytes::<B, D::Error, _>(row))
                 .collect::<Result<Vec<_>, _>>()?,
         })
     }
@@ -599,3 +1285,309 @@ impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for UnsafeKeyMaterial
         })
     }
 }
+
+// Implement Serialize and Deserialize for Committee
+impl<B: PairingBackend<Scalar = Fr>> Serialize for Committee<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Committee", 4)?;
+        state.serialize_field("weights", self.weights())?;
+        state.serialize_field("aggregate_key", self.aggregate_key())?;
+        state.serialize_field("ids", self.ids())?;
+        let signing_keys: Vec<Option<Vec<u8>>> = self
+            .signing_keys()
+            .iter()
+            .map(|key| key.as_ref().map(B::serialize_g1))
+            .collect();
+        state.serialize_field("signing_keys", &signing_keys)?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend<Scalar = Fr>> Deserialize<'de> for Committee<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(bound(deserialize = ""))]
+        struct CommitteeHelper<B: PairingBackend<Scalar = Fr>> {
+            weights: Vec<u64>,
+            aggregate_key: AggregateKey<B>,
+            #[serde(default)]
+            ids: Vec<Option<ParticipantId>>,
+            #[serde(default)]
+            signing_keys: Vec<Option<Vec<u8>>>,
+        }
+
+        let helper = CommitteeHelper::deserialize(deserializer)?;
+        let mut committee = Committee::from_parts(helper.aggregate_key, helper.weights)
+            .map_err(de::Error::custom)?;
+        for (index, id) in helper.ids.into_iter().enumerate() {
+            if let Some(id) = id {
+                committee.register(id, index).map_err(de::Error::custom)?;
+            }
+        }
+        for (index, key) in helper.signing_keys.into_iter().enumerate() {
+            if let Some(key) = key {
+                let key = g1_from_bytes::<B, D::Error>(&key)?;
+                committee
+                    .register_signing_key(index, key)
+                    .map_err(de::Error::custom)?;
+            }
+        }
+        Ok(committee)
+    }
+}
+
+// Implement Serialize and Deserialize for MultiCommitteeCiphertext
+impl<B: PairingBackend> Serialize for MultiCommitteeCiphertext<B> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("MultiCommitteeCiphertext", 4)?;
+        state.serialize_field("headers", &self.headers)?;
+        state.serialize_field("payload", &self.payload)?;
+        state.serialize_field("compressed", &self.compressed)?;
+        state.serialize_field("padded", &self.padded)?;
+        state.end()
+    }
+}
+
+impl<'de, B: PairingBackend> Deserialize<'de> for MultiCommitteeCiphertext<B> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(bound(deserialize = ""))]
+        struct MultiCommitteeCiphertextHelper<B: PairingBackend> {
+            headers: Vec<Ciphertext<B>>,
+            payload: Bounded<u8, PayloadLimit>,
+            #[serde(default)]
+            compressed: bool,
+            #[serde(default)]
+            padded: bool,
+        }
+
+        let helper = MultiCommitteeCiphertextHelper::deserialize(deserializer)?;
+        Ok(MultiCommitteeCiphertext {
+            headers: helper.headers,
+            payload: helper.payload.into_inner(),
+            compressed: helper.compressed,
+            padded: helper.padded,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{
+        AggregateKey, Ciphertext, Committee, IdentityKey, PairingEngine, Params, PartialDecryption,
+        PartialSigningKey, PublicKey, SecretKey, SignedPartialDecryption, SilentThresholdScheme,
+        ThresholdEncryption,
+    };
+
+    #[test]
+    fn json_roundtrip_decrypts() {
+        let mut rng = StdRng::seed_from_u64(1438);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new()
+            .with_epoch(9)
+            .with_label(b"serde");
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(
+                &mut rng,
+                &keys.aggregate_key,
+                &params,
+                2,
+                b"serde roundtrip",
+            )
+            .unwrap();
+
+        let params: Params<PairingEngine> =
+            serde_json::from_str(&serde_json::to_string(&params).unwrap()).unwrap();
+        let agg_key: AggregateKey<PairingEngine> =
+            serde_json::from_str(&serde_json::to_string(&keys.aggregate_key).unwrap()).unwrap();
+        let json = serde_json::to_string(&ct).unwrap();
+        assert!(json.starts_with(r#"{"header":"#));
+        let ct: Ciphertext<PairingEngine> = serde_json::from_str(&json).unwrap();
+        assert_eq!(ct.label, b"serde");
+        scheme.verify_ciphertext(&agg_key, &params, &ct).unwrap();
+
+        let selector = [true, true, true, false];
+        let mut committee = Committee::new(keys.public_keys.clone(), &params).unwrap();
+        let signing_keys: Vec<_> = (0..3)
+            .map(|id| PartialSigningKey::generate(&mut rng, id))
+            .collect();
+        for key in &signing_keys {
+            committee
+                .register_signing_key(key.participant_id, key.public_key())
+                .unwrap();
+        }
+        let committee: Committee<PairingEngine> =
+            serde_json::from_str(&serde_json::to_string(&committee).unwrap()).unwrap();
+        assert_eq!(
+            committee.signing_key(2),
+            Some(&signing_keys[2].public_key())
+        );
+        let signed: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .zip(&signing_keys)
+            .map(|(sk, key)| scheme.partial_decrypt_signed(sk, key, &ct).unwrap())
+            .collect();
+        let signed: Vec<SignedPartialDecryption<PairingEngine>> =
+            serde_json::from_str(&serde_json::to_string(&signed).unwrap()).unwrap();
+        let result = scheme
+            .aggregate_decrypt_signed(&ct, &signed, &selector, &committee)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"serde roundtrip");
+
+        let ct = scheme
+            .encrypt_to_identity(&mut rng, &agg_key, &params, 2, b"carol", b"identity")
+            .unwrap();
+        let shares: Vec<_> = keys.secret_keys[..2]
+            .iter()
+            .map(|sk| scheme.identity_share(sk, b"carol"))
+            .collect();
+        let key = scheme
+            .extract_identity_key(b"carol", 2, &shares, &[true, true, false, false], &agg_key)
+            .unwrap();
+        let key: IdentityKey<PairingEngine> =
+            serde_json::from_str(&serde_json::to_string(&key).unwrap()).unwrap();
+        assert_eq!(
+            scheme.decrypt_with_identity_key(&ct, &key).unwrap(),
+            b"identity"
+        );
+    }
+
+    #[test]
+    fn oversized_ciphertext_fields_are_rejected() {
+        let mut rng = StdRng::seed_from_u64(1443);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"limits")
+            .unwrap();
+        let limits = super::DeserializeLimits::default();
+
+        let mut value = serde_json::to_value(&ct).unwrap();
+        let first = value["proof_g1"][0].clone();
+        value["proof_g1"] = serde_json::Value::Array(vec![first; limits.max_proof_elements + 1]);
+        let err = serde_json::from_value::<Ciphertext<PairingEngine>>(value)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("proof exceeds the limit"), "{err}");
+
+        let mut value = serde_json::to_value(&ct).unwrap();
+        value["gamma_g2"] = serde_json::Value::Array(vec![0.into(); 2048]);
+        assert!(serde_json::from_value::<Ciphertext<PairingEngine>>(value).is_err());
+
+        let mut value = serde_json::to_value(&ct).unwrap();
+        value["header"]["label"] = serde_json::Value::Array(vec![0.into(); 2048]);
+        assert!(serde_json::from_value::<Ciphertext<PairingEngine>>(value).is_err());
+
+        let mut value = serde_json::to_value(&ct).unwrap();
+        value["header"]["payload_len"] = 0.into();
+        assert!(serde_json::from_value::<Ciphertext<PairingEngine>>(value).is_err());
+
+        let json = serde_json::to_string(&ct).unwrap();
+        assert!(serde_json::from_str::<Ciphertext<PairingEngine>>(&json).is_ok());
+    }
+
+    // Element decoding used to zero-pad short byte strings into the backend's
+    // repr, so a truncated encoding silently decoded to a different element.
+    #[test]
+    fn truncated_element_encodings_are_rejected() {
+        let mut rng = StdRng::seed_from_u64(14380);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"truncated")
+            .unwrap();
+        let partial = scheme.partial_decrypt(&keys.secret_keys[1], &ct).unwrap();
+
+        fn truncate(value: &mut serde_json::Value, field: &str) {
+            value[field].as_array_mut().unwrap().pop();
+        }
+
+        let mut value = serde_json::to_value(&keys.secret_keys[1]).unwrap();
+        truncate(&mut value, "scalar");
+        assert!(serde_json::from_value::<SecretKey<PairingEngine>>(value).is_err());
+
+        let mut value = serde_json::to_value(&keys.public_keys[1]).unwrap();
+        truncate(&mut value, "bls_key");
+        assert!(serde_json::from_value::<PublicKey<PairingEngine>>(value).is_err());
+
+        let mut value = serde_json::to_value(&partial).unwrap();
+        truncate(&mut value, "response");
+        assert!(serde_json::from_value::<PartialDecryption<PairingEngine>>(value).is_err());
+
+        // Untouched encodings still decode to the same elements.
+        let json = serde_json::to_string(&keys.secret_keys[1]).unwrap();
+        let decoded: SecretKey<PairingEngine> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.scalar, keys.secret_keys[1].scalar);
+        let json = serde_json::to_string(&partial).unwrap();
+        let decoded: PartialDecryption<PairingEngine> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.response, partial.response);
+    }
+
+    #[test]
+    fn limits_apply_per_call() {
+        use serde::de::DeserializeSeed;
+
+        let mut rng = StdRng::seed_from_u64(14430);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, &[7; 64])
+            .unwrap();
+        let json = serde_json::to_string(&ct).unwrap();
+        let strict = super::DeserializeLimits {
+            max_payload_len: 32,
+            ..super::DeserializeLimits::default()
+        };
+
+        let mut de = serde_json::Deserializer::from_str(&json);
+        let err = strict
+            .deserialize::<Ciphertext<PairingEngine>, _>(&mut de)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("payload exceeds the limit of 32"), "{err}");
+
+        // Nested values are covered, and the limits end with the call.
+        let list = format!("[{json},{json}]");
+        let mut de = serde_json::Deserializer::from_str(&list);
+        assert!(
+            strict
+                .seed::<Vec<Ciphertext<PairingEngine>>>()
+                .deserialize(&mut de)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Vec<Ciphertext<PairingEngine>>>(&list).is_ok());
+
+        // Other threads keep the default limits while a strict call runs.
+        std::thread::scope(|scope| {
+            let worker =
+                scope.spawn(|| serde_json::from_str::<Ciphertext<PairingEngine>>(&json).is_ok());
+            let mut de = serde_json::Deserializer::from_str(&json);
+            assert!(
+                strict
+                    .deserialize::<Ciphertext<PairingEngine>, _>(&mut de)
+                    .is_err()
+            );
+            assert!(worker.join().unwrap());
+        });
+    }
+}
@@ -0,0 +1,457 @@
+//! In-memory multi-party simulation harness.
+//!
+//! [`Simulation`] runs a committee of `n` participants and one coordinator
+//! that talk only through per-node [`mpsc`] channels. Every protocol step goes
+//! through the public scheme API: participants generate their own keys with
+//! [`keygen_single_validator`](ThresholdEncryption::keygen_single_validator)
+//! and send the public halves to the coordinator, which aggregates them. For
+//! decryption the coordinator broadcasts the ciphertext, participants answer
+//! with [`partial_decrypt`](ThresholdEncryption::partial_decrypt), and the
+//! coordinator checks each share with [`verify_partial_decryption`] before
+//! calling [`aggregate_decrypt`](ThresholdEncryption::aggregate_decrypt).
+//!
+//! Messages move in synchronous rounds. Before a message is queued it passes
+//! through the installed [`NetworkHook`], which may deliver it, drop it,
+//! delay it by a number of rounds, or rewrite it in place, so tests can model
+//! crashed, slow and malicious participants deterministically.
+//!
+//! ```rust
+//! use rand::{SeedableRng, rngs::StdRng};
+//! use tess::{Delivery, Envelope, Message, Node, PairingEngine, SilentThresholdScheme, Simulation};
+//!
+//! let mut rng = StdRng::seed_from_u64(1);
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let mut sim = Simulation::new(&mut rng, scheme, 8, 3).unwrap();
+//! sim.run_keygen(&mut rng).unwrap();
+//!
+//! // Participants 5 and 6 never answer; participant 7 is slow.
+//! sim.set_hook(|_round: usize, envelope: &mut Envelope<PairingEngine>| {
+//!     match (envelope.from, &envelope.message) {
+//!         (Node::Participant(5 | 6), Message::Partial(_)) => Delivery::Drop,
+//!         (Node::Participant(7), Message::Partial(_)) => Delivery::Delay(2),
+//!         _ => Delivery::Deliver,
+//!     }
+//! });
+//!
+//! let ct = sim.encrypt(&mut rng, b"simulated").unwrap();
+//! let result = sim.decrypt(&ct).unwrap();
+//! assert_eq!(result.plaintext.unwrap(), b"simulated");
+//! assert_eq!(sim.stats().dropped, 2);
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use rand_core::RngCore;
+
+use crate::{
+    AggregateKey, Ciphertext, DecryptionResult, Error, Fr, Message, Node, PairingBackend, Params,
+    PartialDecryption, PublicKey, SecretKey, SilentThresholdScheme, ThresholdEncryption,
+    verify_partial_decryption,
+};
+
+/// Upper bound on rounds per protocol phase, so a hook that keeps delaying
+/// messages cannot stall a test forever.
+pub const MAX_SIMULATION_ROUNDS: usize = 1024;
+
+/// A message in flight.
+#[derive(Clone, Debug)]
+pub struct Envelope<B: PairingBackend<Scalar = Fr>> {
+    /// Sending node.
+    pub from: Node,
+    /// Receiving node.
+    pub to: Node,
+    /// Payload.
+    pub message: Message<B>,
+}
+
+/// What the network does with a message, as decided by a [`NetworkHook`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Delivery {
+    /// Deliver at the start of the next round.
+    Deliver,
+    /// Discard the message.
+    Drop,
+    /// Deliver this many rounds later than normal.
+    Delay(usize),
+}
+
+/// Network behaviour injected into a [`Simulation`].
+///
+/// Called once per message when it is sent, with the current round number.
+/// The hook may modify the envelope to simulate a faulty or malicious sender.
+/// Closures `FnMut(usize, &mut Envelope<B>) -> Delivery` implement this trait.
+pub trait NetworkHook<B: PairingBackend<Scalar = Fr>>: Send {
+    /// Decides the fate of `envelope`.
+    fn route(&mut self, round: usize, envelope: &mut Envelope<B>) -> Delivery;
+}
+
+impl<B, F> NetworkHook<B> for F
+where
+    B: PairingBackend<Scalar = Fr>,
+    F: FnMut(usize, &mut Envelope<B>) -> Delivery + Send,
+{
+    fn route(&mut self, round: usize, envelope: &mut Envelope<B>) -> Delivery {
+        self(round, envelope)
+    }
+}
+
+/// Delivers every message on time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ReliableNetwork;
+
+impl<B: PairingBackend<Scalar = Fr>> NetworkHook<B> for ReliableNetwork {
+    fn route(&mut self, _round: usize, _envelope: &mut Envelope<B>) -> Delivery {
+        Delivery::Deliver
+    }
+}
+
+/// Message counters accumulated over the lifetime of a [`Simulation`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkStats {
+    /// Rounds executed.
+    pub rounds: usize,
+    /// Messages handed to their recipient.
+    pub delivered: usize,
+    /// Messages discarded by the hook.
+    pub dropped: usize,
+    /// Messages the hook delayed.
+    pub delayed: usize,
+    /// Partial decryptions the coordinator rejected as invalid.
+    pub rejected_partials: usize,
+}
+
+struct Participant<B: PairingBackend<Scalar = Fr>> {
+    id: usize,
+    secret_key: Option<SecretKey<B>>,
+    inbox: Receiver<Envelope<B>>,
+}
+
+/// Committee of in-memory participants driven through the real scheme API.
+///
+/// See the [module documentation](self) for the message flow.
+pub struct Simulation<B: PairingBackend<Scalar = Fr>> {
+    scheme: SilentThresholdScheme<B>,
+    params: Params<B>,
+    threshold: usize,
+    participants: Vec<Participant<B>>,
+    coordinator: Receiver<Envelope<B>>,
+    senders: BTreeMap<Node, Sender<Envelope<B>>>,
+    in_flight: Vec<(usize, Envelope<B>)>,
+    hook: Box<dyn NetworkHook<B>>,
+    round: usize,
+    stats: NetworkStats,
+    public_keys: Vec<PublicKey<B>>,
+    aggregate_key: Option<AggregateKey<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Simulation<B> {
+    /// Generates parameters for `parties` participants and wires up the
+    /// network with a [`ReliableNetwork`].
+    pub fn new<R: RngCore + ?Sized>(
+        rng: &mut R,
+        scheme: SilentThresholdScheme<B>,
+        parties: usize,
+        threshold: usize,
+    ) -> Result<Self, Error> {
+        let params = scheme.param_gen(rng, parties, threshold)?;
+        let mut senders = BTreeMap::new();
+        let participants = (0..parties)
+            .map(|id| {
+                let (sender, inbox) = mpsc::channel();
+                senders.insert(Node::Participant(id), sender);
+                Participant {
+                    id,
+                    secret_key: None,
+                    inbox,
+                }
+            })
+            .collect();
+        let (sender, coordinator) = mpsc::channel();
+        senders.insert(Node::Coordinator, sender);
+
+        Ok(Self {
+            scheme,
+            params,
+            threshold,
+            participants,
+            coordinator,
+            senders,
+            in_flight: Vec::new(),
+            hook: Box::new(ReliableNetwork),
+            round: 0,
+            stats: NetworkStats::default(),
+            public_keys: Vec::new(),
+            aggregate_key: None,
+        })
+    }
+
+    /// Replaces the network hook for all subsequent messages.
+    pub fn set_hook<H: NetworkHook<B> + 'static>(&mut self, hook: H) {
+        self.hook = Box::new(hook);
+    }
+
+    /// Parameters shared by the committee.
+    pub fn params(&self) -> &Params<B> {
+        &self.params
+    }
+
+    /// Aggregate key, once [`run_keygen`](Self::run_keygen) has succeeded.
+    pub fn aggregate_key(&self) -> Option<&AggregateKey<B>> {
+        self.aggregate_key.as_ref()
+    }
+
+    /// Network counters so far.
+    pub fn stats(&self) -> NetworkStats {
+        self.stats
+    }
+
+    /// Runs silent setup: every participant generates its key pair and sends
+    /// the public key to the coordinator, which aggregates them.
+    ///
+    /// Returns [`Error::InvalidConfig`] naming the participants whose public
+    /// keys never arrived.
+    pub fn run_keygen<R: RngCore + ?Sized>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<&AggregateKey<B>, Error> {
+        for index in 0..self.participants.len() {
+            let id = self.participants[index].id;
+            let (secret_key, public_key) =
+                self.scheme.keygen_single_validator(rng, id, &self.params)?;
+            self.participants[index].secret_key = Some(secret_key);
+            self.send(
+                Node::Participant(id),
+                Node::Coordinator,
+                Message::PublicKey(public_key),
+            );
+        }
+
+        let mut received: BTreeMap<usize, PublicKey<B>> = BTreeMap::new();
+        self.run_until_idle(|sim| {
+            while let Ok(envelope) = sim.coordinator.try_recv() {
+                if let (Node::Participant(id), Message::PublicKey(public_key)) =
+                    (envelope.from, envelope.message)
+                    && public_key.participant_id == id
+                {
+                    received.insert(id, public_key);
+                }
+            }
+        });
+
+        let missing: Vec<usize> = (0..self.participants.len())
+            .filter(|id| !received.contains_key(id))
+            .collect();
+        if !missing.is_empty() {
+            return Err(Error::InvalidConfig(format!(
+                "public keys missing for participants {missing:?}"
+            )));
+        }
+        self.public_keys = received.into_values().collect();
+        let aggregate_key = self.scheme.aggregate_public_key(
+            &self.public_keys,
+            &self.params,
+            self.participants.len(),
+            &[],
+        )?;
+        Ok(self.aggregate_key.insert(aggregate_key))
+    }
+
+    /// Encrypts `payload` to the committee at the simulation's threshold.
+    pub fn encrypt<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        payload: &[u8],
+    ) -> Result<Ciphertext<B>, Error> {
+        let aggregate_key = self.require_aggregate_key()?;
+        self.scheme
+            .encrypt(rng, aggregate_key, &self.params, self.threshold, payload)
+    }
+
+    /// Broadcasts `ciphertext`, collects partial decryptions until the network
+    /// is idle, and aggregates the valid ones.
+    ///
+    /// Shares failing [`verify_partial_decryption`] are discarded and counted
+    /// in [`NetworkStats::rejected_partials`]. Errors from
+    /// [`aggregate_decrypt`](ThresholdEncryption::aggregate_decrypt), such as
+    /// [`Error::InsufficientShares`], are returned unchanged.
+    pub fn decrypt(&mut self, ciphertext: &Ciphertext<B>) -> Result<DecryptionResult<B>, Error> {
+        self.require_aggregate_key()?;
+        for id in 0..self.participants.len() {
+            self.send(
+                Node::Coordinator,
+                Node::Participant(id),
+                Message::Ciphertext(Box::new(ciphertext.clone())),
+            );
+        }
+
+        let mut partials: BTreeMap<usize, PartialDecryption<B>> = BTreeMap::new();
+        let mut rejected = 0;
+        self.run_until_idle(|sim| {
+            for index in 0..sim.participants.len() {
+                let participant = &sim.participants[index];
+                let mut replies = Vec::new();
+                while let Ok(envelope) = participant.inbox.try_recv() {
+                    if let (Message::Ciphertext(ct), Some(secret_key)) =
+                        (envelope.message, participant.secret_key.as_ref())
+                        && let Ok(partial) = sim.scheme.partial_decrypt(secret_key, &ct)
+                    {
+                        replies.push(partial);
+                    }
+                }
+                let from = Node::Participant(participant.id);
+                for partial in replies {
+                    sim.send(from, Node::Coordinator, Message::Partial(partial));
+                }
+            }
+
+            while let Ok(envelope) = sim.coordinator.try_recv() {
+                let (Node::Participant(id), Message::Partial(partial)) =
+                    (envelope.from, envelope.message)
+                else {
+                    continue;
+                };
+                match sim.public_keys.get(id) {
+                    Some(public_key)
+                        if verify_partial_decryption(public_key, ciphertext, &partial) =>
+                    {
+                        partials.insert(id, partial);
+                    }
+                    _ => rejected += 1,
+                }
+            }
+        });
+        self.stats.rejected_partials += rejected;
+
+        let mut selector = vec![false; self.participants.len()];
+        for &id in partials.keys() {
+            selector[id] = true;
+        }
+        let partials: Vec<_> = partials.into_values().collect();
+        let aggregate_key = self.require_aggregate_key()?;
+        self.scheme
+            .aggregate_decrypt(ciphertext, &partials, &selector, aggregate_key)
+    }
+
+    fn require_aggregate_key(&self) -> Result<&AggregateKey<B>, Error> {
+        self.aggregate_key
+            .as_ref()
+            .ok_or_else(|| Error::InvalidConfig("run_keygen has not completed".into()))
+    }
+
+    fn send(&mut self, from: Node, to: Node, message: Message<B>) {
+        let mut envelope = Envelope { from, to, message };
+        match self.hook.route(self.round, &mut envelope) {
+            Delivery::Deliver => self.in_flight.push((self.round + 1, envelope)),
+            Delivery::Drop => self.stats.dropped += 1,
+            Delivery::Delay(rounds) => {
+                self.stats.delayed += 1;
+                self.in_flight.push((self.round + 1 + rounds, envelope));
+            }
+        }
+    }
+
+    /// Advances rounds, running `step` after each delivery, until no message
+    /// is in flight or [`MAX_SIMULATION_ROUNDS`] is reached.
+    fn run_until_idle(&mut self, mut step: impl FnMut(&mut Self)) {
+        for _ in 0..MAX_SIMULATION_ROUNDS {
+            if self.in_flight.is_empty() {
+                return;
+            }
+            self.round += 1;
+            self.stats.rounds += 1;
+            let round = self.round;
+            let (due, pending): (Vec<_>, Vec<_>) = core::mem::take(&mut self.in_flight)
+                .into_iter()
+                .partition(|(at, _)| *at <= round);
+            self.in_flight = pending;
+            for (_, envelope) in due {
+                if let Some(sender) = self.senders.get(&envelope.to)
+                    && sender.send(envelope).is_ok()
+                {
+                    self.stats.delivered += 1;
+                }
+            }
+            step(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{CurvePoint, PairingEngine};
+
+    type B = PairingEngine;
+
+    fn simulation(rng: &mut StdRng) -> Simulation<B> {
+        let mut sim = Simulation::new(rng, SilentThresholdScheme::<B>::new(), 8, 3).unwrap();
+        sim.run_keygen(rng).unwrap();
+        sim
+    }
+
+    #[test]
+    fn honest_committee_decrypts() {
+        let mut rng = StdRng::seed_from_u64(1448);
+        let mut sim = simulation(&mut rng);
+        let ct = sim.encrypt(&mut rng, b"all honest").unwrap();
+        let result = sim.decrypt(&ct).unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"all honest");
+        let stats = sim.stats();
+        assert_eq!(stats.delivered, 8 + 8 + 8);
+        assert_eq!((stats.dropped, stats.rejected_partials), (0, 0));
+    }
+
+    #[test]
+    fn tampered_and_missing_shares() {
+        let mut rng = StdRng::seed_from_u64(1448);
+        let mut sim = simulation(&mut rng);
+
+        // Two participants corrupt their shares and three stay silent; the
+        // remaining three still meet the threshold.
+        sim.set_hook(|_round: usize, envelope: &mut Envelope<B>| {
+            match (envelope.from, &mut envelope.message) {
+                (Node::Participant(1 | 2), Message::Partial(partial)) => {
+                    partial.response = partial.response.add(&partial.response);
+                    Delivery::Deliver
+                }
+                (Node::Participant(3..=5), Message::Partial(_)) => Delivery::Drop,
+                _ => Delivery::Deliver,
+            }
+        });
+        let ct = sim.encrypt(&mut rng, b"faulty committee").unwrap();
+        let result = sim.decrypt(&ct).unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"faulty committee");
+        assert_eq!(sim.stats().rejected_partials, 2);
+
+        // One more silent participant leaves too few shares.
+        sim.set_hook(
+            |_round: usize, envelope: &mut Envelope<B>| match envelope.from {
+                Node::Participant(1..=6) => Delivery::Drop,
+                _ => Delivery::Deliver,
+            },
+        );
+        assert!(matches!(
+            sim.decrypt(&ct),
+            Err(Error::InsufficientShares { .. })
+        ));
+    }
+
+    #[test]
+    fn dropped_public_key_fails_keygen() {
+        let mut rng = StdRng::seed_from_u64(1448);
+        let mut sim = Simulation::new(&mut rng, SilentThresholdScheme::<B>::new(), 4, 2).unwrap();
+        sim.set_hook(|_round: usize, envelope: &mut Envelope<B>| {
+            if envelope.from == Node::Participant(2) {
+                Delivery::Drop
+            } else {
+                Delivery::Delay(3)
+            }
+        });
+        let err = sim.run_keygen(&mut rng).unwrap_err();
+        assert!(matches!(err, Error::InvalidConfig(msg) if msg.contains("[2]")));
+        assert!(sim.encrypt(&mut rng, b"x").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Payload key derivation.
+//!
+//! The payload key is derived from the threshold shared secret by a [`Kdf`].
+//! [`XofKdf`], built on the scheme's [`Xof`], is the default. With the `hkdf`
+//! feature, [`HkdfSha256`] derives it with HKDF-SHA256 (RFC 5869) instead, for
+//! deployments restricted to approved primitives. The choice is recorded in
+//! each ciphertext as a [`KdfId`], so decryption always uses the function the
+//! ciphertext was encrypted with.
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use crate::{Error, Xof};
+
+/// Length in bytes of a derived payload key.
+pub const PAYLOAD_KEY_LEN: usize = 32;
+
+/// Derives a payload key from a shared secret and an application context.
+pub trait Kdf: Debug + Send + Sync {
+    /// Identifier recorded in ciphertexts encrypted with this function.
+    fn id(&self) -> KdfId;
+
+    /// Derives the payload key for `secret` under `context`.
+    fn derive(&self, secret: &[u8], context: &[u8]) -> [u8; PAYLOAD_KEY_LEN];
+}
+
+/// Payload key derivation function recorded in a ciphertext header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KdfId {
+    /// [`XofKdf`] over the scheme's XOF.
+    #[default]
+    Xof,
+    /// [`HkdfSha256`].
+    #[cfg(feature = "hkdf")]
+    HkdfSha256,
+}
+
+impl KdfId {
+    /// Header byte for this function.
+    pub fn to_byte(self) -> u8 {
+        match self {
+            KdfId::Xof => 0,
+            #[cfg(feature = "hkdf")]
+            KdfId::HkdfSha256 => 1,
+        }
+    }
+
+    /// Parses a header byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] for unknown identifiers, including
+    /// HKDF-SHA256 when the `hkdf` feature is disabled.
+    pub fn from_byte(byte: u8) -> Result<Self, Error> {
+        match byte {
+            0 => Ok(KdfId::Xof),
+            #[cfg(feature = "hkdf")]
+            1 => Ok(KdfId::HkdfSha256),
+            _ => Err(Error::MalformedInput(format!(
+                "unsupported payload KDF {byte}"
+            ))),
+        }
+    }
+
+    /// Derives a payload key with the function this identifies, using `X` for
+    /// [`KdfId::Xof`].
+    pub(crate) fn derive<X: Xof>(self, secret: &[u8], context: &[u8]) -> [u8; PAYLOAD_KEY_LEN] {
+        match self {
+            KdfId::Xof => XofKdf::<X>::default().derive(secret, context),
+            #[cfg(feature = "hkdf")]
+            KdfId::HkdfSha256 => HkdfSha256.derive(secret, context),
+        }
+    }
+}
+
+/// Payload key derivation with an [`Xof`]: the domain-separated hash of the
+/// secret and the length-prefixed context.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct XofKdf<X: Xof = blake3::Hasher> {
+    _xof: PhantomData<X>,
+}
+
+impl<X: Xof> Kdf for XofKdf<X> {
+    fn id(&self) -> KdfId {
+        KdfId::Xof
+    }
+
+    fn derive(&self, secret: &[u8], context: &[u8]) -> [u8; PAYLOAD_KEY_LEN] {
+        let mut hasher = X::default();
+        hasher.update(b"tess::payload-key");
+        hasher.update(secret);
+        // The empty context appends nothing, keeping keys of context-free
+        // schemes unchanged.
+        if !context.is_empty() {
+            hasher.update(&(context.len() as u64).to_le_bytes());
+            hasher.update(context);
+        }
+        hasher.finalize_bytes()
+    }
+}
+
+/// HKDF-SHA256 payload key derivation, with a fixed salt and the application
+/// context as `info`.
+#[cfg(feature = "hkdf")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HkdfSha256;
+
+#[cfg(feature = "hkdf")]
+impl Kdf for HkdfSha256 {
+    fn id(&self) -> KdfId {
+        KdfId::HkdfSha256
+    }
+
+    fn derive(&self, secret: &[u8], context: &[u8]) -> [u8; PAYLOAD_KEY_LEN] {
+        let hkdf = hkdf::Hkdf::<sha2::Sha256>::new(Some(b"tess::payload-key"), secret);
+        let mut key = [0u8; PAYLOAD_KEY_LEN];
+        hkdf.expand(context, &mut key)
+            .expect("32 bytes is within the HKDF-SHA256 output limit");
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kdf_ids_roundtrip() {
+        assert_eq!(KdfId::from_byte(KdfId::Xof.to_byte()).unwrap(), KdfId::Xof);
+        #[cfg(feature = "hkdf")]
+        assert_eq!(
+            KdfId::from_byte(KdfId::HkdfSha256.to_byte()).unwrap(),
+            KdfId::HkdfSha256
+        );
+        assert!(KdfId::from_byte(0xff).is_err());
+    }
+
+    #[cfg(feature = "hkdf")]
+    #[test]
+    fn hkdf_keys_differ_from_xof_keys_and_bind_context() {
+        let key = HkdfSha256.derive(b"secret", b"context");
+        assert_eq!(
+            key,
+            KdfId::HkdfSha256.derive::<blake3::Hasher>(b"secret", b"context")
+        );
+        assert_ne!(
+            key,
+            XofKdf::<blake3::Hasher>::default().derive(b"secret", b"context")
+        );
+        assert_ne!(key, HkdfSha256.derive(b"secret", b"other"));
+    }
+}
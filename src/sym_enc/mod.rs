@@ -14,7 +14,17 @@
 //! # Implementations
 //!
 //! Currently provides:
-//! - **[`Blake3XorEncryption`]**: XOR-based encryption using BLAKE3 in XOF mode
+//! - **[`XorEncryption`]**: XOR-based encryption with a keystream from any
+//!   [`Xof`]; [`Blake3XorEncryption`] is the BLAKE3 instance
+//! - **[`PaddingScheme`]**: Length-hiding padding applied before encryption
+//! - **[`Kdf`]**: Derivation of the payload key from the shared secret
+//!
+//! # Large Payloads
+//!
+//! Payloads longer than [`PAYLOAD_CHUNK_SIZE`] are split into fixed-size chunks,
+//! each with its own keystream derived from the secret and the chunk index. The
+//! chunks are independent, so with the `parallel` feature they are encrypted and
+//! decrypted concurrently.
 //!
 //! # Example
 //!
@@ -34,11 +44,21 @@
 //! ```
 
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::{fmt::Debug, marker::PhantomData};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use blake3::Hasher;
+use crate::{Error, Xof, XofReader, cpu::xor_in_place};
 
-use crate::Error;
+mod kdf;
+mod padding;
+
+pub use kdf::*;
+pub use padding::*;
+
+/// Chunk size, in bytes, above which payloads are processed in independent chunks.
+pub const PAYLOAD_CHUNK_SIZE: usize = 64 * 1024;
 
 /// Trait for symmetric encryption/decryption operations.
 ///
@@ -88,17 +108,51 @@ pub trait SymmetricEncryption: Debug + Send + Sync {
     ///
     /// The decrypted plaintext, or an error if decryption fails.
     fn decrypt(&self, secret: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Encrypts `buffer` in place.
+    ///
+    /// Only length-preserving schemes can support this. The default implementation
+    /// falls back to [`encrypt`](Self::encrypt) and returns an error if the
+    /// ciphertext length differs from the plaintext length.
+    fn encrypt_in_place(&self, secret: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+        let ciphertext = self.encrypt(secret, buffer)?;
+        copy_same_length(buffer, &ciphertext)
+    }
+
+    /// Decrypts `buffer` in place.
+    ///
+    /// The default implementation falls back to [`decrypt`](Self::decrypt) and
+    /// returns an error if the plaintext length differs from the ciphertext length.
+    fn decrypt_in_place(&self, secret: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+        let plaintext = self.decrypt(secret, buffer)?;
+        copy_same_length(buffer, &plaintext)
+    }
+}
+
+/// Copies `src` into `dst`, rejecting length-changing transformations.
+fn copy_same_length(dst: &mut [u8], src: &[u8]) -> Result<(), Error> {
+    if dst.len() != src.len() {
+        return Err(Error::InvalidConfig(
+            "symmetric encryption does not support in-place operation".into(),
+        ));
+    }
+    dst.copy_from_slice(src);
+    Ok(())
 }
 
-/// BLAKE3-based symmetric encryption using XOR with extended output function (XOF).
+/// Symmetric encryption using XOR with an extendable output function (XOF).
 ///
-/// This implementation uses BLAKE3 in XOF mode to derive a keystream from the secret,
+/// This implementation uses the XOF `X` to derive a keystream from the secret,
 /// then XORs it with the plaintext/ciphertext. This provides confidentiality but not
 /// authentication.
 ///
+/// Payloads that fit in a single [`PAYLOAD_CHUNK_SIZE`] chunk use one keystream.
+/// Longer payloads derive a separate keystream per chunk, domain-separated by
+/// the chunk index, which allows chunks to be processed in parallel.
+///
 /// # Security Considerations
 ///
-/// - **Confidentiality**: Provides semantic security assuming BLAKE3 XOF is a secure PRF
+/// - **Confidentiality**: Provides semantic security assuming the XOF is a secure PRF
 /// - **Authentication**: Does NOT provide authentication or integrity protection
 /// - **Recommended for**: Demonstration and non-critical applications
 /// - **Production alternative**: Consider AES-GCM or ChaCha20-Poly1305 for authenticated encryption
@@ -120,59 +174,186 @@ pub trait SymmetricEncryption: Debug + Send + Sync {
 /// assert_eq!(message, &plaintext[..]);
 /// ```
 #[derive(Debug, Clone)]
-pub struct Blake3XorEncryption {
-    /// Domain separation tag for BLAKE3 KDF.
+pub struct XorEncryption<X: Xof = blake3::Hasher> {
+    /// Domain separation tag for the keystream.
     domain: &'static [u8],
+    _xof: PhantomData<X>,
 }
 
-impl Blake3XorEncryption {
-    /// Creates a new BLAKE3-based encryption with the given domain.
+/// [`XorEncryption`] with a BLAKE3 keystream.
+pub type Blake3XorEncryption = XorEncryption<blake3::Hasher>;
+
+impl<X: Xof> XorEncryption<X> {
+    /// Creates a new XOF-based encryption with the given domain.
     pub fn new(domain: &'static [u8]) -> Self {
-        Self { domain }
+        Self {
+            domain,
+            _xof: PhantomData,
+        }
     }
 }
 
-impl Default for Blake3XorEncryption {
+impl<X: Xof> Default for XorEncryption<X> {
     fn default() -> Self {
         Self::new(b"tess::payload")
     }
 }
 
-impl SymmetricEncryption for Blake3XorEncryption {
+impl<X: Xof> SymmetricEncryption for XorEncryption<X> {
     fn encrypt(&self, secret: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Error> {
-        if plaintext.is_empty() {
-            return Ok(Vec::new());
-        }
-        let keystream = self.derive_keystream(secret, plaintext.len());
-        Ok(xor_bytes(&keystream, plaintext))
+        let mut buffer = plaintext.to_vec();
+        self.apply_keystream(secret, &mut buffer);
+        Ok(buffer)
     }
 
     fn decrypt(&self, secret: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
-        if ciphertext.is_empty() {
-            return Ok(Vec::new());
-        }
-        let keystream = self.derive_keystream(secret, ciphertext.len());
-        Ok(xor_bytes(&keystream, ciphertext))
+        let mut buffer = ciphertext.to_vec();
+        self.apply_keystream(secret, &mut buffer);
+        Ok(buffer)
+    }
+
+    fn encrypt_in_place(&self, secret: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+        self.apply_keystream(secret, buffer);
+        Ok(())
+    }
+
+    fn decrypt_in_place(&self, secret: &[u8], buffer: &mut [u8]) -> Result<(), Error> {
+        self.apply_keystream(secret, buffer);
+        Ok(())
     }
 }
 
-impl Blake3XorEncryption {
-    fn derive_keystream(&self, secret: &[u8], len: usize) -> Vec<u8> {
-        if len == 0 {
-            return Vec::new();
+impl<X: Xof> XorEncryption<X> {
+    /// XORs `data` in place with the keystream for `secret`, chunking long inputs.
+    fn apply_keystream(&self, secret: &[u8], data: &mut [u8]) {
+        let total_len = data.len();
+        if total_len == 0 {
+            return;
+        }
+        if total_len <= PAYLOAD_CHUNK_SIZE {
+            xor_keystream(self.keystream(secret, total_len, None), data);
+            return;
         }
-        let mut hasher = Hasher::new();
+
+        #[cfg(feature = "parallel")]
+        data.par_chunks_mut(PAYLOAD_CHUNK_SIZE)
+            .enumerate()
+            .for_each(|(index, chunk)| {
+                xor_keystream(self.keystream(secret, total_len, Some(index)), chunk)
+            });
+        #[cfg(not(feature = "parallel"))]
+        data.chunks_mut(PAYLOAD_CHUNK_SIZE)
+            .enumerate()
+            .for_each(|(index, chunk)| {
+                xor_keystream(self.keystream(secret, total_len, Some(index)), chunk)
+            });
+    }
+
+    /// Returns the XOF reader for a payload of `len` bytes.
+    ///
+    /// Chunked payloads bind each keystream to its chunk index; single-chunk
+    /// payloads use the unindexed stream.
+    fn keystream(&self, secret: &[u8], len: usize, chunk: Option<usize>) -> X::Reader {
+        let mut hasher = X::default();
         hasher.update(self.domain);
         hasher.update(secret);
         hasher.update(&(len as u64).to_le_bytes());
-        let mut reader = hasher.finalize_xof();
-        let mut keystream = vec![0u8; len];
-        reader.fill(&mut keystream);
-        keystream
+        if let Some(index) = chunk {
+            hasher.update(&(index as u64).to_le_bytes());
+        }
+        hasher.finalize_xof()
+    }
+}
+
+/// Size of the stack buffer the XOF output is read into.
+const KEYSTREAM_BLOCK: usize = 1024;
+
+/// XORs `data` in place with the output of `reader`, one block at a time.
+fn xor_keystream(mut reader: impl XofReader, data: &mut [u8]) {
+    let mut block = [0u8; KEYSTREAM_BLOCK];
+    for chunk in data.chunks_mut(KEYSTREAM_BLOCK) {
+        let keystream = &mut block[..chunk.len()];
+        reader.fill(keystream);
+        xor_in_place(chunk, keystream);
     }
 }
 
-/// XORs two byte slices together.
-fn xor_bytes(a: &[u8], b: &[u8]) -> Vec<u8> {
-    a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunked_roundtrip() {
+        let enc = Blake3XorEncryption::default();
+        let secret = b"chunked-secret";
+        let plaintext: Vec<u8> = (0..3 * PAYLOAD_CHUNK_SIZE + 17)
+            .map(|i| (i % 251) as u8)
+            .collect();
+
+        let ciphertext = enc.encrypt(secret, &plaintext).unwrap();
+        assert_eq!(ciphertext.len(), plaintext.len());
+        assert_ne!(ciphertext, plaintext);
+        let recovered = enc.decrypt(secret, &ciphertext).unwrap();
+        assert_eq!(recovered, plaintext);
+    }
+
+    #[test]
+    fn chunk_keystreams_are_distinct() {
+        let enc = Blake3XorEncryption::default();
+        let zeros = vec![0u8; 2 * PAYLOAD_CHUNK_SIZE];
+        let keystream = enc.encrypt(b"secret", &zeros).unwrap();
+        let (first, second) = keystream.split_at(PAYLOAD_CHUNK_SIZE);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn single_chunk_matches_unchunked_keystream() {
+        let enc = Blake3XorEncryption::default();
+        let plaintext = vec![0xA5u8; PAYLOAD_CHUNK_SIZE];
+        let ciphertext = enc.encrypt(b"secret", &plaintext).unwrap();
+
+        let mut keystream = vec![0u8; plaintext.len()];
+        enc.keystream(b"secret", plaintext.len(), None)
+            .fill(&mut keystream);
+        let expected: Vec<u8> = plaintext
+            .iter()
+            .zip(keystream.iter())
+            .map(|(x, y)| x ^ y)
+            .collect();
+        assert_eq!(ciphertext, expected);
+    }
+
+    #[test]
+    fn in_place_matches_allocating() {
+        let enc = Blake3XorEncryption::default();
+        let secret = b"in-place-secret";
+        for len in [0, 31, PAYLOAD_CHUNK_SIZE + 5] {
+            let plaintext: Vec<u8> = (0..len).map(|i| (i % 253) as u8).collect();
+            let mut buffer = plaintext.clone();
+            enc.encrypt_in_place(secret, &mut buffer).unwrap();
+            assert_eq!(buffer, enc.encrypt(secret, &plaintext).unwrap());
+            enc.decrypt_in_place(secret, &mut buffer).unwrap();
+            assert_eq!(buffer, plaintext);
+        }
+    }
+
+    #[test]
+    fn xor_in_place_matches_bytewise() {
+        let a: Vec<u8> = (0..67u8).collect();
+        let b: Vec<u8> = (0..67u8)
+            .map(|x| x.wrapping_mul(31).wrapping_add(7))
+            .collect();
+        for start in 0..8 {
+            for len in 0..(a.len() - start) {
+                let mut dst = a[start..start + len].to_vec();
+                xor_in_place(&mut dst, &b[start..start + len]);
+                let expected: Vec<u8> = a[start..start + len]
+                    .iter()
+                    .zip(&b[start..start + len])
+                    .map(|(x, y)| x ^ y)
+                    .collect();
+                assert_eq!(dst, expected);
+            }
+        }
+    }
 }
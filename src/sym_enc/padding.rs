@@ -0,0 +1,144 @@
+//! Length-hiding padding for payloads.
+//!
+//! Padding is applied to the plaintext before symmetric encryption so that the
+//! ciphertext length only reveals a coarse bucket rather than the exact message
+//! size. Padded buffers use ISO/IEC 7816-4 style padding: a single `0x80` marker
+//! byte followed by zeros, which can be stripped unambiguously after decryption.
+
+use alloc::vec::Vec;
+
+use crate::Error;
+
+/// Marker byte that starts the padding region.
+const PADDING_MARKER: u8 = 0x80;
+
+/// Padding applied to payloads before encryption.
+///
+/// # Example
+///
+/// ```rust
+/// use tess::PaddingScheme;
+///
+/// let padded = PaddingScheme::FixedBucket(256).pad(b"hello".to_vec());
+/// assert_eq!(padded.len(), 256);
+/// assert_eq!(PaddingScheme::unpad(padded).unwrap(), b"hello");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PaddingScheme {
+    /// No padding; the ciphertext length equals the plaintext length.
+    #[default]
+    None,
+    /// Padmé padding, which leaks at most `O(log log L)` bits of the length `L`
+    /// with at most 12% overhead.
+    Padme,
+    /// Pads to the next multiple of the given bucket size in bytes.
+    FixedBucket(usize),
+}
+
+impl PaddingScheme {
+    /// Returns `true` if this scheme adds padding.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, PaddingScheme::None)
+    }
+
+    /// Returns the padded length for a payload of `len` bytes.
+    ///
+    /// The result always leaves room for the padding marker byte.
+    pub fn padded_len(&self, len: usize) -> usize {
+        let min = len + 1;
+        match *self {
+            PaddingScheme::None => len,
+            PaddingScheme::Padme => padme(min),
+            PaddingScheme::FixedBucket(bucket) => min.div_ceil(bucket.max(1)) * bucket.max(1),
+        }
+    }
+
+    /// Pads `payload` according to this scheme.
+    pub fn pad(&self, mut payload: Vec<u8>) -> Vec<u8> {
+        if !self.is_enabled() {
+            return payload;
+        }
+        let target = self.padded_len(payload.len());
+        payload.reserve_exact(target - payload.len());
+        payload.push(PADDING_MARKER);
+        payload.resize(target, 0);
+        payload
+    }
+
+    /// Strips padding added by [`pad`](Self::pad).
+    ///
+    /// The padding is self-describing, so the scheme used for padding does not
+    /// need to be known.
+    pub fn unpad(mut payload: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let marker = payload
+            .iter()
+            .rposition(|&b| b != 0)
+            .filter(|&i| payload[i] == PADDING_MARKER)
+            .ok_or_else(|| Error::MalformedInput("invalid payload padding".into()))?;
+        payload.truncate(marker);
+        Ok(payload)
+    }
+}
+
+/// Padmé padded length (Nikitin et al., "Reducing Metadata Leakage from
+/// Encrypted Files and Communication with PURBs").
+fn padme(len: usize) -> usize {
+    if len < 2 {
+        return len;
+    }
+    let e = usize::BITS - 1 - len.leading_zeros();
+    let s = u32::BITS - e.leading_zeros();
+    let last_bits = e - s;
+    let mask = (1usize << last_bits) - 1;
+    (len + mask) & !mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padme_matches_reference_values() {
+        let cases = [(1, 1), (9, 10), (100, 104), (1000, 1024), (1025, 1088)];
+        for (len, expected) in cases {
+            assert_eq!(padme(len), expected, "len = {len}");
+        }
+        for len in 1..5000 {
+            let padded = padme(len);
+            assert!(padded >= len);
+            assert!(padded * 100 <= len * 112 + 100);
+        }
+    }
+
+    #[test]
+    fn pad_unpad_roundtrip() {
+        for scheme in [
+            PaddingScheme::None,
+            PaddingScheme::Padme,
+            PaddingScheme::FixedBucket(64),
+        ] {
+            for len in [0usize, 1, 63, 64, 65, 1000] {
+                let payload: Vec<u8> = (0..len).map(|i| (i % 7) as u8).collect();
+                let padded = scheme.pad(payload.clone());
+                assert_eq!(padded.len(), scheme.padded_len(len));
+                if scheme.is_enabled() {
+                    assert_eq!(PaddingScheme::unpad(padded).unwrap(), payload);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_bucket_hides_length_within_bucket() {
+        let scheme = PaddingScheme::FixedBucket(128);
+        assert_eq!(scheme.pad(vec![1; 3]).len(), 128);
+        assert_eq!(scheme.pad(vec![1; 127]).len(), 128);
+        assert_eq!(scheme.pad(vec![1; 128]).len(), 256);
+    }
+
+    #[test]
+    fn unpad_rejects_missing_marker() {
+        assert!(PaddingScheme::unpad(vec![1, 2, 0, 0]).is_err());
+        assert!(PaddingScheme::unpad(vec![0, 0]).is_err());
+    }
+}
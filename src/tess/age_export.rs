@@ -0,0 +1,258 @@
+//! Export of secret key shares as age-encrypted files.
+//!
+//! With the `age` feature, [`AgeExporter`] encrypts each participant's
+//! [`SecretKey`] with the [age](https://age-encryption.org) format to the
+//! participant's recipients (`age1...` X25519 keys or SSH `ssh-ed25519` /
+//! `ssh-rsa` keys), using the `age` crate. Operators then receive and store
+//! their share with their usual tooling, and decrypt it with
+//! `age --decrypt -i <identity> share-<id>.age`.
+//!
+//! The plaintext inside each file is the text encoding produced by
+//! [`encode_share`]:
+//!
+//! ```text
+//! tess-share-v1
+//! participant: 3
+//! scalar: <hex of the backend's scalar encoding>
+//! ```
+//!
+//! which [`decode_share`] turns back into a [`SecretKey`].
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write as _;
+#[cfg(feature = "age")]
+use std::{
+    boxed::Box,
+    format,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{PairingBackend, SecretKey, errors::Error};
+
+/// First line of the share text encoding.
+const SHARE_HEADER: &str = "tess-share-v1";
+
+/// Writes secret key shares as age-encrypted files.
+#[cfg(feature = "age")]
+#[derive(Clone, Debug, Default)]
+pub struct AgeExporter {
+    _private: (),
+}
+
+#[cfg(feature = "age")]
+impl AgeExporter {
+    /// Creates an exporter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encrypts `secret_key` to `recipients` and writes it to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `recipients` is empty or contains a
+    /// key that is neither an age X25519 nor an SSH recipient, and
+    /// [`Error::Io`] if the file cannot be written.
+    pub fn export_share<B: PairingBackend>(
+        &self,
+        secret_key: &SecretKey<B>,
+        recipients: &[&str],
+        path: &Path,
+    ) -> Result<(), Error> {
+        let recipients = recipients
+            .iter()
+            .map(|recipient| parse_recipient(recipient))
+            .collect::<Result<Vec<_>, _>>()?;
+        let encryptor = age::Encryptor::with_recipients(recipients.iter().map(|r| r.as_ref()))
+            .map_err(|e| Error::InvalidConfig(format!("cannot encrypt to age recipients: {e}")))?;
+
+        let io_error = |source| Error::Io {
+            context: format!("failed to write {}", path.display()),
+            source,
+        };
+        let plaintext = encode_share(secret_key);
+        let mut writer = encryptor
+            .wrap_output(File::create(path).map_err(io_error)?)
+            .map_err(io_error)?;
+        writer.write_all(plaintext.as_bytes()).map_err(io_error)?;
+        writer
+            .finish()
+            .and_then(|file| file.sync_all())
+            .map_err(io_error)
+    }
+
+    /// Exports every share into `dir` as `share-<participant_id>.age`, where
+    /// `recipients[i]` is the age recipient of `secret_keys[i]`.
+    ///
+    /// Returns the written paths in the order of `secret_keys`.
+    pub fn export_all<B: PairingBackend>(
+        &self,
+        secret_keys: &[SecretKey<B>],
+        recipients: &[&str],
+        dir: &Path,
+    ) -> Result<Vec<PathBuf>, Error> {
+        if secret_keys.len() != recipients.len() {
+            return Err(Error::MalformedInput(
+                "one age recipient is required per secret key".into(),
+            ));
+        }
+        secret_keys
+            .iter()
+            .zip(recipients)
+            .map(|(secret_key, recipient)| {
+                let path = dir.join(format!("share-{}.age", secret_key.participant_id));
+                self.export_share(secret_key, &[recipient], &path)?;
+                Ok(path)
+            })
+            .collect()
+    }
+}
+
+/// Parses an age X25519 or SSH recipient.
+#[cfg(feature = "age")]
+fn parse_recipient(recipient: &str) -> Result<Box<dyn age::Recipient>, Error> {
+    if let Ok(recipient) = recipient.parse::<age::x25519::Recipient>() {
+        return Ok(Box::new(recipient));
+    }
+    recipient
+        .parse::<age::ssh::Recipient>()
+        .map(|recipient| Box::new(recipient) as Box<dyn age::Recipient>)
+        .map_err(|_| Error::InvalidConfig(format!("invalid age recipient: {recipient}")))
+}
+
+/// Encodes a secret key share in the text format stored inside age files.
+///
+/// The result holds the secret scalar and is zeroized on drop.
+pub fn encode_share<B: PairingBackend>(secret_key: &SecretKey<B>) -> Zeroizing<String> {
+    let mut scalar = B::serialize_scalar(&secret_key.scalar);
+    let mut text = Zeroizing::new(String::with_capacity(
+        SHARE_HEADER.len() + 40 + 2 * scalar.len(),
+    ));
+    let _ = write!(
+        text,
+        "{SHARE_HEADER}\nparticipant: {}\nscalar: ",
+        secret_key.participant_id
+    );
+    for byte in &scalar {
+        let _ = write!(text, "{byte:02x}");
+    }
+    text.push('\n');
+    scalar.zeroize();
+    text
+}
+
+/// Decodes a secret key share written by [`encode_share`].
+///
+/// # Errors
+///
+/// Returns [`Error::MalformedInput`] if the text is not a valid share encoding.
+pub fn decode_share<B: PairingBackend>(text: &str) -> Result<SecretKey<B>, Error> {
+    let malformed = || Error::MalformedInput("invalid share encoding".into());
+    let mut lines = text.lines();
+    if lines.next() != Some(SHARE_HEADER) {
+        return Err(malformed());
+    }
+    let participant_id = lines
+        .next()
+        .and_then(|line| line.strip_prefix("participant: "))
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(malformed)?;
+    let hex = lines
+        .next()
+        .and_then(|line| line.strip_prefix("scalar: "))
+        .ok_or_else(malformed)?;
+    if hex.len() % 2 != 0 || lines.next().is_some() {
+        return Err(malformed());
+    }
+
+    let mut bytes = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| malformed()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let scalar = B::deserialize_scalar(&bytes);
+    bytes.zeroize();
+
+    Ok(SecretKey {
+        participant_id,
+        scalar: scalar.map_err(Error::Backend)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{FieldElement, PairingEngine};
+
+    fn share(rng: &mut StdRng, participant_id: usize) -> SecretKey<PairingEngine> {
+        SecretKey {
+            participant_id,
+            scalar: FieldElement::random(rng),
+        }
+    }
+
+    #[test]
+    fn share_text_encoding_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(1437);
+        let secret_key = share(&mut rng, 5);
+        let text = encode_share(&secret_key);
+        let decoded = decode_share::<PairingEngine>(&text).unwrap();
+        assert_eq!(decoded.participant_id, 5);
+        assert_eq!(decoded.scalar, secret_key.scalar);
+
+        assert!(decode_share::<PairingEngine>("tess-share-v1\nparticipant: 5\n").is_err());
+        assert!(decode_share::<PairingEngine>(&text.replace("scalar: ", "scalar: 0")).is_err());
+    }
+
+    #[cfg(feature = "age")]
+    #[test]
+    fn age_exporter_encrypts_to_recipients() {
+        let dir = std::env::temp_dir().join(format!("tess-age-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut rng = StdRng::seed_from_u64(1438);
+        let keys = [share(&mut rng, 0), share(&mut rng, 1)];
+        let identities = [
+            age::x25519::Identity::generate(),
+            age::x25519::Identity::generate(),
+        ];
+        let recipients: Vec<_> = identities
+            .iter()
+            .map(|identity| identity.to_public().to_string())
+            .collect();
+        let recipients: Vec<&str> = recipients.iter().map(String::as_str).collect();
+        let paths = AgeExporter::new()
+            .export_all(&keys, &recipients, &dir)
+            .unwrap();
+
+        for ((path, key), identity) in paths.iter().zip(&keys).zip(&identities) {
+            let sealed = std::fs::read(path).unwrap();
+            let opened = age::decrypt(identity, &sealed).unwrap();
+            let decoded =
+                decode_share::<PairingEngine>(core::str::from_utf8(&opened).unwrap()).unwrap();
+            assert_eq!(decoded.scalar, key.scalar);
+        }
+
+        // Each share is readable only by its own recipient.
+        let sealed = std::fs::read(&paths[0]).unwrap();
+        assert!(age::decrypt(&identities[1], &sealed).is_err());
+
+        let exporter = AgeExporter::new();
+        assert!(
+            exporter
+                .export_share(&keys[0], &["not-a-recipient"], &dir.join("x.age"))
+                .is_err()
+        );
+        assert!(
+            exporter
+                .export_share(&keys[0], &[], &dir.join("x.age"))
+                .is_err()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
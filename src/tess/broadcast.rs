@@ -0,0 +1,269 @@
+//! 1-of-n broadcast encryption.
+//!
+//! With `threshold == 1` any single committee member may decrypt, so the KZG
+//! machinery of the general scheme is unnecessary. [`ThresholdEncryption::encrypt`]
+//! switches to this mode automatically and produces a ciphertext with:
+//!
+//! - `proof_g1 = [r·g]` and `gamma_g2 = r·h` for fresh randomness `r`,
+//! - no `proof_g2` elements and an identity `shared_secret`,
+//! - one wrapped payload key per member in `broadcast_keys`.
+//!
+//! Member `i` wraps the random payload key under `H(r·pk_i)`, a Diffie-Hellman
+//! value in G1 that only the sender and the holder of `sk_i` can compute,
+//! hashed together with the ciphertext header.
+//! Well-formedness is a single pairing check `e(r·g, h) = e(g, r·h)`, and any
+//! member decrypts directly with [`SilentThresholdScheme::decrypt_broadcast`]
+//! without partial decryptions or aggregation.
+
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+use smallvec::{SmallVec, smallvec};
+
+use super::scheme::update_context;
+use crate::{
+    AggregateKey, Ciphertext, CurvePoint, DEK_LEN, FieldElement, Fr, KdfId, PairingBackend,
+    SchemeId, SecretKey, SilentThresholdScheme, TargetGroup, Xof, errors::Error,
+    sym_enc::SymmetricEncryption,
+};
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Encrypts `payload` so that any one member of `agg_key` can decrypt it.
+    ///
+    /// Fails with [`Error::InvalidConfig`] if a slot holds the identity key,
+    /// whose wrapping key anyone can compute; encryption only reaches this
+    /// path for keys without vacancies, since they raise the threshold.
+    pub(super) fn encrypt_broadcast<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        agg_key: &AggregateKey<B>,
+        payload: Vec<u8>,
+        deterministic: bool,
+    ) -> Result<Ciphertext<B>, Error> {
+        if let Some(pk) = agg_key.public_keys.iter().find(|pk| pk.is_vacant()) {
+            return Err(Error::InvalidConfig(format!(
+                "slot {} holds the identity key and cannot receive a broadcast key",
+                pk.participant_id
+            )));
+        }
+        let r = Fr::random(rng);
+        let ephemeral = B::G1::mul_generator(&r);
+        let mut payload_key = [0u8; DEK_LEN];
+        rng.fill_bytes(&mut payload_key);
+
+        let (payload, compressed) = self.compress_payload(payload)?;
+        let padded = self.padding.is_enabled();
+        let mut payload = self.padding.pad(payload);
+        self.symmetric_enc
+            .encrypt_in_place(&payload_key, &mut payload)?;
+        // The payload key is random and wrapped per member, not derived.
+        let header = self.header(
+            SchemeId::Broadcast,
+            agg_key.fingerprint(),
+            1,
+            payload.len(),
+            KdfId::default(),
+        )?;
+        let header_bytes = header.to_bytes();
+
+        let broadcast_keys = agg_key
+            .public_keys
+            .iter()
+            .map(|pk| {
+                let shared = pk.bls_key.mul_scalar(&r);
+                wrap(
+                    &key_wrapping_key::<B, X>(
+                        &ephemeral,
+                        &shared,
+                        pk.participant_id,
+                        &header_bytes,
+                        &self.context,
+                    ),
+                    &payload_key,
+                )
+            })
+            .collect();
+
+        Ok(Ciphertext {
+            gamma_g2: B::G2::mul_generator(&r),
+            proof_g1: smallvec![ephemeral],
+            proof_g2: SmallVec::new(),
+            shared_secret: B::Target::identity(),
+            threshold: 1,
+            epoch: header.epoch,
+            committee: header.committee,
+            label: header.label,
+            payload,
+            compressed,
+            padded,
+            deterministic,
+            kdf: header.cipher_suite,
+            validity_proof: None,
+            sender_signature: None,
+            broadcast_keys,
+        })
+    }
+
+    /// Checks a broadcast ciphertext's header with a single pairing equation.
+    pub(super) fn verify_broadcast(
+        &self,
+        agg_key: &AggregateKey<B>,
+        ciphertext: &Ciphertext<B>,
+    ) -> Result<(), Error> {
+        let ephemeral = broadcast_ephemeral(ciphertext)?;
+        if ciphertext.broadcast_keys.len() != agg_key.public_keys.len() {
+            return Err(Error::MalformedInput(
+                "broadcast ciphertext key count does not match committee".into(),
+            ));
+        }
+        if ephemeral.is_identity() {
+            return Err(Error::MalformedInput(
+                "broadcast ciphertext randomness is the identity".into(),
+            ));
+        }
+        let product = B::multi_pairing(
+            &[*ephemeral, B::G1::generator().negate()],
+            &[B::G2::generator(), ciphertext.gamma_g2],
+        )
+        .map_err(Error::Backend)?;
+        if product != B::Target::identity() {
+            return Err(Error::MalformedInput(
+                "broadcast ciphertext header is inconsistent".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Decrypts a 1-of-n broadcast ciphertext with a single member's secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the ciphertext is not a broadcast
+    /// ciphertext or has no key for the member.
+    pub fn decrypt_broadcast(
+        &self,
+        secret_key: &SecretKey<B>,
+        ciphertext: &Ciphertext<B>,
+    ) -> Result<Vec<u8>, Error> {
+        let ephemeral = broadcast_ephemeral(ciphertext)?;
+        let wrapped = ciphertext
+            .broadcast_keys
+            .get(secret_key.participant_id)
+            .ok_or_else(|| {
+                Error::MalformedInput("broadcast ciphertext has no key for participant".into())
+            })?;
+        let shared = ephemeral.mul_scalar(&secret_key.scalar);
+        let payload_key = wrap(
+            &key_wrapping_key::<B, X>(
+                ephemeral,
+                &shared,
+                secret_key.participant_id,
+                &ciphertext.header().to_bytes(),
+                &self.context,
+            ),
+            wrapped,
+        );
+
+        let mut payload = ciphertext.payload.clone();
+        self.symmetric_enc
+            .decrypt_in_place(&payload_key, &mut payload)?;
+        self.restore_payload(payload, ciphertext.padded, ciphertext.compressed)
+    }
+}
+
+/// Rejects broadcast ciphertexts on the partial-decryption path.
+pub(super) fn reject_broadcast<B: PairingBackend>(ciphertext: &Ciphertext<B>) -> Result<(), Error> {
+    if ciphertext.is_broadcast() {
+        return Err(Error::InvalidConfig(
+            "broadcast ciphertexts are decrypted directly with decrypt_broadcast".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn broadcast_ephemeral<B: PairingBackend>(ciphertext: &Ciphertext<B>) -> Result<&B::G1, Error> {
+    if !ciphertext.is_broadcast()
+        || ciphertext.threshold != 1
+        || ciphertext.proof_g1.len() != 1
+        || !ciphertext.proof_g2.is_empty()
+    {
+        return Err(Error::MalformedInput(
+            "ciphertext is not a broadcast ciphertext".into(),
+        ));
+    }
+    Ok(&ciphertext.proof_g1[0])
+}
+
+/// Derives member `participant_id`'s key-wrapping key from the DH value `r·pk_i`.
+fn key_wrapping_key<B: PairingBackend, X: Xof>(
+    ephemeral: &B::G1,
+    shared: &B::G1,
+    participant_id: usize,
+    header: &[u8],
+    context: &[u8],
+) -> [u8; DEK_LEN] {
+    let mut hasher = X::derive_key("tess::broadcast-key-wrap");
+    hasher.update(ephemeral.to_repr().as_ref());
+    hasher.update(shared.to_repr().as_ref());
+    hasher.update(&(participant_id as u64).to_le_bytes());
+    hasher.update(header);
+    update_context(&mut hasher, context);
+    hasher.finalize_bytes()
+}
+
+fn wrap(kek: &[u8; DEK_LEN], key: &[u8; DEK_LEN]) -> [u8; DEK_LEN] {
+    core::array::from_fn(|i| kek[i] ^ key[i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, ThresholdEncryption};
+
+    #[test]
+    fn any_member_decrypts_broadcast_ciphertext() {
+        let mut rng = StdRng::seed_from_u64(73);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let params = scheme.param_gen(&mut rng, parties, 1).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let ct = scheme
+            .encrypt(&mut rng, agg, &params, 1, b"to anyone")
+            .unwrap();
+        assert!(ct.is_broadcast());
+        assert!(ct.proof_g2.is_empty());
+        scheme.verify_ciphertext(agg, &params, &ct).unwrap();
+
+        for sk in &keys.secret_keys {
+            assert_eq!(scheme.decrypt_broadcast(sk, &ct).unwrap(), b"to anyone");
+        }
+        assert!(scheme.partial_decrypt(&keys.secret_keys[1], &ct).is_err());
+
+        let outsider = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        assert_ne!(
+            scheme
+                .decrypt_broadcast(&outsider.secret_keys[2], &ct)
+                .unwrap(),
+            b"to anyone"
+        );
+
+        let mut bad_header = ct.clone();
+        bad_header.gamma_g2 = bad_header.gamma_g2.add(&CurvePoint::generator());
+        assert!(scheme.verify_ciphertext(agg, &params, &bad_header).is_err());
+
+        let general = scheme
+            .encrypt(&mut rng, agg, &params, 2, b"to two")
+            .unwrap();
+        assert!(!general.is_broadcast());
+        assert!(
+            scheme
+                .decrypt_broadcast(&keys.secret_keys[0], &general)
+                .is_err()
+        );
+    }
+}
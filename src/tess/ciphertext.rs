@@ -3,8 +3,11 @@
 //! This module defines the output types for encryption and decryption operations:
 //!
 //! - [`Ciphertext`]: The encrypted message with KZG proofs
-//! - [`PartialDecryption`]: A participant's decryption share
+//! - [`PartialDecryption`]: A participant's decryption share, checked with
+//!   [`verify_partial_decryption`], or many at once with
+//!   [`verify_partial_decryptions`]
 //! - [`DecryptionResult`]: The final decrypted plaintext
+//! - [`DecryptionProof`]: Evidence that a decryption result is correct
 //!
 //! # Ciphertext Structure
 //!
@@ -13,6 +16,23 @@
 //! - **KZG Proofs**: Zero-knowledge proofs in G1 and G2 that enable verification
 //! - **Shared Secret**: Precomputed pairing result for efficient verification
 //! - **Threshold**: The minimum number of shares required for decryption
+//! - **Header Metadata**: Epoch, committee fingerprint, and label, exposed
+//!   with the other routing fields through [`Ciphertext::header`]
+//!
+//! # Ciphertext Size
+//!
+//! A threshold ciphertext carries [`PROOF_G1_LEN`] G1 and [`PROOF_G2_LEN`] G2
+//! proof elements whatever the committee size and threshold: the committee is
+//! folded into the aggregate key and the threshold into a single SRS power
+//! before encryption, so the group elements are constant size already and
+//! there is no separate compact profile to select. Every proof element is
+//! paired with a term that depends on which members decrypt, which is only
+//! known at aggregation time, so the eight cannot be combined further without
+//! changing the scheme. Deployments that post ciphertexts on chain can shrink
+//! the G2 elements with [`Swapped`](crate::Swapped), drop the length prefixes
+//! with [`FixedCiphertext`](crate::FixedCiphertext), and budget with
+//! [`Params::ciphertext_size`](crate::Params::ciphertext_size), which depends
+//! only on the payload length.
 //!
 //! # Decryption Protocol
 //!
@@ -40,7 +60,30 @@
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
-use crate::PairingBackend;
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use smallvec::SmallVec;
+
+use crate::{
+    COMMITTEE_FINGERPRINT_LEN, CiphertextProof, CurvePoint, DEK_LEN, FieldElement, KdfId,
+    MsmProvider, PairingBackend, PublicKey, SenderSignature,
+};
+
+/// Number of G1 proof elements in a threshold ciphertext, for any committee
+/// size and threshold.
+pub const PROOF_G1_LEN: usize = 2;
+
+/// Number of G2 proof elements in a threshold ciphertext, for any committee
+/// size and threshold.
+pub const PROOF_G2_LEN: usize = 6;
+
+/// G1 proof elements of a [`Ciphertext`], stored inline up to the
+/// [`PROOF_G1_LEN`] a threshold ciphertext carries.
+pub type ProofG1<B> = SmallVec<[<B as PairingBackend>::G1; PROOF_G1_LEN]>;
+
+/// G2 proof elements of a [`Ciphertext`], stored inline up to the
+/// [`PROOF_G2_LEN`] a threshold ciphertext carries.
+pub type ProofG2<B> = SmallVec<[<B as PairingBackend>::G2; PROOF_G2_LEN]>;
 
 /// Ciphertext output from threshold encryption.
 ///
@@ -54,7 +97,15 @@ use crate::PairingBackend;
 /// - `proof_g2`: KZG proof elements in G2 for verification
 /// - `shared_secret`: Precomputed pairing result for efficiency
 /// - `threshold`: Minimum number of partial decryptions required
+/// - `epoch`, `committee`, `label`: Routing metadata; see [`CiphertextHeader`](crate::CiphertextHeader)
 /// - `payload`: Encrypted message bytes
+/// - `compressed`: Whether the payload was zstd-compressed before encryption
+/// - `padded`: Whether length-hiding padding was applied before encryption
+/// - `deterministic`: Whether the ciphertext was produced by deterministic encryption
+/// - `kdf`: Function that derived the payload key from the shared secret
+/// - `validity_proof`: Proof of well-formedness, checkable with an [`AggregateKeyDigest`]
+/// - `sender_signature`: Sender's BLS signature, set by signcryption
+/// - `broadcast_keys`: Per-member wrapped payload keys, set only in 1-of-n broadcast mode
 ///
 /// # Example
 ///
@@ -87,15 +138,44 @@ pub struct Ciphertext<B: PairingBackend> {
     /// Random G2 element used during encryption.
     pub gamma_g2: B::G2,
     /// KZG proof elements in G1.
-    pub proof_g1: Vec<B::G1>,
+    pub proof_g1: ProofG1<B>,
     /// KZG proof elements in G2.
-    pub proof_g2: Vec<B::G2>,
+    pub proof_g2: ProofG2<B>,
     /// Precomputed pairing result for verification.
     pub shared_secret: B::Target,
     /// Threshold required for decryption.
     pub threshold: usize,
+    /// Application epoch the ciphertext was encrypted in.
+    pub epoch: u64,
+    /// Fingerprint of the aggregate key encrypted to.
+    pub committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+    /// Application label.
+    pub label: Vec<u8>,
     /// Encrypted payload bytes.
     pub payload: Vec<u8>,
+    /// Whether the payload was compressed before encryption.
+    pub compressed: bool,
+    /// Whether length-hiding padding was applied before encryption.
+    pub padded: bool,
+    /// Whether encryption randomness was derived deterministically from the inputs.
+    pub deterministic: bool,
+    /// Function that derived the payload key from the shared secret.
+    pub kdf: KdfId,
+    /// Proof that the ciphertext was formed honestly, if present.
+    pub validity_proof: Option<CiphertextProof<B>>,
+    /// Sender's signature over the ciphertext, if it was signcrypted.
+    pub sender_signature: Option<SenderSignature<B>>,
+    /// Wrapped payload key for each member; non-empty only for 1-of-n broadcast
+    /// ciphertexts.
+    pub broadcast_keys: Vec<[u8; DEK_LEN]>,
+}
+
+impl<B: PairingBackend> Ciphertext<B> {
+    /// Returns `true` if this is a 1-of-n broadcast ciphertext, which any single
+    /// member decrypts directly.
+    pub fn is_broadcast(&self) -> bool {
+        !self.broadcast_keys.is_empty()
+    }
 }
 
 /// Partial decryption share from a single participant.
@@ -142,6 +222,116 @@ impl<B: PairingBackend> Clone for PartialDecryption<B> {
     }
 }
 
+/// Checks a partial decryption against its participant's public key.
+///
+/// An honest share is `response = sk · gamma_g2` for the key `bls_key = sk · g`,
+/// so it satisfies `e(bls_key, gamma_g2) = e(g, response)`. The check needs
+/// only public data, letting relays and observers drop bad shares before they
+/// reach the aggregator. Returns `false` when the participant ids differ.
+///
+/// ```rust
+/// use rand::thread_rng;
+/// use tess::{PairingEngine, SilentThresholdScheme, ThresholdEncryption, verify_partial_decryption};
+///
+/// let mut rng = thread_rng();
+/// let scheme = SilentThresholdScheme::<PairingEngine>::new();
+/// let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+/// let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+/// let ct = scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"message").unwrap();
+///
+/// let partial = scheme.partial_decrypt(&keys.secret_keys[1], &ct).unwrap();
+/// assert!(verify_partial_decryption(&keys.public_keys[1], &ct, &partial));
+/// assert!(!verify_partial_decryption(&keys.public_keys[2], &ct, &partial));
+/// ```
+pub fn verify_partial_decryption<B: PairingBackend>(
+    public_key: &PublicKey<B>,
+    ciphertext: &Ciphertext<B>,
+    partial: &PartialDecryption<B>,
+) -> bool {
+    public_key.participant_id == partial.participant_id
+        && partial_matches::<B>(&public_key.bls_key, &ciphertext.gamma_g2, partial)
+}
+
+/// Checks partial decryptions of one ciphertext together, returning the
+/// positions in `partials` of the shares that do not verify against
+/// `public_keys`, which are indexed by participant id.
+///
+/// Checking `t` shares with [`verify_partial_decryption`] costs `2t` Miller
+/// loops and `t` final exponentiations. Here they are folded into one randomly
+/// weighted equation, `e(Σ wᵢ·pkᵢ, gamma_g2) = e(g, Σ wᵢ·responseᵢ)`, so an
+/// honest batch costs two MSMs and a single two-term pairing at any
+/// threshold; the shares are only checked one by one, to name the bad ones,
+/// when that equation fails. The weights are derived from the ciphertext and
+/// the shares, so a dishonest share passes with negligible probability.
+///
+/// Public keys carry no precomputed pairing hints for this: their elements
+/// are in G1, where the backends do no Miller-loop precomputation, and the
+/// G2 operand changes with every ciphertext.
+pub fn verify_partial_decryptions<B: PairingBackend>(
+    public_keys: &[PublicKey<B>],
+    ciphertext: &Ciphertext<B>,
+    partials: &[PartialDecryption<B>],
+) -> Vec<usize> {
+    let known = |partial: &PartialDecryption<B>| {
+        public_keys
+            .get(partial.participant_id)
+            .filter(|key| key.participant_id == partial.participant_id)
+    };
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(b"tess::partial-batch");
+    hasher.update(B::serialize_g2(&ciphertext.gamma_g2).as_ref());
+    let mut keys = Vec::with_capacity(partials.len());
+    let mut responses = Vec::with_capacity(partials.len());
+    for partial in partials {
+        if let Some(public_key) = known(partial) {
+            hasher.update(&(partial.participant_id as u64).to_le_bytes());
+            hasher.update(B::serialize_g2(&partial.response).as_ref());
+            keys.push(public_key.bls_key);
+            responses.push(partial.response);
+        }
+    }
+    let mut rng = ChaCha20Rng::from_seed(*hasher.finalize().as_bytes());
+    let weights = (0..keys.len())
+        .map(|_| B::Scalar::random(&mut rng))
+        .collect::<Vec<_>>();
+    let batch_holds = B::Msm::msm_g1(&keys, &weights)
+        .and_then(|key| {
+            let response = B::Msm::msm_g2(&responses, &weights)?;
+            B::multi_pairing(
+                &[key, B::G1::generator().negate()],
+                &[ciphertext.gamma_g2, response],
+            )
+        })
+        .is_ok_and(|product| product == <B::Target as crate::TargetGroup>::identity());
+
+    partials
+        .iter()
+        .enumerate()
+        .filter(|(_, partial)| match known(partial) {
+            Some(public_key) => {
+                !batch_holds
+                    && !partial_matches::<B>(&public_key.bls_key, &ciphertext.gamma_g2, partial)
+            }
+            None => true,
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Pairing check behind [`verify_partial_decryption`], without the id check.
+pub(super) fn partial_matches<B: PairingBackend>(
+    bls_key: &B::G1,
+    gamma_g2: &B::G2,
+    partial: &PartialDecryption<B>,
+) -> bool {
+    // e(bls_key, gamma_g2) · e(-g, response) = 1
+    B::multi_pairing(
+        &[*bls_key, B::G1::generator().negate()],
+        &[*gamma_g2, partial.response],
+    )
+    .is_ok_and(|product| product == <B::Target as crate::TargetGroup>::identity())
+}
+
 /// Decryption result containing the recovered plaintext.
 ///
 /// This structure is returned after successfully aggregating at least `t`
@@ -150,6 +340,7 @@ impl<B: PairingBackend> Clone for PartialDecryption<B> {
 /// # Fields
 ///
 /// - `plaintext`: The recovered plaintext bytes, or `None` if decryption failed
+/// - `proof`: Evidence for the plaintext, when the scheme is configured to emit it
 ///
 /// # Example
 ///
@@ -186,7 +377,29 @@ impl<B: PairingBackend> Clone for PartialDecryption<B> {
 /// assert_eq!(result.plaintext.as_ref().unwrap(), message);
 /// ```
 #[derive(Clone, Debug)]
-pub struct DecryptionResult {
+pub struct DecryptionResult<B: PairingBackend> {
     /// Decrypted plaintext if verification succeeded.
     pub plaintext: Option<Vec<u8>>,
+    /// Proof that the plaintext is consistent with the ciphertext, if requested.
+    pub proof: Option<DecryptionProof<B>>,
+}
+
+/// Publicly checkable evidence for a [`DecryptionResult`].
+///
+/// Carries the contributing partial decryptions and the selector used to
+/// aggregate them. A verifier checks each share against its participant's BLS
+/// key with a pairing equation, then recomputes the aggregation, so anyone with
+/// the aggregate key can confirm the plaintext without trusting the aggregator.
+///
+/// There is no separate KZG opening of the shared secret. The secret is a
+/// target-group element rather than a polynomial evaluation, and aggregation
+/// already binds it to the ciphertext: the pairing of the aggregated terms
+/// with the ciphertext's proof elements must reproduce `shared_secret` before
+/// any payload key is derived.
+#[derive(Clone, Debug)]
+pub struct DecryptionProof<B: PairingBackend> {
+    /// Participants whose shares were aggregated.
+    pub selector: Vec<bool>,
+    /// The contributing partial decryptions.
+    pub partials: Vec<PartialDecryption<B>>,
 }
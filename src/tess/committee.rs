@@ -0,0 +1,838 @@
+//! Committee membership and key management.
+//!
+//! A [`Committee`] bundles everything callers otherwise pass around separately:
+//! the participants' public keys, an optional per-participant weight (for
+//! example stake), and the [`AggregateKey`] derived from them. It guarantees the
+//! keys are indexed by participant id, so selectors and partial decryptions
+//! line up with the aggregate key.
+//!
+//! Weights are bookkeeping for the caller; the threshold scheme itself counts
+//! each participant once.
+//!
+//! # Capacity
+//!
+//! The evaluation domain fixed by [`Params`] is the committee's capacity, which
+//! may exceed its current membership. [`Committee::with_capacity`] fills the
+//! slots without a member with [`PublicKey::vacant`] placeholders, and
+//! [`Committee::add_member`] later occupies one without touching the
+//! parameters or the other members' keys. A vacant slot holds the key of the
+//! zero secret, so its share is public: encryption raises a threshold by the
+//! number of vacancies (see [`Committee::encryption_threshold`]) and
+//! [`Committee::vacant_partials`] supplies their shares when aggregating.
+//!
+//! # Revocation
+//!
+//! [`Committee::revoke`] excludes a member from the aggregate key while keeping
+//! its slot and key on record. Like a vacancy, a revoked slot then holds a
+//! public zero share, which aggregate decryption uses when the slot is selected;
+//! encryption itself raises the threshold by the number of revoked members
+//! (see [`AggregateKey::ciphertext_threshold`]), so a ciphertext still needs
+//! the requested number of shares from unrevoked members. Partial decryptions
+//! from the revoked member are rejected with [`Error::RevokedParticipant`].
+//!
+//! # Participant Identifiers
+//!
+//! The scheme addresses participants by their index in the evaluation domain.
+//! Applications that name participants differently, for example by UUID or a
+//! hash of a long-term key, can [`Committee::register`] a [`ParticipantId`] for
+//! each member and translate with [`Committee::index_of`] and
+//! [`Committee::id_of`] at the boundary.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AggregateKey, CurvePoint, Fr, PairingBackend, Params, PartialDecryption, PublicKey,
+    errors::Error,
+};
+
+/// A set of participants together with their derived aggregate key.
+///
+/// # Example
+///
+/// ```rust
+/// use rand::thread_rng;
+/// use tess::{Committee, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+///
+/// let mut rng = thread_rng();
+/// let scheme = SilentThresholdScheme::<PairingEngine>::new();
+/// let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+/// let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+///
+/// let committee = Committee::new(keys.public_keys, &params).unwrap();
+/// assert_eq!(committee.len(), 4);
+/// assert!(committee.contains(3));
+/// assert_eq!(committee.total_weight(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Committee<B: PairingBackend<Scalar = Fr>> {
+    weights: Vec<u64>,
+    aggregate_key: AggregateKey<B>,
+    ids: Vec<Option<ParticipantId>>,
+    indices: BTreeMap<ParticipantId, usize>,
+    signing_keys: Vec<Option<B::G1>>,
+}
+
+/// Application-level identifier of a committee member.
+///
+/// An opaque 32-byte value that a [`Committee`] maps to a domain index. Short
+/// identifiers such as UUIDs can be hashed into one with
+/// [`ParticipantId::from_external`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ParticipantId(pub [u8; 32]);
+
+impl ParticipantId {
+    /// Derives an identifier by hashing an arbitrary external identifier.
+    pub fn from_external(external: &[u8]) -> Self {
+        let mut hasher = blake3::Hasher::new_derive_key("tess::participant-id");
+        hasher.update(external);
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// Derives an identifier from a participant's BLS public key.
+    pub fn from_public_key<B: PairingBackend>(public_key: &PublicKey<B>) -> Self {
+        Self::from_external(&B::serialize_g1(&public_key.bls_key))
+    }
+
+    /// Raw identifier bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Differences between two committees, keyed by participant id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommitteeDiff {
+    /// Participants present only in the newer committee.
+    pub added: Vec<usize>,
+    /// Participants present only in the older committee.
+    pub removed: Vec<usize>,
+    /// Participants present in both whose key or weight changed.
+    pub changed: Vec<usize>,
+}
+
+impl CommitteeDiff {
+    /// Returns `true` if the committees have the same members, keys, and weights.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> Committee<B> {
+    /// Builds a committee with unit weights from the participants' public keys.
+    pub fn new(public_keys: Vec<PublicKey<B>>, params: &Params<B>) -> Result<Self, Error> {
+        let weights = alloc::vec![1; public_keys.len()];
+        Self::with_weights(public_keys, weights, params)
+    }
+
+    /// Builds a committee with explicit per-participant weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if the keys are not ordered by participant
+    /// id starting at zero, the weight count differs from the key count, or key
+    /// aggregation fails.
+    pub fn with_weights(
+        public_keys: Vec<PublicKey<B>>,
+        weights: Vec<u64>,
+        params: &Params<B>,
+    ) -> Result<Self, Error> {
+        check_public_keys(&public_keys)?;
+        let aggregate_key = AggregateKey::aggregate_keys(&public_keys, params, public_keys.len())?;
+        Self::from_parts(aggregate_key, weights)
+    }
+
+    /// Builds a committee spanning the full domain of `params` from the keys of
+    /// its current members.
+    ///
+    /// Members keep their participant ids and unit weight; every other slot is
+    /// vacant with weight zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if a member id is outside the domain or
+    /// repeated, a member key is itself vacant, or key aggregation fails.
+    pub fn with_capacity(members: Vec<PublicKey<B>>, params: &Params<B>) -> Result<Self, Error> {
+        let capacity = params.parties();
+        let mut slots: Vec<Option<PublicKey<B>>> = alloc::vec![None; capacity];
+        for pk in members {
+            check_member(&pk, capacity)?;
+            let id = pk.participant_id;
+            if slots[id].replace(pk).is_some() {
+                return Err(Error::InvalidConfig(format!(
+                    "participant {id} appears twice in the committee"
+                )));
+            }
+        }
+        let weights = slots.iter().map(|slot| u64::from(slot.is_some())).collect();
+        let public_keys = slots
+            .into_iter()
+            .enumerate()
+            .map(|(id, slot)| slot.unwrap_or_else(|| PublicKey::vacant(id, capacity)))
+            .collect();
+        Self::with_weights(public_keys, weights, params)
+    }
+
+    /// Occupies a vacant slot with a new member and re-aggregates the key.
+    ///
+    /// The member's key must be derived from the same `params` the committee
+    /// was built with; the keys of existing members stay valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if the slot is outside the committee or
+    /// already occupied, or the new key is vacant.
+    pub fn add_member(
+        &mut self,
+        public_key: PublicKey<B>,
+        weight: u64,
+        params: &Params<B>,
+    ) -> Result<(), Error> {
+        check_member(&public_key, self.capacity())?;
+        let id = public_key.participant_id;
+        if !self.is_vacant(id) {
+            return Err(Error::InvalidConfig(format!(
+                "committee slot {id} is already occupied"
+            )));
+        }
+        let mut public_keys = self.aggregate_key.public_keys.clone();
+        public_keys[id] = public_key;
+        self.reaggregate(public_keys, params)?;
+        self.weights[id] = weight;
+        Ok(())
+    }
+
+    /// Revokes an active member, excluding its key from the aggregate key.
+    ///
+    /// The member's weight drops to zero and its identifier, if registered,
+    /// stays mapped to the slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `participant_id` is not an active
+    /// member.
+    pub fn revoke(&mut self, participant_id: usize, params: &Params<B>) -> Result<(), Error> {
+        if !self.contains(participant_id) {
+            return Err(Error::InvalidConfig(format!(
+                "participant {participant_id} is not an active member"
+            )));
+        }
+        let public_keys = self.aggregate_key.public_keys.clone();
+        self.aggregate_key.revoked.push(participant_id);
+        self.reaggregate(public_keys, params)?;
+        self.weights[participant_id] = 0;
+        Ok(())
+    }
+
+    fn reaggregate(
+        &mut self,
+        public_keys: Vec<PublicKey<B>>,
+        params: &Params<B>,
+    ) -> Result<(), Error> {
+        self.aggregate_key = AggregateKey::aggregate_keys_with_revocations(
+            &public_keys,
+            params,
+            public_keys.len(),
+            &self.aggregate_key.revoked,
+        )?;
+        Ok(())
+    }
+
+    /// Wraps an existing aggregate key, validating its public keys and weights.
+    pub fn from_parts(aggregate_key: AggregateKey<B>, weights: Vec<u64>) -> Result<Self, Error> {
+        check_public_keys(&aggregate_key.public_keys)?;
+        if weights.len() != aggregate_key.public_keys.len() {
+            return Err(Error::InvalidConfig(
+                "committee weight count does not match public keys".into(),
+            ));
+        }
+        Ok(Self {
+            ids: alloc::vec![None; weights.len()],
+            indices: BTreeMap::new(),
+            signing_keys: alloc::vec![None; weights.len()],
+            weights,
+            aggregate_key,
+        })
+    }
+
+    /// Maps `id` to the member at domain index `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `index` is not an active member, or
+    /// either the index or the identifier is already registered.
+    pub fn register(&mut self, id: ParticipantId, index: usize) -> Result<(), Error> {
+        if !self.contains(index) {
+            return Err(Error::InvalidConfig(format!(
+                "participant {index} is not an active member"
+            )));
+        }
+        if self.ids[index].is_some() || self.indices.contains_key(&id) {
+            return Err(Error::InvalidConfig(format!(
+                "participant {index} or its identifier is already registered"
+            )));
+        }
+        self.ids[index] = Some(id);
+        self.indices.insert(id, index);
+        Ok(())
+    }
+
+    /// Domain index registered for `id`.
+    pub fn index_of(&self, id: &ParticipantId) -> Option<usize> {
+        self.indices.get(id).copied()
+    }
+
+    /// Identifier registered for domain index `index`.
+    pub fn id_of(&self, index: usize) -> Option<&ParticipantId> {
+        self.ids.get(index)?.as_ref()
+    }
+
+    /// Registered identifiers, indexed by domain index.
+    pub fn ids(&self) -> &[Option<ParticipantId>] {
+        &self.ids
+    }
+
+    /// Records the public [`PartialSigningKey`](crate::PartialSigningKey) of
+    /// the member at domain index `index`.
+    ///
+    /// [`SilentThresholdScheme::aggregate_decrypt_signed`](crate::SilentThresholdScheme::aggregate_decrypt_signed)
+    /// checks the member's signed shares against this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `index` is not an active member or
+    /// already has a signing key, or the key is the identity.
+    pub fn register_signing_key(&mut self, index: usize, signing_key: B::G1) -> Result<(), Error> {
+        if !self.contains(index) {
+            return Err(Error::InvalidConfig(format!(
+                "participant {index} is not an active member"
+            )));
+        }
+        if signing_key.is_identity() {
+            return Err(Error::InvalidConfig(
+                "a signing key cannot be the identity".into(),
+            ));
+        }
+        if self.signing_keys[index].is_some() {
+            return Err(Error::InvalidConfig(format!(
+                "participant {index} already has a signing key"
+            )));
+        }
+        self.signing_keys[index] = Some(signing_key);
+        Ok(())
+    }
+
+    /// Signing key registered for the member at domain index `index`.
+    pub fn signing_key(&self, index: usize) -> Option<&B::G1> {
+        self.signing_keys.get(index)?.as_ref()
+    }
+
+    /// Registered signing keys, indexed by domain index.
+    pub fn signing_keys(&self) -> &[Option<B::G1>] {
+        &self.signing_keys
+    }
+
+    /// Builds a decryption selector marking the members named by `ids`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if an identifier is not registered.
+    pub fn selector_for(&self, ids: &[ParticipantId]) -> Result<Vec<bool>, Error> {
+        let mut selector = alloc::vec![false; self.len()];
+        for id in ids {
+            let index = self.index_of(id).ok_or_else(|| {
+                Error::InvalidConfig("participant identifier is not registered".into())
+            })?;
+            selector[index] = true;
+        }
+        Ok(selector)
+    }
+
+    /// Number of slots, occupied or vacant.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Number of slots in the evaluation domain; an alias for [`Committee::len`].
+    pub fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    /// Number of members that are neither vacant nor revoked.
+    pub fn active_len(&self) -> usize {
+        self.participant_ids()
+            .filter(|&id| self.contains(id))
+            .count()
+    }
+
+    /// Returns `true` if `participant_id` has been revoked.
+    pub fn is_revoked(&self, participant_id: usize) -> bool {
+        self.aggregate_key
+            .revoked
+            .binary_search(&participant_id)
+            .is_ok()
+    }
+
+    /// Sorted ids of the revoked members.
+    pub fn revoked(&self) -> &[usize] {
+        &self.aggregate_key.revoked
+    }
+
+    /// Returns `true` if slot `participant_id` exists and holds no member.
+    pub fn is_vacant(&self, participant_id: usize) -> bool {
+        self.public_key(participant_id)
+            .is_some_and(PublicKey::is_vacant)
+    }
+
+    /// Iterates over the ids of the vacant slots.
+    pub fn vacancies(&self) -> impl Iterator<Item = usize> + '_ {
+        self.participant_ids().filter(|&id| self.is_vacant(id))
+    }
+
+    /// Threshold recorded in ciphertexts that require `threshold` shares from
+    /// members.
+    ///
+    /// The shares of vacant and revoked slots are public, so each is added on
+    /// top. Encryption applies the same increase itself through
+    /// [`AggregateKey::ciphertext_threshold`]; pass `threshold`, not the
+    /// result, to [`ThresholdEncryption::encrypt`](crate::ThresholdEncryption::encrypt).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `threshold` is zero or the raised
+    /// threshold is not below the capacity.
+    pub fn encryption_threshold(&self, threshold: usize) -> Result<usize, Error> {
+        let inactive = self.capacity() - self.active_len();
+        if threshold == 0 || threshold + inactive >= self.capacity() {
+            return Err(Error::InvalidConfig(format!(
+                "threshold {threshold} with {inactive} inactive slots does not fit {} slots",
+                self.capacity()
+            )));
+        }
+        Ok(self.aggregate_key.ciphertext_threshold(threshold))
+    }
+
+    /// Partial decryptions of the vacant slots, which are the identity for any
+    /// ciphertext.
+    pub fn vacant_partials(&self) -> Vec<PartialDecryption<B>> {
+        self.vacancies()
+            .map(|participant_id| PartialDecryption {
+                participant_id,
+                response: B::G2::identity(),
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the committee has no participants.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Returns `true` if `participant_id` is an active member.
+    pub fn contains(&self, participant_id: usize) -> bool {
+        participant_id < self.len()
+            && !self.is_vacant(participant_id)
+            && !self.is_revoked(participant_id)
+    }
+
+    /// Iterates over the slot ids, including vacant ones.
+    pub fn participant_ids(&self) -> core::ops::Range<usize> {
+        0..self.len()
+    }
+
+    /// Public key of `participant_id`, if it is a member.
+    pub fn public_key(&self, participant_id: usize) -> Option<&PublicKey<B>> {
+        self.aggregate_key.public_keys.get(participant_id)
+    }
+
+    /// Public keys of all participants, indexed by participant id.
+    pub fn public_keys(&self) -> &[PublicKey<B>] {
+        &self.aggregate_key.public_keys
+    }
+
+    /// Weight of `participant_id`, if it is a member.
+    pub fn weight(&self, participant_id: usize) -> Option<u64> {
+        self.weights.get(participant_id).copied()
+    }
+
+    /// Weights of all participants, indexed by participant id.
+    pub fn weights(&self) -> &[u64] {
+        &self.weights
+    }
+
+    /// Sum of all participants' weights.
+    pub fn total_weight(&self) -> u64 {
+        self.weights.iter().sum()
+    }
+
+    /// Sum of the weights of the participants marked in `selector`.
+    pub fn selected_weight(&self, selector: &[bool]) -> u64 {
+        self.weights
+            .iter()
+            .zip(selector)
+            .filter(|(_, selected)| **selected)
+            .map(|(weight, _)| weight)
+            .sum()
+    }
+
+    /// Aggregate key derived from the committee's public keys.
+    pub fn aggregate_key(&self) -> &AggregateKey<B> {
+        &self.aggregate_key
+    }
+
+    /// Consumes the committee and returns its aggregate key.
+    pub fn into_aggregate_key(self) -> AggregateKey<B> {
+        self.aggregate_key
+    }
+
+    /// Lists the participants added, removed, or changed going from `self` to `other`.
+    pub fn diff(&self, other: &Self) -> CommitteeDiff {
+        let shared = self.len().min(other.len());
+        CommitteeDiff {
+            added: (shared..other.len()).collect(),
+            removed: (shared..self.len()).collect(),
+            changed: (0..shared)
+                .filter(|&id| {
+                    self.weights[id] != other.weights[id]
+                        || self.public_keys()[id].bls_key.to_repr().as_ref()
+                            != other.public_keys()[id].bls_key.to_repr().as_ref()
+                })
+                .collect(),
+        }
+    }
+}
+
+fn check_public_keys<B: PairingBackend>(public_keys: &[PublicKey<B>]) -> Result<(), Error> {
+    if public_keys
+        .iter()
+        .enumerate()
+        .any(|(idx, pk)| pk.participant_id != idx)
+    {
+        return Err(Error::InvalidConfig(
+            "committee public keys must be ordered by participant id".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn check_member<B: PairingBackend>(
+    public_key: &PublicKey<B>,
+    capacity: usize,
+) -> Result<(), Error> {
+    if public_key.participant_id >= capacity {
+        return Err(Error::InvalidConfig(format!(
+            "participant {} is outside a committee of {capacity} slots",
+            public_key.participant_id
+        )));
+    }
+    if public_key.is_vacant() {
+        return Err(Error::InvalidConfig(
+            "a vacant key cannot be a committee member".into(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SecretKey, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn committee_membership_and_diff() {
+        let mut rng = StdRng::seed_from_u64(67);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let params = scheme.param_gen(&mut rng, parties, 4).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let weights = (1..=parties as u64).collect::<Vec<_>>();
+        let committee =
+            Committee::with_weights(keys.public_keys.clone(), weights.clone(), &params).unwrap();
+
+        assert_eq!(committee.len(), parties);
+        assert!(committee.contains(7) && !committee.contains(8));
+        assert_eq!(committee.weight(2), Some(3));
+        assert_eq!(committee.total_weight(), 36);
+        let mut selector = vec![false; parties];
+        selector[0] = true;
+        selector[7] = true;
+        assert_eq!(committee.selected_weight(&selector), 9);
+        assert!(
+            committee
+                .aggregate_key()
+                .ask
+                .sub(&keys.aggregate_key.ask)
+                .is_identity()
+        );
+        assert!(committee.diff(&committee).is_empty());
+
+        // Replace participant 3's key and bump participant 5's weight.
+        let mut public_keys = keys.public_keys.clone();
+        let (_, replacement) = scheme
+            .keygen_single_validator(&mut rng, 3, &params)
+            .unwrap();
+        public_keys[3] = replacement;
+        let mut new_weights = weights;
+        new_weights[5] = 100;
+        let updated = Committee::with_weights(public_keys, new_weights, &params).unwrap();
+        let diff = committee.diff(&updated);
+        assert_eq!(diff.changed, vec![3, 5]);
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+
+        let mut shuffled = keys.public_keys.clone();
+        shuffled.swap(0, 1);
+        assert!(Committee::new(shuffled, &params).is_err());
+    }
+
+    #[test]
+    fn vacant_slots_can_be_filled_later() {
+        let mut rng = StdRng::seed_from_u64(1461);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+
+        let mut secret_keys = Vec::new();
+        let mut members = Vec::new();
+        for id in [0, 2, 3, 5, 6] {
+            let (sk, pk) = scheme
+                .keygen_single_validator(&mut rng, id, &params)
+                .unwrap();
+            secret_keys.push(sk);
+            members.push(pk);
+        }
+        let mut committee = Committee::with_capacity(members.clone(), &params).unwrap();
+        assert_eq!((committee.capacity(), committee.active_len()), (8, 5));
+        assert_eq!(committee.vacancies().collect::<Vec<_>>(), vec![1, 4, 7]);
+        assert!(committee.contains(2) && !committee.contains(1));
+        assert_eq!(committee.total_weight(), 5);
+
+        let decrypt = |committee: &Committee<PairingEngine>, sks: &[SecretKey<PairingEngine>]| {
+            let ct = scheme
+                .encrypt(
+                    &mut StdRng::seed_from_u64(0),
+                    committee.aggregate_key(),
+                    &params,
+                    2,
+                    b"slots",
+                )
+                .unwrap();
+            assert_eq!(ct.threshold, committee.encryption_threshold(2).unwrap());
+            let mut partials = committee.vacant_partials();
+            partials.extend(
+                sks.iter()
+                    .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap()),
+            );
+            let mut selector = vec![false; committee.capacity()];
+            for partial in &partials {
+                selector[partial.participant_id] = true;
+            }
+            scheme.aggregate_decrypt(&ct, &partials, &selector, committee.aggregate_key())
+        };
+        let result = decrypt(&committee, &secret_keys[..2]).unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"slots"[..]));
+        assert!(decrypt(&committee, &secret_keys[..1]).is_err());
+
+        let (sk, pk) = scheme
+            .keygen_single_validator(&mut rng, 4, &params)
+            .unwrap();
+        committee.add_member(pk.clone(), 3, &params).unwrap();
+        assert!(committee.add_member(pk, 3, &params).is_err());
+        assert_eq!(committee.encryption_threshold(2).unwrap(), 4);
+        assert_eq!(committee.weight(4), Some(3));
+        assert_eq!(committee.public_key(0).unwrap().bls_key, members[0].bls_key);
+        let result = decrypt(&committee, &[secret_keys[0].clone(), sk]).unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"slots"[..]));
+
+        assert!(committee.encryption_threshold(6).is_err());
+        members.push(members[0].clone());
+        assert!(Committee::with_capacity(members, &params).is_err());
+    }
+
+    #[test]
+    fn participant_ids_map_to_domain_indices() {
+        let mut rng = StdRng::seed_from_u64(1462);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let (_, newcomer) = scheme
+            .keygen_single_validator(&mut rng, 3, &params)
+            .unwrap();
+        let mut committee =
+            Committee::with_capacity(keys.public_keys[..3].to_vec(), &params).unwrap();
+
+        let alice = ParticipantId::from_external(b"alice");
+        let bob = ParticipantId::from_public_key(&keys.public_keys[2]);
+        committee.register(alice, 0).unwrap();
+        committee.register(bob, 2).unwrap();
+        assert!(committee.register(alice, 1).is_err());
+        assert!(committee.register(ParticipantId([7; 32]), 2).is_err());
+        assert!(committee.register(ParticipantId([7; 32]), 3).is_err());
+
+        assert_eq!(committee.index_of(&bob), Some(2));
+        assert_eq!(committee.id_of(0), Some(&alice));
+        assert_eq!(committee.id_of(1), None);
+        assert_eq!(
+            committee.selector_for(&[bob, alice]).unwrap(),
+            vec![true, false, true, false]
+        );
+        assert!(committee.selector_for(&[ParticipantId([7; 32])]).is_err());
+
+        let carol = ParticipantId::from_external(b"carol");
+        committee.add_member(newcomer, 1, &params).unwrap();
+        committee.register(carol, 3).unwrap();
+        assert_eq!(committee.index_of(&carol), Some(3));
+        assert_eq!(committee.index_of(&alice), Some(0));
+    }
+
+    #[test]
+    fn revoked_members_are_excluded() {
+        let mut rng = StdRng::seed_from_u64(1464);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+        let mut committee = Committee::new(keys.public_keys.clone(), &params).unwrap();
+
+        committee.revoke(3, &params).unwrap();
+        assert!(committee.revoke(3, &params).is_err());
+        assert!(committee.is_revoked(3) && !committee.contains(3));
+        assert_eq!((committee.active_len(), committee.weight(3)), (7, Some(0)));
+
+        // Revoking matches replacing the key with the zero key.
+        let mut zeroed = keys.public_keys.clone();
+        zeroed[3] = PublicKey::vacant(3, 8);
+        let expected = AggregateKey::aggregate_keys(&zeroed, &params, 8).unwrap();
+        let revoked = committee.aggregate_key();
+        assert!(revoked.ask.sub(&expected.ask).is_identity());
+        assert_eq!(revoked.lagrange_row_sums, expected.lagrange_row_sums);
+        assert_eq!(revoked.public_keys[3].bls_key, keys.public_keys[3].bls_key);
+
+        let ct = scheme
+            .encrypt(&mut rng, committee.aggregate_key(), &params, 3, b"revoked")
+            .unwrap();
+        assert_eq!(ct.threshold, committee.encryption_threshold(3).unwrap());
+        assert_eq!(ct.threshold, 4);
+        let partials: Vec<_> = keys.secret_keys[..4]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let mut selector = vec![false; 8];
+        selector[..4].fill(true);
+
+        // The revoked slot is selected without a partial and counts as the zero share.
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials[..3], &selector, committee.aggregate_key())
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"revoked"[..]));
+        assert!(matches!(
+            scheme.aggregate_decrypt(&ct, &partials, &selector, committee.aggregate_key()),
+            Err(Error::RevokedParticipant { participant_id: 3 })
+        ));
+    }
+
+    #[test]
+    fn revocations_do_not_lower_the_encryption_threshold() {
+        let mut rng = StdRng::seed_from_u64(1464);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 5).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+        let agg_key =
+            AggregateKey::aggregate_keys_with_revocations(&keys.public_keys, &params, 8, &[6, 7])
+                .unwrap();
+
+        // Encrypting directly, without a committee, still needs 3 real shares.
+        let ct = scheme
+            .encrypt(&mut rng, &agg_key, &params, 3, b"revoked")
+            .unwrap();
+        assert_eq!(ct.threshold, 5);
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+
+        // t - r real partials plus the two revoked zero shares fall short.
+        let mut selector = vec![false; 8];
+        selector[0] = true;
+        selector[6..].fill(true);
+        assert!(matches!(
+            scheme.aggregate_decrypt(&ct, &partials[..1], &selector, &agg_key),
+            Err(Error::InsufficientShares { .. })
+        ));
+        selector[1] = true;
+        assert!(
+            scheme
+                .aggregate_decrypt(&ct, &partials[..2], &selector, &agg_key)
+                .is_err()
+        );
+
+        selector[2] = true;
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &agg_key)
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"revoked"[..]));
+    }
+
+    #[test]
+    fn vacancies_do_not_lower_the_encryption_threshold() {
+        let mut rng = StdRng::seed_from_u64(1461);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 5).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+        let committee = Committee::with_capacity(keys.public_keys[..6].to_vec(), &params).unwrap();
+        let agg_key = committee.aggregate_key();
+
+        // Encrypting directly to the key, without encryption_threshold, still
+        // needs 3 real shares.
+        let ct = scheme
+            .encrypt(&mut rng, agg_key, &params, 3, b"vacant")
+            .unwrap();
+        assert_eq!(ct.threshold, 5);
+        let mut partials = committee.vacant_partials();
+        partials.extend(
+            keys.secret_keys[..3]
+                .iter()
+                .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap()),
+        );
+
+        // t - v real partials plus the two vacant shares fall short.
+        let mut selector = vec![false; 8];
+        selector[6..].fill(true);
+        selector[0] = true;
+        assert!(matches!(
+            scheme.aggregate_decrypt(&ct, &partials[..3], &selector, agg_key),
+            Err(Error::InsufficientShares { .. })
+        ));
+        selector[1] = true;
+        assert!(
+            scheme
+                .aggregate_decrypt(&ct, &partials[..4], &selector, agg_key)
+                .is_err()
+        );
+        selector[2] = true;
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, agg_key)
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"vacant"[..]));
+
+        // At threshold 1 the vacancies keep encryption off the broadcast path,
+        // which would wrap the payload key under the identity for them.
+        let ct = scheme
+            .encrypt(&mut rng, agg_key, &params, 1, b"vacant")
+            .unwrap();
+        assert!(!ct.is_broadcast());
+        assert_eq!(ct.threshold, 3);
+        let mut selector = vec![false; 8];
+        selector[6..].fill(true);
+        assert!(matches!(
+            scheme.aggregate_decrypt(&ct, &committee.vacant_partials(), &selector, agg_key),
+            Err(Error::InsufficientShares { .. })
+        ));
+        assert!(
+            scheme
+                .encrypt_broadcast(&mut rng, agg_key, b"vacant".to_vec(), false)
+                .is_err()
+        );
+    }
+}
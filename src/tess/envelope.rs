@@ -0,0 +1,450 @@
+//! Encrypted share distribution envelopes.
+//!
+//! A trusted dealer (or a DKG round that ends with per-party shares) hands each
+//! participant its [`SecretKey`] inside a [`ShareEnvelope`]. Envelopes are
+//! sealed with HPKE (RFC 9180) in authenticated mode (`mode_auth`) using the
+//! suite DHKEM(X25519, HKDF-SHA256), HKDF-SHA256 and ChaCha20Poly1305:
+//!
+//! - `info` is the fixed string `tess::share-envelope v2`,
+//! - the associated data is the participant index as a little-endian `u64`,
+//! - the plaintext is the backend's canonical encoding of the secret scalar.
+//!
+//! Any RFC 9180 implementation can open an envelope given the recipient's
+//! X25519 secret key and the sender's public key. Only the holder of the
+//! recipient's [`TransportKey`] can open it, and opening succeeds only if it
+//! was sealed by the expected sender. Transport keys are separate from
+//! threshold key shares and are registered with the dealer out of band.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use chacha20poly1305::{
+    ChaCha20Poly1305, KeyInit,
+    aead::{Aead, Payload},
+};
+use hkdf::Hkdf;
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+use crate::{PairingBackend, SecretKey, errors::Error};
+
+/// HPKE `info` binding envelopes to this protocol.
+const ENVELOPE_INFO: &[u8] = b"tess::share-envelope v2";
+
+/// HPKE mode identifier for `mode_auth`.
+const MODE_AUTH: u8 = 0x02;
+
+/// `"KEM" || I2OSP(kem_id, 2)` for DHKEM(X25519, HKDF-SHA256).
+const KEM_SUITE_ID: &[u8] = b"KEM\x00\x20";
+
+/// `"HPKE" || I2OSP(kem_id, 2) || I2OSP(kdf_id, 2) || I2OSP(aead_id, 2)`.
+const HPKE_SUITE_ID: &[u8] = b"HPKE\x00\x20\x00\x01\x00\x03";
+
+/// Long-term X25519 key a participant registers for receiving share envelopes.
+#[derive(Clone)]
+pub struct TransportKey {
+    secret: StaticSecret,
+}
+
+impl fmt::Debug for TransportKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransportKey")
+            .field("public_key", &self.public_key())
+            .finish_non_exhaustive()
+    }
+}
+
+impl TransportKey {
+    /// Samples a fresh transport key.
+    pub fn generate<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut bytes = Zeroizing::new([0u8; 32]);
+        rng.fill_bytes(bytes.as_mut());
+        Self::from_bytes(*bytes)
+    }
+
+    /// Restores a transport key from its 32-byte X25519 secret.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self {
+            secret: StaticSecret::from(bytes),
+        }
+    }
+
+    /// Returns the 32-byte X25519 secret for storage.
+    pub fn to_bytes(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.secret.to_bytes())
+    }
+
+    /// Returns the X25519 public key to register with dealers.
+    pub fn public_key(&self) -> [u8; 32] {
+        PublicKey::from(&self.secret).to_bytes()
+    }
+}
+
+/// A participant's secret key share, encrypted and authenticated for transport.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareEnvelope {
+    /// Participant the share belongs to.
+    pub participant_id: usize,
+    /// Sender's X25519 transport public key.
+    pub sender: [u8; 32],
+    /// HPKE encapsulated key `enc`.
+    pub encapsulated: [u8; 32],
+    /// AEAD ciphertext of the secret scalar, including the 16-byte tag.
+    pub ciphertext: Vec<u8>,
+}
+
+impl ShareEnvelope {
+    /// Encrypts `secret_key` to `recipient`, authenticated by `sender`.
+    pub fn seal<B: PairingBackend, R: RngCore + ?Sized>(
+        rng: &mut R,
+        secret_key: &SecretKey<B>,
+        sender: &TransportKey,
+        recipient: &[u8; 32],
+    ) -> Result<Self, Error> {
+        let ephemeral = TransportKey::generate(rng);
+        let encapsulated = ephemeral.public_key();
+        let sender_pk = sender.public_key();
+        let recipient_pk = PublicKey::from(*recipient);
+
+        let mut dh = Zeroizing::new([0u8; 64]);
+        dh[..32].copy_from_slice(diffie_hellman(&ephemeral, &recipient_pk)?.as_ref());
+        dh[32..].copy_from_slice(diffie_hellman(sender, &recipient_pk)?.as_ref());
+        let shared_secret = extract_and_expand(
+            dh.as_ref(),
+            &kem_context(&encapsulated, recipient, &sender_pk),
+        );
+
+        let scalar = Zeroizing::new(B::serialize_scalar(&secret_key.scalar));
+        let ciphertext = seal_aead(
+            &shared_secret,
+            MODE_AUTH,
+            &(secret_key.participant_id as u64).to_le_bytes(),
+            &scalar,
+        )?;
+
+        Ok(Self {
+            participant_id: secret_key.participant_id,
+            sender: sender_pk,
+            encapsulated,
+            ciphertext,
+        })
+    }
+
+    /// Seals one envelope per secret key, where `recipients[i]` is the
+    /// transport key registered by participant `secret_keys[i].participant_id`.
+    pub fn seal_all<B: PairingBackend, R: RngCore + ?Sized>(
+        rng: &mut R,
+        secret_keys: &[SecretKey<B>],
+        sender: &TransportKey,
+        recipients: &[[u8; 32]],
+    ) -> Result<Vec<Self>, Error> {
+        if secret_keys.len() != recipients.len() {
+            return Err(Error::MalformedInput(
+                "one recipient key is required per secret key".into(),
+            ));
+        }
+        secret_keys
+            .iter()
+            .zip(recipients)
+            .map(|(secret_key, recipient)| Self::seal(rng, secret_key, sender, recipient))
+            .collect()
+    }
+
+    /// Authenticates the envelope against `expected_sender` and decrypts the
+    /// share with the recipient's transport key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the envelope was sealed by another
+    /// sender, for another recipient, or was modified in transit.
+    pub fn open<B: PairingBackend>(
+        &self,
+        recipient: &TransportKey,
+        expected_sender: &[u8; 32],
+    ) -> Result<SecretKey<B>, Error> {
+        if self.sender != *expected_sender {
+            return Err(Error::MalformedInput(
+                "envelope was sealed by a different sender".into(),
+            ));
+        }
+
+        let mut dh = Zeroizing::new([0u8; 64]);
+        dh[..32].copy_from_slice(
+            diffie_hellman(recipient, &PublicKey::from(self.encapsulated))?.as_ref(),
+        );
+        dh[32..]
+            .copy_from_slice(diffie_hellman(recipient, &PublicKey::from(self.sender))?.as_ref());
+        let shared_secret = extract_and_expand(
+            dh.as_ref(),
+            &kem_context(&self.encapsulated, &recipient.public_key(), &self.sender),
+        );
+
+        let scalar = open_aead(
+            &shared_secret,
+            MODE_AUTH,
+            &(self.participant_id as u64).to_le_bytes(),
+            &self.ciphertext,
+        )?;
+
+        Ok(SecretKey {
+            participant_id: self.participant_id,
+            scalar: B::deserialize_scalar(&scalar).map_err(Error::Backend)?,
+        })
+    }
+}
+
+/// X25519, rejecting the all-zero output of low-order public keys.
+fn diffie_hellman(secret: &TransportKey, public: &PublicKey) -> Result<Zeroizing<[u8; 32]>, Error> {
+    let shared = secret.secret.diffie_hellman(public);
+    if !shared.was_contributory() {
+        return Err(Error::MalformedInput(
+            "envelope public key has low order".into(),
+        ));
+    }
+    Ok(Zeroizing::new(shared.to_bytes()))
+}
+
+/// `enc || pkR || pkS` for the auth-mode KEM.
+fn kem_context(encapsulated: &[u8; 32], recipient: &[u8; 32], sender: &[u8; 32]) -> [u8; 96] {
+    let mut context = [0u8; 96];
+    context[..32].copy_from_slice(encapsulated);
+    context[32..64].copy_from_slice(recipient);
+    context[64..].copy_from_slice(sender);
+    context
+}
+
+fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Zeroizing<[u8; 32]> {
+    let mut labeled_ikm = Zeroizing::new(Vec::with_capacity(
+        7 + suite_id.len() + label.len() + ikm.len(),
+    ));
+    labeled_ikm.extend_from_slice(b"HPKE-v1");
+    labeled_ikm.extend_from_slice(suite_id);
+    labeled_ikm.extend_from_slice(label);
+    labeled_ikm.extend_from_slice(ikm);
+    let (prk, _) = Hkdf::<Sha256>::extract(Some(salt), &labeled_ikm);
+    Zeroizing::new(prk.into())
+}
+
+fn labeled_expand(suite_id: &[u8], prk: &[u8; 32], label: &[u8], info: &[u8], out: &mut [u8]) {
+    let mut labeled_info = Vec::with_capacity(9 + suite_id.len() + label.len() + info.len());
+    labeled_info.extend_from_slice(&(out.len() as u16).to_be_bytes());
+    labeled_info.extend_from_slice(b"HPKE-v1");
+    labeled_info.extend_from_slice(suite_id);
+    labeled_info.extend_from_slice(label);
+    labeled_info.extend_from_slice(info);
+    Hkdf::<Sha256>::from_prk(prk)
+        .expect("PRK is one hash length")
+        .expand(&labeled_info, out)
+        .expect("HPKE outputs are shorter than 255 hash lengths");
+}
+
+/// DHKEM `ExtractAndExpand`.
+fn extract_and_expand(dh: &[u8], kem_context: &[u8]) -> Zeroizing<[u8; 32]> {
+    let eae_prk = labeled_extract(KEM_SUITE_ID, b"", b"eae_prk", dh);
+    let mut shared_secret = Zeroizing::new([0u8; 32]);
+    labeled_expand(
+        KEM_SUITE_ID,
+        &eae_prk,
+        b"shared_secret",
+        kem_context,
+        shared_secret.as_mut(),
+    );
+    shared_secret
+}
+
+/// HPKE `KeySchedule` without a PSK, returning the AEAD key and base nonce.
+fn key_schedule(
+    mode: u8,
+    shared_secret: &[u8; 32],
+    info: &[u8],
+) -> (Zeroizing<[u8; 32]>, [u8; 12]) {
+    let psk_id_hash = labeled_extract(HPKE_SUITE_ID, b"", b"psk_id_hash", b"");
+    let info_hash = labeled_extract(HPKE_SUITE_ID, b"", b"info_hash", info);
+    let mut context = [0u8; 65];
+    context[0] = mode;
+    context[1..33].copy_from_slice(psk_id_hash.as_ref());
+    context[33..].copy_from_slice(info_hash.as_ref());
+
+    let secret = labeled_extract(HPKE_SUITE_ID, shared_secret, b"secret", b"");
+    let mut key = Zeroizing::new([0u8; 32]);
+    let mut base_nonce = [0u8; 12];
+    labeled_expand(HPKE_SUITE_ID, &secret, b"key", &context, key.as_mut());
+    labeled_expand(
+        HPKE_SUITE_ID,
+        &secret,
+        b"base_nonce",
+        &context,
+        &mut base_nonce,
+    );
+    (key, base_nonce)
+}
+
+/// Single-shot HPKE `Seal` (sequence number zero).
+fn seal_aead(shared_secret: &[u8; 32], mode: u8, aad: &[u8], msg: &[u8]) -> Result<Vec<u8>, Error> {
+    let (key, nonce) = key_schedule(mode, shared_secret, ENVELOPE_INFO);
+    ChaCha20Poly1305::new(key.as_ref().into())
+        .encrypt(&nonce.into(), Payload { msg, aad })
+        .map_err(|_| Error::MalformedInput("envelope encryption failed".into()))
+}
+
+/// Single-shot HPKE `Open` (sequence number zero).
+fn open_aead(
+    shared_secret: &[u8; 32],
+    mode: u8,
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let (key, nonce) = key_schedule(mode, shared_secret, ENVELOPE_INFO);
+    ChaCha20Poly1305::new(key.as_ref().into())
+        .decrypt(
+            &nonce.into(),
+            Payload {
+                msg: ciphertext,
+                aad,
+            },
+        )
+        .map(Zeroizing::new)
+        .map_err(|_| Error::MalformedInput("envelope authentication failed".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn share_envelopes_roundtrip_and_authenticate() {
+        let mut rng = StdRng::seed_from_u64(1436);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+
+        let dealer = TransportKey::generate(&mut rng);
+        let transport: Vec<_> = (0..4).map(|_| TransportKey::generate(&mut rng)).collect();
+        let recipients: Vec<_> = transport.iter().map(TransportKey::public_key).collect();
+
+        let envelopes =
+            ShareEnvelope::seal_all(&mut rng, &keys.secret_keys, &dealer, &recipients).unwrap();
+        for (i, envelope) in envelopes.iter().enumerate() {
+            let opened: SecretKey<PairingEngine> =
+                envelope.open(&transport[i], &dealer.public_key()).unwrap();
+            assert_eq!(opened.participant_id, i);
+            assert_eq!(opened.scalar, keys.secret_keys[i].scalar);
+        }
+
+        // Another participant cannot open the envelope.
+        assert!(
+            envelopes[0]
+                .open::<PairingEngine>(&transport[1], &dealer.public_key())
+                .is_err()
+        );
+
+        // An impostor dealer is rejected, even when claiming the dealer's key.
+        let impostor = TransportKey::generate(&mut rng);
+        let mut forged =
+            ShareEnvelope::seal(&mut rng, &keys.secret_keys[0], &impostor, &recipients[0]).unwrap();
+        assert!(
+            forged
+                .open::<PairingEngine>(&transport[0], &dealer.public_key())
+                .is_err()
+        );
+        forged.sender = dealer.public_key();
+        assert!(
+            forged
+                .open::<PairingEngine>(&transport[0], &dealer.public_key())
+                .is_err()
+        );
+
+        // Tampering with the ciphertext or the index breaks authentication.
+        let mut tampered = envelopes[0].clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(
+            tampered
+                .open::<PairingEngine>(&transport[0], &dealer.public_key())
+                .is_err()
+        );
+        let mut reindexed = envelopes[0].clone();
+        reindexed.participant_id = 1;
+        assert!(
+            reindexed
+                .open::<PairingEngine>(&transport[0], &dealer.public_key())
+                .is_err()
+        );
+
+        // Low-order encapsulated keys are rejected.
+        let mut low_order = envelopes[0].clone();
+        low_order.encapsulated = [0u8; 32];
+        assert!(
+            low_order
+                .open::<PairingEngine>(&transport[0], &dealer.public_key())
+                .is_err()
+        );
+    }
+
+    /// Opens a base-mode vector produced by an independent RFC 9180
+    /// implementation (pyca/cryptography) with this module's KEM, key
+    /// schedule and AEAD. Auth mode only adds the static DH and `pkS`.
+    #[test]
+    fn key_schedule_matches_reference_hpke() {
+        const MODE_BASE: u8 = 0x00;
+        let mut secret = [0u8; 32];
+        for (i, byte) in secret.iter_mut().enumerate() {
+            *byte = i as u8 + 1;
+        }
+        let recipient = TransportKey::from_bytes(secret);
+        let sealed = hex(
+            "340b8135c06cf0a5c346c707e624de9a64f3117c6f575b5bb0ca9dc0b7056a44\
+             10a90ef6c4f4aab1fd293469e6f1e7e70be06ef6aedb8d9767f4c13fae1c12db\
+             1a5867d8d561a5e5021d44c662",
+        );
+        let encapsulated: [u8; 32] = sealed[..32].try_into().unwrap();
+
+        let dh = diffie_hellman(&recipient, &PublicKey::from(encapsulated)).unwrap();
+        let mut context = [0u8; 64];
+        context[..32].copy_from_slice(&encapsulated);
+        context[32..].copy_from_slice(&recipient.public_key());
+        let shared_secret = extract_and_expand(dh.as_ref(), &context);
+
+        let (key, nonce) = key_schedule(MODE_BASE, &shared_secret, b"tess-hpke-reference");
+        let opened = ChaCha20Poly1305::new(key.as_ref().into())
+            .decrypt(&nonce.into(), &sealed[32..])
+            .unwrap();
+        assert_eq!(opened, b"tess share envelope reference");
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn share_envelope_serde_roundtrip() {
+        use crate::FieldElement;
+
+        let mut rng = StdRng::seed_from_u64(1437);
+        let secret_key = SecretKey::<PairingEngine> {
+            participant_id: 3,
+            scalar: FieldElement::random(&mut rng),
+        };
+        let dealer = TransportKey::generate(&mut rng);
+        let recipient = TransportKey::generate(&mut rng);
+        let envelope =
+            ShareEnvelope::seal(&mut rng, &secret_key, &dealer, &recipient.public_key()).unwrap();
+
+        let json = serde_json::to_string(&envelope).unwrap();
+        let decoded: ShareEnvelope = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, envelope);
+        let opened: SecretKey<PairingEngine> =
+            decoded.open(&recipient, &dealer.public_key()).unwrap();
+        assert_eq!(opened.participant_id, 3);
+        assert_eq!(opened.scalar, secret_key.scalar);
+    }
+}
@@ -0,0 +1,269 @@
+//! Compile-time sized ciphertexts and public keys.
+//!
+//! Embedded and on-chain verifiers usually know the committee size and the
+//! payload length ahead of time and cannot afford heap allocation.
+//! [`FixedCiphertext<B, P>`] holds a `P`-byte payload and its proofs in
+//! arrays, and [`FixedPublicKey<B, N>`] holds the hints for a committee of `N`.
+//! Both convert to and from the heap-backed types, so a full node can produce
+//! them for a constrained consumer:
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{
+//!     FixedCiphertext, FixedPublicKey, PairingEngine, SilentThresholdScheme,
+//!     ThresholdEncryption,
+//! };
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let ct = scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"16-byte message!").unwrap();
+//!
+//! let fixed = FixedCiphertext::<PairingEngine, 16>::try_from(&ct).unwrap();
+//! let key = FixedPublicKey::<PairingEngine, 4>::try_from(&keys.public_keys[1]).unwrap();
+//! let partial = scheme.partial_decrypt(&keys.secret_keys[1], &ct).unwrap();
+//! assert!(fixed.verify_partial(&key, &partial));
+//! ```
+//!
+//! Fixed ciphertexts carry no attachments: broadcast and labelled ciphertexts
+//! are rejected, and a validity proof or sender signature is not carried over. The fixed
+//! types themselves never allocate, but a backend's pairing may.
+
+use alloc::vec::Vec;
+
+use super::ciphertext::partial_matches;
+use crate::{
+    COMMITTEE_FINGERPRINT_LEN, Ciphertext, KdfId, PROOF_G1_LEN, PROOF_G2_LEN, PairingBackend,
+    PartialDecryption, PublicKey, errors::Error,
+};
+
+/// [`Ciphertext`] with a `P`-byte payload and no attachments, stored without
+/// heap allocation.
+#[derive(Clone, Debug)]
+pub struct FixedCiphertext<B: PairingBackend, const P: usize> {
+    /// Random G2 element used during encryption.
+    pub gamma_g2: B::G2,
+    /// KZG proof elements in G1.
+    pub proof_g1: [B::G1; PROOF_G1_LEN],
+    /// KZG proof elements in G2.
+    pub proof_g2: [B::G2; PROOF_G2_LEN],
+    /// Precomputed pairing result for verification.
+    pub shared_secret: B::Target,
+    /// Threshold required for decryption.
+    pub threshold: usize,
+    /// Application epoch the ciphertext was encrypted in.
+    pub epoch: u64,
+    /// Fingerprint of the aggregate key encrypted to.
+    pub committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+    /// Encrypted payload bytes.
+    pub payload: [u8; P],
+    /// Whether the payload was compressed before encryption.
+    pub compressed: bool,
+    /// Whether length-hiding padding was applied before encryption.
+    pub padded: bool,
+    /// Whether encryption randomness was derived deterministically from the inputs.
+    pub deterministic: bool,
+    /// Function that derived the payload key from the shared secret.
+    pub kdf: KdfId,
+}
+
+/// [`PublicKey`] for a committee of `N` participants, stored without heap
+/// allocation.
+#[derive(Clone, Debug)]
+pub struct FixedPublicKey<B: PairingBackend, const N: usize> {
+    /// Participant identifier (0-indexed).
+    pub participant_id: usize,
+    /// Standard BLS public key for the participant.
+    pub bls_key: B::G1,
+    /// Commitment to the participant's L_i(x).
+    pub lagrange_li: B::G1,
+    /// Commitment to L_i(x) - L_i(0).
+    pub lagrange_li_minus0: B::G1,
+    /// Commitment to x * L_i(x).
+    pub lagrange_li_x: B::G1,
+    /// Commitments to L_i(x) * L_j(z) for all j.
+    pub lagrange_li_lj_z: [B::G1; N],
+}
+
+impl<B: PairingBackend, const P: usize> FixedCiphertext<B, P> {
+    /// Checks a partial decryption against its participant's public key, as
+    /// [`verify_partial_decryption`](crate::verify_partial_decryption) does.
+    pub fn verify_partial<const N: usize>(
+        &self,
+        public_key: &FixedPublicKey<B, N>,
+        partial: &PartialDecryption<B>,
+    ) -> bool {
+        public_key.participant_id == partial.participant_id
+            && partial_matches::<B>(&public_key.bls_key, &self.gamma_g2, partial)
+    }
+}
+
+impl<B: PairingBackend, const P: usize> TryFrom<&Ciphertext<B>> for FixedCiphertext<B, P> {
+    type Error = Error;
+
+    /// Copies a ciphertext into fixed storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the payload is not exactly `P`
+    /// bytes, the proof vectors have the wrong sizes, or the ciphertext is a
+    /// broadcast ciphertext or carries a label.
+    fn try_from(ciphertext: &Ciphertext<B>) -> Result<Self, Error> {
+        if ciphertext.is_broadcast() {
+            return Err(Error::MalformedInput(
+                "broadcast ciphertexts have no fixed-size form".into(),
+            ));
+        }
+        if !ciphertext.label.is_empty() {
+            return Err(Error::MalformedInput(
+                "labelled ciphertexts have no fixed-size form".into(),
+            ));
+        }
+        let payload = ciphertext.payload.as_slice().try_into().map_err(|_| {
+            Error::MalformedInput(format!(
+                "payload is {} bytes, expected {P}",
+                ciphertext.payload.len()
+            ))
+        })?;
+        let proof_g1 = ciphertext.proof_g1.as_slice().try_into().map_err(|_| {
+            Error::MalformedInput(format!("ciphertext must have {PROOF_G1_LEN} G1 proofs"))
+        })?;
+        let proof_g2 = ciphertext.proof_g2.as_slice().try_into().map_err(|_| {
+            Error::MalformedInput(format!("ciphertext must have {PROOF_G2_LEN} G2 proofs"))
+        })?;
+        Ok(Self {
+            gamma_g2: ciphertext.gamma_g2,
+            proof_g1,
+            proof_g2,
+            shared_secret: ciphertext.shared_secret.clone(),
+            threshold: ciphertext.threshold,
+            epoch: ciphertext.epoch,
+            committee: ciphertext.committee,
+            payload,
+            compressed: ciphertext.compressed,
+            padded: ciphertext.padded,
+            deterministic: ciphertext.deterministic,
+            kdf: ciphertext.kdf,
+        })
+    }
+}
+
+impl<B: PairingBackend, const P: usize> From<FixedCiphertext<B, P>> for Ciphertext<B> {
+    fn from(fixed: FixedCiphertext<B, P>) -> Self {
+        Ciphertext {
+            gamma_g2: fixed.gamma_g2,
+            proof_g1: fixed.proof_g1.into_iter().collect(),
+            proof_g2: fixed.proof_g2.into_iter().collect(),
+            shared_secret: fixed.shared_secret,
+            threshold: fixed.threshold,
+            epoch: fixed.epoch,
+            committee: fixed.committee,
+            label: Vec::new(),
+            payload: fixed.payload.to_vec(),
+            compressed: fixed.compressed,
+            padded: fixed.padded,
+            deterministic: fixed.deterministic,
+            kdf: fixed.kdf,
+            validity_proof: None,
+            sender_signature: None,
+            broadcast_keys: Vec::new(),
+        }
+    }
+}
+
+impl<B: PairingBackend, const N: usize> TryFrom<&PublicKey<B>> for FixedPublicKey<B, N> {
+    type Error = Error;
+
+    /// Copies a public key into fixed storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the key was not generated for a
+    /// committee of `N` participants.
+    fn try_from(public_key: &PublicKey<B>) -> Result<Self, Error> {
+        let lagrange_li_lj_z = public_key
+            .lagrange_li_lj_z
+            .as_ref()
+            .try_into()
+            .map_err(|_| {
+                Error::MalformedInput(format!(
+                    "public key has {} hints, expected {N}",
+                    public_key.lagrange_li_lj_z.len()
+                ))
+            })?;
+        Ok(Self {
+            participant_id: public_key.participant_id,
+            bls_key: public_key.bls_key,
+            lagrange_li: public_key.lagrange_li,
+            lagrange_li_minus0: public_key.lagrange_li_minus0,
+            lagrange_li_x: public_key.lagrange_li_x,
+            lagrange_li_lj_z,
+        })
+    }
+}
+
+impl<B: PairingBackend, const N: usize> From<FixedPublicKey<B, N>> for PublicKey<B> {
+    fn from(fixed: FixedPublicKey<B, N>) -> Self {
+        PublicKey {
+            participant_id: fixed.participant_id,
+            bls_key: fixed.bls_key,
+            lagrange_li: fixed.lagrange_li,
+            lagrange_li_minus0: fixed.lagrange_li_minus0,
+            lagrange_li_x: fixed.lagrange_li_x,
+            lagrange_li_lj_z: fixed.lagrange_li_lj_z.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    type B = PairingEngine;
+
+    #[test]
+    fn fixed_types_roundtrip_and_verify() {
+        let mut rng = StdRng::seed_from_u64(1455);
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"fixed")
+            .unwrap();
+
+        assert!(FixedCiphertext::<B, 4>::try_from(&ct).is_err());
+        assert!(FixedPublicKey::<B, 8>::try_from(&keys.public_keys[0]).is_err());
+
+        let fixed = FixedCiphertext::<B, 5>::try_from(&ct).unwrap();
+        let key = FixedPublicKey::<B, 4>::try_from(&keys.public_keys[2]).unwrap();
+        let partial = scheme.partial_decrypt(&keys.secret_keys[2], &ct).unwrap();
+        assert!(fixed.verify_partial(&key, &partial));
+        let other = scheme.partial_decrypt(&keys.secret_keys[3], &ct).unwrap();
+        assert!(!fixed.verify_partial(&key, &other));
+
+        let restored = Ciphertext::from(fixed);
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &restored).unwrap())
+            .collect();
+        let result = scheme
+            .aggregate_decrypt(
+                &restored,
+                &partials,
+                &[true, true, true, false],
+                &keys.aggregate_key,
+            )
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"fixed"[..]));
+
+        let restored_key = PublicKey::from(key);
+        assert_eq!(
+            restored_key.lagrange_li_lj_z,
+            keys.public_keys[2].lagrange_li_lj_z
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! Ciphertext metadata that can be read before any expensive check.
+//!
+//! The header's encoding is mixed into the payload key derivation and bound
+//! into the validity proof and sender signature, so altering any field makes
+//! the ciphertext fail verification and leaves the committee unable to
+//! recover the payload. Serialized ciphertexts carry the header ahead of the
+//! proofs.
+
+use alloc::vec::Vec;
+
+use crate::{
+    AggregateKey, Ciphertext, CurvePoint, Fr, KdfId, PairingBackend, TargetGroup, errors::Error,
+};
+
+const HEADER_MAGIC: &[u8; 7] = b"TESSCTH";
+
+/// Version of the encoding written by [`CiphertextHeader::to_bytes`].
+pub const CIPHERTEXT_HEADER_VERSION: u8 = 1;
+
+/// Longest label a ciphertext header may carry, in bytes.
+pub const MAX_LABEL_LEN: usize = 1024;
+
+/// Length of a committee fingerprint.
+pub const COMMITTEE_FINGERPRINT_LEN: usize = 32;
+
+/// Encryption mode that produced a ciphertext.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SchemeId {
+    /// `t`-of-`n` silent threshold encryption.
+    #[default]
+    Threshold,
+    /// 1-of-`n` broadcast encryption.
+    Broadcast,
+}
+
+impl SchemeId {
+    /// Header byte for this mode.
+    pub fn to_byte(self) -> u8 {
+        match self {
+            SchemeId::Threshold => 0,
+            SchemeId::Broadcast => 1,
+        }
+    }
+
+    /// Parses a header byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] for unknown identifiers.
+    pub fn from_byte(byte: u8) -> Result<Self, Error> {
+        match byte {
+            0 => Ok(SchemeId::Threshold),
+            1 => Ok(SchemeId::Broadcast),
+            _ => Err(Error::MalformedInput(format!(
+                "unsupported ciphertext scheme {byte}"
+            ))),
+        }
+    }
+}
+
+/// Routing and validation metadata of a [`Ciphertext`].
+///
+/// Gathers what a relayer or decrypting coordinator needs to route a
+/// ciphertext or turn it away before any pairing work: the mode that produced
+/// it, the epoch and committee it targets, an application label, the
+/// threshold, the payload length and the cipher suite.
+/// [`Ciphertext::header`] assembles it, and [`CiphertextHeader::to_bytes`]
+/// encodes it as:
+///
+/// | Bytes | Field |
+/// |-------|-------|
+/// | 7 | magic `TESSCTH` |
+/// | 1 | [`CIPHERTEXT_HEADER_VERSION`] |
+/// | 1 | [`SchemeId`] |
+/// | 1 | cipher suite ([`KdfId`]) |
+/// | 8 | epoch, little-endian |
+/// | 32 | committee fingerprint |
+/// | 8 | threshold, little-endian |
+/// | 8 | payload length, little-endian |
+/// | 8 + n | label, length-prefixed |
+///
+/// Changing any field after encryption leaves the committee unable to recover
+/// the payload.
+///
+/// # Example
+///
+/// ```rust
+/// use rand::thread_rng;
+/// use tess::{CiphertextHeader, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+///
+/// let mut rng = thread_rng();
+/// let scheme = SilentThresholdScheme::<PairingEngine>::new()
+///     .with_epoch(7)
+///     .with_label(b"orders");
+/// let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+/// let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+/// let ct = scheme.encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"bid").unwrap();
+///
+/// let header = CiphertextHeader::from_bytes(&ct.header().to_bytes()).unwrap();
+/// assert_eq!((header.epoch, header.label.as_slice()), (7, &b"orders"[..]));
+/// assert!(header.is_for(&keys.aggregate_key));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CiphertextHeader {
+    /// Mode that produced the ciphertext.
+    pub scheme: SchemeId,
+    /// Application epoch the ciphertext was encrypted in.
+    pub epoch: u64,
+    /// Fingerprint of the aggregate key encrypted to; see
+    /// [`AggregateKey::fingerprint`].
+    pub committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+    /// Application label, at most [`MAX_LABEL_LEN`] bytes.
+    pub label: Vec<u8>,
+    /// Threshold required for decryption.
+    pub threshold: usize,
+    /// Length of the encrypted payload in bytes.
+    pub payload_len: usize,
+    /// Function that derived the payload key from the shared secret; the
+    /// keystream is the scheme's XOF.
+    pub cipher_suite: KdfId,
+}
+
+impl CiphertextHeader {
+    /// Canonical encoding, as laid out in the [type docs](Self).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = HEADER_MAGIC.to_vec();
+        out.push(CIPHERTEXT_HEADER_VERSION);
+        out.push(self.scheme.to_byte());
+        out.push(self.cipher_suite.to_byte());
+        out.extend_from_slice(&self.epoch.to_le_bytes());
+        out.extend_from_slice(&self.committee);
+        out.extend_from_slice(&(self.threshold as u64).to_le_bytes());
+        out.extend_from_slice(&(self.payload_len as u64).to_le_bytes());
+        out.extend_from_slice(&(self.label.len() as u64).to_le_bytes());
+        out.extend_from_slice(&self.label);
+        out
+    }
+
+    /// Decodes a header written by [`CiphertextHeader::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the bytes are not a header of this
+    /// version, name an unknown scheme or cipher suite, carry a label longer
+    /// than [`MAX_LABEL_LEN`], or are followed by trailing bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let Some([version, scheme, suite, rest @ ..]) = bytes.strip_prefix(HEADER_MAGIC.as_slice())
+        else {
+            return Err(Error::MalformedInput("not a ciphertext header".into()));
+        };
+        if *version != CIPHERTEXT_HEADER_VERSION {
+            return Err(Error::MalformedInput(format!(
+                "unsupported ciphertext header version {version}"
+            )));
+        }
+        let truncated = || Error::MalformedInput("ciphertext header is truncated".into());
+        let (fixed, label) = rest
+            .split_first_chunk::<{ 8 + COMMITTEE_FINGERPRINT_LEN + 24 }>()
+            .ok_or_else(truncated)?;
+        let (epoch, fixed) = fixed.split_first_chunk::<8>().ok_or_else(truncated)?;
+        let (committee, fixed) = fixed
+            .split_first_chunk::<COMMITTEE_FINGERPRINT_LEN>()
+            .ok_or_else(truncated)?;
+        let [threshold, payload_len, label_len] = [0, 8, 16].map(|at| {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&fixed[at..at + 8]);
+            u64::from_le_bytes(word)
+        });
+        let to_usize = |value: u64| usize::try_from(value).map_err(|_| truncated());
+        if to_usize(label_len)? != label.len() {
+            return Err(Error::MalformedInput(
+                "ciphertext header label length does not match".into(),
+            ));
+        }
+        let header = Self {
+            scheme: SchemeId::from_byte(*scheme)?,
+            epoch: u64::from_le_bytes(*epoch),
+            committee: *committee,
+            label: label.to_vec(),
+            threshold: to_usize(threshold)?,
+            payload_len: to_usize(payload_len)?,
+            cipher_suite: KdfId::from_byte(*suite)?,
+        };
+        header.check_label()?;
+        Ok(header)
+    }
+
+    /// Returns `true` if the ciphertext was encrypted to `agg_key`.
+    pub fn is_for<B: PairingBackend<Scalar = Fr>>(&self, agg_key: &AggregateKey<B>) -> bool {
+        self.committee == agg_key.fingerprint()
+    }
+
+    pub(crate) fn check_label(&self) -> Result<(), Error> {
+        check_label(&self.label)
+    }
+
+    /// Fails unless the ciphertext was encrypted to `agg_key`.
+    pub(crate) fn check_committee<B: PairingBackend<Scalar = Fr>>(
+        &self,
+        agg_key: &AggregateKey<B>,
+    ) -> Result<(), Error> {
+        if self.is_for(agg_key) {
+            Ok(())
+        } else {
+            Err(Error::MalformedInput(
+                "ciphertext was encrypted to a different committee".into(),
+            ))
+        }
+    }
+}
+
+impl<B: PairingBackend> Ciphertext<B> {
+    /// Metadata of this ciphertext.
+    pub fn header(&self) -> CiphertextHeader {
+        CiphertextHeader {
+            scheme: if self.is_broadcast() {
+                SchemeId::Broadcast
+            } else {
+                SchemeId::Threshold
+            },
+            epoch: self.epoch,
+            committee: self.committee,
+            label: self.label.clone(),
+            threshold: self.threshold,
+            payload_len: self.payload.len(),
+            cipher_suite: self.kdf,
+        }
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
+    /// Fingerprint identifying this aggregate key in ciphertext headers.
+    ///
+    /// Hashes `ask`, `z_g2` and the precomputed pairing under the domain
+    /// separator "tess::committee-fingerprint", so it changes whenever
+    /// membership or revocations change the key.
+    pub fn fingerprint(&self) -> [u8; COMMITTEE_FINGERPRINT_LEN] {
+        committee_fingerprint::<B>(&self.ask, &self.z_g2, &self.precomputed_pairing)
+    }
+}
+
+pub(crate) fn committee_fingerprint<B: PairingBackend>(
+    ask: &B::G1,
+    z_g2: &B::G2,
+    pairing_base: &B::Target,
+) -> [u8; COMMITTEE_FINGERPRINT_LEN] {
+    let mut hasher = blake3::Hasher::new_derive_key("tess::committee-fingerprint");
+    hasher.update(ask.to_repr().as_ref());
+    hasher.update(z_g2.to_repr().as_ref());
+    hasher.update(pairing_base.to_repr().as_ref());
+    *hasher.finalize().as_bytes()
+}
+
+/// Absorbs the header fields not otherwise covered by ciphertext transcripts:
+/// the epoch, committee fingerprint and length-prefixed label.
+pub(crate) fn bind_header_metadata<B: PairingBackend>(
+    hasher: &mut blake3::Hasher,
+    ciphertext: &Ciphertext<B>,
+) {
+    hasher.update(&ciphertext.epoch.to_le_bytes());
+    hasher.update(&ciphertext.committee);
+    hasher.update(&(ciphertext.label.len() as u64).to_le_bytes());
+    hasher.update(&ciphertext.label);
+}
+
+pub(crate) fn check_label(label: &[u8]) -> Result<(), Error> {
+    if label.len() > MAX_LABEL_LEN {
+        return Err(Error::MalformedInput(format!(
+            "ciphertext label is {} bytes, at most {MAX_LABEL_LEN} allowed",
+            label.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn header_is_bound_to_the_payload_key() {
+        let mut rng = StdRng::seed_from_u64(1471);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new()
+            .with_epoch(3)
+            .with_label(b"route");
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"header")
+            .unwrap();
+
+        let header = ct.header();
+        assert_eq!(header.scheme, SchemeId::Threshold);
+        assert_eq!(header.payload_len, ct.payload.len());
+        assert_eq!(
+            CiphertextHeader::from_bytes(&header.to_bytes()).unwrap(),
+            header
+        );
+        let mut bytes = header.to_bytes();
+        bytes.push(0);
+        assert!(CiphertextHeader::from_bytes(&bytes).is_err());
+
+        let selector = vec![true, true, false, false];
+        let partials = keys.secret_keys[..2]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect::<Vec<_>>();
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"header");
+
+        let mut relabelled = ct.clone();
+        relabelled.label = b"other".to_vec();
+        assert!(
+            scheme
+                .verify_ciphertext(&keys.aggregate_key, &params, &relabelled)
+                .is_err()
+        );
+        let result = scheme
+            .aggregate_decrypt(&relabelled, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_ne!(result.plaintext.unwrap(), b"header");
+
+        let other = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        assert!(!header.is_for(&other.aggregate_key));
+        assert!(
+            scheme
+                .aggregate_decrypt(&ct, &partials, &selector, &other.aggregate_key)
+                .is_err()
+        );
+    }
+}
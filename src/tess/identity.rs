@@ -0,0 +1,299 @@
+//! Threshold identity-based encryption.
+//!
+//! In this mode the committee's aggregate key acts as an IBE master public
+//! key. A sender encrypts to an arbitrary identity string with
+//! [`SilentThresholdScheme::encrypt_to_identity`]: the ciphertext's
+//! `gamma_g2`, normally `γ·h` for a fresh `γ`, is the identity hashed into the
+//! group partial decryptions live in (G2, or `B::G1` under
+//! [`Swapped`](crate::Swapped)). A member's share of the identity's key is
+//! then `sk·H(id)`, the partial decryption of every ciphertext to that
+//! identity, and [`SilentThresholdScheme::extract_identity_key`] checks `t`
+//! shares and aggregates them into an [`IdentityKey`]. Its holder decrypts
+//! every ciphertext encrypted to the identity at that threshold without
+//! involving the committee again.
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let agg = &keys.aggregate_key;
+//!
+//! let ct = scheme
+//!     .encrypt_to_identity(&mut rng, agg, &params, 2, b"alice@example.com", b"hello")
+//!     .unwrap();
+//!
+//! // Later, the committee serves Alice's key request.
+//! let shares: Vec<_> = keys.secret_keys[..2]
+//!     .iter()
+//!     .map(|sk| scheme.identity_share(sk, b"alice@example.com"))
+//!     .collect();
+//! let key = scheme
+//!     .extract_identity_key(b"alice@example.com", 2, &shares, &[true, true, false, false], agg)
+//!     .unwrap();
+//! assert_eq!(scheme.decrypt_with_identity_key(&ct, &key).unwrap(), b"hello");
+//! ```
+//!
+//! The crate does not authenticate extraction requests; each member decides
+//! whom to serve shares to. An identity key opens only ciphertexts to the
+//! same committee, threshold and identity, and anyone holding it can decrypt
+//! all of them, so identities should be scoped (for example by appending an
+//! epoch) when keys are meant to expire.
+
+use alloc::vec::Vec;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+
+use super::{
+    broadcast::reject_broadcast,
+    prepared::EncryptionBases,
+    scheme::{DecryptionTerms, check_encryption_threshold},
+};
+use crate::{
+    AggregateKey, COMMITTEE_FINGERPRINT_LEN, Ciphertext, CurvePoint, EncryptionKey, FieldElement,
+    Fr, MsmProvider, PairingBackend, Params, PartialDecryption, SecretKey, SilentThresholdScheme,
+    Xof, errors::Error, sym_enc::SymmetricEncryption,
+};
+
+/// Domain separation tag for hashing identities to the partial-decryption group.
+const IDENTITY_DST: &[u8] = b"TESS-IBE-V01-H2G2";
+
+/// Decryption key for one identity, aggregated from `t` committee shares.
+#[derive(Clone, Debug)]
+pub struct IdentityKey<B: PairingBackend> {
+    /// Identity the key was extracted for.
+    pub identity: Vec<u8>,
+    /// Threshold of the ciphertexts the key opens.
+    pub threshold: usize,
+    /// Fingerprint of the aggregate key the shares were checked against.
+    pub committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+    pub(crate) terms: DecryptionTerms<B>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Encrypts `payload` to `identity` under the committee's aggregate key.
+    ///
+    /// The ciphertext is an ordinary threshold ciphertext and also decrypts
+    /// with [`partial_decrypt`](crate::ThresholdEncryption::partial_decrypt)
+    /// and [`aggregate_decrypt`](crate::ThresholdEncryption::aggregate_decrypt).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `threshold` is below 2 or exceeds
+    /// the SRS; 1-of-n encryption uses per-member keys and has no identity
+    /// form.
+    pub fn encrypt_to_identity<R: RngCore + ?Sized, K: EncryptionKey<B> + ?Sized>(
+        &self,
+        rng: &mut R,
+        agg_key: &K,
+        params: &Params<B>,
+        threshold: usize,
+        identity: &[u8],
+        payload: &[u8],
+    ) -> Result<Ciphertext<B>, Error> {
+        if threshold == 1 {
+            return Err(Error::InvalidConfig(
+                "identity encryption requires a threshold of at least 2".into(),
+            ));
+        }
+        let threshold = check_encryption_threshold(agg_key.aggregate_key(), params, threshold)?;
+        let gamma_g2 = identity_point::<B>(identity);
+        let payload = payload.to_vec();
+        match agg_key.prepared_bases(threshold) {
+            Some(bases) => self.encrypt_with_gamma(rng, bases, gamma_g2, payload, false),
+            None => {
+                let bases = EncryptionBases::new(agg_key.aggregate_key(), params, threshold)?;
+                self.encrypt_with_gamma(rng, &bases, gamma_g2, payload, false)
+            }
+        }
+    }
+
+    /// Computes this member's share `sk·H(identity)` of the identity's key.
+    pub fn identity_share(
+        &self,
+        secret_key: &SecretKey<B>,
+        identity: &[u8],
+    ) -> PartialDecryption<B> {
+        PartialDecryption {
+            participant_id: secret_key.participant_id,
+            response: identity_point::<B>(identity).mul_scalar(&secret_key.scalar),
+        }
+    }
+
+    /// Checks the selected shares against their members' keys and aggregates
+    /// them into the key for ciphertexts to `identity` at `threshold`.
+    ///
+    /// The shares are checked with one randomly weighted pairing equation,
+    /// `e(Σ wᵢ·pkᵢ, H(id)) = e(g, Σ wᵢ·shareᵢ)`, since unlike decryption
+    /// there is no ciphertext to catch a bad share afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if a share's id is out of range or a
+    /// share does not verify, and the selector and share errors of
+    /// [`ThresholdEncryption::aggregate_decrypt`](crate::ThresholdEncryption::aggregate_decrypt).
+    pub fn extract_identity_key(
+        &self,
+        identity: &[u8],
+        threshold: usize,
+        shares: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<IdentityKey<B>, Error> {
+        let threshold = agg_key.ciphertext_threshold(threshold);
+        let point = identity_point::<B>(identity);
+        let mut hasher = X::default();
+        hasher.update(b"tess::identity-shares");
+        hasher.update(point.to_repr().as_ref());
+        let mut keys = Vec::with_capacity(shares.len());
+        let mut responses = Vec::with_capacity(shares.len());
+        for share in shares {
+            let public_key = agg_key
+                .public_keys
+                .get(share.participant_id)
+                .ok_or_else(|| Error::MalformedInput("share id out of range".into()))?;
+            hasher.update(&(share.participant_id as u64).to_le_bytes());
+            hasher.update(share.response.to_repr().as_ref());
+            keys.push(public_key.bls_key);
+            responses.push(share.response);
+        }
+        let mut rng = ChaCha20Rng::from_seed(hasher.finalize_bytes());
+        let weights = (0..keys.len())
+            .map(|_| Fr::random(&mut rng))
+            .collect::<Vec<_>>();
+        let weighted_keys = B::Msm::msm_g1(&keys, &weights).map_err(Error::Backend)?;
+        let weighted_responses = B::Msm::msm_g2(&responses, &weights).map_err(Error::Backend)?;
+        if B::pairing(&weighted_keys, &point)
+            != B::pairing(&B::G1::generator(), &weighted_responses)
+        {
+            return Err(Error::MalformedInput(
+                "identity key shares contain an invalid share".into(),
+            ));
+        }
+
+        Ok(IdentityKey {
+            identity: identity.to_vec(),
+            threshold,
+            committee: agg_key.fingerprint(),
+            terms: self.decryption_terms(threshold, shares, selector, agg_key)?,
+        })
+    }
+
+    /// Decrypts a ciphertext encrypted to `key`'s identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the ciphertext was encrypted to
+    /// another identity, committee or threshold, or fails verification.
+    pub fn decrypt_with_identity_key(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        key: &IdentityKey<B>,
+    ) -> Result<Vec<u8>, Error> {
+        reject_broadcast(ciphertext)?;
+        if ciphertext.committee != key.committee || ciphertext.threshold != key.threshold {
+            return Err(Error::MalformedInput(
+                "ciphertext was encrypted to a different committee or threshold".into(),
+            ));
+        }
+        if !ciphertext
+            .gamma_g2
+            .sub(&identity_point::<B>(&key.identity))
+            .is_identity()
+        {
+            return Err(Error::MalformedInput(
+                "ciphertext was not encrypted to this identity".into(),
+            ));
+        }
+        if let Some(signature) = &ciphertext.sender_signature {
+            signature.verify(ciphertext)?;
+        }
+        let payload_key = self.payload_key_from_terms(ciphertext, &key.terms)?;
+        let plaintext = self
+            .symmetric_enc
+            .decrypt(&payload_key, &ciphertext.payload)?;
+        self.restore_payload(plaintext, ciphertext.padded, ciphertext.compressed)
+    }
+}
+
+/// Hashes an identity to the group partial decryptions live in.
+fn identity_point<B: PairingBackend>(identity: &[u8]) -> B::G2 {
+    B::G2::hash_to_curve(identity, IDENTITY_DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, ThresholdEncryption};
+
+    #[test]
+    fn identity_keys_open_only_their_identity() {
+        let mut rng = StdRng::seed_from_u64(1479);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let agg = &keys.aggregate_key;
+        let alice = b"alice@example.com";
+
+        let first = scheme
+            .encrypt_to_identity(&mut rng, agg, &params, 2, alice, b"first")
+            .unwrap();
+        let second = scheme
+            .encrypt_to_identity(&mut rng, agg, &params, 2, alice, b"second")
+            .unwrap();
+        let bob = scheme
+            .encrypt_to_identity(&mut rng, agg, &params, 2, b"bob", b"bob")
+            .unwrap();
+        let random = scheme.encrypt(&mut rng, agg, &params, 2, b"plain").unwrap();
+
+        let selector = [true, false, true, false];
+        let mut shares = [0, 2]
+            .map(|id| scheme.identity_share(&keys.secret_keys[id], alice))
+            .to_vec();
+        let key = scheme
+            .extract_identity_key(alice, 2, &shares, &selector, agg)
+            .unwrap();
+        assert_eq!(
+            scheme.decrypt_with_identity_key(&first, &key).unwrap(),
+            b"first"
+        );
+        assert_eq!(
+            scheme.decrypt_with_identity_key(&second, &key).unwrap(),
+            b"second"
+        );
+        assert!(scheme.decrypt_with_identity_key(&bob, &key).is_err());
+        assert!(scheme.decrypt_with_identity_key(&random, &key).is_err());
+
+        // Identity ciphertexts still decrypt the ordinary way.
+        let partials: Vec<_> = [0, 1]
+            .iter()
+            .map(|&id| {
+                scheme
+                    .partial_decrypt(&keys.secret_keys[id], &first)
+                    .unwrap()
+            })
+            .collect();
+        let result = scheme
+            .aggregate_decrypt(&first, &partials, &[true, true, false, false], agg)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"first");
+
+        shares[1] = scheme.identity_share(&keys.secret_keys[2], b"bob");
+        assert!(
+            scheme
+                .extract_identity_key(alice, 2, &shares, &selector, agg)
+                .is_err()
+        );
+        assert!(
+            scheme
+                .encrypt_to_identity(&mut rng, agg, &params, 1, alice, b"")
+                .is_err()
+        );
+    }
+}
@@ -31,7 +31,7 @@
 //! These precomputed values eliminate the need for polynomial interpolation
 //! during decryption, significantly improving performance.
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use core::fmt::Debug;
 
 #[cfg(feature = "parallel")]
@@ -102,7 +102,7 @@ pub struct PublicKey<B: PairingBackend> {
     /// Commitment to x * L_i(x).
     pub lagrange_li_x: B::G1,
     /// Commitments to L_i(x) * L_j(z) for all j.
-    pub lagrange_li_lj_z: Vec<B::G1>,
+    pub lagrange_li_lj_z: Box<[B::G1]>,
 }
 
 impl<B: PairingBackend> Clone for PublicKey<B> {
@@ -118,6 +118,29 @@ impl<B: PairingBackend> Clone for PublicKey<B> {
     }
 }
 
+impl<B: PairingBackend> PublicKey<B> {
+    /// Placeholder key for an unoccupied committee slot.
+    ///
+    /// Every commitment is the identity, which is the key of the zero secret, so
+    /// the slot adds nothing to the aggregate key and its partial decryption is
+    /// the identity. `parties` is the size of the evaluation domain.
+    pub fn vacant(participant_id: usize, parties: usize) -> Self {
+        Self {
+            participant_id,
+            bls_key: B::G1::identity(),
+            lagrange_li: B::G1::identity(),
+            lagrange_li_minus0: B::G1::identity(),
+            lagrange_li_x: B::G1::identity(),
+            lagrange_li_lj_z: alloc::vec![B::G1::identity(); parties].into_boxed_slice(),
+        }
+    }
+
+    /// Returns `true` if this is a [`PublicKey::vacant`] placeholder.
+    pub fn is_vacant(&self) -> bool {
+        self.bls_key.is_identity()
+    }
+}
+
 impl<B: PairingBackend<Scalar = Fr>> SecretKey<B> {
     /// Derives a public key from a secret key using precomputed Lagrange commitments.
     ///
@@ -169,14 +192,14 @@ impl<B: PairingBackend<Scalar = Fr>> SecretKey<B> {
             return Err(BackendError::Math("participant id out of bounds"));
         }
 
-        let bls_key = B::G1::generator().mul_scalar(&self.scalar);
+        let bls_key = B::G1::mul_generator(&self.scalar);
         let lagrange_li = powers.li[self.participant_id].mul_scalar(&self.scalar);
         let lagrange_li_minus0 = powers.li_minus0[self.participant_id].mul_scalar(&self.scalar);
         let lagrange_li_x = powers.li_x[self.participant_id].mul_scalar(&self.scalar);
         let lagrange_li_lj_z = powers.li_lj_z[self.participant_id]
             .iter()
             .map(|elem| elem.mul_scalar(&self.scalar))
-            .collect::<Vec<_>>();
+            .collect();
 
         Ok(PublicKey {
             participant_id: self.participant_id,
@@ -201,6 +224,7 @@ impl<B: PairingBackend<Scalar = Fr>> SecretKey<B> {
 /// - `z_g2`: Commitment to the vanishing polynomial in G2
 /// - `lagrange_row_sums`: Precomputed sums of Lagrange commitments for verification
 /// - `precomputed_pairing`: Precomputed pairing for efficient verification
+/// - `revoked`: Participants excluded from `ask` and the row sums
 #[derive(Clone, Debug)]
 pub struct AggregateKey<B: PairingBackend<Scalar = Fr>> {
     /// Public keys for all participants.
@@ -215,6 +239,9 @@ pub struct AggregateKey<B: PairingBackend<Scalar = Fr>> {
     pub precomputed_pairing: B::Target,
     /// KZG parameters used to derive commitments.
     pub kzg_params: SRS<B>,
+    /// Sorted ids of revoked participants, whose keys are kept in
+    /// `public_keys` but contribute nothing to `ask` or the row sums.
+    pub revoked: Vec<usize>,
 }
 
 impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
@@ -222,12 +249,61 @@ impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
     ///
     /// This function combines the public keys of all participants to create
     /// an aggregate key used for encryption and verification.
-    #[instrument(level = "info", skip_all, fields(parties, num_keys = public_keys.len()))]
     pub fn aggregate_keys(
         public_keys: &[PublicKey<B>],
         params: &Params<B>,
         parties: usize,
     ) -> Result<AggregateKey<B>, Error> {
+        Self::aggregate_keys_with_revocations(public_keys, params, parties, &[])
+    }
+
+    /// Threshold recorded in ciphertexts that need `threshold` partial
+    /// decryptions from active participants.
+    ///
+    /// Each revoked or [vacant](PublicKey::is_vacant) slot holds a public zero
+    /// share, so encryption adds one to the threshold per such slot;
+    /// otherwise `threshold - r - v` real shares would open a ciphertext to a
+    /// key with `r` revocations and `v` vacancies.
+    pub fn ciphertext_threshold(&self, threshold: usize) -> usize {
+        let vacancies = self
+            .public_keys
+            .iter()
+            .filter(|pk| pk.is_vacant() && self.revoked.binary_search(&pk.participant_id).is_err())
+            .count();
+        threshold + self.revoked.len() + vacancies
+    }
+
+    /// Aggregates public keys, excluding the participants in `revoked`.
+    ///
+    /// Revoked keys stay in `public_keys` but are left out of `ask` and the
+    /// Lagrange row sums, so the result equals the aggregate over a committee
+    /// in which they hold the zero secret. Aggregate decryption rejects their
+    /// partial decryptions with [`Error::RevokedParticipant`]; their slots may
+    /// still be selected, contributing the zero share. Since anyone can supply
+    /// those shares, encryption raises the threshold by the number of revoked
+    /// participants; see [`AggregateKey::ciphertext_threshold`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if a revoked id is not below `parties`,
+    /// in addition to the errors of [`AggregateKey::aggregate_keys`].
+    #[instrument(level = "info", skip_all, fields(parties, num_keys = public_keys.len(), revoked = revoked.len()))]
+    pub fn aggregate_keys_with_revocations(
+        public_keys: &[PublicKey<B>],
+        params: &Params<B>,
+        parties: usize,
+        revoked: &[usize],
+    ) -> Result<AggregateKey<B>, Error> {
+        if let Some(id) = revoked.iter().find(|&&id| id >= parties) {
+            return Err(Error::InvalidConfig(format!(
+                "revoked participant {id} is outside a committee of {parties}"
+            )));
+        }
+        let mut revoked = revoked.to_vec();
+        revoked.sort_unstable();
+        revoked.dedup();
+        let is_active = |pk: &&PublicKey<B>| revoked.binary_search(&pk.participant_id).is_err();
+
         if public_keys.is_empty() {
             return Err(Error::InvalidConfig(
                 "cannot aggregate empty public key set".into(),
@@ -242,6 +318,7 @@ impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
             {
                 public_keys
                     .par_iter()
+                    .filter(is_active)
                     .map(|pk| pk.lagrange_li)
                     .reduce(B::G1::identity, |acc, val| acc.add(&val))
             }
@@ -249,6 +326,7 @@ impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
             {
                 public_keys
                     .iter()
+                    .filter(is_active)
                     .fold(B::G1::identity(), |acc, pk| acc.add(&pk.lagrange_li))
             }
         };
@@ -260,7 +338,7 @@ impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
                     .into_par_iter()
                     .map(|idx| {
                         let mut row = B::G1::identity();
-                        for pk in public_keys {
+                        for pk in public_keys.iter().filter(is_active) {
                             if let Some(val) = pk.lagrange_li_lj_z.get(idx) {
                                 row = row.add(val);
                             }
@@ -274,7 +352,7 @@ impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
                 (0..parties)
                     .map(|idx| {
                         let mut row = B::G1::identity();
-                        for pk in public_keys {
+                        for pk in public_keys.iter().filter(is_active) {
                             if let Some(val) = pk.lagrange_li_lj_z.get(idx) {
                                 row = row.add(val);
                             }
@@ -297,6 +375,7 @@ impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
             lagrange_row_sums,
             precomputed_pairing: params.srs.e_gh.clone(),
             kzg_params: params.srs.clone(),
+            revoked,
         })
     }
 }
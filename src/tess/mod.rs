@@ -33,16 +33,77 @@ use rand_core::RngCore;
 use crate::{Fr, PairingBackend, errors::Error};
 
 mod scheme;
-pub use scheme::{SilentThreshold, SilentThresholdScheme};
+#[cfg(feature = "std")]
+pub(crate) use scheme::DecryptionTerms;
+pub use scheme::{DEK_LEN, SilentThreshold, SilentThresholdScheme};
 
 mod keys;
 pub use keys::{AggregateKey, PublicKey, SecretKey, UnsafeKeyMaterial};
 
 mod params;
-pub use params::Params;
+pub use params::{ParameterWarning, Params, RECOMMENDED_SECURITY_BITS};
+#[cfg(feature = "borsh")]
+pub(crate) use params::{g1_size, g2_size, target_size};
+
+mod committee;
+pub use committee::{Committee, CommitteeDiff, ParticipantId};
+
+mod registry;
+pub use registry::PublicKeyRegistry;
+
+mod registration;
+pub use registration::{CommitteeRegistration, REGISTRATION_VERSION, RegisteredMember};
 
 mod ciphertext;
-pub use ciphertext::{Ciphertext, DecryptionResult, PartialDecryption};
+pub use ciphertext::{
+    Ciphertext, DecryptionProof, DecryptionResult, PROOF_G1_LEN, PROOF_G2_LEN, PartialDecryption,
+    ProofG1, ProofG2, verify_partial_decryption, verify_partial_decryptions,
+};
+
+mod header;
+pub use header::{
+    CIPHERTEXT_HEADER_VERSION, COMMITTEE_FINGERPRINT_LEN, CiphertextHeader, MAX_LABEL_LEN, SchemeId,
+};
+
+mod fixed;
+pub use fixed::{FixedCiphertext, FixedPublicKey};
+
+mod validity;
+pub use validity::{AggregateKeyDigest, CiphertextProof};
+
+mod reencrypt;
+pub use reencrypt::{
+    AuthenticatedRekeyBatch, ReEncryptedCiphertext, ReEncryptionShare, ShareOriginProof,
+};
+
+mod broadcast;
+
+mod prepared;
+pub use prepared::{EncryptionKey, PreparedAggregateKey};
+
+mod multi;
+pub use multi::{EncryptionTarget, MultiCommitteeCiphertext};
+
+mod signcrypt;
+pub use signcrypt::{SenderKey, SenderSignature};
+
+#[cfg(feature = "hpke")]
+mod envelope;
+#[cfg(feature = "hpke")]
+pub use envelope::{ShareEnvelope, TransportKey};
+
+mod signed_partial;
+pub use signed_partial::{PartialSigningKey, SignedPartialDecryption};
+
+mod identity;
+pub use identity::IdentityKey;
+
+#[cfg(feature = "std")]
+mod age_export;
+#[cfg(feature = "age")]
+pub use age_export::AgeExporter;
+#[cfg(feature = "std")]
+pub use age_export::{decode_share, encode_share};
 
 /// High-level threshold scheme interface.
 ///
@@ -105,23 +166,45 @@ pub trait ThresholdEncryption<B: PairingBackend<Scalar = Fr>>:
     ) -> Result<(SecretKey<B>, PublicKey<B>), Error>;
 
     /// Recomputes the aggregate key from public keys using precomputed Lagrange powers.
+    ///
+    /// Participants listed in `revoked` are excluded from the aggregate; see
+    /// [`AggregateKey::aggregate_keys_with_revocations`].
     fn aggregate_public_key(
         &self,
         public_keys: &[PublicKey<B>],
         params: &Params<B>,
         parties: usize,
+        revoked: &[usize],
     ) -> Result<AggregateKey<B>, Error>;
 
     /// Encrypts a payload using the aggregate key.
-    fn encrypt<R: RngCore + ?Sized>(
+    ///
+    /// `agg_key` may be an [`AggregateKey`] or a [`PreparedAggregateKey`]; the
+    /// latter reuses precomputed tables when encrypting at its threshold.
+    ///
+    /// `threshold` counts partial decryptions from active members. If
+    /// `agg_key` has revoked participants or vacant slots, the ciphertext
+    /// records the raised [`AggregateKey::ciphertext_threshold`].
+    fn encrypt<R: RngCore + ?Sized, K: EncryptionKey<B> + ?Sized>(
         &self,
         rng: &mut R,
-        agg_key: &AggregateKey<B>,
+        agg_key: &K,
         params: &Params<B>,
         threshold: usize,
         payload: &[u8],
     ) -> Result<Ciphertext<B>, Error>;
 
+    /// Checks that a ciphertext is well formed without any secret material.
+    ///
+    /// Relayers and mempools can use this to reject malformed ciphertexts before
+    /// asking the committee for partial decryptions.
+    fn verify_ciphertext(
+        &self,
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        ciphertext: &Ciphertext<B>,
+    ) -> Result<(), Error>;
+
     /// Computes a partial decryption share.
     fn partial_decrypt(
         &self,
@@ -130,11 +213,20 @@ pub trait ThresholdEncryption<B: PairingBackend<Scalar = Fr>>:
     ) -> Result<PartialDecryption<B>, Error>;
 
     /// Aggregates partial decryptions to recover the plaintext.
+    ///
+    /// If the ciphertext carries a sender signature, it is verified before any
+    /// plaintext is released.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InsufficientShares`] before any pairing work if fewer
+    /// than `ciphertext.threshold` distinct partials from unrevoked committee
+    /// members are supplied.
     fn aggregate_decrypt(
         &self,
         ciphertext: &Ciphertext<B>,
         partials: &[PartialDecryption<B>],
         selector: &[bool],
         agg_key: &AggregateKey<B>,
-    ) -> Result<DecryptionResult, Error>;
+    ) -> Result<DecryptionResult<B>, Error>;
 }
@@ -0,0 +1,205 @@
+//! Encryption to any one of several committees.
+//!
+//! A [`MultiCommitteeCiphertext`] encrypts the payload once under a random
+//! data-encryption key and carries one header per committee, each wrapping that
+//! key with [`SilentThresholdScheme::wrap_key`]. Any single committee can
+//! recover the payload (for example the current epoch's committee or an
+//! emergency recovery committee) by decrypting its own header; the headers are
+//! indexed in the order the targets were given at encryption time.
+
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+use tracing::instrument;
+
+use crate::{
+    AggregateKey, Ciphertext, DEK_LEN, DecryptionResult, Fr, PairingBackend, Params,
+    PartialDecryption, SilentThresholdScheme, Xof, errors::Error, sym_enc::SymmetricEncryption,
+};
+
+/// A committee a [`MultiCommitteeCiphertext`] is encrypted to.
+#[derive(Clone, Copy, Debug)]
+pub struct EncryptionTarget<'a, B: PairingBackend<Scalar = Fr>> {
+    /// The committee's aggregate key.
+    pub aggregate_key: &'a AggregateKey<B>,
+    /// Parameters the committee's keys were generated with.
+    pub params: &'a Params<B>,
+    /// Number of the committee's members required to decrypt.
+    pub threshold: usize,
+}
+
+/// Ciphertext recoverable by any one of several committees.
+#[derive(Clone, Debug)]
+pub struct MultiCommitteeCiphertext<B: PairingBackend> {
+    /// One header per committee, each wrapping the payload key.
+    pub headers: Vec<Ciphertext<B>>,
+    /// Payload encrypted under the shared data-encryption key.
+    pub payload: Vec<u8>,
+    /// Whether the payload was compressed before encryption.
+    pub compressed: bool,
+    /// Whether length-hiding padding was applied before encryption.
+    pub padded: bool,
+}
+
+impl<B: PairingBackend> MultiCommitteeCiphertext<B> {
+    /// Returns the header for the committee at `index`, against which its members
+    /// compute partial decryptions.
+    pub fn header(&self, index: usize) -> Result<&Ciphertext<B>, Error> {
+        self.headers
+            .get(index)
+            .ok_or_else(|| Error::MalformedInput("committee header index out of range".into()))
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Encrypts `payload` so that any one of `targets` can decrypt it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `targets` is empty, and any error from
+    /// wrapping the key for a target.
+    #[instrument(level = "info", skip_all, fields(committees = targets.len(), payload_len = payload.len()))]
+    pub fn encrypt_for_any<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        targets: &[EncryptionTarget<'_, B>],
+        payload: &[u8],
+    ) -> Result<MultiCommitteeCiphertext<B>, Error> {
+        if targets.is_empty() {
+            return Err(Error::InvalidConfig(
+                "at least one committee is required".into(),
+            ));
+        }
+        let mut dek = [0u8; DEK_LEN];
+        rng.fill_bytes(&mut dek);
+
+        let headers = targets
+            .iter()
+            .map(|target| {
+                self.wrap_key(
+                    rng,
+                    target.aggregate_key,
+                    target.params,
+                    target.threshold,
+                    &dek,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (payload, compressed) = self.compress_payload(payload.to_vec())?;
+        let padded = self.padding.is_enabled();
+        let mut payload = self.padding.pad(payload);
+        self.symmetric_enc.encrypt_in_place(&dek, &mut payload)?;
+
+        Ok(MultiCommitteeCiphertext {
+            headers,
+            payload,
+            compressed,
+            padded,
+        })
+    }
+
+    /// Decrypts a multi-committee ciphertext using the header at `header`.
+    ///
+    /// `partials` must be partial decryptions of that header by members of the
+    /// committee holding `agg_key`. The result carries no decryption proof, since
+    /// the partials attest to the wrapped key rather than the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if `header` is out of range, and
+    /// otherwise the errors of [`SilentThresholdScheme::unwrap_key`].
+    #[instrument(level = "info", skip_all, fields(header, provided = partials.len()))]
+    pub fn aggregate_decrypt_header(
+        &self,
+        ciphertext: &MultiCommitteeCiphertext<B>,
+        header: usize,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<DecryptionResult<B>, Error> {
+        let dek = self.unwrap_key(ciphertext.header(header)?, partials, selector, agg_key)?;
+        Ok(DecryptionResult {
+            plaintext: Some(self.open_with_key(ciphertext, &dek)?),
+            proof: None,
+        })
+    }
+
+    /// Decrypts a multi-committee payload with an already recovered key, for
+    /// example one read from a 1-of-n header with
+    /// [`SilentThresholdScheme::decrypt_broadcast`].
+    pub fn open_with_key(
+        &self,
+        ciphertext: &MultiCommitteeCiphertext<B>,
+        dek: &[u8; DEK_LEN],
+    ) -> Result<Vec<u8>, Error> {
+        let mut payload = ciphertext.payload.clone();
+        self.symmetric_enc.decrypt_in_place(dek, &mut payload)?;
+        self.restore_payload(payload, ciphertext.padded, ciphertext.compressed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PaddingScheme, PairingEngine, ThresholdEncryption};
+
+    #[test]
+    fn either_committee_decrypts() {
+        let mut rng = StdRng::seed_from_u64(79);
+        let scheme =
+            SilentThresholdScheme::<PairingEngine>::new().with_padding(PaddingScheme::Padme);
+
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+        let current = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+        let recovery_params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let recovery = scheme.keygen_unsafe(&mut rng, 4, &recovery_params).unwrap();
+
+        let targets = [
+            EncryptionTarget {
+                aggregate_key: &current.aggregate_key,
+                params: &params,
+                threshold: 4,
+            },
+            EncryptionTarget {
+                aggregate_key: &recovery.aggregate_key,
+                params: &recovery_params,
+                threshold: 2,
+            },
+        ];
+        let ct = scheme
+            .encrypt_for_any(&mut rng, &targets, b"epoch secret")
+            .unwrap();
+        assert_eq!(ct.headers.len(), 2);
+
+        for (index, keys, share_count) in [(0, &current, 5), (1, &recovery, 3)] {
+            let header = ct.header(index).unwrap();
+            let parties = keys.secret_keys.len();
+            let mut selector = vec![false; parties];
+            let mut partials = Vec::new();
+            for (i, selected) in selector.iter_mut().enumerate().take(share_count) {
+                *selected = true;
+                partials.push(
+                    scheme
+                        .partial_decrypt(&keys.secret_keys[i], header)
+                        .unwrap(),
+                );
+            }
+            let result = scheme
+                .aggregate_decrypt_header(&ct, index, &partials, &selector, &keys.aggregate_key)
+                .unwrap();
+            assert_eq!(result.plaintext.unwrap(), b"epoch secret");
+
+            // Partials for one header do not open the other.
+            let other = 1 - index;
+            assert!(
+                scheme
+                    .aggregate_decrypt_header(&ct, other, &partials, &selector, &keys.aggregate_key)
+                    .is_err()
+            );
+        }
+        assert!(ct.header(2).is_err());
+    }
+}
@@ -24,14 +24,115 @@
 //!
 //! In production deployments, consider using a multi-party computation (MPC)
 //! ceremony to generate the SRS, eliminating the need to trust a single party.
+//! [`KeyCeremony`](crate::KeyCeremony) runs one among the committee members.
 //!
 //! # Reusability
 //!
 //! Once generated, the same parameters can be reused for multiple independent
 //! instances of the threshold encryption scheme, as long as all instances use
 //! the same number of participants.
+//!
+//! # Resizing
+//!
+//! [`Params::from_srs`] derives parameters for any committee size up to the
+//! degree of an existing SRS, and [`SRS::truncate`] and [`SRS::extend_with`]
+//! adjust that degree, so one ceremony output serves committees of many sizes
+//! without regenerating from tau.
+//!
+//! # Size Estimation
+//!
+//! [`Params::ciphertext_size`], [`Params::public_key_size`] and
+//! [`Params::aggregate_key_size`] report how many bytes the group elements of
+//! each artifact occupy in the backend's compressed encoding, which is useful
+//! for budgeting bandwidth and on-chain storage before deployment. Container
+//! framing (length prefixes, flags, participant indices) is not included.
+//!
+//! [`Params::estimate_memory`] projects the in-memory footprint of parameters,
+//! key material and the aggregate key for a committee size without generating
+//! anything, so configurations that would exhaust RAM can be rejected before
+//! setup starts.
+//!
+//! # Auditing
+//!
+//! [`Params::validate`] rejects inconsistent thresholds and reports weak but
+//! usable configurations as [`ParameterWarning`]s, such as backends below
+//! [`RECOMMENDED_SECURITY_BITS`] or thresholds that a small coalition can meet.
+
+use alloc::vec::Vec;
+use core::{fmt, mem::size_of};
+
+use crate::{
+    AggregateKey, CurvePoint, Error, Fr, LagrangePowers, MixedRadixEvaluationDomain, PROOF_G1_LEN,
+    PROOF_G2_LEN, PairingBackend, PublicKey, SRS, SecretKey, TargetGroup,
+};
+
+/// Minimum backend security level, in bits, below which
+/// [`Params::validate`] emits [`ParameterWarning::LowSecurity`].
+pub const RECOMMENDED_SECURITY_BITS: u32 = 112;
+
+/// Non-fatal findings reported by [`Params::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParameterWarning {
+    /// The pairing backend offers less than [`RECOMMENDED_SECURITY_BITS`].
+    LowSecurity {
+        /// Estimated security level of the backend.
+        bits: u32,
+    },
+    /// Fewer than a third of the parties suffice to decrypt.
+    LowThreshold {
+        /// Number of shares required to decrypt.
+        threshold: usize,
+        /// Total number of parties.
+        parties: usize,
+    },
+}
+
+impl fmt::Display for ParameterWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParameterWarning::LowSecurity { bits } => write!(
+                f,
+                "backend offers ~{} bits of security, below the recommended {}",
+                bits, RECOMMENDED_SECURITY_BITS
+            ),
+            ParameterWarning::LowThreshold { threshold, parties } => write!(
+                f,
+                "threshold {} is less than a third of {} parties",
+                threshold, parties
+            ),
+        }
+    }
+}
 
-use crate::{Fr, LagrangePowers, PairingBackend, SRS};
+/// Projected heap and inline memory, in bytes, returned by
+/// [`Params::estimate_memory`].
+///
+/// Counts the group elements, scalars and vector headers of each artifact as
+/// laid out in memory by the backend, which is larger than the compressed
+/// encodings reported by [`Params::aggregate_key_size`] and friends. Allocator
+/// overhead and spare vector capacity are not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryEstimate {
+    /// KZG structured reference string inside [`Params`].
+    pub srs: usize,
+    /// Lagrange commitments inside [`Params`], dominated by the
+    /// `parties × parties` cross terms.
+    pub lagrange_powers: usize,
+    /// Secret and public keys of every party, as returned by keygen.
+    pub key_material: usize,
+    /// Aggregate key, including its copies of the public keys and the SRS.
+    pub aggregate_key: usize,
+}
+
+impl MemoryEstimate {
+    /// Sum of all components.
+    pub fn total(&self) -> usize {
+        self.srs
+            .saturating_add(self.lagrange_powers)
+            .saturating_add(self.key_material)
+            .saturating_add(self.aggregate_key)
+    }
+}
 
 /// Structured Reference String for the threshold encryption scheme.
 ///
@@ -56,3 +157,367 @@ pub struct Params<B: PairingBackend<Scalar = Fr>> {
     /// Precomputed Lagrange polynomial commitments.
     pub lagrange_powers: LagrangePowers<B>,
 }
+
+impl<B: PairingBackend<Scalar = Fr>> Params<B> {
+    /// Builds parameters for a committee of `parties` from an existing SRS.
+    ///
+    /// The SRS is cut down to degree `parties` and the Lagrange commitments are
+    /// derived from its powers with [`LagrangePowers::from_srs`], so one large
+    /// ceremony output can serve committees of many sizes without access to
+    /// tau. The result matches [`ThresholdEncryption::param_gen`] run with the
+    /// same trapdoor.
+    ///
+    /// [`ThresholdEncryption::param_gen`]: crate::ThresholdEncryption::param_gen
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if `parties` is not a supported domain
+    /// size or the SRS degree is below `parties`.
+    pub fn from_srs(srs: &SRS<B>, parties: usize) -> Result<Self, Error> {
+        if MixedRadixEvaluationDomain::new(parties).is_none() {
+            return Err(Error::InvalidConfig(
+                "parties must be of the form 2^a * 3^b supported by the scalar field".into(),
+            ));
+        }
+        if srs.max_degree() < parties {
+            return Err(Error::InvalidConfig(format!(
+                "SRS degree {} is below committee size {parties}",
+                srs.max_degree()
+            )));
+        }
+        let mut srs = srs.clone();
+        srs.truncate(parties).map_err(Error::Backend)?;
+        let lagrange_powers = LagrangePowers::from_srs(&srs, parties).map_err(Error::Backend)?;
+        srs.lagrange_basis_g = lagrange_powers.li.clone();
+        Ok(Self {
+            srs,
+            lagrange_powers,
+        })
+    }
+
+    /// Number of participants these parameters were generated for.
+    pub fn parties(&self) -> usize {
+        self.lagrange_powers.li.len()
+    }
+
+    /// Checks `threshold` against these parameters and audits the combination.
+    ///
+    /// Returns [`Error::InvalidConfig`] if the threshold is unusable, and
+    /// otherwise the list of [`ParameterWarning`]s, which is empty for a
+    /// configuration without known weaknesses.
+    pub fn validate(&self, threshold: usize) -> Result<Vec<ParameterWarning>, Error> {
+        let parties = self.parties();
+        if threshold == 0 {
+            return Err(Error::InvalidConfig(
+                "threshold must be greater than 0".into(),
+            ));
+        }
+        if threshold >= parties {
+            return Err(Error::InvalidConfig(
+                "threshold must be less than parties".into(),
+            ));
+        }
+
+        let mut warnings = Vec::new();
+        let bits = B::security_bits();
+        if bits < RECOMMENDED_SECURITY_BITS {
+            warnings.push(ParameterWarning::LowSecurity { bits });
+        }
+        if threshold * 3 < parties {
+            warnings.push(ParameterWarning::LowThreshold { threshold, parties });
+        }
+        Ok(warnings)
+    }
+
+    /// Compressed size in bytes of a threshold ciphertext carrying a payload
+    /// of `payload_len` bytes.
+    ///
+    /// Counts `gamma_g2`, the [`PROOF_G1_LEN`] G1 and [`PROOF_G2_LEN`] G2
+    /// proof elements, the shared secret and the encrypted payload, none of
+    /// which depend on the committee size or threshold. Optional attachments
+    /// (validity proofs, sender signatures, broadcast keys) are not included.
+    pub fn ciphertext_size(&self, payload_len: usize) -> usize {
+        (1 + PROOF_G2_LEN) * g2_size::<B>()
+            + PROOF_G1_LEN * g1_size::<B>()
+            + target_size::<B>()
+            + payload_len
+    }
+
+    /// Compressed size in bytes of a single participant's public key.
+    pub fn public_key_size(&self) -> usize {
+        let cross_terms = self.lagrange_powers.li_lj_z.first().map_or(0, Vec::len);
+        (4 + cross_terms) * g1_size::<B>()
+    }
+
+    /// Compressed size in bytes of the aggregate key, including the embedded
+    /// public keys and KZG parameters.
+    pub fn aggregate_key_size(&self) -> usize {
+        let g1 = g1_size::<B>();
+        let g2 = g2_size::<B>();
+        let gt = target_size::<B>();
+        let parties = self.parties();
+        let srs = (self.srs.powers_of_g.len() + self.srs.lagrange_basis_g.len()) * g1
+            + self.srs.powers_of_h.len() * g2
+            + gt;
+        parties * self.public_key_size() + (1 + parties) * g1 + g2 + gt + srs
+    }
+
+    /// Projects the memory needed to hold parameters, key material and the
+    /// aggregate key for a committee of `parties`.
+    ///
+    /// Runs in constant time and allocates nothing, so it can gate
+    /// [`param_gen`](crate::ThresholdEncryption::param_gen) and keygen. Sizes
+    /// saturate at `usize::MAX` instead of overflowing.
+    ///
+    /// ```rust
+    /// use tess::{PairingEngine, Params};
+    ///
+    /// let estimate = Params::<PairingEngine>::estimate_memory(1 << 14);
+    /// // The n² Lagrange cross terms dominate large committees.
+    /// assert!(estimate.lagrange_powers > estimate.srs);
+    /// assert!(estimate.total() > 1 << 30);
+    /// ```
+    pub fn estimate_memory(parties: usize) -> MemoryEstimate {
+        let g1 = size_of::<B::G1>();
+        let vec = size_of::<Vec<B::G1>>();
+        let g1_vec = |len: usize| len.saturating_mul(g1).saturating_add(vec);
+
+        let srs = size_of::<SRS<B>>()
+            .saturating_add(parties.saturating_add(1).saturating_mul(g1))
+            .saturating_add(parties.saturating_add(1).saturating_mul(size_of::<B::G2>()))
+            .saturating_add(parties.saturating_mul(g1));
+        let lagrange_powers = size_of::<LagrangePowers<B>>()
+            .saturating_add(parties.saturating_mul(3 * g1))
+            .saturating_add(parties.saturating_mul(g1_vec(parties)));
+        let public_keys = parties
+            .saturating_mul(size_of::<PublicKey<B>>().saturating_add(parties.saturating_mul(g1)));
+        let key_material = parties
+            .saturating_mul(size_of::<SecretKey<B>>())
+            .saturating_add(public_keys);
+        let aggregate_key = size_of::<AggregateKey<B>>()
+            .saturating_add(public_keys)
+            .saturating_add(parties.saturating_mul(g1))
+            .saturating_add(srs - size_of::<SRS<B>>());
+
+        MemoryEstimate {
+            srs,
+            lagrange_powers,
+            key_material,
+            aggregate_key,
+        }
+    }
+}
+
+pub(crate) fn g1_size<B: PairingBackend>() -> usize {
+    B::serialize_g1(&B::G1::generator()).len()
+}
+
+pub(crate) fn g2_size<B: PairingBackend>() -> usize {
+    B::serialize_g2(&B::G2::generator()).len()
+}
+
+pub(crate) fn target_size<B: PairingBackend>() -> usize {
+    B::serialize_target(&B::Target::generator()).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{
+        FieldElement, PairingEngine, SilentThresholdScheme, ThresholdEncryption,
+        arith::cached_lagrange_polys,
+    };
+
+    #[test]
+    fn ciphertext_size_is_independent_of_committee_and_threshold() {
+        type B = PairingEngine;
+        let mut rng = StdRng::seed_from_u64(1478);
+        let scheme = SilentThresholdScheme::<B>::new();
+        let payload = b"constant size";
+        let mut sizes = Vec::new();
+        for (parties, threshold) in [(4, 2), (16, 3), (16, 11)] {
+            let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+            let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+            let ct = scheme
+                .encrypt(&mut rng, &keys.aggregate_key, &params, threshold, payload)
+                .unwrap();
+            assert_eq!(ct.proof_g1.len(), PROOF_G1_LEN);
+            assert_eq!(ct.proof_g2.len(), PROOF_G2_LEN);
+            sizes.push(params.ciphertext_size(payload.len()));
+        }
+        assert!(sizes.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+
+    #[test]
+    fn size_estimates_match_encoded_artifacts() {
+        type B = PairingEngine;
+        let mut rng = StdRng::seed_from_u64(1430);
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+        let payload = b"size estimation payload";
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 4, payload)
+            .unwrap();
+
+        let g1 = |p: &<B as PairingBackend>::G1| B::serialize_g1(p).len();
+        let g2 = |p: &<B as PairingBackend>::G2| B::serialize_g2(p).len();
+
+        let ct_size = g2(&ct.gamma_g2)
+            + ct.proof_g1.iter().map(g1).sum::<usize>()
+            + ct.proof_g2.iter().map(g2).sum::<usize>()
+            + B::serialize_target(&ct.shared_secret).len()
+            + ct.payload.len();
+        assert_eq!(params.ciphertext_size(payload.len()), ct_size);
+
+        let pk = &keys.public_keys[0];
+        let pk_size = g1(&pk.bls_key)
+            + g1(&pk.lagrange_li)
+            + g1(&pk.lagrange_li_minus0)
+            + g1(&pk.lagrange_li_x)
+            + pk.lagrange_li_lj_z.iter().map(g1).sum::<usize>();
+        assert_eq!(params.public_key_size(), pk_size);
+
+        let agg = &keys.aggregate_key;
+        let srs = &agg.kzg_params;
+        let agg_size = agg.public_keys.len() * pk_size
+            + g1(&agg.ask)
+            + g2(&agg.z_g2)
+            + agg.lagrange_row_sums.iter().map(g1).sum::<usize>()
+            + B::serialize_target(&agg.precomputed_pairing).len()
+            + srs.powers_of_g.iter().map(g1).sum::<usize>()
+            + srs.powers_of_h.iter().map(g2).sum::<usize>()
+            + B::serialize_target(&srs.e_gh).len()
+            + srs.lagrange_basis_g.iter().map(g1).sum::<usize>();
+        assert_eq!(params.aggregate_key_size(), agg_size);
+    }
+
+    #[test]
+    fn memory_estimate_matches_generated_artifacts() {
+        type B = PairingEngine;
+        type G1 = <B as PairingBackend>::G1;
+        let mut rng = StdRng::seed_from_u64(1446);
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+
+        let g1 = size_of::<G1>();
+        let srs_heap = |srs: &SRS<B>| {
+            (srs.powers_of_g.len() + srs.lagrange_basis_g.len()) * g1
+                + srs.powers_of_h.len() * size_of::<<B as PairingBackend>::G2>()
+        };
+        let pk_size =
+            |pk: &PublicKey<B>| size_of::<PublicKey<B>>() + pk.lagrange_li_lj_z.len() * g1;
+        let powers = &params.lagrange_powers;
+        let public_keys: usize = keys.public_keys.iter().map(pk_size).sum();
+        let agg = &keys.aggregate_key;
+
+        let estimate = Params::<B>::estimate_memory(8);
+        assert_eq!(estimate.srs, size_of::<SRS<B>>() + srs_heap(&params.srs));
+        assert_eq!(
+            estimate.lagrange_powers,
+            size_of::<LagrangePowers<B>>()
+                + (powers.li.len() + powers.li_minus0.len() + powers.li_x.len()) * g1
+                + powers
+                    .li_lj_z
+                    .iter()
+                    .map(|row| size_of::<Vec<G1>>() + row.len() * g1)
+                    .sum::<usize>()
+        );
+        assert_eq!(
+            estimate.key_material,
+            keys.secret_keys.len() * size_of::<SecretKey<B>>() + public_keys
+        );
+        assert_eq!(
+            estimate.aggregate_key,
+            size_of::<AggregateKey<B>>()
+                + agg.public_keys.iter().map(pk_size).sum::<usize>()
+                + agg.lagrange_row_sums.len() * g1
+                + srs_heap(&agg.kzg_params)
+        );
+        assert_eq!(Params::<B>::estimate_memory(usize::MAX).total(), usize::MAX);
+    }
+
+    #[test]
+    fn validate_reports_warnings() {
+        let mut rng = StdRng::seed_from_u64(1431);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 16, 8).unwrap();
+
+        assert!(matches!(params.validate(0), Err(Error::InvalidConfig(_))));
+        assert!(matches!(params.validate(16), Err(Error::InvalidConfig(_))));
+
+        let low_security = PairingEngine::security_bits() < RECOMMENDED_SECURITY_BITS;
+        let warnings = params.validate(8).unwrap();
+        assert_eq!(warnings.len(), usize::from(low_security));
+
+        let warnings = params.validate(2).unwrap();
+        assert!(warnings.contains(&ParameterWarning::LowThreshold {
+            threshold: 2,
+            parties: 16
+        }));
+    }
+
+    #[test]
+    fn params_from_one_srs_serve_many_committees() {
+        type B = PairingEngine;
+        let mut rng = StdRng::seed_from_u64(1458);
+        let tau = Fr::random(&mut rng);
+        let mut srs = SRS::<B>::new_unsafe(&tau, 4).unwrap();
+        let other = SRS::<B>::new_unsafe(&Fr::random(&mut rng), 12).unwrap();
+        assert!(
+            srs.extend_with(&other.powers_of_g[5..], &other.powers_of_h[5..])
+                .is_err()
+        );
+        assert_eq!(srs.max_degree(), 4);
+
+        let full = SRS::<B>::new_unsafe(&tau, 12).unwrap();
+        srs.extend_with(&full.powers_of_g[5..], &full.powers_of_h[5..])
+            .unwrap();
+        assert_eq!(srs.powers_of_g, full.powers_of_g);
+        assert_eq!(srs.powers_of_h, full.powers_of_h);
+        srs.truncate(6).unwrap();
+        srs.extend_unsafe(&tau, 12).unwrap();
+        assert_eq!(srs.powers_of_g, full.powers_of_g);
+        assert!(srs.truncate(13).is_err());
+
+        for parties in [4, 12] {
+            let params = Params::from_srs(&full, parties).unwrap();
+            let lagranges = cached_lagrange_polys(parties).unwrap();
+            let expected =
+                LagrangePowers::<B>::precompute_lagrange_powers(&lagranges, parties, &tau).unwrap();
+            assert_eq!(params.srs.max_degree(), parties);
+            assert_eq!(params.lagrange_powers.li, expected.li);
+            assert_eq!(params.lagrange_powers.li_minus0, expected.li_minus0);
+            assert_eq!(params.lagrange_powers.li_x, expected.li_x);
+            assert_eq!(params.lagrange_powers.li_lj_z, expected.li_lj_z);
+            assert_eq!(
+                params.srs.vanishing_commitment_g2(parties).unwrap(),
+                full.powers_of_h[parties].sub(&full.powers_of_h[0])
+            );
+        }
+        assert!(Params::from_srs(&full, 16).is_err());
+
+        let scheme = SilentThresholdScheme::<B>::new();
+        let params = Params::from_srs(&full, 4).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"resized")
+            .unwrap();
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let result = scheme
+            .aggregate_decrypt(
+                &ct,
+                &partials,
+                &[true, true, true, false],
+                &keys.aggregate_key,
+            )
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"resized"[..]));
+    }
+}
@@ -0,0 +1,258 @@
+//! Aggregate keys prepared for repeated encryption.
+//!
+//! Every encryption multiplies the same committee-dependent bases (`ask`,
+//! `g^{τ^t}`, `z`, `h^τ`, `h^τ - h`) and the group generators by fresh
+//! randomness. [`AggregateKey::prepare`] builds fixed-base windowed tables for
+//! the committee bases once, after which each multiplication is a handful of
+//! table additions instead of a full double-and-add; the generators go through
+//! the backend's own tables via [`CurvePoint::mul_generator`].
+//! [`ThresholdEncryption::encrypt`]
+//! accepts either an [`AggregateKey`] or a [`PreparedAggregateKey`] through the
+//! [`EncryptionKey`] trait.
+//!
+//! The Lagrange row sums are only used during decryption, where they enter a
+//! multi-scalar multiplication with per-ciphertext scalars, so they are not
+//! tabulated.
+//!
+//! Table lookups are indexed by scalar windows and are not hardened against
+//! cache-timing side channels.
+
+use super::scheme::check_encryption_threshold;
+use crate::{
+    AggregateKey, AggregateKeyDigest, CurvePoint, FixedBaseTable, Fr, PairingBackend, Params,
+    errors::Error,
+};
+
+/// Anything encryption can run against: a plain or prepared aggregate key.
+pub trait EncryptionKey<B: PairingBackend<Scalar = Fr>>: Sync {
+    /// The underlying aggregate key.
+    fn aggregate_key(&self) -> &AggregateKey<B>;
+
+    /// Precomputed bases for `threshold`, if available.
+    #[doc(hidden)]
+    fn prepared_bases(&self, threshold: usize) -> Option<&EncryptionBases<B>> {
+        let _ = threshold;
+        None
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> EncryptionKey<B> for AggregateKey<B> {
+    fn aggregate_key(&self) -> &AggregateKey<B> {
+        self
+    }
+}
+
+/// An aggregate key with precomputed tables for encrypting at one threshold.
+///
+/// # Example
+///
+/// ```rust
+/// use rand::thread_rng;
+/// use tess::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+///
+/// let mut rng = thread_rng();
+/// let scheme = SilentThresholdScheme::<PairingEngine>::new();
+/// let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+/// let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+///
+/// let prepared = keys.aggregate_key.prepare(&params, 4).unwrap();
+/// let ciphertext = scheme.encrypt(&mut rng, &prepared, &params, 4, b"fast").unwrap();
+/// assert_eq!(ciphertext.threshold, 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedAggregateKey<B: PairingBackend<Scalar = Fr>> {
+    aggregate_key: AggregateKey<B>,
+    bases: EncryptionBases<B>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> AggregateKey<B> {
+    /// Precomputes encryption tables for ciphertexts with `threshold`.
+    ///
+    /// `threshold` is the value later passed to encryption; the tables are
+    /// built for the raised [`AggregateKey::ciphertext_threshold`].
+    pub fn prepare(
+        &self,
+        params: &Params<B>,
+        threshold: usize,
+    ) -> Result<PreparedAggregateKey<B>, Error> {
+        let threshold = check_encryption_threshold(self, params, threshold)?;
+        let mut bases = EncryptionBases::new(self, params, threshold)?;
+        bases.tables = Some(BaseTables::new(&bases));
+        Ok(PreparedAggregateKey {
+            aggregate_key: self.clone(),
+            bases,
+        })
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> PreparedAggregateKey<B> {
+    /// Threshold recorded in ciphertexts encrypted with the tables.
+    pub fn threshold(&self) -> usize {
+        self.bases.digest.threshold
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>> EncryptionKey<B> for PreparedAggregateKey<B> {
+    fn aggregate_key(&self) -> &AggregateKey<B> {
+        &self.aggregate_key
+    }
+
+    fn prepared_bases(&self, threshold: usize) -> Option<&EncryptionBases<B>> {
+        (threshold == self.threshold()).then_some(&self.bases)
+    }
+}
+
+/// Aggregate-key-dependent values shared by every encryption to one committee
+/// and threshold.
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+pub struct EncryptionBases<B: PairingBackend<Scalar = Fr>> {
+    pub(super) digest: AggregateKeyDigest<B>,
+    /// `h^τ - h`, the base of the last G2 ciphertext element.
+    h_tau_minus_h: B::G2,
+    tables: Option<BaseTables<B>>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> EncryptionBases<B> {
+    pub(super) fn new(
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        threshold: usize,
+    ) -> Result<Self, Error> {
+        let digest = AggregateKeyDigest::new(agg_key, params, threshold)?;
+        let h_tau_minus_h = digest.h_tau.sub(&B::G2::generator());
+        Ok(Self {
+            digest,
+            h_tau_minus_h,
+            tables: None,
+        })
+    }
+
+    /// `s·ask`
+    pub(super) fn ask(&self, s: &Fr) -> B::G1 {
+        match &self.tables {
+            Some(tables) => tables.ask.mul(s),
+            None => self.digest.ask.mul_scalar(s),
+        }
+    }
+
+    /// `s·g^{τ^t}`
+    pub(super) fn g_tau_threshold(&self, s: &Fr) -> B::G1 {
+        match &self.tables {
+            Some(tables) => tables.g_tau_threshold.mul(s),
+            None => self.digest.g_tau_threshold.mul_scalar(s),
+        }
+    }
+
+    /// `s·g`, through the backend's generator table.
+    pub(super) fn g(&self, s: &Fr) -> B::G1 {
+        B::G1::mul_generator(s)
+    }
+
+    /// `s·h`, through the backend's generator table.
+    pub(super) fn h(&self, s: &Fr) -> B::G2 {
+        B::G2::mul_generator(s)
+    }
+
+    /// `s·z`
+    pub(super) fn z(&self, s: &Fr) -> B::G2 {
+        match &self.tables {
+            Some(tables) => tables.z.mul(s),
+            None => self.digest.z_g2.mul_scalar(s),
+        }
+    }
+
+    /// `s·h^τ`
+    pub(super) fn h_tau(&self, s: &Fr) -> B::G2 {
+        match &self.tables {
+            Some(tables) => tables.h_tau.mul(s),
+            None => self.digest.h_tau.mul_scalar(s),
+        }
+    }
+
+    /// `s·(h^τ - h)`
+    pub(super) fn h_tau_minus_h(&self, s: &Fr) -> B::G2 {
+        match &self.tables {
+            Some(tables) => tables.h_tau_minus_h.mul(s),
+            None => self.h_tau_minus_h.mul_scalar(s),
+        }
+    }
+}
+
+/// Windowed tables for every fixed encryption base.
+#[derive(Clone, Debug)]
+struct BaseTables<B: PairingBackend<Scalar = Fr>> {
+    ask: FixedBaseTable<B::G1>,
+    g_tau_threshold: FixedBaseTable<B::G1>,
+    z: FixedBaseTable<B::G2>,
+    h_tau: FixedBaseTable<B::G2>,
+    h_tau_minus_h: FixedBaseTable<B::G2>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> BaseTables<B> {
+    fn new(bases: &EncryptionBases<B>) -> Self {
+        Self {
+            ask: FixedBaseTable::new(&bases.digest.ask),
+            g_tau_threshold: FixedBaseTable::new(&bases.digest.g_tau_threshold),
+            z: FixedBaseTable::new(&bases.digest.z_g2),
+            h_tau: FixedBaseTable::new(&bases.digest.h_tau),
+            h_tau_minus_h: FixedBaseTable::new(&bases.h_tau_minus_h),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn prepared_key_encrypts_like_plain_key() {
+        let mut rng = StdRng::seed_from_u64(97);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let prepared = keys.aggregate_key.prepare(&params, threshold).unwrap();
+        assert_eq!(prepared.threshold(), threshold);
+
+        // Same randomness through either key form yields the same ciphertext.
+        let plain = scheme
+            .encrypt(
+                &mut StdRng::seed_from_u64(1),
+                &keys.aggregate_key,
+                &params,
+                threshold,
+                b"prepared",
+            )
+            .unwrap();
+        let fast = scheme
+            .encrypt(
+                &mut StdRng::seed_from_u64(1),
+                &prepared,
+                &params,
+                threshold,
+                b"prepared",
+            )
+            .unwrap();
+        assert_eq!(plain.payload, fast.payload);
+        for (a, b) in plain.proof_g2.iter().zip(&fast.proof_g2) {
+            assert!(a.sub(b).is_identity());
+        }
+        scheme
+            .verify_ciphertext(&keys.aggregate_key, &params, &fast)
+            .unwrap();
+
+        // Other thresholds fall back to the unprepared path.
+        let other = scheme
+            .encrypt(&mut rng, &prepared, &params, threshold - 1, b"fallback")
+            .unwrap();
+        scheme
+            .verify_ciphertext(&keys.aggregate_key, &params, &other)
+            .unwrap();
+    }
+}
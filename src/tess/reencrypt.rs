@@ -0,0 +1,889 @@
+//! Re-encryption of ciphertexts to a new committee.
+//!
+//! When committee membership changes, existing ciphertexts can be moved to the
+//! new committee's [`AggregateKey`] without any party learning the plaintext:
+//!
+//! 1. Each participating old member computes its partial decryption and, instead
+//!    of publishing it, threshold-encrypts it to the new committee
+//!    ([`SilentThresholdScheme::re_encrypt_share`]). The share carries a
+//!    [`ShareOriginProof`], a Schnorr proof that it was produced by the holder of
+//!    the member's secret key, bound to both the original ciphertext and the
+//!    encrypted partial.
+//! 2. Any coordinator checks the origin proofs and bundles at least `t` shares
+//!    with the original ciphertext ([`SilentThresholdScheme::re_encrypt`]).
+//!    Together the shares act as the re-encryption key for that ciphertext.
+//! 3. The new committee decrypts the shares with its own partial decryptions
+//!    ([`SilentThresholdScheme::partial_decrypt_re_encrypted`]), and the
+//!    aggregator checks every recovered old partial against the old member's
+//!    public key before combining them
+//!    ([`SilentThresholdScheme::aggregate_decrypt_re_encrypted`]).
+//!
+//! The origin proof is **not** a validity proof: it does not show that the
+//! encrypted partial is the member's correct partial decryption, since no proof
+//! here relates the encrypted bytes to `sk * gamma_g2`, and the partial is
+//! hidden under the new committee's key, so bundling cannot check it either. A
+//! faulty member can contribute a share that passes [`SilentThresholdScheme::re_encrypt`] and
+//! [`SilentThresholdScheme::rekey_ciphertexts`] yet does not decrypt to a valid
+//! partial. Such shares are only detected during aggregation, which skips them,
+//! so a coordinator that bundles `t + f` shares (always including participant
+//! 0's) tolerates up to `f` faulty members. A bundle of exactly `t` shares is
+//! decryptable only if every member was honest.
+//!
+//! Old partials are only ever visible to whoever aggregates new-committee
+//! decryptions, which is the same trust placed in a regular decryption
+//! aggregator. The old committee never sees the plaintext, and fewer than `t`
+//! members of the new committee learn nothing.
+
+use alloc::vec::Vec;
+
+use blake3::Hasher;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use tracing::{debug, instrument};
+
+use crate::{
+    AggregateKey, Ciphertext, CurvePoint, DecryptionResult, FieldElement, Fr, MsmProvider,
+    PairingBackend, Params, PartialDecryption, SecretKey, SilentThresholdScheme, TargetGroup,
+    ThresholdEncryption, Xof, errors::Error, verify_partial_decryption,
+};
+
+/// Schnorr proof of knowledge of a participant's secret key, authenticating
+/// the origin of a re-encryption share.
+///
+/// Proves knowledge of `sk` such that `bls_key = sk * g`, bound via Fiat-Shamir
+/// to the ciphertext being re-encrypted and to the encrypted partial. It is not
+/// a validity proof and says nothing about what the encrypted partial
+/// contains; see the module docs.
+#[derive(Clone, Debug)]
+pub struct ShareOriginProof<B: PairingBackend> {
+    /// Commitment `r * g`.
+    pub commitment: B::G1,
+    /// Response `r + c * sk`.
+    pub response: B::Scalar,
+}
+
+/// An old committee member's contribution to re-encrypting a ciphertext.
+#[derive(Clone, Debug)]
+pub struct ReEncryptionShare<B: PairingBackend> {
+    /// Old committee participant identifier.
+    pub participant_id: usize,
+    /// The member's partial decryption, encrypted to the new committee.
+    pub encrypted_partial: Ciphertext<B>,
+    /// Proof that the share was produced by the member's secret key holder.
+    pub origin_proof: ShareOriginProof<B>,
+}
+
+/// A ciphertext re-targeted to a new committee.
+#[derive(Clone, Debug)]
+pub struct ReEncryptedCiphertext<B: PairingBackend> {
+    /// The original ciphertext under the old committee's key.
+    pub original: Ciphertext<B>,
+    /// Authenticated re-encryption shares, sorted by participant identifier.
+    pub shares: Vec<ReEncryptionShare<B>>,
+}
+
+impl<B: PairingBackend> ReEncryptedCiphertext<B> {
+    /// Number of shares beyond the original threshold.
+    ///
+    /// This is how many shares carrying wrong partials the bundle can absorb
+    /// and still decrypt. With zero spares, decryption needs every contributing
+    /// old member to have been honest.
+    pub fn spare_shares(&self) -> usize {
+        self.shares.len().saturating_sub(self.original.threshold)
+    }
+}
+
+/// Result of [`SilentThresholdScheme::rekey_ciphertexts`]: ciphertexts whose
+/// re-encryption shares all carry valid origin proofs.
+///
+/// Passing the batch check says who produced each share, not that the shares
+/// decrypt. Whether a ciphertext survives faulty members depends on its
+/// [`spare_shares`](ReEncryptedCiphertext::spare_shares), and
+/// [`without_spares`](Self::without_spares) lists the ones that do not.
+#[derive(Clone, Debug)]
+pub struct AuthenticatedRekeyBatch<B: PairingBackend> {
+    /// Re-encrypted ciphertexts, in the order of the input ciphertexts.
+    pub ciphertexts: Vec<ReEncryptedCiphertext<B>>,
+}
+
+impl<B: PairingBackend> AuthenticatedRekeyBatch<B> {
+    /// Indices of ciphertexts bundled with exactly `threshold` shares, which
+    /// become undecryptable if any contributing member sent a wrong partial.
+    pub fn without_spares(&self) -> impl Iterator<Item = usize> + '_ {
+        self.ciphertexts
+            .iter()
+            .enumerate()
+            .filter(|(_, ciphertext)| ciphertext.spare_shares() == 0)
+            .map(|(index, _)| index)
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Produces an old committee member's re-encryption share for `ciphertext`.
+    ///
+    /// The member's partial decryption is encrypted to `new_agg_key` with
+    /// `new_threshold`, so only the new committee can recover it.
+    #[instrument(level = "trace", skip_all, fields(participant_id = secret_key.participant_id))]
+    pub fn re_encrypt_share<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        secret_key: &SecretKey<B>,
+        ciphertext: &Ciphertext<B>,
+        new_agg_key: &AggregateKey<B>,
+        new_params: &Params<B>,
+        new_threshold: usize,
+    ) -> Result<ReEncryptionShare<B>, Error> {
+        let partial = self.partial_decrypt(secret_key, ciphertext)?;
+        let encrypted_partial = self.without_payload_transforms().encrypt_owned(
+            rng,
+            new_agg_key,
+            new_params,
+            new_threshold,
+            partial.response.to_repr().as_ref().to_vec(),
+        )?;
+
+        let g = B::G1::generator();
+        let bls_key = g.mul_scalar(&secret_key.scalar);
+        let nonce = Fr::random(rng);
+        let commitment = g.mul_scalar(&nonce);
+        let challenge = origin_challenge::<B>(
+            secret_key.participant_id,
+            &bls_key,
+            &commitment,
+            ciphertext,
+            &encrypted_partial,
+        );
+        let response = nonce + challenge * secret_key.scalar;
+
+        Ok(ReEncryptionShare {
+            participant_id: secret_key.participant_id,
+            encrypted_partial,
+            origin_proof: ShareOriginProof {
+                commitment,
+                response,
+            },
+        })
+    }
+
+    /// Checks a re-encryption share's origin proof against the old aggregate key.
+    ///
+    /// This authenticates the share's sender only. A share that passes may
+    /// still carry a wrong partial; that is only detected when it is decrypted.
+    pub fn verify_share_origin(
+        &self,
+        share: &ReEncryptionShare<B>,
+        ciphertext: &Ciphertext<B>,
+        old_agg_key: &AggregateKey<B>,
+    ) -> Result<(), Error> {
+        let terms = origin_terms(share, ciphertext, old_agg_key)?;
+        let lhs = B::G1::mul_generator(&terms.response);
+        let rhs = terms
+            .commitment
+            .add(&terms.bls_key.mul_scalar(&terms.challenge));
+        if !lhs.sub(&rhs).is_identity() {
+            return Err(Error::MalformedInput(
+                "invalid re-encryption share origin proof".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Transforms `ciphertext` to target the new committee using authenticated
+    /// shares.
+    ///
+    /// This does not ensure the result is decryptable: a share with a valid
+    /// origin proof may still carry a wrong partial, which is only detected by
+    /// [`aggregate_decrypt_re_encrypted`](Self::aggregate_decrypt_re_encrypted).
+    /// Supply more than `ciphertext.threshold` shares to tolerate faulty
+    /// members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any origin proof fails, if participant ids are
+    /// duplicated, if participant 0 (which anchors interpolation) is missing, or
+    /// if fewer than `ciphertext.threshold` shares are supplied.
+    #[instrument(level = "info", skip_all, fields(required = ciphertext.threshold, provided = shares.len()))]
+    pub fn re_encrypt(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        shares: Vec<ReEncryptionShare<B>>,
+        old_agg_key: &AggregateKey<B>,
+    ) -> Result<ReEncryptedCiphertext<B>, Error> {
+        let shares = check_share_set(ciphertext, shares)?;
+        for share in &shares {
+            self.verify_share_origin(share, ciphertext, old_agg_key)?;
+        }
+
+        Ok(ReEncryptedCiphertext {
+            original: ciphertext.clone(),
+            shares,
+        })
+    }
+
+    /// Produces one member's re-encryption shares for a batch of ciphertexts.
+    ///
+    /// Equivalent to calling [`re_encrypt_share`](Self::re_encrypt_share) for each
+    /// ciphertext, with the shares computed in parallel when the `parallel`
+    /// feature is enabled.
+    #[instrument(level = "info", skip_all, fields(participant_id = secret_key.participant_id, ciphertexts = ciphertexts.len()))]
+    pub fn re_encrypt_shares<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        secret_key: &SecretKey<B>,
+        ciphertexts: &[Ciphertext<B>],
+        new_agg_key: &AggregateKey<B>,
+        new_params: &Params<B>,
+        new_threshold: usize,
+    ) -> Result<Vec<ReEncryptionShare<B>>, Error> {
+        let seeds = ciphertexts
+            .iter()
+            .map(|_| {
+                let mut seed = [0u8; 32];
+                rng.fill_bytes(&mut seed);
+                seed
+            })
+            .collect::<Vec<_>>();
+        let share_for = |(ciphertext, seed): (&Ciphertext<B>, &[u8; 32])| {
+            let mut rng = ChaCha20Rng::from_seed(*seed);
+            self.re_encrypt_share(
+                &mut rng,
+                secret_key,
+                ciphertext,
+                new_agg_key,
+                new_params,
+                new_threshold,
+            )
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            ciphertexts
+                .par_iter()
+                .zip(seeds.par_iter())
+                .map(share_for)
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            ciphertexts
+                .iter()
+                .zip(seeds.iter())
+                .map(share_for)
+                .collect()
+        }
+    }
+
+    /// Re-keys a batch of stored ciphertexts to a new committee.
+    ///
+    /// `rekey_material[i]` holds the re-encryption shares for `old_ctxts[i]`. All
+    /// share origin proofs across the batch are checked together with a single
+    /// randomized multi-scalar multiplication, which is much cheaper than
+    /// verifying each proof separately; `rng` supplies the random weights.
+    ///
+    /// As with [`re_encrypt`](Self::re_encrypt), only the share origin proofs
+    /// are checked, not the encrypted partials. A successful return therefore
+    /// means every share is authentic, not that the ciphertexts decrypt; use
+    /// [`AuthenticatedRekeyBatch::without_spares`] to find the ciphertexts that
+    /// a single faulty member would make undecryptable.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error found, with the same conditions as
+    /// [`re_encrypt`](Self::re_encrypt), or [`Error::MalformedInput`] if the
+    /// inputs have different lengths.
+    #[instrument(level = "info", skip_all, fields(ciphertexts = old_ctxts.len()))]
+    pub fn rekey_ciphertexts<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        old_ctxts: &[Ciphertext<B>],
+        rekey_material: Vec<Vec<ReEncryptionShare<B>>>,
+        old_agg_key: &AggregateKey<B>,
+    ) -> Result<AuthenticatedRekeyBatch<B>, Error> {
+        if old_ctxts.len() != rekey_material.len() {
+            return Err(Error::MalformedInput(
+                "rekey material does not match ciphertext count".into(),
+            ));
+        }
+        let share_sets = old_ctxts
+            .iter()
+            .zip(rekey_material)
+            .map(|(ciphertext, shares)| check_share_set(ciphertext, shares))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let pairs = old_ctxts
+            .iter()
+            .zip(&share_sets)
+            .flat_map(|(ciphertext, shares)| shares.iter().map(move |share| (ciphertext, share)))
+            .collect::<Vec<_>>();
+        let terms = {
+            #[cfg(feature = "parallel")]
+            {
+                pairs
+                    .par_iter()
+                    .map(|(ciphertext, share)| origin_terms(share, ciphertext, old_agg_key))
+                    .collect::<Result<Vec<_>, Error>>()?
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                pairs
+                    .iter()
+                    .map(|(ciphertext, share)| origin_terms(share, ciphertext, old_agg_key))
+                    .collect::<Result<Vec<_>, Error>>()?
+            }
+        };
+
+        // sum_i w_i * (z_i * g - R_i - c_i * pk_i) == 0 for random weights w_i.
+        let mut bases = Vec::with_capacity(2 * terms.len() + 1);
+        let mut scalars = Vec::with_capacity(2 * terms.len() + 1);
+        let mut generator_scalar = Fr::zero();
+        for term in &terms {
+            let weight = Fr::random(rng);
+            generator_scalar += weight * term.response;
+            bases.push(term.commitment);
+            scalars.push(-weight);
+            bases.push(term.bls_key);
+            scalars.push(-(weight * term.challenge));
+        }
+        bases.push(B::G1::generator());
+        scalars.push(generator_scalar);
+        let combined = B::Msm::msm_g1(&bases, &scalars).map_err(Error::Backend)?;
+        if !combined.is_identity() {
+            // Locate the offending share for a precise error.
+            for (ciphertext, share) in &pairs {
+                self.verify_share_origin(share, ciphertext, old_agg_key)?;
+            }
+            return Err(Error::MalformedInput(
+                "invalid re-encryption share origin proof".into(),
+            ));
+        }
+
+        Ok(AuthenticatedRekeyBatch {
+            ciphertexts: old_ctxts
+                .iter()
+                .zip(share_sets)
+                .map(|(ciphertext, shares)| ReEncryptedCiphertext {
+                    original: ciphertext.clone(),
+                    shares,
+                })
+                .collect(),
+        })
+    }
+
+    /// Computes a new committee member's partial decryptions of a re-encrypted
+    /// ciphertext, one per share in share order.
+    #[instrument(level = "trace", skip_all, fields(participant_id = secret_key.participant_id))]
+    pub fn partial_decrypt_re_encrypted(
+        &self,
+        secret_key: &SecretKey<B>,
+        re_encrypted: &ReEncryptedCiphertext<B>,
+    ) -> Result<Vec<PartialDecryption<B>>, Error> {
+        re_encrypted
+            .shares
+            .iter()
+            .map(|share| self.partial_decrypt(secret_key, &share.encrypted_partial))
+            .collect()
+    }
+
+    /// Recovers the plaintext of a re-encrypted ciphertext.
+    ///
+    /// `new_partials` holds one entry per contributing new committee member, as
+    /// returned by [`partial_decrypt_re_encrypted`](Self::partial_decrypt_re_encrypted),
+    /// and `new_selector` marks those members. Each recovered old partial is
+    /// checked against the old member's public key before it is used; shares
+    /// that do not decrypt to a valid partial are skipped, and decryption
+    /// succeeds if the remaining shares still meet the threshold.
+    ///
+    /// # Errors
+    ///
+    /// If the valid shares are not enough to decrypt, returns the error of the
+    /// first rejected share, such as [`Error::MalformedInput`] for a partial
+    /// that does not match the participant's key.
+    #[instrument(level = "info", skip_all, fields(shares = re_encrypted.shares.len(), provided = new_partials.len()))]
+    pub fn aggregate_decrypt_re_encrypted(
+        &self,
+        re_encrypted: &ReEncryptedCiphertext<B>,
+        new_partials: &[Vec<PartialDecryption<B>>],
+        new_selector: &[bool],
+        new_agg_key: &AggregateKey<B>,
+        old_agg_key: &AggregateKey<B>,
+    ) -> Result<DecryptionResult<B>, Error>
+    where
+        <B::G2 as CurvePoint<Fr>>::Repr: From<Vec<u8>>,
+    {
+        let share_count = re_encrypted.shares.len();
+        if new_partials
+            .iter()
+            .any(|member| member.len() != share_count)
+        {
+            return Err(Error::MalformedInput(
+                "partial count does not match re-encryption shares".into(),
+            ));
+        }
+
+        let original = &re_encrypted.original;
+        let mut old_selector = vec![false; old_agg_key.public_keys.len()];
+        let mut old_partials = Vec::with_capacity(share_count);
+        let mut first_rejection = None;
+        for (index, share) in re_encrypted.shares.iter().enumerate() {
+            let member_partials = new_partials
+                .iter()
+                .map(|member| member[index].clone())
+                .collect::<Vec<_>>();
+            match self.recover_old_partial(
+                share,
+                original,
+                &member_partials,
+                new_selector,
+                new_agg_key,
+                old_agg_key,
+            ) {
+                Ok(partial) => {
+                    old_selector[share.participant_id] = true;
+                    old_partials.push(partial);
+                }
+                Err(err) => {
+                    debug!(participant_id = share.participant_id, error = %err, "skipping re-encryption share");
+                    first_rejection.get_or_insert(err);
+                }
+            }
+        }
+
+        match (
+            self.aggregate_decrypt(original, &old_partials, &old_selector, old_agg_key),
+            first_rejection,
+        ) {
+            (Err(_), Some(rejection)) => Err(rejection),
+            (result, _) => result,
+        }
+    }
+
+    /// Decrypts one share's encrypted partial and checks it against the old
+    /// member's public key.
+    fn recover_old_partial(
+        &self,
+        share: &ReEncryptionShare<B>,
+        original: &Ciphertext<B>,
+        member_partials: &[PartialDecryption<B>],
+        new_selector: &[bool],
+        new_agg_key: &AggregateKey<B>,
+        old_agg_key: &AggregateKey<B>,
+    ) -> Result<PartialDecryption<B>, Error>
+    where
+        <B::G2 as CurvePoint<Fr>>::Repr: From<Vec<u8>>,
+    {
+        let decrypted = self.aggregate_decrypt(
+            &share.encrypted_partial,
+            member_partials,
+            new_selector,
+            new_agg_key,
+        )?;
+        let response = decode_partial::<B>(decrypted.plaintext.as_deref().unwrap_or(&[]))?;
+
+        let public_key = old_agg_key
+            .public_keys
+            .get(share.participant_id)
+            .ok_or_else(|| Error::MalformedInput("re-encryption share id out of range".into()))?;
+        let partial = PartialDecryption {
+            participant_id: share.participant_id,
+            response,
+        };
+        if !verify_partial_decryption(public_key, original, &partial) {
+            return Err(Error::MalformedInput(
+                "re-encrypted partial does not match participant key".into(),
+            ));
+        }
+        Ok(partial)
+    }
+}
+
+/// Sorts a ciphertext's re-encryption shares and checks they form a usable set.
+fn check_share_set<B: PairingBackend>(
+    ciphertext: &Ciphertext<B>,
+    mut shares: Vec<ReEncryptionShare<B>>,
+) -> Result<Vec<ReEncryptionShare<B>>, Error> {
+    shares.sort_by_key(|share| share.participant_id);
+    if shares
+        .windows(2)
+        .any(|pair| pair[0].participant_id == pair[1].participant_id)
+    {
+        return Err(Error::MalformedInput(
+            "duplicate re-encryption share".into(),
+        ));
+    }
+    if shares.len() < ciphertext.threshold {
+        return Err(Error::InsufficientShares {
+            required: ciphertext.threshold,
+            provided: shares.len(),
+        });
+    }
+    if shares.first().map(|share| share.participant_id) != Some(0) {
+        return Err(Error::MalformedInput(
+            "re-encryption requires a share from participant 0".into(),
+        ));
+    }
+    Ok(shares)
+}
+
+/// Values entering the verification equation `z * g == R + c * pk` of an origin proof.
+struct OriginTerms<B: PairingBackend> {
+    commitment: B::G1,
+    bls_key: B::G1,
+    response: B::Scalar,
+    challenge: B::Scalar,
+}
+
+/// Looks up the share's public key and recomputes its origin proof challenge.
+fn origin_terms<B: PairingBackend<Scalar = Fr>>(
+    share: &ReEncryptionShare<B>,
+    ciphertext: &Ciphertext<B>,
+    old_agg_key: &AggregateKey<B>,
+) -> Result<OriginTerms<B>, Error> {
+    let public_key = old_agg_key
+        .public_keys
+        .get(share.participant_id)
+        .ok_or_else(|| Error::MalformedInput("re-encryption share id out of range".into()))?;
+    let challenge = origin_challenge::<B>(
+        share.participant_id,
+        &public_key.bls_key,
+        &share.origin_proof.commitment,
+        ciphertext,
+        &share.encrypted_partial,
+    );
+    Ok(OriginTerms {
+        commitment: share.origin_proof.commitment,
+        bls_key: public_key.bls_key,
+        response: share.origin_proof.response,
+        challenge,
+    })
+}
+
+/// Decodes a G2 partial decryption response from its byte representation.
+fn decode_partial<B: PairingBackend>(bytes: &[u8]) -> Result<B::G2, Error>
+where
+    <B::G2 as CurvePoint<B::Scalar>>::Repr: From<Vec<u8>>,
+{
+    let expected = B::G2::generator().to_repr().as_ref().len();
+    if bytes.len() != expected {
+        return Err(Error::MalformedInput(
+            "re-encrypted partial has invalid length".into(),
+        ));
+    }
+    B::G2::from_repr(&bytes.to_vec().into()).map_err(Error::Backend)
+}
+
+/// Derives the Fiat-Shamir challenge for a share origin proof.
+fn origin_challenge<B: PairingBackend<Scalar = Fr>>(
+    participant_id: usize,
+    bls_key: &B::G1,
+    commitment: &B::G1,
+    ciphertext: &Ciphertext<B>,
+    encrypted_partial: &Ciphertext<B>,
+) -> Fr {
+    let mut hasher = Hasher::new();
+    hasher.update(b"tess::re-encryption-proof");
+    hasher.update(&(participant_id as u64).to_le_bytes());
+    hasher.update(bls_key.to_repr().as_ref());
+    hasher.update(commitment.to_repr().as_ref());
+    hasher.update(ciphertext.gamma_g2.to_repr().as_ref());
+    hasher.update(ciphertext.shared_secret.to_repr().as_ref());
+    hasher.update(encrypted_partial.gamma_g2.to_repr().as_ref());
+    hasher.update(encrypted_partial.shared_secret.to_repr().as_ref());
+    hasher.update(&encrypted_partial.payload);
+    let mut rng = ChaCha20Rng::from_seed(*hasher.finalize().as_bytes());
+    Fr::random(&mut rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::PairingEngine;
+
+    #[test]
+    fn re_encryption_moves_ciphertext_to_new_committee() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let old_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let new_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let payload = b"carried across committees";
+        let ct = scheme
+            .encrypt(
+                &mut rng,
+                &old_keys.aggregate_key,
+                &params,
+                threshold,
+                payload,
+            )
+            .unwrap();
+
+        let shares = old_keys.secret_keys[..threshold]
+            .iter()
+            .map(|sk| {
+                scheme
+                    .re_encrypt_share(
+                        &mut rng,
+                        sk,
+                        &ct,
+                        &new_keys.aggregate_key,
+                        &params,
+                        threshold,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let re_ct = scheme
+            .re_encrypt(&ct, shares, &old_keys.aggregate_key)
+            .unwrap();
+
+        let mut new_selector = vec![false; parties];
+        let mut new_partials = Vec::with_capacity(threshold);
+        for (i, selected) in new_selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            new_partials.push(
+                scheme
+                    .partial_decrypt_re_encrypted(&new_keys.secret_keys[i], &re_ct)
+                    .unwrap(),
+            );
+        }
+
+        let result = scheme
+            .aggregate_decrypt_re_encrypted(
+                &re_ct,
+                &new_partials,
+                &new_selector,
+                &new_keys.aggregate_key,
+                &old_keys.aggregate_key,
+            )
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&payload[..]));
+    }
+
+    #[test]
+    fn rekey_ciphertexts_batch() {
+        let mut rng = StdRng::seed_from_u64(31);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let old_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let new_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let payloads = [b"first".to_vec(), b"second".to_vec(), b"third".to_vec()];
+        let ciphertexts = payloads
+            .iter()
+            .map(|payload| {
+                scheme
+                    .encrypt(
+                        &mut rng,
+                        &old_keys.aggregate_key,
+                        &params,
+                        threshold,
+                        payload,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Each member re-encrypts the whole archive; regroup per ciphertext.
+        let mut rekey_material = vec![Vec::new(); ciphertexts.len()];
+        for sk in &old_keys.secret_keys[..threshold] {
+            let shares = scheme
+                .re_encrypt_shares(
+                    &mut rng,
+                    sk,
+                    &ciphertexts,
+                    &new_keys.aggregate_key,
+                    &params,
+                    threshold,
+                )
+                .unwrap();
+            for (material, share) in rekey_material.iter_mut().zip(shares) {
+                material.push(share);
+            }
+        }
+
+        let mut tampered = rekey_material.clone();
+        tampered[2][1].origin_proof.response = Fr::one();
+        let res =
+            scheme.rekey_ciphertexts(&mut rng, &ciphertexts, tampered, &old_keys.aggregate_key);
+        assert!(matches!(res, Err(Error::MalformedInput(_))));
+
+        let rekeyed = scheme
+            .rekey_ciphertexts(
+                &mut rng,
+                &ciphertexts,
+                rekey_material,
+                &old_keys.aggregate_key,
+            )
+            .unwrap();
+
+        let mut new_selector = vec![false; parties];
+        for selected in new_selector.iter_mut().take(threshold) {
+            *selected = true;
+        }
+        // Exactly `threshold` shares each: authentic, but with no spares.
+        assert!(rekeyed.without_spares().eq(0..ciphertexts.len()));
+        for (re_ct, payload) in rekeyed.ciphertexts.iter().zip(&payloads) {
+            let new_partials = new_keys.secret_keys[..threshold]
+                .iter()
+                .map(|sk| scheme.partial_decrypt_re_encrypted(sk, re_ct).unwrap())
+                .collect::<Vec<_>>();
+            let result = scheme
+                .aggregate_decrypt_re_encrypted(
+                    re_ct,
+                    &new_partials,
+                    &new_selector,
+                    &new_keys.aggregate_key,
+                    &old_keys.aggregate_key,
+                )
+                .unwrap();
+            assert_eq!(result.plaintext.as_ref(), Some(payload));
+        }
+    }
+
+    #[test]
+    fn re_encrypt_rejects_forged_share() {
+        let mut rng = StdRng::seed_from_u64(29);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let old_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let new_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let ct = scheme
+            .encrypt(&mut rng, &old_keys.aggregate_key, &params, threshold, b"x")
+            .unwrap();
+
+        let mut shares = old_keys.secret_keys[..threshold]
+            .iter()
+            .map(|sk| {
+                scheme
+                    .re_encrypt_share(
+                        &mut rng,
+                        sk,
+                        &ct,
+                        &new_keys.aggregate_key,
+                        &params,
+                        threshold,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        // Claim participant 1's share came from participant 5.
+        shares[1].participant_id = 5;
+
+        let res = scheme.re_encrypt(&ct, shares, &old_keys.aggregate_key);
+        assert!(matches!(res, Err(Error::MalformedInput(_))));
+    }
+
+    #[test]
+    fn aggregation_skips_shares_with_wrong_partials() {
+        let mut rng = StdRng::seed_from_u64(1395);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let old_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let new_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &old_keys.aggregate_key, &params, threshold, b"y")
+            .unwrap();
+
+        let mut shares = old_keys.secret_keys[..=threshold]
+            .iter()
+            .map(|sk| {
+                scheme
+                    .re_encrypt_share(
+                        &mut rng,
+                        sk,
+                        &ct,
+                        &new_keys.aggregate_key,
+                        &params,
+                        threshold,
+                    )
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Participant 2 encrypts a random point instead of its partial, with
+        // an honest origin proof over the bogus ciphertext.
+        let sk = &old_keys.secret_keys[2];
+        let bogus: <PairingEngine as PairingBackend>::G2 =
+            CurvePoint::mul_generator(&Fr::random(&mut rng));
+        let bogus_repr = bogus.to_repr();
+        let bogus_bytes: &[u8] = bogus_repr.as_ref();
+        let encrypted_partial = scheme
+            .without_payload_transforms()
+            .encrypt_owned(
+                &mut rng,
+                &new_keys.aggregate_key,
+                &params,
+                threshold,
+                bogus_bytes.to_vec(),
+            )
+            .unwrap();
+        let g = <PairingEngine as PairingBackend>::G1::generator();
+        let nonce = Fr::random(&mut rng);
+        let commitment = g.mul_scalar(&nonce);
+        let challenge = origin_challenge::<PairingEngine>(
+            2,
+            &g.mul_scalar(&sk.scalar),
+            &commitment,
+            &ct,
+            &encrypted_partial,
+        );
+        shares[2] = ReEncryptionShare {
+            participant_id: 2,
+            encrypted_partial,
+            origin_proof: ShareOriginProof {
+                commitment,
+                response: nonce + challenge * sk.scalar,
+            },
+        };
+
+        let mut new_selector = vec![false; parties];
+        for selected in new_selector.iter_mut().take(threshold) {
+            *selected = true;
+        }
+        let decrypt = |re_ct: &ReEncryptedCiphertext<PairingEngine>| {
+            let new_partials = new_keys.secret_keys[..threshold]
+                .iter()
+                .map(|sk| scheme.partial_decrypt_re_encrypted(sk, re_ct).unwrap())
+                .collect::<Vec<_>>();
+            scheme.aggregate_decrypt_re_encrypted(
+                re_ct,
+                &new_partials,
+                &new_selector,
+                &new_keys.aggregate_key,
+                &old_keys.aggregate_key,
+            )
+        };
+
+        // Bundling accepts the faulty share; a spare honest share covers it.
+        let re_ct = scheme
+            .re_encrypt(&ct, shares.clone(), &old_keys.aggregate_key)
+            .unwrap();
+        assert_eq!(re_ct.spare_shares(), 1);
+        let result = decrypt(&re_ct).unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"y"[..]));
+
+        // Without a spare share the faulty one makes the bundle undecryptable.
+        shares.truncate(threshold);
+        let re_ct = scheme
+            .re_encrypt(&ct, shares, &old_keys.aggregate_key)
+            .unwrap();
+        assert!(matches!(decrypt(&re_ct), Err(Error::MalformedInput(_))));
+    }
+}
@@ -0,0 +1,234 @@
+//! Committee registration payloads for on-chain programs.
+//!
+//! A Solana or EVM program that routes ciphertexts to a committee, or checks
+//! partial decryptions, needs the committee's BLS keys and the fingerprint
+//! ciphertext headers carry, but not its Lagrange hints or SRS-derived
+//! elements. [`CommitteeRegistration`] is that subset, and
+//! [`CommitteeRegistration::to_bytes`] writes it in a fixed little-endian
+//! layout that a program can parse without a serialization framework:
+//!
+//! | Field | Size | Content |
+//! |-------|------|---------|
+//! | version | 1 | [`REGISTRATION_VERSION`] |
+//! | fingerprint | 32 | [`AggregateKey::fingerprint`] |
+//! | key length | 2 | `u16`, bytes per BLS key |
+//! | member count | 4 | `u32` |
+//! | members | count × (9 + key length) | `u64` weight, `u8` active flag, BLS key |
+//!
+//! Members appear in participant id order, so a member's position is its id.
+//! Keys use the backend's compressed encoding, 48 bytes on BLS12-381. Vacant
+//! and revoked slots are listed with the active flag cleared, so ids stay
+//! aligned with selectors.
+//!
+//! ```rust
+//! use rand::thread_rng;
+//! use tess::{
+//!     Committee, CommitteeRegistration, PairingBackend, PairingEngine, SilentThresholdScheme,
+//!     ThresholdEncryption,
+//! };
+//!
+//! let mut rng = thread_rng();
+//! let scheme = SilentThresholdScheme::<PairingEngine>::new();
+//! let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+//! let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+//! let committee = Committee::new(keys.public_keys, &params).unwrap();
+//!
+//! let blob = CommitteeRegistration::from_committee(&committee).to_bytes().unwrap();
+//! let key_len = PairingEngine::serialize_g1(&committee.public_keys()[0].bls_key).len();
+//! assert_eq!(blob.len(), 39 + 4 * (9 + key_len));
+//! let registration = CommitteeRegistration::from_bytes(&blob).unwrap();
+//! assert_eq!(registration.fingerprint, committee.aggregate_key().fingerprint());
+//! ```
+//!
+//! [`AggregateKey::fingerprint`]: crate::AggregateKey::fingerprint
+
+use alloc::vec::Vec;
+
+use crate::{COMMITTEE_FINGERPRINT_LEN, Committee, Fr, PairingBackend, errors::Error};
+
+/// Layout version written as the first byte of a registration payload.
+pub const REGISTRATION_VERSION: u8 = 1;
+
+/// Bytes before the first member: version, fingerprint, key length and count.
+const PREFIX_LEN: usize = 1 + COMMITTEE_FINGERPRINT_LEN + 2 + 4;
+
+/// Public description of a committee for anchoring it on-chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitteeRegistration {
+    /// Fingerprint of the committee's aggregate key.
+    pub fingerprint: [u8; COMMITTEE_FINGERPRINT_LEN],
+    /// Members indexed by participant id.
+    pub members: Vec<RegisteredMember>,
+}
+
+/// One committee slot in a [`CommitteeRegistration`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisteredMember {
+    /// Weight assigned by the committee.
+    pub weight: u64,
+    /// Whether the slot holds a member that is neither vacant nor revoked.
+    pub active: bool,
+    /// Compressed BLS public key.
+    pub bls_key: Vec<u8>,
+}
+
+impl CommitteeRegistration {
+    /// Extracts the registration of `committee`.
+    pub fn from_committee<B: PairingBackend<Scalar = Fr>>(committee: &Committee<B>) -> Self {
+        Self {
+            fingerprint: committee.aggregate_key().fingerprint(),
+            members: committee
+                .public_keys()
+                .iter()
+                .zip(committee.weights())
+                .map(|(public_key, &weight)| RegisteredMember {
+                    weight,
+                    active: committee.contains(public_key.participant_id),
+                    bls_key: B::serialize_g1(&public_key.bls_key),
+                })
+                .collect(),
+        }
+    }
+
+    /// Encodes the registration in the layout described in the
+    /// [module documentation](self).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if the members' keys differ in length
+    /// or the key length or member count do not fit their fields.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let key_len = self
+            .members
+            .first()
+            .map_or(0, |member| member.bls_key.len());
+        if self
+            .members
+            .iter()
+            .any(|member| member.bls_key.len() != key_len)
+        {
+            return Err(Error::InvalidConfig(
+                "registered keys differ in length".into(),
+            ));
+        }
+        let encoded_key_len = u16::try_from(key_len)
+            .map_err(|_| Error::InvalidConfig("registered key is too long".into()))?;
+        let count = u32::try_from(self.members.len())
+            .map_err(|_| Error::InvalidConfig("too many registered members".into()))?;
+
+        let mut out = Vec::with_capacity(PREFIX_LEN + self.members.len() * (9 + key_len));
+        out.push(REGISTRATION_VERSION);
+        out.extend_from_slice(&self.fingerprint);
+        out.extend_from_slice(&encoded_key_len.to_le_bytes());
+        out.extend_from_slice(&count.to_le_bytes());
+        for member in &self.members {
+            out.extend_from_slice(&member.weight.to_le_bytes());
+            out.push(u8::from(member.active));
+            out.extend_from_slice(&member.bls_key);
+        }
+        Ok(out)
+    }
+
+    /// Decodes a payload produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// The keys are returned as bytes; decode them with the backend's
+    /// [`PairingBackend::deserialize_g1_batch`] to check they are valid points.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] for an unknown version, a length that
+    /// does not match the member count, or an active flag other than 0 or 1.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let malformed = |reason: &str| Error::MalformedInput(reason.into());
+        if bytes.len() < PREFIX_LEN {
+            return Err(malformed("registration payload is truncated"));
+        }
+        let (prefix, body) = bytes.split_at(PREFIX_LEN);
+        if prefix[0] != REGISTRATION_VERSION {
+            return Err(malformed("unsupported registration version"));
+        }
+        let mut fingerprint = [0u8; COMMITTEE_FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(&prefix[1..1 + COMMITTEE_FINGERPRINT_LEN]);
+        let rest = &prefix[1 + COMMITTEE_FINGERPRINT_LEN..];
+        let key_len = u16::from_le_bytes([rest[0], rest[1]]) as usize;
+        let count = u32::from_le_bytes([rest[2], rest[3], rest[4], rest[5]]) as usize;
+
+        let member_len = 9 + key_len;
+        if count.checked_mul(member_len) != Some(body.len()) {
+            return Err(malformed("registration length does not match member count"));
+        }
+        let members = body
+            .chunks_exact(member_len)
+            .map(|member| {
+                let (weight, rest) = member.split_at(8);
+                let active = match rest[0] {
+                    0 => false,
+                    1 => true,
+                    _ => return Err(malformed("invalid active flag in registration")),
+                };
+                Ok(RegisteredMember {
+                    weight: u64::from_le_bytes(weight.try_into().unwrap()),
+                    active,
+                    bls_key: rest[1..].to_vec(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            fingerprint,
+            members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{CurvePoint, PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn registration_roundtrips_and_rejects_malformed_payloads() {
+        let mut rng = StdRng::seed_from_u64(1482);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let mut committee =
+            Committee::with_capacity(keys.public_keys[..3].to_vec(), &params).unwrap();
+        committee.revoke(1, &params).unwrap();
+
+        let registration = CommitteeRegistration::from_committee(&committee);
+        assert_eq!(
+            registration
+                .members
+                .iter()
+                .map(|member| member.active)
+                .collect::<Vec<_>>(),
+            [true, false, true, false]
+        );
+        let blob = registration.to_bytes().unwrap();
+        let key_len = registration.members[0].bls_key.len();
+        assert_eq!(blob.len(), PREFIX_LEN + 4 * (9 + key_len));
+        let decoded = CommitteeRegistration::from_bytes(&blob).unwrap();
+        assert_eq!(decoded, registration);
+        let bls_keys = decoded
+            .members
+            .iter()
+            .map(|member| &member.bls_key)
+            .collect::<Vec<_>>();
+        let points = PairingEngine::deserialize_g1_batch(&bls_keys).unwrap();
+        assert!(points[2].sub(&keys.public_keys[2].bls_key).is_identity());
+
+        assert!(CommitteeRegistration::from_bytes(&blob[..blob.len() - 1]).is_err());
+        let mut bad = blob.clone();
+        bad[0] = REGISTRATION_VERSION + 1;
+        assert!(CommitteeRegistration::from_bytes(&bad).is_err());
+        let mut bad = blob;
+        bad[PREFIX_LEN + 8] = 2;
+        assert!(CommitteeRegistration::from_bytes(&bad).is_err());
+
+        let mut uneven = registration;
+        uneven.members[0].bls_key.pop();
+        assert!(uneven.to_bytes().is_err());
+    }
+}
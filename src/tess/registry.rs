@@ -0,0 +1,225 @@
+//! Collection of public keys submitted during silent setup.
+//!
+//! Participants publish their [`PublicKey`]s independently, so a coordinator
+//! receives them one at a time, possibly more than once and possibly from
+//! parties claiming the same slot. [`PublicKeyRegistry`] accepts each key only
+//! if its participant id is free and its `bls_key` has not been seen before,
+//! keeps the keys indexed by both, and hands a complete, id-ordered slice to
+//! [`ThresholdEncryption::aggregate_public_key`].
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    AggregateKey, Committee, Fr, PairingBackend, Params, PublicKey, ThresholdEncryption,
+    errors::Error,
+};
+
+/// Public keys of a committee indexed by participant id and BLS key.
+///
+/// # Example
+///
+/// ```rust
+/// use rand::thread_rng;
+/// use tess::{PairingEngine, PublicKeyRegistry, SilentThresholdScheme, ThresholdEncryption};
+///
+/// let mut rng = thread_rng();
+/// let scheme = SilentThresholdScheme::<PairingEngine>::new();
+/// let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+///
+/// let mut registry = PublicKeyRegistry::new(&params);
+/// for id in 0..4 {
+///     let (_, pk) = scheme.keygen_single_validator(&mut rng, id, &params).unwrap();
+///     registry.insert(pk.clone()).unwrap();
+///     // Submitting the same key twice is rejected.
+///     assert!(registry.insert(pk).is_err());
+/// }
+/// let aggregate_key = registry.aggregate(&scheme, &params).unwrap();
+/// assert_eq!(aggregate_key.public_keys.len(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PublicKeyRegistry<B: PairingBackend> {
+    parties: usize,
+    keys: BTreeMap<usize, PublicKey<B>>,
+    by_bls_key: BTreeMap<Vec<u8>, usize>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> PublicKeyRegistry<B> {
+    /// Creates an empty registry for the committee size of `params`.
+    pub fn new(params: &Params<B>) -> Self {
+        Self {
+            parties: params.parties(),
+            keys: BTreeMap::new(),
+            by_bls_key: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a participant's public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] if the participant id is outside the
+    /// committee or already taken, or the same BLS key is registered under
+    /// any id. Returns [`Error::MalformedInput`] if the key is vacant or its
+    /// Lagrange hints do not match the committee size.
+    pub fn insert(&mut self, public_key: PublicKey<B>) -> Result<(), Error> {
+        let id = public_key.participant_id;
+        if id >= self.parties {
+            return Err(Error::InvalidConfig(format!(
+                "participant {id} is outside a committee of {}",
+                self.parties
+            )));
+        }
+        if public_key.is_vacant() || public_key.lagrange_li_lj_z.len() != self.parties {
+            return Err(Error::MalformedInput(format!(
+                "public key of participant {id} is not a key for this committee"
+            )));
+        }
+        if self.keys.contains_key(&id) {
+            return Err(Error::InvalidConfig(format!(
+                "participant {id} is already registered"
+            )));
+        }
+        let bls_key = B::serialize_g1(&public_key.bls_key);
+        if let Some(existing) = self.by_bls_key.get(&bls_key) {
+            return Err(Error::InvalidConfig(format!(
+                "public key of participant {id} duplicates participant {existing}"
+            )));
+        }
+        self.by_bls_key.insert(bls_key, id);
+        self.keys.insert(id, public_key);
+        Ok(())
+    }
+
+    /// Removes and returns the key registered for `participant_id`.
+    pub fn remove(&mut self, participant_id: usize) -> Option<PublicKey<B>> {
+        let public_key = self.keys.remove(&participant_id)?;
+        self.by_bls_key
+            .remove(&B::serialize_g1(&public_key.bls_key));
+        Some(public_key)
+    }
+
+    /// Key registered for `participant_id`.
+    pub fn get(&self, participant_id: usize) -> Option<&PublicKey<B>> {
+        self.keys.get(&participant_id)
+    }
+
+    /// Key whose BLS key is `bls_key`.
+    pub fn find_by_bls_key(&self, bls_key: &B::G1) -> Option<&PublicKey<B>> {
+        let id = self.by_bls_key.get(&B::serialize_g1(bls_key))?;
+        self.keys.get(id)
+    }
+
+    /// Returns `true` if a key is registered for `participant_id`.
+    pub fn contains(&self, participant_id: usize) -> bool {
+        self.keys.contains_key(&participant_id)
+    }
+
+    /// Number of registered keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if no key is registered.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns `true` once every participant has a key.
+    pub fn is_complete(&self) -> bool {
+        self.keys.len() == self.parties
+    }
+
+    /// Participant ids that have no key yet.
+    pub fn missing(&self) -> Vec<usize> {
+        (0..self.parties)
+            .filter(|id| !self.keys.contains_key(id))
+            .collect()
+    }
+
+    /// Registered keys in participant id order.
+    pub fn iter(&self) -> impl Iterator<Item = &PublicKey<B>> {
+        self.keys.values()
+    }
+
+    /// Complete key list ordered by participant id.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidConfig`] naming the first participant without
+    /// a key.
+    pub fn public_keys(&self) -> Result<Vec<PublicKey<B>>, Error> {
+        if let Some(id) = self.missing().first() {
+            return Err(Error::InvalidConfig(format!(
+                "participant {id} has not registered a public key"
+            )));
+        }
+        Ok(self.keys.values().cloned().collect())
+    }
+
+    /// Aggregates the complete key list with `scheme`.
+    pub fn aggregate<S: ThresholdEncryption<B>>(
+        &self,
+        scheme: &S,
+        params: &Params<B>,
+    ) -> Result<AggregateKey<B>, Error> {
+        scheme.aggregate_public_key(&self.public_keys()?, params, self.parties, &[])
+    }
+
+    /// Builds a committee from the registered keys, leaving missing
+    /// participants as vacant slots.
+    pub fn committee(&self, params: &Params<B>) -> Result<Committee<B>, Error> {
+        Committee::with_capacity(self.keys.values().cloned().collect(), params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{CurvePoint, PairingEngine, SilentThresholdScheme};
+
+    #[test]
+    fn registry_rejects_duplicates_and_collisions() {
+        let mut rng = StdRng::seed_from_u64(1463);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+
+        let mut registry = PublicKeyRegistry::new(&params);
+        for pk in keys.public_keys[..3].iter().cloned() {
+            registry.insert(pk).unwrap();
+        }
+        assert_eq!(registry.missing(), vec![3]);
+        assert!(registry.aggregate(&scheme, &params).is_err());
+        assert_eq!(registry.committee(&params).unwrap().active_len(), 3);
+
+        // Same id with a fresh key, and a known key claimed for another id.
+        let (_, rival) = scheme
+            .keygen_single_validator(&mut rng, 1, &params)
+            .unwrap();
+        assert!(registry.insert(rival).is_err());
+        let mut copied = keys.public_keys[0].clone();
+        copied.participant_id = 3;
+        assert!(registry.insert(copied).is_err());
+        assert!(
+            registry
+                .insert(PublicKey::vacant(3, params.parties()))
+                .is_err()
+        );
+
+        registry.insert(keys.public_keys[3].clone()).unwrap();
+        assert!(registry.is_complete());
+        let found = registry
+            .find_by_bls_key(&keys.public_keys[2].bls_key)
+            .unwrap();
+        assert_eq!(found.participant_id, 2);
+
+        let aggregate_key = registry.aggregate(&scheme, &params).unwrap();
+        assert!(aggregate_key.ask.sub(&keys.aggregate_key.ask).is_identity());
+
+        let removed = registry.remove(2).unwrap();
+        assert!(registry.find_by_bls_key(&removed.bls_key).is_none());
+        registry.insert(removed).unwrap();
+    }
+}
@@ -78,49 +78,276 @@
 //! assert_eq!(result.plaintext.unwrap(), message);
 //! ```
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::{fmt::Debug, marker::PhantomData};
 
-use blake3::Hasher;
-use rand_core::RngCore;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
 #[cfg(feature = "parallel")]
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use smallvec::{SmallVec, smallvec};
+use subtle::ConstantTimeEq;
 use tracing::instrument;
 
+use super::{broadcast::reject_broadcast, prepared::EncryptionBases};
 use crate::{
-    AggregateKey, Ciphertext, DecryptionResult, DensePolynomial, Fr, KZG, LagrangePowers,
-    PairingBackend, Params, PartialDecryption, Polynomial, PolynomialCommitment, PublicKey,
-    Radix2EvaluationDomain, SRS, SecretKey, TargetGroup, ThresholdEncryption, UnsafeKeyMaterial,
+    AggregateKey, AggregateKeyDigest, COMMITTEE_FINGERPRINT_LEN, Ciphertext, CiphertextHeader,
+    DecryptionProof, DecryptionResult, DensePolynomial, EncryptionKey, Fr, KZG, LagrangePowers,
+    MAX_LABEL_LEN, MixedRadixEvaluationDomain, MsmProvider, PROOF_G1_LEN, PROOF_G2_LEN,
+    PairingBackend, Params, PartialDecryption, Polynomial, PolynomialCommitment, PublicKey, SRS,
+    SchemeId, SecretKey, SenderKey, TargetGroup, ThresholdEncryption, UnsafeKeyMaterial,
     arith::{CurvePoint, FieldElement},
-    build_lagrange_polys,
+    cached_lagrange_polys,
     errors::{BackendError, Error},
-    sym_enc::{Blake3XorEncryption, SymmetricEncryption},
+    selector_polynomial,
+    sym_enc::{KdfId, PaddingScheme, SymmetricEncryption, XorEncryption},
+    xof::Xof,
 };
 
+/// Length in bytes of a data-encryption key accepted by
+/// [`SilentThresholdScheme::wrap_key`].
+pub const DEK_LEN: usize = 32;
+
 /// The Silent Threshold scheme implementation.
+///
+/// `X` is the [`Xof`] used for the payload keystream, payload key derivation
+/// and the scheme's transcripts; it defaults to BLAKE3.
 #[derive(Debug)]
-pub struct SilentThresholdScheme<B: PairingBackend> {
+pub struct SilentThresholdScheme<B: PairingBackend, X: Xof = blake3::Hasher> {
     _phantom: PhantomData<B>,
-    symmetric_enc: Blake3XorEncryption,
+    pub(super) symmetric_enc: XorEncryption<X>,
+    pub(super) padding: PaddingScheme,
+    decryption_proofs: bool,
+    pub(super) context: Vec<u8>,
+    pub(super) epoch: u64,
+    pub(super) label: Vec<u8>,
+    kdf: KdfId,
+    #[cfg(feature = "compression")]
+    compression_level: Option<i32>,
+    #[cfg(feature = "compression")]
+    max_decompressed_len: usize,
 }
 
 /// Type alias for the silent threshold scheme implementation.
 pub type SilentThreshold<B> = SilentThresholdScheme<B>;
 
-impl<B: PairingBackend> SilentThresholdScheme<B> {
+impl<B: PairingBackend, X: Xof> SilentThresholdScheme<B, X> {
     /// Creates a new Silent Threshold scheme instance.
     pub fn new() -> Self {
-        Self {
-            _phantom: PhantomData,
-            symmetric_enc: Blake3XorEncryption::default(),
-        }
+        Self::with_encryption(XorEncryption::default())
     }
 
     /// Creates a new Silent Threshold scheme with a custom symmetric encryption.
-    pub fn with_encryption(symmetric_enc: Blake3XorEncryption) -> Self {
+    pub fn with_encryption(symmetric_enc: XorEncryption<X>) -> Self {
         Self {
             _phantom: PhantomData,
             symmetric_enc,
+            padding: PaddingScheme::None,
+            decryption_proofs: false,
+            context: Vec::new(),
+            epoch: 0,
+            label: Vec::new(),
+            kdf: KdfId::default(),
+            #[cfg(feature = "compression")]
+            compression_level: None,
+            #[cfg(feature = "compression")]
+            max_decompressed_len: crate::DeserializeLimits::default().max_payload_len,
+        }
+    }
+
+    /// Sets the length-hiding padding applied to payloads before encryption.
+    ///
+    /// Padding is applied after compression and recorded in the ciphertext's
+    /// `padded` flag, so decryption strips it without needing the same setting.
+    pub fn with_padding(mut self, padding: PaddingScheme) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Enables zstd compression of payloads before encryption.
+    ///
+    /// Compression is applied only when it shrinks the payload; the ciphertext's
+    /// `compressed` flag tells decryption whether to decompress. `level` is the
+    /// zstd compression level (`0` selects the zstd default).
+    ///
+    /// # Security
+    ///
+    /// The compressed length depends on the plaintext's content, not just its
+    /// length, so an observer who can influence part of a payload may learn
+    /// the rest from ciphertext sizes. Combine compression with
+    /// [`with_padding`](Self::with_padding) for payloads that mix secret and
+    /// attacker-chosen data, or leave it off.
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, level: i32) -> Self {
+        self.compression_level = Some(level);
+        self
+    }
+
+    /// Caps decompressed payloads at `limits.max_payload_len` bytes.
+    ///
+    /// Decryption stops decompressing and fails once a payload exceeds the
+    /// cap, so a small ciphertext cannot expand into an arbitrarily large
+    /// allocation. The default is the cap of [`DeserializeLimits::default`].
+    ///
+    /// [`DeserializeLimits::default`]: crate::DeserializeLimits::default
+    #[cfg(feature = "compression")]
+    pub fn with_decompression_limits(mut self, limits: crate::DeserializeLimits) -> Self {
+        self.max_decompressed_len = limits.max_payload_len;
+        self
+    }
+
+    /// Makes aggregate decryption attach a [`DecryptionProof`] to its result.
+    ///
+    /// The proof lets anyone holding the aggregate key check the plaintext with
+    /// [`verify_decryption`](Self::verify_decryption).
+    pub fn with_decryption_proofs(mut self, enabled: bool) -> Self {
+        self.decryption_proofs = enabled;
+        self
+    }
+
+    /// Binds payload keys to an application context such as an app id, chain
+    /// id, or epoch.
+    ///
+    /// The context is mixed into the key derivation of every ciphertext, so
+    /// the encryptor and the decrypting coordinator must use the same context.
+    /// Decrypting under another context yields an unrelated key and therefore
+    /// garbage, even with the same committee and partials. The default empty
+    /// context derives the same keys as a scheme without one.
+    pub fn with_context(mut self, context: &[u8]) -> Self {
+        self.context = context.to_vec();
+        self
+    }
+
+    /// Sets the epoch recorded in the header of every ciphertext.
+    ///
+    /// Unlike the context, the epoch travels with the ciphertext, so receivers
+    /// can route on it and decryption follows the ciphertext rather than this
+    /// setting.
+    pub fn with_epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Sets the label recorded in the header of every ciphertext.
+    ///
+    /// Encryption fails if the label is longer than
+    /// [`MAX_LABEL_LEN`] bytes.
+    pub fn with_label(mut self, label: &[u8]) -> Self {
+        self.label = label.to_vec();
+        self
+    }
+
+    /// Header for a ciphertext produced with this scheme's settings.
+    pub(super) fn header(
+        &self,
+        scheme: SchemeId,
+        committee: [u8; COMMITTEE_FINGERPRINT_LEN],
+        threshold: usize,
+        payload_len: usize,
+        cipher_suite: KdfId,
+    ) -> Result<CiphertextHeader, Error> {
+        if self.label.len() > MAX_LABEL_LEN {
+            return Err(Error::InvalidConfig(format!(
+                "label exceeds {MAX_LABEL_LEN} bytes"
+            )));
+        }
+        Ok(CiphertextHeader {
+            scheme,
+            epoch: self.epoch,
+            committee,
+            label: self.label.clone(),
+            threshold,
+            payload_len,
+            cipher_suite,
+        })
+    }
+
+    /// Selects the function that derives payload keys from the shared secret.
+    ///
+    /// The choice is recorded in each ciphertext's `kdf` field, and decryption
+    /// follows the ciphertext rather than this setting.
+    pub fn with_kdf(mut self, kdf: KdfId) -> Self {
+        self.kdf = kdf;
+        self
+    }
+
+    /// Builds the decryption proof from the selected partials, if enabled.
+    fn decryption_proof(
+        &self,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+    ) -> Option<DecryptionProof<B>> {
+        self.decryption_proofs.then(|| DecryptionProof {
+            selector: selector.to_vec(),
+            partials: partials
+                .iter()
+                .filter(|partial| selector.get(partial.participant_id) == Some(&true))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Returns a scheme with the same symmetric encryption but no compression or
+    /// padding, for encrypting fixed-size key material.
+    pub(super) fn without_payload_transforms(&self) -> Self {
+        Self::with_encryption(self.symmetric_enc.clone()).with_kdf(self.kdf)
+    }
+
+    /// Compresses `payload` if compression is enabled and beneficial.
+    ///
+    /// Returns the bytes to encrypt and whether they are compressed.
+    pub(super) fn compress_payload(&self, payload: Vec<u8>) -> Result<(Vec<u8>, bool), Error> {
+        #[cfg(feature = "compression")]
+        if let Some(level) = self.compression_level {
+            let compressed = zstd::bulk::compress(&payload, level)
+                .map_err(|e| Error::Backend(BackendError::Other(e.to_string())))?;
+            if compressed.len() < payload.len() {
+                return Ok((compressed, true));
+            }
+        }
+        Ok((payload, false))
+    }
+
+    /// Reverses the padding and compression recorded in the ciphertext header.
+    pub(super) fn restore_payload(
+        &self,
+        plaintext: Vec<u8>,
+        padded: bool,
+        compressed: bool,
+    ) -> Result<Vec<u8>, Error> {
+        let plaintext = if padded {
+            PaddingScheme::unpad(plaintext)?
+        } else {
+            plaintext
+        };
+        if !compressed {
+            return Ok(plaintext);
+        }
+        #[cfg(feature = "compression")]
+        {
+            use std::io::Read;
+
+            let limit = self.max_decompressed_len;
+            let failed = |_| Error::MalformedInput("payload decompression failed".into());
+            let decoder =
+                zstd::stream::Decoder::with_buffer(plaintext.as_slice()).map_err(failed)?;
+            let mut payload = Vec::new();
+            decoder
+                .take(limit as u64 + 1)
+                .read_to_end(&mut payload)
+                .map_err(failed)?;
+            if payload.len() > limit {
+                return Err(Error::MalformedInput(format!(
+                    "decompressed payload exceeds {limit} bytes"
+                )));
+            }
+            Ok(payload)
+        }
+        #[cfg(not(feature = "compression"))]
+        {
+            Err(Error::InvalidConfig(
+                "compressed payloads require the `compression` feature".into(),
+            ))
         }
     }
 
@@ -137,7 +364,10 @@ impl<B: PairingBackend> SilentThresholdScheme<B> {
     /// # Returns
     ///
     /// A vector of secret keys, one per participant, with IDs 0..parties-1
-    fn generate_secret_keys<R: RngCore + ?Sized>(rng: &mut R, parties: usize) -> Vec<SecretKey<B>> {
+    pub(crate) fn generate_secret_keys<R: RngCore + ?Sized>(
+        rng: &mut R,
+        parties: usize,
+    ) -> Vec<SecretKey<B>> {
         (0..parties)
             .map(|participant_id| SecretKey {
                 participant_id,
@@ -147,13 +377,34 @@ impl<B: PairingBackend> SilentThresholdScheme<B> {
     }
 }
 
-impl<B: PairingBackend> Default for SilentThresholdScheme<B> {
+impl<B: PairingBackend, X: Xof> Default for SilentThresholdScheme<B, X> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdScheme<B> {
+impl<B: PairingBackend, X: Xof> Clone for SilentThresholdScheme<B, X> {
+    fn clone(&self) -> Self {
+        Self {
+            _phantom: PhantomData,
+            symmetric_enc: self.symmetric_enc.clone(),
+            padding: self.padding,
+            decryption_proofs: self.decryption_proofs,
+            context: self.context.clone(),
+            epoch: self.epoch,
+            label: self.label.clone(),
+            kdf: self.kdf,
+            #[cfg(feature = "compression")]
+            compression_level: self.compression_level,
+            #[cfg(feature = "compression")]
+            max_decompressed_len: self.max_decompressed_len,
+        }
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> ThresholdEncryption<B>
+    for SilentThresholdScheme<B, X>
+{
     #[instrument(level = "info", skip_all, fields(parties, threshold))]
     fn param_gen<R: RngCore + ?Sized>(
         &self,
@@ -171,9 +422,9 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
                 "threshold must be greater than 0".into(),
             ));
         }
-        if !parties.is_power_of_two() {
+        if MixedRadixEvaluationDomain::new(parties).is_none() {
             return Err(Error::InvalidConfig(
-                "parties must be a power of two".into(),
+                "parties must be of the form 2^a * 3^b supported by the scalar field".into(),
             ));
         }
 
@@ -184,7 +435,7 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
             })?;
 
             // Build Lagrange polynomials for the evaluation domain of size `parties`.
-            let lagranges = build_lagrange_polys(parties).map_err(|e| {
+            let lagranges = cached_lagrange_polys(parties).map_err(|e| {
                 Error::Backend(BackendError::Other(format!(
                     "Lagrange polynomials failed: {}",
                     e
@@ -196,6 +447,10 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
                 LagrangePowers::precompute_lagrange_powers(&lagranges, parties, &tau)
                     .map_err(Error::Backend)?;
 
+            // The committee's Lagrange commitments double as the SRS Lagrange basis.
+            let mut srs = srs;
+            srs.lagrange_basis_g = lagrange_powers.li.clone();
+
             Ok(Params {
                 srs,
                 lagrange_powers,
@@ -276,35 +531,215 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
         public_keys: &[PublicKey<B>],
         params: &Params<B>,
         parties: usize,
+        revoked: &[usize],
     ) -> Result<AggregateKey<B>, Error> {
-        AggregateKey::aggregate_keys(public_keys, params, parties)
+        AggregateKey::aggregate_keys_with_revocations(public_keys, params, parties, revoked)
     }
 
     #[instrument(level = "info", skip_all, fields(threshold, payload_len = payload.len()))]
-    fn encrypt<R: RngCore + ?Sized>(
+    fn encrypt<R: RngCore + ?Sized, K: EncryptionKey<B> + ?Sized>(
         &self,
         rng: &mut R,
-        agg_key: &AggregateKey<B>,
+        agg_key: &K,
         params: &Params<B>,
         threshold: usize,
         payload: &[u8],
     ) -> Result<Ciphertext<B>, Error> {
-        if threshold == 0 {
-            return Err(Error::InvalidConfig(
-                "threshold must be greater than 0".into(),
+        self.encrypt_owned(rng, agg_key, params, threshold, payload.to_vec())
+    }
+
+    #[instrument(level = "debug", skip_all, fields(threshold = ciphertext.threshold))]
+    fn verify_ciphertext(
+        &self,
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        ciphertext: &Ciphertext<B>,
+    ) -> Result<(), Error> {
+        let header = ciphertext.header();
+        header.check_label()?;
+        header.check_committee(agg_key)?;
+        if ciphertext.is_broadcast() {
+            return self.verify_broadcast(agg_key, ciphertext);
+        }
+        if ciphertext.threshold == 0 || ciphertext.threshold >= params.srs.powers_of_g.len() {
+            return Err(Error::MalformedInput(
+                "ciphertext threshold is out of range".into(),
             ));
         }
-        if threshold >= params.srs.powers_of_g.len() {
-            return Err(Error::InvalidConfig(
-                "threshold exceeds available SRS powers".into(),
+        if ciphertext.gamma_g2.is_identity() {
+            return Err(Error::MalformedInput(
+                "ciphertext gamma is the identity".into(),
             ));
         }
+        AggregateKeyDigest::new(agg_key, params, ciphertext.threshold)?
+            .verify_ciphertext(ciphertext)
+    }
+
+    #[instrument(level = "trace", skip_all, fields(participant_id = secret_key.participant_id))]
+    fn partial_decrypt(
+        &self,
+        secret_key: &SecretKey<B>,
+        ciphertext: &Ciphertext<B>,
+    ) -> Result<PartialDecryption<B>, Error> {
+        reject_broadcast(ciphertext)?;
+        let response = ciphertext.gamma_g2.mul_scalar(&secret_key.scalar);
+        Ok(PartialDecryption {
+            participant_id: secret_key.participant_id,
+            response,
+        })
+    }
+
+    #[instrument(level = "info", skip_all, fields(required = ciphertext.threshold, provided = partials.len()))]
+    fn aggregate_decrypt(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<DecryptionResult<B>, Error> {
+        if let Some(signature) = &ciphertext.sender_signature {
+            signature.verify(ciphertext)?;
+        }
+        let payload_key = self.recover_payload_key(ciphertext, partials, selector, agg_key)?;
+        let plaintext = self
+            .symmetric_enc
+            .decrypt(&payload_key, &ciphertext.payload)?;
+        let plaintext =
+            self.restore_payload(plaintext, ciphertext.padded, ciphertext.compressed)?;
+
+        Ok(DecryptionResult {
+            plaintext: Some(plaintext),
+            proof: self.decryption_proof(partials, selector),
+        })
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Encrypts an owned payload buffer in place.
+    ///
+    /// Behaves like [`ThresholdEncryption::encrypt`] but takes ownership of the
+    /// payload, so no copy is made. Any buffer convertible into `Vec<u8>` is
+    /// accepted; for example `bytes::Bytes` converts without copying when it is
+    /// uniquely owned.
+    #[instrument(level = "info", skip_all, fields(threshold))]
+    pub fn encrypt_owned<R: RngCore + ?Sized, K: EncryptionKey<B> + ?Sized>(
+        &self,
+        rng: &mut R,
+        agg_key: &K,
+        params: &Params<B>,
+        threshold: usize,
+        payload: impl Into<Vec<u8>>,
+    ) -> Result<Ciphertext<B>, Error> {
+        self.encrypt_with_mode(rng, agg_key, params, threshold, payload.into(), false)
+    }
+
+    /// Encrypts a batch of payloads to the same committee and threshold.
+    ///
+    /// The aggregate-key-dependent bases (the ciphertext digest and derived SRS
+    /// elements) are computed once for the whole batch rather than per payload,
+    /// and the payloads are encrypted in parallel when the `parallel` feature is
+    /// enabled. Each ciphertext is independent and decrypts like one produced by
+    /// [`ThresholdEncryption::encrypt`].
+    #[instrument(level = "info", skip_all, fields(threshold, batch = payloads.len()))]
+    pub fn encrypt_batch<
+        R: RngCore + ?Sized,
+        K: EncryptionKey<B> + ?Sized,
+        P: AsRef<[u8]> + Sync,
+    >(
+        &self,
+        rng: &mut R,
+        agg_key: &K,
+        params: &Params<B>,
+        threshold: usize,
+        payloads: &[P],
+    ) -> Result<Vec<Ciphertext<B>>, Error> {
+        let threshold = check_encryption_threshold(agg_key.aggregate_key(), params, threshold)?;
+        let owned;
+        let bases = match agg_key.prepared_bases(threshold) {
+            Some(bases) => Some(bases),
+            None if threshold > 1 => {
+                owned = EncryptionBases::new(agg_key.aggregate_key(), params, threshold)?;
+                Some(&owned)
+            }
+            None => None,
+        };
+        let seeds = payloads
+            .iter()
+            .map(|_| {
+                let mut seed = [0u8; 32];
+                rng.fill_bytes(&mut seed);
+                seed
+            })
+            .collect::<Vec<_>>();
+        let encrypt_one = |(payload, seed): (&P, &[u8; 32])| {
+            let mut rng = ChaCha20Rng::from_seed(*seed);
+            let payload = payload.as_ref().to_vec();
+            match &bases {
+                Some(bases) => self.encrypt_with_bases(&mut rng, bases, payload, false),
+                None => self.encrypt_broadcast(&mut rng, agg_key.aggregate_key(), payload, false),
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            payloads
+                .par_iter()
+                .zip(seeds.par_iter())
+                .map(encrypt_one)
+                .collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            payloads.iter().zip(seeds.iter()).map(encrypt_one).collect()
+        }
+    }
 
-        let g = B::G1::generator();
-        let h = B::G2::generator();
+    /// Shared encryption path; `deterministic` is recorded in the header before
+    /// the validity proof is computed over it.
+    fn encrypt_with_mode<R: RngCore + ?Sized, K: EncryptionKey<B> + ?Sized>(
+        &self,
+        rng: &mut R,
+        agg_key: &K,
+        params: &Params<B>,
+        threshold: usize,
+        payload: Vec<u8>,
+        deterministic: bool,
+    ) -> Result<Ciphertext<B>, Error> {
+        let threshold = check_encryption_threshold(agg_key.aggregate_key(), params, threshold)?;
+        if threshold == 1 {
+            return self.encrypt_broadcast(rng, agg_key.aggregate_key(), payload, deterministic);
+        }
+        if let Some(bases) = agg_key.prepared_bases(threshold) {
+            return self.encrypt_with_bases(rng, bases, payload, deterministic);
+        }
+        let bases = EncryptionBases::new(agg_key.aggregate_key(), params, threshold)?;
+        self.encrypt_with_bases(rng, &bases, payload, deterministic)
+    }
 
+    /// Encrypts one payload with precomputed aggregate-key bases.
+    fn encrypt_with_bases<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        bases: &EncryptionBases<B>,
+        payload: Vec<u8>,
+        deterministic: bool,
+    ) -> Result<Ciphertext<B>, Error> {
         let gamma = Fr::random(rng);
-        let gamma_g2 = h.mul_scalar(&gamma);
+        let gamma_g2 = bases.h(&gamma);
+        self.encrypt_with_gamma(rng, bases, gamma_g2, payload, deterministic)
+    }
+
+    /// Encrypts one payload against `gamma_g2`, the point members multiply by
+    /// their secret keys to decrypt.
+    pub(super) fn encrypt_with_gamma<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        bases: &EncryptionBases<B>,
+        gamma_g2: B::G2,
+        payload: Vec<u8>,
+        deterministic: bool,
+    ) -> Result<Ciphertext<B>, Error> {
+        let digest = &bases.digest;
 
         let s0 = Fr::random(rng);
         let s1 = Fr::random(rng);
@@ -315,151 +750,399 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
         // Create proof elements
 
         // sa1[0] = s0*ask + s3*g^{tau^{t}} + s4*g
-        // sa1[0] = (apk.ask * s[0]) + (params.powers_of_g[t] * s[3]) + (params.powers_of_g[0] * s[4]);
-        let sa1_0 = agg_key
-            .ask
-            .mul_scalar(&s0)
-            .add(&params.srs.powers_of_g[threshold].mul_scalar(&s3))
-            .add(&g.mul_scalar(&s4));
+        let sa1_0 = bases
+            .ask(&s0)
+            .add(&bases.g_tau_threshold(&s3))
+            .add(&bases.g(&s4));
 
         // sa1[1] = s2*g
-        let sa1_1 = g.mul_scalar(&s2);
+        let sa1_1 = bases.g(&s2);
 
         // sa2[0] = s0*h + s2*gamma_g2
-        let sa2_0 = h.mul_scalar(&s0).add(&gamma_g2.mul_scalar(&s2));
+        let sa2_0 =
+            B::Msm::msm_g2(&[B::G2::generator(), gamma_g2], &[s0, s2]).map_err(Error::Backend)?;
 
         // sa2[1] = s0*z_g2
-        let sa2_1 = agg_key.z_g2.mul_scalar(&s0);
+        let sa2_1 = bases.z(&s0);
 
         // sa2[2] = s0*h^tau + s1*h^tau
-        let sa2_2 = params.srs.powers_of_h[1].mul_scalar(&(s0 + s1));
+        let sa2_2 = bases.h_tau(&(s0 + s1));
 
         // sa2[3] = s1*h
-        let sa2_3 = h.mul_scalar(&s1);
+        let sa2_3 = bases.h(&s1);
 
         // sa2[4] = s3*h
-        let sa2_4 = h.mul_scalar(&s3);
+        let sa2_4 = bases.h(&s3);
 
         // sa2[5] = s4*h^{tau - omega^0}
-        let sa2_5 = params.srs.powers_of_h[1]
-            .sub(&params.srs.powers_of_h[0])
-            .mul_scalar(&s4);
-
-        let proof_g1 = vec![sa1_0, sa1_1];
-        let proof_g2 = vec![sa2_0, sa2_1, sa2_2, sa2_3, sa2_4, sa2_5];
+        let sa2_5 = bases.h_tau_minus_h(&s4);
+
+        let proof_g1 = smallvec![sa1_0, sa1_1];
+        let proof_g2 = smallvec![sa2_0, sa2_1, sa2_2, sa2_3, sa2_4, sa2_5];
+
+        let (payload, compressed) = self.compress_payload(payload)?;
+        let padded = self.padding.is_enabled();
+        let mut payload = self.padding.pad(payload);
+        let header = self.header(
+            SchemeId::Threshold,
+            digest.fingerprint(),
+            digest.threshold,
+            payload.len(),
+            self.kdf,
+        )?;
 
         // Compute shared secret from s4 and pairing
         // enc_key = e_gh^s4
-        let shared_secret = agg_key.precomputed_pairing.mul_scalar(&s4);
-        let payload_key = derive_payload_key::<B>(&shared_secret);
+        let shared_secret = digest.pairing_base.mul_scalar(&s4);
+        let payload_key =
+            derive_payload_key::<B, X>(self.kdf, &shared_secret, &header, &self.context);
+        self.symmetric_enc
+            .encrypt_in_place(&payload_key, &mut payload)?;
 
-        let payload_ct = self.symmetric_enc.encrypt(&payload_key, payload)?;
-
-        Ok(Ciphertext {
+        let mut ciphertext = Ciphertext {
             gamma_g2,
             proof_g1,
             proof_g2,
             shared_secret,
-            threshold,
-            payload: payload_ct,
-        })
+            threshold: digest.threshold,
+            epoch: header.epoch,
+            committee: header.committee,
+            label: header.label,
+            payload,
+            compressed,
+            padded,
+            deterministic,
+            kdf: self.kdf,
+            validity_proof: None,
+            sender_signature: None,
+            broadcast_keys: Vec::new(),
+        };
+        ciphertext.validity_proof = Some(digest.prove(rng, &ciphertext, &[s0, s1, s2, s3, s4])?);
+        Ok(ciphertext)
     }
 
-    #[instrument(level = "trace", skip_all, fields(participant_id = secret_key.participant_id))]
-    fn partial_decrypt(
+    /// Encrypts a payload deterministically.
+    ///
+    /// All encryption randomness is derived from the aggregate key, threshold,
+    /// `label`, and payload, so identical inputs produce identical ciphertexts.
+    /// This supports deduplicating storage, and the mode is recorded in the
+    /// ciphertext's `deterministic` flag.
+    ///
+    /// # Security
+    ///
+    /// Deterministic ciphertexts reveal when two encryptions share the same
+    /// inputs, and anyone holding the aggregate key can confirm a guessed
+    /// plaintext. Use [`ThresholdEncryption::encrypt`] unless deduplication is
+    /// required, and use distinct labels to separate contexts.
+    #[instrument(level = "info", skip_all, fields(threshold, payload_len = payload.len()))]
+    pub fn encrypt_deterministic(
         &self,
-        secret_key: &SecretKey<B>,
-        ciphertext: &Ciphertext<B>,
-    ) -> Result<PartialDecryption<B>, Error> {
-        let response = ciphertext.gamma_g2.mul_scalar(&secret_key.scalar);
-        Ok(PartialDecryption {
-            participant_id: secret_key.participant_id,
-            response,
-        })
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        threshold: usize,
+        label: &[u8],
+        payload: &[u8],
+    ) -> Result<Ciphertext<B>, Error> {
+        let seed = derive_deterministic_seed::<B, X>(agg_key, threshold, label, payload);
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        self.encrypt_with_mode(&mut rng, agg_key, params, threshold, payload.to_vec(), true)
+    }
+
+    /// Encrypts a payload and signs the ciphertext with the sender's key.
+    ///
+    /// Aggregate decryption verifies the signature before releasing the
+    /// plaintext; use [`SilentThresholdScheme::aggregate_decrypt_from`] to also
+    /// require a specific sender.
+    #[instrument(level = "info", skip_all, fields(threshold, payload_len = payload.len()))]
+    pub fn signcrypt<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        threshold: usize,
+        payload: &[u8],
+        sender: &SenderKey<B>,
+    ) -> Result<Ciphertext<B>, Error> {
+        let mut ciphertext = self.encrypt(rng, agg_key, params, threshold, payload)?;
+        ciphertext.sender_signature = Some(sender.sign(&ciphertext));
+        Ok(ciphertext)
     }
 
+    /// Aggregates partial decryptions of a ciphertext signcrypted by `sender`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the ciphertext is unsigned, was signed
+    /// by a different key, or its signature does not verify; otherwise behaves
+    /// like [`ThresholdEncryption::aggregate_decrypt`].
     #[instrument(level = "info", skip_all, fields(required = ciphertext.threshold, provided = partials.len()))]
-    fn aggregate_decrypt(
+    pub fn aggregate_decrypt_from(
         &self,
         ciphertext: &Ciphertext<B>,
         partials: &[PartialDecryption<B>],
         selector: &[bool],
         agg_key: &AggregateKey<B>,
-    ) -> Result<DecryptionResult, Error> {
-        if partials.is_empty() {
-            return Err(Error::NotEnoughShares {
-                required: ciphertext.threshold,
-                provided: 0,
-            });
+        sender: &B::G1,
+    ) -> Result<DecryptionResult<B>, Error> {
+        let signature = ciphertext
+            .sender_signature
+            .as_ref()
+            .ok_or_else(|| Error::MalformedInput("ciphertext is not signed".into()))?;
+        if !signature.sender.sub(sender).is_identity() {
+            return Err(Error::MalformedInput(
+                "ciphertext was signed by a different sender".into(),
+            ));
         }
+        self.aggregate_decrypt(ciphertext, partials, selector, agg_key)
+    }
 
-        if partials.len() < ciphertext.threshold {
-            return Err(Error::NotEnoughShares {
-                required: ciphertext.threshold,
-                provided: partials.len(),
-            });
-        }
+    /// Threshold-encrypts an externally supplied 32-byte data-encryption key.
+    ///
+    /// Systems that encrypt bulk data with their own AEAD can use this to place
+    /// only the DEK under committee control. The key is encrypted without
+    /// compression or padding, regardless of this scheme's configuration.
+    #[instrument(level = "info", skip_all, fields(threshold))]
+    pub fn wrap_key<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        threshold: usize,
+        dek: &[u8; DEK_LEN],
+    ) -> Result<Ciphertext<B>, Error> {
+        self.without_payload_transforms().encrypt_owned(
+            rng,
+            agg_key,
+            params,
+            threshold,
+            dek.to_vec(),
+        )
+    }
 
-        let parties = agg_key.public_keys.len();
-        if parties == 0 {
-            return Err(Error::InvalidConfig("require at least one party".into()));
-        }
-        if !parties.is_power_of_two() {
-            return Err(Error::InvalidConfig(
-                "parties must be a power of two".into(),
+    /// Recovers a data-encryption key wrapped by [`wrap_key`](Self::wrap_key).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ThresholdEncryption::aggregate_decrypt`], and
+    /// [`Error::MalformedInput`] if the ciphertext does not carry a 32-byte key.
+    #[instrument(level = "info", skip_all, fields(required = ciphertext.threshold, provided = partials.len()))]
+    pub fn unwrap_key(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<[u8; DEK_LEN], Error> {
+        if ciphertext.compressed || ciphertext.padded || ciphertext.payload.len() != DEK_LEN {
+            return Err(Error::MalformedInput(
+                "ciphertext does not wrap a data-encryption key".into(),
             ));
         }
-        if selector.len() != parties {
-            return Err(Error::SelectorMismatch {
-                expected: parties,
-                actual: selector.len(),
-            });
+        let payload_key = self.recover_payload_key(ciphertext, partials, selector, agg_key)?;
+        let mut dek = [0u8; DEK_LEN];
+        dek.copy_from_slice(&ciphertext.payload);
+        self.symmetric_enc
+            .decrypt_in_place(&payload_key, &mut dek)?;
+        Ok(dek)
+    }
+
+    /// Aggregates partial decryptions and decrypts the ciphertext payload in place.
+    ///
+    /// Behaves like [`ThresholdEncryption::aggregate_decrypt`] but consumes the
+    /// ciphertext and reuses its payload buffer for the plaintext.
+    #[instrument(level = "info", skip_all, fields(required = ciphertext.threshold, provided = partials.len()))]
+    pub fn aggregate_decrypt_owned(
+        &self,
+        ciphertext: Ciphertext<B>,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<DecryptionResult<B>, Error> {
+        if let Some(signature) = &ciphertext.sender_signature {
+            signature.verify(&ciphertext)?;
         }
-        if !selector[0] {
+        let payload_key = self.recover_payload_key(&ciphertext, partials, selector, agg_key)?;
+        let mut payload = ciphertext.payload;
+        self.symmetric_enc
+            .decrypt_in_place(&payload_key, &mut payload)?;
+        let payload = self.restore_payload(payload, ciphertext.padded, ciphertext.compressed)?;
+
+        Ok(DecryptionResult {
+            plaintext: Some(payload),
+            proof: self.decryption_proof(partials, selector),
+        })
+    }
+
+    /// Verifies a decryption result against the ciphertext and aggregate key.
+    ///
+    /// Checks every partial in the result's proof against its participant's BLS
+    /// key with a single randomly weighted pairing equation, re-runs the
+    /// aggregation, and compares the recomputed plaintext with the claimed one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the result has no proof or plaintext,
+    /// a share is invalid, or the plaintext does not match; aggregation errors are
+    /// returned as from [`ThresholdEncryption::aggregate_decrypt`].
+    #[instrument(level = "info", skip_all, fields(required = ciphertext.threshold))]
+    pub fn verify_decryption(
+        &self,
+        result: &DecryptionResult<B>,
+        ciphertext: &Ciphertext<B>,
+        agg_key: &AggregateKey<B>,
+    ) -> Result<(), Error> {
+        let proof = result
+            .proof
+            .as_ref()
+            .ok_or_else(|| Error::MalformedInput("decryption result has no proof".into()))?;
+        let claimed = result
+            .plaintext
+            .as_ref()
+            .ok_or_else(|| Error::MalformedInput("decryption result has no plaintext".into()))?;
+
+        // e(sum_i w_i * pk_i, gamma) == e(g, sum_i w_i * partial_i)
+        let mut hasher = X::default();
+        hasher.update(b"tess::decryption-proof");
+        hasher.update(ciphertext.gamma_g2.to_repr().as_ref());
+        let mut keys = Vec::with_capacity(proof.partials.len());
+        let mut responses = Vec::with_capacity(proof.partials.len());
+        for partial in &proof.partials {
+            let public_key = agg_key
+                .public_keys
+                .get(partial.participant_id)
+                .ok_or_else(|| Error::MalformedInput("partial id out of range".into()))?;
+            hasher.update(&(partial.participant_id as u64).to_le_bytes());
+            hasher.update(partial.response.to_repr().as_ref());
+            keys.push(public_key.bls_key);
+            responses.push(partial.response);
+        }
+        let mut rng = ChaCha20Rng::from_seed(hasher.finalize_bytes());
+        let weights = (0..keys.len())
+            .map(|_| Fr::random(&mut rng))
+            .collect::<Vec<_>>();
+        let weighted_keys = B::Msm::msm_g1(&keys, &weights).map_err(Error::Backend)?;
+        let weighted_responses = B::Msm::msm_g2(&responses, &weights).map_err(Error::Backend)?;
+        if B::pairing(&weighted_keys, &ciphertext.gamma_g2)
+            != B::pairing(&B::G1::generator(), &weighted_responses)
+        {
             return Err(Error::MalformedInput(
-                "selector[0] must be true to anchor interpolation".into(),
+                "decryption proof contains an invalid partial".into(),
             ));
         }
-        if ciphertext.proof_g1.len() != 2 || ciphertext.proof_g2.len() != 6 {
+
+        let recomputed = self.without_payload_transforms().aggregate_decrypt(
+            ciphertext,
+            &proof.partials,
+            &proof.selector,
+            agg_key,
+        )?;
+        let recomputed = recomputed.plaintext.unwrap_or_default();
+        if !bool::from(recomputed.as_slice().ct_eq(claimed.as_slice())) {
             return Err(Error::MalformedInput(
-                "ciphertext proof sizes are invalid".into(),
+                "decryption result does not match ciphertext".into(),
             ));
         }
+        Ok(())
+    }
 
-        let mut partial_map: Vec<Option<&PartialDecryption<B>>> = vec![None; parties];
-        for partial in partials {
+    /// Verifies the partial decryptions against the ciphertext and derives the
+    /// symmetric payload key.
+    fn recover_payload_key(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<[u8; 32], Error> {
+        reject_broadcast(ciphertext)?;
+        ciphertext.header().check_committee(agg_key)?;
+        let terms = self.decryption_terms(ciphertext.threshold, partials, selector, agg_key)?;
+        self.payload_key_from_terms(ciphertext, &terms)
+    }
+
+    /// Aggregates the selected partials into the ciphertext-independent half
+    /// of the decryption pairing.
+    pub(super) fn decryption_terms(
+        &self,
+        threshold: usize,
+        partials: &[PartialDecryption<B>],
+        selector: &[bool],
+        agg_key: &AggregateKey<B>,
+    ) -> Result<DecryptionTerms<B>, Error> {
+        let parties = agg_key.public_keys.len();
+        let revoked = |idx: usize| agg_key.revoked.binary_search(&idx).is_ok();
+
+        // Only distinct, in-range, unrevoked partials count as shares; selected
+        // revoked slots contribute a zero share without one.
+        let provided = partials
+            .iter()
+            .map(|partial| partial.participant_id)
+            .filter(|&id| id < parties && !revoked(id))
+            .collect::<BTreeSet<_>>()
+            .len();
+        if provided == 0 || provided + agg_key.revoked.len() < threshold {
+            return Err(Error::InsufficientShares {
+                required: threshold,
+                provided,
+            });
+        }
+
+        if parties == 0 {
+            return Err(Error::InvalidConfig("require at least one party".into()));
+        }
+        if MixedRadixEvaluationDomain::new(parties).is_none() {
+            return Err(Error::InvalidConfig(
+                "parties must be of the form 2^a * 3^b supported by the scalar field".into(),
+            ));
+        }
+        if selector.len() != parties {
+            return Err(Error::SelectorMismatch {
+                expected: parties,
+                actual: selector.len(),
+            });
+        }
+        if !selector[0] {
+            return Err(Error::MalformedInput(
+                "selector[0] must be true to anchor interpolation".into(),
+            ));
+        }
+
+        let mut partial_map: Vec<Option<&PartialDecryption<B>>> = vec![None; parties];
+        for partial in partials {
+            if revoked(partial.participant_id) {
+                return Err(Error::RevokedParticipant {
+                    participant_id: partial.participant_id,
+                });
+            }
             if partial.participant_id < parties {
                 partial_map[partial.participant_id] = Some(partial);
             }
         }
 
-        let domain = Radix2EvaluationDomain::new(parties)
+        let domain = MixedRadixEvaluationDomain::new(parties)
             .ok_or_else(|| Error::InvalidConfig("invalid evaluation domain size".into()))?;
         let domain_elements = domain.elements();
 
-        let mut points = vec![domain_elements[0]];
         let mut selected_indices = Vec::new();
         for (idx, &is_selected) in selector.iter().enumerate() {
             if is_selected {
-                if partial_map[idx].is_none() {
+                if partial_map[idx].is_none() && !revoked(idx) {
                     return Err(Error::MalformedInput(
                         "missing partial decryption for selected party".into(),
                     ));
                 }
                 selected_indices.push(idx);
-            } else {
-                points.push(domain_elements[idx]);
             }
         }
 
-        if selected_indices.len() < ciphertext.threshold {
-            return Err(Error::NotEnoughShares {
-                required: ciphertext.threshold,
+        if selected_indices.len() < threshold {
+            return Err(Error::InsufficientShares {
+                required: threshold,
                 provided: selected_indices.len(),
             });
         }
 
-        let b_polynomial = interp_mostly_zero(Fr::one(), &points)?;
+        let b_polynomial = selector_polynomial(selector).map_err(Error::Backend)?;
         let b_evals: Vec<Fr> = domain.fft(b_polynomial.coeffs());
 
         let scalars: Vec<Fr> = selected_indices.iter().map(|&idx| b_evals[idx]).collect();
@@ -480,7 +1163,7 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
         let q0_g1 = <KZG as PolynomialCommitment<B>>::commit_g1(&agg_key.kzg_params, &q0)
             .map_err(Error::Backend)?;
 
-        let mut bhat_coeffs = vec![Fr::zero(); ciphertext.threshold];
+        let mut bhat_coeffs = vec![Fr::zero(); threshold];
         bhat_coeffs.extend_from_slice(b_polynomial.coeffs());
         let bhat = DensePolynomial::from_coefficients_vec(bhat_coeffs);
         let bhat_g1 = <KZG as PolynomialCommitment<B>>::commit_g1(&agg_key.kzg_params, &bhat)
@@ -494,190 +1177,190 @@ impl<B: PairingBackend<Scalar = Fr>> ThresholdEncryption<B> for SilentThresholdS
                 )))?;
         let scaled_scalars: Vec<Fr> = scalars.iter().map(|scalar| *scalar * party_inv).collect();
 
-        let apk = if scalars.is_empty() {
-            B::G1::identity()
-        } else {
+        let apk = {
             let bases: Vec<B::G1> = selected_indices
                 .iter()
-                .map(|&idx| agg_key.public_keys[idx].bls_key)
+                .map(|&idx| {
+                    if revoked(idx) {
+                        B::G1::identity()
+                    } else {
+                        agg_key.public_keys[idx].bls_key
+                    }
+                })
                 .collect();
-            B::G1::multi_scalar_multiplication(&bases, &scaled_scalars)
+            B::Msm::msm_g1(&bases, &scaled_scalars).map_err(Error::Backend)?
         };
 
-        let sigma = if scalars.is_empty() {
-            B::G2::identity()
-        } else {
+        let sigma = {
             let bases: Vec<B::G2> = selected_indices
                 .iter()
-                .map(|&idx| partial_map[idx].unwrap().response)
+                .map(|&idx| partial_map[idx].map_or(B::G2::identity(), |p| p.response))
                 .collect();
-            B::G2::multi_scalar_multiplication(&bases, &scaled_scalars)
+            B::Msm::msm_g2(&bases, &scaled_scalars).map_err(Error::Backend)?
         };
 
-        let qx = if scalars.is_empty() {
-            B::G1::identity()
-        } else {
+        let qx = {
             let points: Vec<B::G1> = selected_indices
                 .iter()
-                .map(|&idx| agg_key.public_keys[idx].lagrange_li_x)
+                .map(|&idx| {
+                    if revoked(idx) {
+                        B::G1::identity()
+                    } else {
+                        agg_key.public_keys[idx].lagrange_li_x
+                    }
+                })
                 .collect();
-            B::G1::multi_scalar_multiplication(&points, &scalars)
+            B::Msm::msm_g1(&points, &scalars).map_err(Error::Backend)?
         };
 
-        let qz = if scalars.is_empty() {
-            B::G1::identity()
-        } else {
+        let qz = {
             let points: Vec<B::G1> = selected_indices
                 .iter()
                 .map(|&idx| agg_key.lagrange_row_sums[idx])
                 .collect();
-            B::G1::multi_scalar_multiplication(&points, &scalars)
+            B::Msm::msm_g1(&points, &scalars).map_err(Error::Backend)?
         };
 
-        let qhatx = if scalars.is_empty() {
-            B::G1::identity()
-        } else {
+        let qhatx = {
             let points: Vec<B::G1> = selected_indices
                 .iter()
-                .map(|&idx| agg_key.public_keys[idx].lagrange_li_minus0)
+                .map(|&idx| {
+                    if revoked(idx) {
+                        B::G1::identity()
+                    } else {
+                        agg_key.public_keys[idx].lagrange_li_minus0
+                    }
+                })
                 .collect();
-            B::G1::multi_scalar_multiplication(&points, &scalars)
+            B::Msm::msm_g1(&points, &scalars).map_err(Error::Backend)?
         };
 
-        let w1 = [
-            apk.negate(),
-            qz.negate(),
-            qx.negate(),
-            qhatx,
-            bhat_g1.negate(),
-            q0_g1.negate(),
-        ];
-        let w2 = [b_g2, sigma];
-
-        let mut enc_key_lhs = w1.to_vec();
+        Ok(DecryptionTerms {
+            g1: [
+                apk.negate(),
+                qz.negate(),
+                qx.negate(),
+                qhatx,
+                bhat_g1.negate(),
+                q0_g1.negate(),
+            ],
+            g2: [b_g2, sigma],
+        })
+    }
+
+    /// Completes the decryption pairing with the ciphertext's proof elements,
+    /// checks it against the shared secret, and derives the payload key.
+    pub(super) fn payload_key_from_terms(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        terms: &DecryptionTerms<B>,
+    ) -> Result<[u8; 32], Error> {
+        if ciphertext.proof_g1.len() != PROOF_G1_LEN || ciphertext.proof_g2.len() != PROOF_G2_LEN {
+            return Err(Error::MalformedInput(
+                "ciphertext proof sizes are invalid".into(),
+            ));
+        }
+        let mut enc_key_lhs = SmallVec::<[B::G1; 8]>::from_slice(&terms.g1);
         enc_key_lhs.extend_from_slice(&ciphertext.proof_g1);
-        let mut enc_key_rhs = ciphertext.proof_g2.clone();
-        enc_key_rhs.extend_from_slice(&w2);
+        let mut enc_key_rhs = SmallVec::<[B::G2; 8]>::from_slice(&ciphertext.proof_g2);
+        enc_key_rhs.extend_from_slice(&terms.g2);
 
         let enc_key = B::multi_pairing(&enc_key_lhs, &enc_key_rhs).map_err(Error::Backend)?;
-        if enc_key != ciphertext.shared_secret {
+        if !bool::from(enc_key.ct_eq(&ciphertext.shared_secret)) {
             return Err(Error::MalformedInput(
                 "ciphertext verification failed".into(),
             ));
         }
 
-        let payload_key = derive_payload_key::<B>(&enc_key);
-        let plaintext = self
-            .symmetric_enc
-            .decrypt(&payload_key, &ciphertext.payload)?;
-
-        Ok(DecryptionResult {
-            plaintext: Some(plaintext),
-        })
+        Ok(derive_payload_key::<B, X>(
+            ciphertext.kdf,
+            &enc_key,
+            &ciphertext.header(),
+            &self.context,
+        ))
     }
 }
 
-/// Constructs a polynomial that evaluates to `eval` at the first point and zero at all others.
-///
-/// This is a specialized Lagrange interpolation that efficiently constructs a polynomial
-/// b(x) such that:
-/// - b(points[0]) = eval
-/// - b(points[i]) = 0 for i > 0
-///
-/// This is used during aggregate decryption to construct the interpolation polynomial
-/// for combining partial decryption shares.
-///
-/// # Algorithm
-///
-/// The polynomial is constructed by:
-/// 1. Building the product (x - points[1])(x - points[2])...(x - points[n-1])
-/// 2. Evaluating this product at points[0] to get the normalization factor
-/// 3. Scaling the coefficients so that b(points[0]) = eval
-///
-/// # Arguments
-///
-/// * `eval` - The desired evaluation at the first point (typically 1)
-/// * `points` - The evaluation points, where points[0] is the "anchor" point
-///
-/// # Returns
-///
-/// A polynomial satisfying the interpolation constraints, or an error if the
-/// anchor point is a root of the vanishing polynomial.
-///
-/// # Errors
-///
-/// Returns `Error::Backend` if the interpolation anchor cannot be inverted,
-/// which would indicate that points[0] is equal to one of the other points.
-#[instrument(level = "info", skip_all)]
-fn interp_mostly_zero(eval: Fr, points: &[Fr]) -> Result<DensePolynomial, Error> {
-    if points.is_empty() {
-        return Ok(DensePolynomial::from_coefficients_vec(vec![Fr::one()]));
-    }
+/// Pairing terms aggregated from a set of partial decryptions, paired with a
+/// ciphertext's proof elements to recover its shared secret.
+#[derive(Clone, Debug)]
+pub(crate) struct DecryptionTerms<B: PairingBackend> {
+    /// Negated `apk`, `qz`, `qx`, then `qhatx`, negated `bhat` and `q0`,
+    /// paired with the G2 proof elements.
+    pub(crate) g1: [B::G1; 6],
+    /// `b` and the aggregated responses, paired with the G1 proof elements.
+    pub(crate) g2: [B::G2; 2],
+}
 
-    let mut coeffs = vec![Fr::one()];
-    for point in points.iter().skip(1) {
-        let neg_point = -*point;
-        coeffs.push(Fr::zero());
-        for i in (0..coeffs.len() - 1).rev() {
-            let (left, right) = coeffs.split_at_mut(i + 1);
-            let coef = &mut left[i];
-            let next = &mut right[0];
-            *next += *coef;
-            *coef *= neg_point;
-        }
+pub(super) fn check_encryption_threshold<B: PairingBackend<Scalar = Fr>>(
+    agg_key: &AggregateKey<B>,
+    params: &Params<B>,
+    threshold: usize,
+) -> Result<usize, Error> {
+    if threshold == 0 {
+        return Err(Error::InvalidConfig(
+            "threshold must be greater than 0".into(),
+        ));
     }
-
-    let mut scale = *coeffs.last().unwrap();
-    let anchor = points[0];
-    for coef in coeffs.iter().rev().skip(1) {
-        scale = scale * anchor + *coef;
+    let threshold = agg_key.ciphertext_threshold(threshold);
+    if threshold >= params.srs.powers_of_g.len() {
+        return Err(Error::InvalidConfig(
+            "threshold exceeds available SRS powers".into(),
+        ));
     }
+    Ok(threshold)
+}
 
-    let scale_inv = scale.invert().ok_or(Error::Backend(BackendError::Math(
-        "failed to invert interpolation anchor",
-    )))?;
-    let multiplier = eval * scale_inv;
+/// Derives the payload key from a pairing target group element.
+///
+/// The element's encoding is passed to the [`Kdf`](crate::Kdf) identified by
+/// `kdf` together with the ciphertext header's encoding followed by the
+/// application context set with [`SilentThresholdScheme::with_context`]. The
+/// header encoding is self-delimiting, so the two cannot be confused. The
+/// default [`KdfId::Xof`] hashes them under the domain separator
+/// "tess::payload-key" with the scheme's XOF.
+fn derive_payload_key<B: PairingBackend, X: Xof>(
+    kdf: KdfId,
+    enc_key: &B::Target,
+    header: &CiphertextHeader,
+    context: &[u8],
+) -> [u8; 32] {
+    let mut info = header.to_bytes();
+    info.extend_from_slice(context);
+    kdf.derive::<X>(enc_key.to_repr().as_ref(), &info)
+}
 
-    for coeff in coeffs.iter_mut() {
-        *coeff *= multiplier;
+/// Appends a length-prefixed application context; the empty context appends
+/// nothing, keeping keys of context-free schemes unchanged.
+pub(super) fn update_context<X: Xof>(hasher: &mut X, context: &[u8]) {
+    if !context.is_empty() {
+        hasher.update(&(context.len() as u64).to_le_bytes());
+        hasher.update(context);
     }
-
-    Ok(DensePolynomial::from_coefficients_vec(coeffs))
 }
 
-/// Derives a symmetric encryption key from a pairing target group element.
+/// Derives the encryption randomness seed for deterministic mode.
 ///
-/// Uses BLAKE3 as a key derivation function (KDF) to convert the shared secret
-/// from the pairing operation into a 32-byte symmetric key suitable for payload
-/// encryption.
-///
-/// # Domain Separation
-///
-/// The derivation uses the domain separator "tess::payload-key" to ensure
-/// cryptographic independence from other uses of BLAKE3 in the system.
-///
-/// # Arguments
-///
-/// * `enc_key` - The shared secret from the pairing operation e(g,h)^s
-///
-/// # Returns
-///
-/// A 32-byte symmetric key derived deterministically from the input
-///
-/// # Security
-///
-/// The derived key is computationally indistinguishable from random under
-/// the assumption that BLAKE3 is a secure hash function and the input
-/// has sufficient entropy.
-fn derive_payload_key<B: PairingBackend>(enc_key: &B::Target) -> [u8; 32] {
-    let mut hasher = Hasher::new();
-    hasher.update(b"tess::payload-key");
-    let repr = enc_key.to_repr();
-    hasher.update(repr.as_ref());
-    let digest = hasher.finalize();
-    let mut key = [0u8; 32];
-    key.copy_from_slice(digest.as_bytes());
-    key
+/// Binds the aggregate key, threshold, label, and payload under the domain
+/// separator "tess::deterministic-encrypt". Variable-length inputs are length
+/// prefixed so distinct (label, payload) pairs never collide.
+fn derive_deterministic_seed<B: PairingBackend<Scalar = Fr>, X: Xof>(
+    agg_key: &AggregateKey<B>,
+    threshold: usize,
+    label: &[u8],
+    payload: &[u8],
+) -> [u8; 32] {
+    let mut hasher = X::default();
+    hasher.update(b"tess::deterministic-encrypt");
+    hasher.update(agg_key.ask.to_repr().as_ref());
+    hasher.update(agg_key.z_g2.to_repr().as_ref());
+    hasher.update(agg_key.precomputed_pairing.to_repr().as_ref());
+    hasher.update(&(threshold as u64).to_le_bytes());
+    hasher.update(&(label.len() as u64).to_le_bytes());
+    hasher.update(label);
+    hasher.update(&(payload.len() as u64).to_le_bytes());
+    hasher.update(payload);
+    hasher.finalize_bytes()
 }
 
 #[cfg(test)]
@@ -687,6 +1370,92 @@ mod tests {
 
     use crate::PairingEngine;
 
+    #[test]
+    fn verify_partial_decryption_rejects_bad_shares() {
+        let mut rng = StdRng::seed_from_u64(1447);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"relay")
+            .unwrap();
+
+        for (sk, pk) in keys.secret_keys.iter().zip(&keys.public_keys) {
+            let partial = scheme.partial_decrypt(sk, &ct).unwrap();
+            assert!(crate::verify_partial_decryption(pk, &ct, &partial));
+        }
+
+        let mut partial = scheme.partial_decrypt(&keys.secret_keys[0], &ct).unwrap();
+        partial.response = partial
+            .response
+            .add(&<PairingEngine as PairingBackend>::G2::generator());
+        assert!(!crate::verify_partial_decryption(
+            &keys.public_keys[0],
+            &ct,
+            &partial
+        ));
+
+        let mut partial = scheme.partial_decrypt(&keys.secret_keys[0], &ct).unwrap();
+        partial.participant_id = 1;
+        assert!(!crate::verify_partial_decryption(
+            &keys.public_keys[1],
+            &ct,
+            &partial
+        ));
+    }
+
+    #[test]
+    fn batch_share_verification_names_bad_shares() {
+        let mut rng = StdRng::seed_from_u64(1480);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 8, 4).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 8, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 4, b"batch")
+            .unwrap();
+        let mut partials: Vec<_> = keys
+            .secret_keys
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        assert!(crate::verify_partial_decryptions(&keys.public_keys, &ct, &partials).is_empty());
+
+        partials[2].response = partials[2]
+            .response
+            .add(&<PairingEngine as PairingBackend>::G2::generator());
+        partials[5].participant_id = 6;
+        partials[7].participant_id = 8;
+        assert_eq!(
+            crate::verify_partial_decryptions(&keys.public_keys, &ct, &partials),
+            [2, 5, 7]
+        );
+        assert!(crate::verify_partial_decryptions(&keys.public_keys, &ct, &[]).is_empty());
+    }
+
+    #[test]
+    fn duplicate_partials_do_not_meet_threshold() {
+        let mut rng = StdRng::seed_from_u64(1469);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 3).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 3, b"quorum")
+            .unwrap();
+
+        let first = scheme.partial_decrypt(&keys.secret_keys[0], &ct).unwrap();
+        let second = scheme.partial_decrypt(&keys.secret_keys[1], &ct).unwrap();
+        let partials = vec![first.clone(), second, first];
+        let selector = vec![true, true, true, false];
+        let res = scheme.aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key);
+        assert!(matches!(
+            res,
+            Err(Error::InsufficientShares {
+                required: 3,
+                provided: 2
+            })
+        ));
+    }
+
     #[test]
     fn e2e_negative_tampered_ciphertext() {
         let mut rng = thread_rng();
@@ -716,6 +1485,555 @@ mod tests {
         assert!(matches!(res, Err(Error::MalformedInput(_))));
     }
 
+    #[cfg(feature = "hkdf")]
+    #[test]
+    fn hkdf_payload_keys_are_recorded_and_followed() {
+        let mut rng = StdRng::seed_from_u64(1457);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new().with_kdf(KdfId::HkdfSha256);
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"hkdf")
+            .unwrap();
+        assert_eq!(ct.kdf, KdfId::HkdfSha256);
+        scheme
+            .verify_ciphertext(&keys.aggregate_key, &params, &ct)
+            .unwrap();
+
+        // Decryption follows the header, not the decryptor's configuration.
+        let decryptor = SilentThresholdScheme::<PairingEngine>::new();
+        let selector = [true, true, true, false];
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| decryptor.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let result = decryptor
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"hkdf"[..]));
+
+        let mut relabelled = ct.clone();
+        relabelled.kdf = KdfId::Xof;
+        assert!(
+            scheme
+                .verify_ciphertext(&keys.aggregate_key, &params, &relabelled)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn proofs_are_stored_inline() {
+        let mut rng = StdRng::seed_from_u64(1454);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"inline")
+            .unwrap();
+
+        assert!(!ct.proof_g1.spilled());
+        assert!(!ct.proof_g2.spilled());
+        assert_eq!(keys.public_keys[0].lagrange_li_lj_z.len(), 4);
+    }
+
+    #[test]
+    fn owned_payload_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let payload = b"owned payload".to_vec();
+        let ct = scheme
+            .encrypt_owned(
+                &mut rng,
+                &keys.aggregate_key,
+                &params,
+                threshold,
+                payload.clone(),
+            )
+            .unwrap();
+
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+
+        let borrowed = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(borrowed.plaintext.as_deref(), Some(payload.as_slice()));
+
+        let owned = scheme
+            .aggregate_decrypt_owned(ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(owned.plaintext, Some(payload));
+    }
+
+    #[test]
+    fn swapped_groups_roundtrip() {
+        type B = crate::Swapped<PairingEngine>;
+        let mut rng = StdRng::seed_from_u64(1429);
+        let scheme = SilentThresholdScheme::<B>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let payload = b"swapped groups";
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, threshold, payload)
+            .unwrap();
+
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+
+        // Partial responses now live in the base backend's G1, which has the
+        // shorter encoding on every real curve.
+        #[cfg(any(feature = "blst", feature = "ark_bls12381", feature = "ark_bn254"))]
+        {
+            let plain_response = <PairingEngine as PairingBackend>::G2::generator();
+            assert!(
+                B::serialize_g2(&partials[0].response).len()
+                    < PairingEngine::serialize_g2(&plain_response).len()
+            );
+        }
+
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(payload.as_slice()));
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compressed_payload_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new().with_compression(3);
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let payload = b"mempool-tx;".repeat(512);
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, threshold, &payload)
+            .unwrap();
+        assert!(ct.compressed);
+        assert!(ct.payload.len() < payload.len());
+
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext, Some(payload));
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn decompression_is_capped() {
+        let mut rng = StdRng::seed_from_u64(1391);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new().with_compression(3);
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+
+        // 64 KiB of zeros compresses to a few dozen bytes.
+        let payload = vec![0u8; 64 << 10];
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, &payload)
+            .unwrap();
+        assert!(ct.payload.len() < 1024);
+        let partials: Vec<_> = keys.secret_keys[..2]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let selector = [true, true, false, false];
+
+        let strict = scheme
+            .clone()
+            .with_decompression_limits(crate::DeserializeLimits {
+                max_payload_len: 32 << 10,
+                ..Default::default()
+            });
+        assert!(matches!(
+            strict.aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key),
+            Err(Error::MalformedInput(_))
+        ));
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext, Some(payload));
+    }
+
+    #[test]
+    fn padded_payload_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new()
+            .with_padding(PaddingScheme::FixedBucket(256));
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let payload = b"short message".to_vec();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, threshold, &payload)
+            .unwrap();
+        assert!(ct.padded);
+        assert_eq!(ct.payload.len(), 256);
+
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+
+        // Padding is self-describing, so a scheme without padding configured can decrypt.
+        let result = SilentThresholdScheme::<PairingEngine>::new()
+            .aggregate_decrypt_owned(ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext, Some(payload));
+    }
+
+    #[test]
+    fn deterministic_encryption_is_repeatable() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let payload = b"dedup me";
+        let ct_a = scheme
+            .encrypt_deterministic(agg, &params, threshold, b"label", payload)
+            .unwrap();
+        let ct_b = scheme
+            .encrypt_deterministic(agg, &params, threshold, b"label", payload)
+            .unwrap();
+        let ct_other = scheme
+            .encrypt_deterministic(agg, &params, threshold, b"other", payload)
+            .unwrap();
+        assert!(ct_a.deterministic);
+        assert_eq!(ct_a.gamma_g2, ct_b.gamma_g2);
+        assert_eq!(ct_a.proof_g1, ct_b.proof_g1);
+        assert_eq!(ct_a.proof_g2, ct_b.proof_g2);
+        assert_eq!(ct_a.payload, ct_b.payload);
+        assert_ne!(ct_a.gamma_g2, ct_other.gamma_g2);
+
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct_a).unwrap());
+        }
+        let result = scheme
+            .aggregate_decrypt(&ct_a, &partials, &selector, agg)
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&payload[..]));
+    }
+
+    #[test]
+    fn wrap_unwrap_key_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(19);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new()
+            .with_padding(PaddingScheme::FixedBucket(128));
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+
+        let dek = [0x5Au8; DEK_LEN];
+        let ct = scheme
+            .wrap_key(&mut rng, &keys.aggregate_key, &params, threshold, &dek)
+            .unwrap();
+        assert_eq!(ct.payload.len(), DEK_LEN);
+        assert!(!ct.padded);
+
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+        let recovered = scheme
+            .unwrap_key(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(recovered, dek);
+
+        let mut truncated = ct.clone();
+        truncated.payload.pop();
+        let res = scheme.unwrap_key(&truncated, &partials, &selector, &keys.aggregate_key);
+        assert!(matches!(res, Err(Error::MalformedInput(_))));
+    }
+
+    #[test]
+    fn verify_ciphertext_accepts_honest_and_rejects_malformed() {
+        let mut rng = StdRng::seed_from_u64(41);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let ct = scheme
+            .encrypt(&mut rng, agg, &params, threshold, b"relay me")
+            .unwrap();
+        scheme.verify_ciphertext(agg, &params, &ct).unwrap();
+
+        let mut bad_gamma = ct.clone();
+        bad_gamma.gamma_g2 = <PairingEngine as PairingBackend>::G2::identity();
+        assert!(scheme.verify_ciphertext(agg, &params, &bad_gamma).is_err());
+
+        let mut bad_proof = ct.clone();
+        bad_proof.proof_g1[1] = bad_proof.proof_g1[0];
+        assert!(scheme.verify_ciphertext(agg, &params, &bad_proof).is_err());
+
+        let mut bad_threshold = ct.clone();
+        bad_threshold.threshold = params.srs.powers_of_g.len();
+        assert!(
+            scheme
+                .verify_ciphertext(agg, &params, &bad_threshold)
+                .is_err()
+        );
+
+        let other_keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        assert!(
+            scheme
+                .verify_ciphertext(&other_keys.aggregate_key, &params, &ct)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn decryption_proof_verifies() {
+        let mut rng = StdRng::seed_from_u64(43);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new().with_decryption_proofs(true);
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let ct = scheme
+            .encrypt(&mut rng, agg, &params, threshold, b"auditable")
+            .unwrap();
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::with_capacity(threshold);
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, agg)
+            .unwrap();
+        assert!(result.proof.is_some());
+        scheme.verify_decryption(&result, &ct, agg).unwrap();
+
+        let mut wrong_plaintext = result.clone();
+        wrong_plaintext.plaintext = Some(b"forged".to_vec());
+        assert!(
+            scheme
+                .verify_decryption(&wrong_plaintext, &ct, agg)
+                .is_err()
+        );
+
+        let mut bad_share = result.clone();
+        let proof = bad_share.proof.as_mut().unwrap();
+        proof.partials[1].response = proof.partials[2].response;
+        assert!(matches!(
+            scheme.verify_decryption(&bad_share, &ct, agg),
+            Err(Error::MalformedInput(_))
+        ));
+
+        let plain = SilentThresholdScheme::<PairingEngine>::new()
+            .aggregate_decrypt(&ct, &partials, &selector, agg)
+            .unwrap();
+        assert!(plain.proof.is_none());
+    }
+
+    #[test]
+    fn signcrypted_ciphertext_checks_sender() {
+        let mut rng = StdRng::seed_from_u64(71);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let sender = SenderKey::<PairingEngine>::generate(&mut rng);
+        let other = SenderKey::<PairingEngine>::generate(&mut rng);
+
+        let ct = scheme
+            .signcrypt(
+                &mut rng,
+                &keys.aggregate_key,
+                &params,
+                threshold,
+                b"signed",
+                &sender,
+            )
+            .unwrap();
+        let mut selector = vec![false; parties];
+        let mut partials = Vec::new();
+        for (i, selected) in selector.iter_mut().enumerate().take(threshold + 1) {
+            *selected = true;
+            partials.push(scheme.partial_decrypt(&keys.secret_keys[i], &ct).unwrap());
+        }
+
+        let result = scheme
+            .aggregate_decrypt_from(
+                &ct,
+                &partials,
+                &selector,
+                &keys.aggregate_key,
+                &sender.public_key(),
+            )
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"signed");
+        assert!(
+            scheme
+                .aggregate_decrypt_from(
+                    &ct,
+                    &partials,
+                    &selector,
+                    &keys.aggregate_key,
+                    &other.public_key(),
+                )
+                .is_err()
+        );
+
+        // Re-signing with another key, or tampering, fails before decryption.
+        let mut forged = ct.clone();
+        let mut signature = other.sign(&ct);
+        signature.sender = sender.public_key();
+        forged.sender_signature = Some(signature);
+        assert!(
+            scheme
+                .aggregate_decrypt(&forged, &partials, &selector, &keys.aggregate_key)
+                .is_err()
+        );
+        let mut tampered = ct.clone();
+        tampered.threshold += 1;
+        assert!(
+            tampered
+                .sender_signature
+                .as_ref()
+                .unwrap()
+                .verify(&tampered)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn mixed_radix_committee_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(101);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 12;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let ct = scheme
+            .encrypt(&mut rng, agg, &params, threshold, b"twelve parties")
+            .unwrap();
+        scheme.verify_ciphertext(agg, &params, &ct).unwrap();
+
+        let mut selector = vec![false; parties];
+        for idx in [0, 3, 7, 10, 11] {
+            selector[idx] = true;
+        }
+        let partials: Vec<_> = selector
+            .iter()
+            .enumerate()
+            .filter(|(_, selected)| **selected)
+            .map(|(idx, _)| scheme.partial_decrypt(&keys.secret_keys[idx], &ct).unwrap())
+            .collect();
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, agg)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"twelve parties");
+
+        assert!(matches!(
+            scheme.param_gen(&mut rng, 10, threshold),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn encrypt_batch_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(83);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let payloads: Vec<Vec<u8>> = (0..5u8).map(|i| vec![i; 10 + i as usize]).collect();
+        let cts = scheme
+            .encrypt_batch(&mut rng, agg, &params, threshold, &payloads)
+            .unwrap();
+        assert_eq!(cts.len(), payloads.len());
+
+        let mut selector = vec![false; parties];
+        selector[..=threshold].fill(true);
+        for (ct, payload) in cts.iter().zip(&payloads) {
+            scheme.verify_ciphertext(agg, &params, ct).unwrap();
+            let partials: Vec<_> = keys.secret_keys[..=threshold]
+                .iter()
+                .map(|sk| scheme.partial_decrypt(sk, ct).unwrap())
+                .collect();
+            let result = scheme
+                .aggregate_decrypt(ct, &partials, &selector, agg)
+                .unwrap();
+            assert_eq!(&result.plaintext.unwrap(), payload);
+        }
+        assert!(!cts[0].gamma_g2.sub(&cts[1].gamma_g2).is_identity());
+
+        let empty: [&[u8]; 0] = [];
+        assert!(
+            scheme
+                .encrypt_batch(&mut rng, agg, &params, threshold, &empty)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            scheme
+                .encrypt_batch(&mut rng, agg, &params, 0, &payloads)
+                .is_err()
+        );
+    }
+
     #[test]
     fn keygen_single_validator_matches_keygen_unsafe() {
         let scheme = SilentThresholdScheme::<PairingEngine>::new();
@@ -777,25 +2095,66 @@ mod tests {
         );
     }
 
-    #[test]
-    fn interp_mostly_zero_respects_constraints() {
-        let points = vec![Fr::one(), Fr::from_u64(3), Fr::from_u64(5)];
-        let poly = interp_mostly_zero(Fr::one(), &points).unwrap();
-
-        assert_eq!(poly.evaluate(&points[0]), Fr::one());
-        for point in points.iter().skip(1) {
-            assert_eq!(poly.evaluate(point), Fr::zero());
-        }
-    }
-
     #[test]
     fn derive_payload_key_deterministic() {
         let g1 = <PairingEngine as PairingBackend>::G1::generator();
         let g2 = <PairingEngine as PairingBackend>::G2::generator();
         let enc_key = <PairingEngine as PairingBackend>::pairing(&g1, &g2);
 
-        let key_a = derive_payload_key::<PairingEngine>(&enc_key);
-        let key_b = derive_payload_key::<PairingEngine>(&enc_key);
-        assert_eq!(key_a, key_b);
+        let header = CiphertextHeader::default();
+        let derive = |header: &CiphertextHeader| {
+            derive_payload_key::<PairingEngine, blake3::Hasher>(KdfId::Xof, &enc_key, header, &[])
+        };
+        assert_eq!(derive(&header), derive(&header));
+        let epoch = CiphertextHeader {
+            epoch: 1,
+            ..header.clone()
+        };
+        assert_ne!(derive(&header), derive(&epoch));
+    }
+
+    #[test]
+    fn application_context_binds_payload_key() {
+        let mut rng = StdRng::seed_from_u64(1442);
+        let chain_a =
+            SilentThresholdScheme::<PairingEngine>::new().with_context(b"app/chain-1/epoch-7");
+        let chain_b =
+            SilentThresholdScheme::<PairingEngine>::new().with_context(b"app/chain-2/epoch-7");
+        let plain = SilentThresholdScheme::<PairingEngine>::new();
+        let params = chain_a.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = chain_a.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let agg = &keys.aggregate_key;
+
+        let ct = chain_a
+            .encrypt(&mut rng, agg, &params, 2, b"context bound")
+            .unwrap();
+        let selector = [true, true, true, false];
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| chain_a.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let decrypt = |scheme: &SilentThresholdScheme<PairingEngine>| {
+            scheme
+                .aggregate_decrypt(&ct, &partials, &selector, agg)
+                .unwrap()
+                .plaintext
+                .unwrap()
+        };
+        assert_eq!(decrypt(&chain_a), b"context bound");
+        assert_ne!(decrypt(&chain_b), b"context bound");
+        assert_ne!(decrypt(&plain), b"context bound");
+
+        let broadcast = chain_a
+            .encrypt(&mut rng, agg, &params, 1, b"to anyone")
+            .unwrap();
+        let sk = &keys.secret_keys[2];
+        assert_eq!(
+            chain_a.decrypt_broadcast(sk, &broadcast).unwrap(),
+            b"to anyone"
+        );
+        assert_ne!(
+            chain_b.decrypt_broadcast(sk, &broadcast).unwrap(),
+            b"to anyone"
+        );
     }
 }
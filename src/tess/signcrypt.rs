@@ -0,0 +1,147 @@
+//! Sender-authenticated ciphertexts (signcryption).
+//!
+//! A sender holding a [`SenderKey`] signs the ciphertext with a BLS signature
+//! over the same backend: the public key is `sk·g` in G1 and the signature is
+//! `sk·H(m)` in G2, where `m` is a digest of every ciphertext field except the
+//! signature itself. The signature travels in the ciphertext, and aggregate
+//! decryption checks it before any plaintext is released.
+
+use alloc::vec::Vec;
+
+use blake3::Hasher;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use super::header::bind_header_metadata;
+use crate::{
+    Ciphertext, CurvePoint, FieldElement, KdfId, PairingBackend, TargetGroup, errors::Error,
+};
+
+/// Domain separation tag for hashing ciphertext digests to G2.
+const SIGNATURE_DST: &[u8] = b"TESS-SIGNCRYPT-V01-BLS-G2";
+
+/// Signing key of a ciphertext sender.
+#[derive(Clone, Debug)]
+pub struct SenderKey<B: PairingBackend> {
+    /// Secret signing scalar.
+    pub scalar: B::Scalar,
+}
+
+impl<B: PairingBackend> Zeroize for SenderKey<B> {
+    fn zeroize(&mut self) {
+        self.scalar = B::Scalar::zero();
+    }
+}
+
+impl<B: PairingBackend> Drop for SenderKey<B> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// BLS signature binding a ciphertext to its sender.
+#[derive(Clone, Debug)]
+pub struct SenderSignature<B: PairingBackend> {
+    /// Sender's public key `sk·g`.
+    pub sender: B::G1,
+    /// Signature `sk·H(m)` over the ciphertext digest.
+    pub signature: B::G2,
+}
+
+impl<B: PairingBackend> SenderKey<B> {
+    /// Samples a fresh sender key.
+    pub fn generate<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            scalar: B::Scalar::random(rng),
+        }
+    }
+
+    /// Returns the sender's public key `sk·g`.
+    pub fn public_key(&self) -> B::G1 {
+        B::G1::mul_generator(&self.scalar)
+    }
+
+    /// Signs `ciphertext`, ignoring any signature it already carries.
+    pub fn sign(&self, ciphertext: &Ciphertext<B>) -> SenderSignature<B> {
+        SenderSignature {
+            sender: self.public_key(),
+            signature: message_point(ciphertext).mul_scalar(&self.scalar),
+        }
+    }
+}
+
+impl<B: PairingBackend> SenderSignature<B> {
+    /// Verifies the signature over `ciphertext`: `e(g, σ) = e(pk, H(m))`.
+    pub fn verify(&self, ciphertext: &Ciphertext<B>) -> Result<(), Error> {
+        if self.sender.is_identity() {
+            return Err(Error::MalformedInput(
+                "sender public key is the identity".into(),
+            ));
+        }
+        let product = B::multi_pairing(
+            &[B::G1::generator(), self.sender.negate()],
+            &[self.signature, message_point(ciphertext)],
+        )
+        .map_err(Error::Backend)?;
+        if product != B::Target::identity() {
+            return Err(Error::MalformedInput(
+                "sender signature does not verify".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<B: PairingBackend> Ciphertext<B> {
+    /// BLAKE3 digest of every field except the sender signature.
+    ///
+    /// Sender signatures and
+    /// [`SignedPartialDecryption`](crate::SignedPartialDecryption)s are made
+    /// over this digest.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = Hasher::new();
+        hasher.update(self.gamma_g2.to_repr().as_ref());
+        hasher.update(&(self.proof_g1.len() as u64).to_le_bytes());
+        for point in &self.proof_g1 {
+            hasher.update(point.to_repr().as_ref());
+        }
+        hasher.update(&(self.proof_g2.len() as u64).to_le_bytes());
+        for point in &self.proof_g2 {
+            hasher.update(point.to_repr().as_ref());
+        }
+        hasher.update(self.shared_secret.to_repr().as_ref());
+        hasher.update(&(self.threshold as u64).to_le_bytes());
+        hasher.update(&[
+            self.compressed as u8,
+            self.padded as u8,
+            self.deterministic as u8,
+        ]);
+        if self.kdf != KdfId::default() {
+            hasher.update(&[self.kdf.to_byte()]);
+        }
+        if let Some(proof) = &self.validity_proof {
+            let scalars: Vec<_> = core::iter::once(&proof.challenge)
+                .chain(&proof.responses)
+                .collect();
+            hasher.update(&(scalars.len() as u64).to_le_bytes());
+            for scalar in scalars {
+                hasher.update(scalar.to_repr().as_ref());
+            }
+        } else {
+            hasher.update(&0u64.to_le_bytes());
+        }
+        hasher.update(&(self.broadcast_keys.len() as u64).to_le_bytes());
+        for key in &self.broadcast_keys {
+            hasher.update(key);
+        }
+        bind_header_metadata(&mut hasher, self);
+        hasher.update(&(self.payload.len() as u64).to_le_bytes());
+        hasher.update(&self.payload);
+        *hasher.finalize().as_bytes()
+    }
+}
+
+/// Hashes the signed ciphertext fields to G2.
+fn message_point<B: PairingBackend>(ciphertext: &Ciphertext<B>) -> B::G2 {
+    B::G2::hash_to_curve(&ciphertext.digest(), SIGNATURE_DST)
+}
@@ -0,0 +1,331 @@
+//! Signed partial decryptions for untrusted transports.
+//!
+//! A [`PartialDecryption`] is self-verifying against the ciphertext, but it
+//! does not say who sent it or for which ciphertext and epoch it was meant.
+//! [`SignedPartialDecryption`] wraps it with a BLS signature `sk·H(m)` in G2
+//! by the participant's [`PartialSigningKey`], where `m` covers the
+//! participant id, the encoded response, the [`Ciphertext::digest`] and the
+//! ciphertext's epoch. Coordinators check it against the signing key the
+//! member registered with [`Committee::register_signing_key`], so they can
+//! drop forged or replayed shares with one pairing equation before the
+//! decryption math, and a signed share is attributable to its signer.
+//!
+//! The signing key is separate from the threshold [`SecretKey`]. A partial
+//! decryption is `sk·gamma_g2` for whatever `gamma_g2` a ciphertext carries,
+//! so signing with the threshold key would let anyone obtain a member's
+//! signature on a message of their choosing by sending it a ciphertext with
+//! `gamma_g2 = H(m)`, and frame the member with a validly signed bad share.
+
+use alloc::vec::Vec;
+
+use blake3::Hasher;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+use crate::{
+    Ciphertext, Committee, CurvePoint, DecryptionResult, FieldElement, Fr, PairingBackend,
+    PartialDecryption, SecretKey, SilentThresholdScheme, TargetGroup, ThresholdEncryption, Xof,
+    errors::Error,
+};
+
+/// Domain separation tag for hashing signed partial decryptions to G2.
+const PARTIAL_SIGNATURE_DST: &[u8] = b"TESS-PARTIAL-V01-BLS-G2";
+
+/// Key a participant signs its partial decryptions with.
+///
+/// Generated independently of the participant's threshold [`SecretKey`]; its
+/// [`public_key`](Self::public_key) is registered with the committee through
+/// [`Committee::register_signing_key`].
+#[derive(Clone, Debug)]
+pub struct PartialSigningKey<B: PairingBackend> {
+    /// Participant the key signs shares for.
+    pub participant_id: usize,
+    /// Secret signing scalar.
+    pub scalar: B::Scalar,
+}
+
+impl<B: PairingBackend> Zeroize for PartialSigningKey<B> {
+    fn zeroize(&mut self) {
+        self.scalar = B::Scalar::zero();
+    }
+}
+
+impl<B: PairingBackend> Drop for PartialSigningKey<B> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<B: PairingBackend> PartialSigningKey<B> {
+    /// Samples a fresh signing key for `participant_id`.
+    pub fn generate<R: RngCore + ?Sized>(rng: &mut R, participant_id: usize) -> Self {
+        Self {
+            participant_id,
+            scalar: B::Scalar::random(rng),
+        }
+    }
+
+    /// Returns the public signing key `sk·g`.
+    pub fn public_key(&self) -> B::G1 {
+        B::G1::mul_generator(&self.scalar)
+    }
+}
+
+/// A partial decryption signed by the participant that produced it.
+#[derive(Clone, Debug)]
+pub struct SignedPartialDecryption<B: PairingBackend> {
+    /// The signed decryption share.
+    pub partial: PartialDecryption<B>,
+    /// [`Ciphertext::digest`] of the ciphertext the share decrypts.
+    pub ciphertext_digest: [u8; 32],
+    /// Epoch from the ciphertext's header.
+    pub epoch: u64,
+    /// Signature `sk·H(m)` by the participant's [`PartialSigningKey`].
+    pub signature: B::G2,
+}
+
+impl<B: PairingBackend> SignedPartialDecryption<B> {
+    /// Signs `partial`, a share of `ciphertext`, with `signing_key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the share belongs to another
+    /// participant.
+    pub fn sign(
+        signing_key: &PartialSigningKey<B>,
+        ciphertext: &Ciphertext<B>,
+        partial: PartialDecryption<B>,
+    ) -> Result<Self, Error> {
+        if partial.participant_id != signing_key.participant_id {
+            return Err(Error::MalformedInput(format!(
+                "participant {} cannot sign the share of participant {}",
+                signing_key.participant_id, partial.participant_id
+            )));
+        }
+        let ciphertext_digest = ciphertext.digest();
+        let signature = message_point(&partial, &ciphertext_digest, ciphertext.epoch)
+            .mul_scalar(&signing_key.scalar);
+        Ok(Self {
+            partial,
+            ciphertext_digest,
+            epoch: ciphertext.epoch,
+            signature,
+        })
+    }
+
+    /// Checks the signature against the signer's public `signing_key` and
+    /// that the share was signed for `ciphertext`: `e(g, σ) = e(pk, H(m))`.
+    ///
+    /// Only the signature is checked; the share itself is checked by
+    /// [`verify_partial_decryption`](crate::verify_partial_decryption) or
+    /// during aggregation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the share is for another
+    /// ciphertext or epoch, the signing key is the identity, or the signature
+    /// does not verify.
+    pub fn verify(&self, signing_key: &B::G1, ciphertext: &Ciphertext<B>) -> Result<(), Error> {
+        let id = self.partial.participant_id;
+        if signing_key.is_identity() {
+            return Err(Error::MalformedInput(format!(
+                "participant {id} has no signing key"
+            )));
+        }
+        if self.epoch != ciphertext.epoch || self.ciphertext_digest != ciphertext.digest() {
+            return Err(Error::MalformedInput(format!(
+                "share of participant {id} was signed for another ciphertext"
+            )));
+        }
+        let product = B::multi_pairing(
+            &[B::G1::generator(), signing_key.negate()],
+            &[
+                self.signature,
+                message_point(&self.partial, &self.ciphertext_digest, self.epoch),
+            ],
+        )
+        .map_err(Error::Backend)?;
+        if product != B::Target::identity() {
+            return Err(Error::MalformedInput(format!(
+                "signature on the share of participant {id} does not verify"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<B: PairingBackend<Scalar = Fr>, X: Xof> SilentThresholdScheme<B, X> {
+    /// Computes a partial decryption with `secret_key` and signs it for
+    /// transport with `signing_key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the keys belong to different
+    /// participants, and the errors of
+    /// [`ThresholdEncryption::partial_decrypt`].
+    pub fn partial_decrypt_signed(
+        &self,
+        secret_key: &SecretKey<B>,
+        signing_key: &PartialSigningKey<B>,
+        ciphertext: &Ciphertext<B>,
+    ) -> Result<SignedPartialDecryption<B>, Error> {
+        let partial = self.partial_decrypt(secret_key, ciphertext)?;
+        SignedPartialDecryption::sign(signing_key, ciphertext, partial)
+    }
+
+    /// Verifies the signature on every share against the signing key its
+    /// signer registered in `committee`, then aggregates the shares like
+    /// [`ThresholdEncryption::aggregate_decrypt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] naming the first share whose signer
+    /// has no registered signing key or whose signature does not verify,
+    /// before any decryption work; otherwise the errors of
+    /// [`ThresholdEncryption::aggregate_decrypt`].
+    pub fn aggregate_decrypt_signed(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        signed: &[SignedPartialDecryption<B>],
+        selector: &[bool],
+        committee: &Committee<B>,
+    ) -> Result<DecryptionResult<B>, Error> {
+        for share in signed {
+            let id = share.partial.participant_id;
+            let signing_key = committee.signing_key(id).ok_or_else(|| {
+                Error::MalformedInput(format!("participant {id} has no registered signing key"))
+            })?;
+            share.verify(signing_key, ciphertext)?;
+        }
+        let partials = signed
+            .iter()
+            .map(|share| share.partial.clone())
+            .collect::<Vec<_>>();
+        self.aggregate_decrypt(ciphertext, &partials, selector, committee.aggregate_key())
+    }
+}
+
+/// Hashes the signed share, ciphertext digest and epoch to G2.
+fn message_point<B: PairingBackend>(
+    partial: &PartialDecryption<B>,
+    ciphertext_digest: &[u8; 32],
+    epoch: u64,
+) -> B::G2 {
+    let mut hasher = Hasher::new();
+    hasher.update(&(partial.participant_id as u64).to_le_bytes());
+    hasher.update(&B::serialize_g2(&partial.response));
+    hasher.update(ciphertext_digest);
+    hasher.update(&epoch.to_le_bytes());
+    B::G2::hash_to_curve(hasher.finalize().as_bytes(), PARTIAL_SIGNATURE_DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::PairingEngine;
+
+    #[test]
+    fn signed_shares_are_checked_before_aggregation() {
+        let mut rng = StdRng::seed_from_u64(1472);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new().with_epoch(5);
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let signing_keys = (0..4)
+            .map(|id| PartialSigningKey::generate(&mut rng, id))
+            .collect::<Vec<_>>();
+        let mut committee = Committee::new(keys.public_keys.clone(), &params).unwrap();
+        for key in &signing_keys[..3] {
+            committee
+                .register_signing_key(key.participant_id, key.public_key())
+                .unwrap();
+        }
+        let agg = committee.aggregate_key();
+        let ct = scheme
+            .encrypt(&mut rng, agg, &params, 2, b"signed")
+            .unwrap();
+        let other = scheme.encrypt(&mut rng, agg, &params, 2, b"other").unwrap();
+
+        let mut signed = keys.secret_keys[..2]
+            .iter()
+            .zip(&signing_keys)
+            .map(|(sk, key)| scheme.partial_decrypt_signed(sk, key, &ct).unwrap())
+            .collect::<Vec<_>>();
+        let selector = [true, true, false, false];
+        let result = scheme
+            .aggregate_decrypt_signed(&ct, &signed, &selector, &committee)
+            .unwrap();
+        assert_eq!(result.plaintext.unwrap(), b"signed");
+
+        // A share replayed against another ciphertext is rejected.
+        let key_1 = signing_keys[1].public_key();
+        assert!(signed[1].verify(&key_1, &other).is_err());
+
+        // Re-attributing a share to another participant breaks the signature.
+        let mut forged = signed[1].clone();
+        forged.partial.participant_id = 2;
+        assert!(forged.verify(&signing_keys[2].public_key(), &ct).is_err());
+
+        // Shares from members without a registered key are rejected.
+        let unregistered = scheme
+            .partial_decrypt_signed(&keys.secret_keys[3], &signing_keys[3], &ct)
+            .unwrap();
+        assert!(
+            scheme
+                .aggregate_decrypt_signed(
+                    &ct,
+                    &[signed[0].clone(), unregistered],
+                    &[true, false, false, true],
+                    &committee,
+                )
+                .is_err()
+        );
+
+        // So does swapping in a different response.
+        signed[1].partial.response = signed[0].partial.response;
+        assert!(
+            scheme
+                .aggregate_decrypt_signed(&ct, &signed, &selector, &committee)
+                .is_err()
+        );
+
+        let partial = scheme.partial_decrypt(&keys.secret_keys[3], &ct).unwrap();
+        assert!(SignedPartialDecryption::sign(&signing_keys[2], &ct, partial).is_err());
+        assert!(
+            scheme
+                .partial_decrypt_signed(&keys.secret_keys[0], &signing_keys[1], &ct)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn decryption_shares_do_not_forge_signatures() {
+        let mut rng = StdRng::seed_from_u64(1472);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let signing_key = PartialSigningKey::<PairingEngine>::generate(&mut rng, 0);
+        let agg = &keys.aggregate_key;
+        let ct = scheme
+            .encrypt(&mut rng, agg, &params, 2, b"signed")
+            .unwrap();
+
+        // An attacker who wants a signature on a bad share sends the member a
+        // ciphertext whose gamma_g2 is the message point; the partial
+        // decryption it gets back is sk·H(m).
+        let bad_share = PartialDecryption::<PairingEngine> {
+            participant_id: 0,
+            response: ct.gamma_g2.add(&CurvePoint::generator()),
+        };
+        let oracle = message_point(&bad_share, &ct.digest(), ct.epoch)
+            .mul_scalar(&keys.secret_keys[0].scalar);
+        let framed = SignedPartialDecryption {
+            partial: bad_share,
+            ciphertext_digest: ct.digest(),
+            epoch: ct.epoch,
+            signature: oracle,
+        };
+        assert!(framed.verify(&signing_key.public_key(), &ct).is_err());
+    }
+}
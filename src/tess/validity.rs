@@ -0,0 +1,301 @@
+//! Publicly verifiable ciphertext well-formedness.
+//!
+//! Every element of a ciphertext is a fixed linear combination of public bases
+//! with the encryptor's randomness `(s0, s1, s2, s3, s4)`:
+//!
+//! ```text
+//! proof_g1[0] = s0·ask + s3·g^{τ^t} + s4·g      proof_g2[2] = (s0 + s1)·h^τ
+//! proof_g1[1] = s2·g                            proof_g2[3] = s1·h
+//! proof_g2[0] = s0·h + s2·γh                    proof_g2[4] = s3·h
+//! proof_g2[1] = s0·z                            proof_g2[5] = s4·(h^τ - h)
+//! shared_secret = e(g, h)^{s4}
+//! ```
+//!
+//! The encryptor attaches a Fiat-Shamir Schnorr proof of knowledge of that
+//! randomness ([`CiphertextProof`]), bound to the whole ciphertext including its
+//! payload. Verification needs only an [`AggregateKeyDigest`], a handful of group
+//! elements, instead of the full [`AggregateKey`] with its O(n) vectors.
+
+use alloc::vec::Vec;
+
+use blake3::Hasher;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+
+use super::header::{bind_header_metadata, committee_fingerprint};
+use crate::{
+    AggregateKey, COMMITTEE_FINGERPRINT_LEN, Ciphertext, CurvePoint, FieldElement, Fr, KdfId,
+    MsmProvider, PROOF_G1_LEN, PROOF_G2_LEN, PairingBackend, Params, TargetGroup, errors::Error,
+};
+
+/// Number of randomness scalars proven in a [`CiphertextProof`].
+const WITNESS_LEN: usize = 5;
+
+/// Succinct digest of an aggregate key for ciphertext verification.
+///
+/// Holds only the public bases that appear in ciphertexts encrypted for one
+/// threshold, so light clients can verify ciphertexts without the full
+/// [`AggregateKey`] or SRS.
+#[derive(Clone, Debug)]
+pub struct AggregateKeyDigest<B: PairingBackend> {
+    /// Threshold the digest verifies ciphertexts for.
+    pub threshold: usize,
+    /// Aggregate secret-key commitment `ask` from the aggregate key.
+    pub ask: B::G1,
+    /// SRS element `g^{τ^t}` for the digest's threshold.
+    pub g_tau_threshold: B::G1,
+    /// Vanishing polynomial commitment `z` in G2.
+    pub z_g2: B::G2,
+    /// SRS element `h^τ`.
+    pub h_tau: B::G2,
+    /// Pairing base `e(g, h)` used to derive shared secrets.
+    pub pairing_base: B::Target,
+}
+
+/// Proof that a ciphertext was formed honestly from some encryption randomness.
+#[derive(Clone, Debug)]
+pub struct CiphertextProof<B: PairingBackend> {
+    /// Fiat-Shamir challenge.
+    pub challenge: B::Scalar,
+    /// Responses `r_i + c·s_i` for the five randomness scalars.
+    pub responses: Vec<B::Scalar>,
+}
+
+impl<B: PairingBackend<Scalar = Fr>> AggregateKeyDigest<B> {
+    /// Extracts the digest for ciphertexts encrypted under `agg_key` with `threshold`.
+    pub fn new(
+        agg_key: &AggregateKey<B>,
+        params: &Params<B>,
+        threshold: usize,
+    ) -> Result<Self, Error> {
+        let g_tau_threshold =
+            *params.srs.powers_of_g.get(threshold).ok_or_else(|| {
+                Error::InvalidConfig("threshold exceeds available SRS powers".into())
+            })?;
+        let h_tau = *params
+            .srs
+            .powers_of_h
+            .get(1)
+            .ok_or_else(|| Error::InvalidConfig("SRS lacks h^tau".into()))?;
+        Ok(Self {
+            threshold,
+            ask: agg_key.ask,
+            g_tau_threshold,
+            z_g2: agg_key.z_g2,
+            h_tau,
+            pairing_base: agg_key.precomputed_pairing.clone(),
+        })
+    }
+
+    /// Fingerprint of the aggregate key this digest was extracted from; see
+    /// [`AggregateKey::fingerprint`].
+    pub fn fingerprint(&self) -> [u8; COMMITTEE_FINGERPRINT_LEN] {
+        committee_fingerprint::<B>(&self.ask, &self.z_g2, &self.pairing_base)
+    }
+
+    /// Verifies a ciphertext's well-formedness proof.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MalformedInput`] if the ciphertext's threshold or
+    /// committee differs from the digest's, its proof vectors have the wrong
+    /// sizes, the proof is missing, or the proof does not verify.
+    pub fn verify_ciphertext(&self, ciphertext: &Ciphertext<B>) -> Result<(), Error> {
+        if ciphertext.threshold != self.threshold {
+            return Err(Error::MalformedInput(
+                "ciphertext threshold does not match digest".into(),
+            ));
+        }
+        if ciphertext.committee != self.fingerprint() {
+            return Err(Error::MalformedInput(
+                "ciphertext was encrypted to a different committee".into(),
+            ));
+        }
+        if ciphertext.proof_g1.len() != PROOF_G1_LEN || ciphertext.proof_g2.len() != PROOF_G2_LEN {
+            return Err(Error::MalformedInput(
+                "ciphertext proof sizes are invalid".into(),
+            ));
+        }
+        let proof = ciphertext
+            .validity_proof
+            .as_ref()
+            .ok_or_else(|| Error::MalformedInput("ciphertext has no validity proof".into()))?;
+        if proof.responses.len() != WITNESS_LEN {
+            return Err(Error::MalformedInput(
+                "ciphertext validity proof is malformed".into(),
+            ));
+        }
+
+        // Recompute the prover's commitments as L(z) - c·statement.
+        let c = proof.challenge;
+        let commitments = self.linear_map(ciphertext, &proof.responses, Some(c))?;
+        if self.challenge(ciphertext, &commitments) != c {
+            return Err(Error::MalformedInput(
+                "ciphertext validity proof does not verify".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Proves knowledge of the randomness `witness` used to form `ciphertext`.
+    pub(super) fn prove<R: RngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+        ciphertext: &Ciphertext<B>,
+        witness: &[Fr; WITNESS_LEN],
+    ) -> Result<CiphertextProof<B>, Error> {
+        let nonces: Vec<Fr> = (0..WITNESS_LEN).map(|_| Fr::random(rng)).collect();
+        let commitments = self.linear_map(ciphertext, &nonces, None)?;
+        let challenge = self.challenge(ciphertext, &commitments);
+        let responses = nonces
+            .iter()
+            .zip(witness)
+            .map(|(nonce, secret)| *nonce + challenge * *secret)
+            .collect();
+        Ok(CiphertextProof {
+            challenge,
+            responses,
+        })
+    }
+
+    /// Evaluates the ciphertext's linear relations at `s`, less
+    /// `c`·statement when a challenge `c` is given.
+    fn linear_map(
+        &self,
+        ciphertext: &Ciphertext<B>,
+        s: &[Fr],
+        challenge: Option<Fr>,
+    ) -> Result<Images<B>, Error> {
+        let g = B::G1::generator();
+        let h = B::G2::generator();
+        let mut g1_terms = [
+            (
+                vec![self.ask, self.g_tau_threshold, g],
+                vec![s[0], s[3], s[4]],
+            ),
+            (vec![g], vec![s[2]]),
+        ];
+        let mut g2_terms = [
+            (vec![h, ciphertext.gamma_g2], vec![s[0], s[2]]),
+            (vec![self.z_g2], vec![s[0]]),
+            (vec![self.h_tau], vec![s[0] + s[1]]),
+            (vec![h], vec![s[1]]),
+            (vec![h], vec![s[3]]),
+            (vec![self.h_tau.sub(&h)], vec![s[4]]),
+        ];
+        let mut gt = self.pairing_base.mul_scalar(&s[4]);
+        if let Some(c) = challenge {
+            for ((bases, scalars), statement) in g1_terms.iter_mut().zip(&ciphertext.proof_g1) {
+                bases.push(*statement);
+                scalars.push(-c);
+            }
+            for ((bases, scalars), statement) in g2_terms.iter_mut().zip(&ciphertext.proof_g2) {
+                bases.push(*statement);
+                scalars.push(-c);
+            }
+            gt = gt.combine(&ciphertext.shared_secret.mul_scalar(&-c));
+        }
+
+        let mut g1 = [B::G1::identity(); 2];
+        for (image, (bases, scalars)) in g1.iter_mut().zip(&g1_terms) {
+            *image = B::Msm::msm_g1(bases, scalars).map_err(Error::Backend)?;
+        }
+        let mut g2 = [B::G2::identity(); 6];
+        for (image, (bases, scalars)) in g2.iter_mut().zip(&g2_terms) {
+            *image = B::Msm::msm_g2(bases, scalars).map_err(Error::Backend)?;
+        }
+        Ok(Images { g1, g2, gt })
+    }
+
+    /// Derives the Fiat-Shamir challenge over the digest, ciphertext, and commitments.
+    fn challenge(&self, ciphertext: &Ciphertext<B>, commitments: &Images<B>) -> Fr {
+        let mut hasher = Hasher::new();
+        hasher.update(b"tess::ciphertext-validity");
+        hasher.update(&(self.threshold as u64).to_le_bytes());
+        hasher.update(self.ask.to_repr().as_ref());
+        hasher.update(self.g_tau_threshold.to_repr().as_ref());
+        hasher.update(self.z_g2.to_repr().as_ref());
+        hasher.update(self.h_tau.to_repr().as_ref());
+        hasher.update(self.pairing_base.to_repr().as_ref());
+
+        hasher.update(ciphertext.gamma_g2.to_repr().as_ref());
+        for point in &ciphertext.proof_g1 {
+            hasher.update(point.to_repr().as_ref());
+        }
+        for point in &ciphertext.proof_g2 {
+            hasher.update(point.to_repr().as_ref());
+        }
+        hasher.update(ciphertext.shared_secret.to_repr().as_ref());
+        hasher.update(&[
+            ciphertext.compressed as u8,
+            ciphertext.padded as u8,
+            ciphertext.deterministic as u8,
+        ]);
+        // Only non-default KDFs are bound, keeping earlier proofs valid.
+        if ciphertext.kdf != KdfId::default() {
+            hasher.update(&[ciphertext.kdf.to_byte()]);
+        }
+        bind_header_metadata(&mut hasher, ciphertext);
+        hasher.update(&(ciphertext.payload.len() as u64).to_le_bytes());
+        hasher.update(&ciphertext.payload);
+
+        for point in &commitments.g1 {
+            hasher.update(point.to_repr().as_ref());
+        }
+        for point in &commitments.g2 {
+            hasher.update(point.to_repr().as_ref());
+        }
+        hasher.update(commitments.gt.to_repr().as_ref());
+
+        let mut rng = ChaCha20Rng::from_seed(*hasher.finalize().as_bytes());
+        Fr::random(&mut rng)
+    }
+}
+
+/// Images of the ciphertext's linear relations.
+struct Images<B: PairingBackend> {
+    g1: [B::G1; 2],
+    g2: [B::G2; 6],
+    gt: B::Target,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    #[test]
+    fn digest_verifies_honest_ciphertexts_and_rejects_tampering() {
+        let mut rng = StdRng::seed_from_u64(37);
+        let scheme = SilentThresholdScheme::<PairingEngine>::new();
+
+        let parties = 8;
+        let threshold = 4;
+        let params = scheme.param_gen(&mut rng, parties, threshold).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, parties, &params).unwrap();
+        let digest = AggregateKeyDigest::new(&keys.aggregate_key, &params, threshold).unwrap();
+
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, threshold, b"light")
+            .unwrap();
+        digest.verify_ciphertext(&ct).unwrap();
+
+        let mut bad_point = ct.clone();
+        bad_point.proof_g2[3] = bad_point.proof_g2[3].add(&CurvePoint::generator());
+        assert!(digest.verify_ciphertext(&bad_point).is_err());
+
+        let mut bad_payload = ct.clone();
+        bad_payload.payload[0] ^= 1;
+        assert!(digest.verify_ciphertext(&bad_payload).is_err());
+
+        let mut stripped = ct.clone();
+        stripped.validity_proof = None;
+        assert!(digest.verify_ciphertext(&stripped).is_err());
+
+        let other_digest =
+            AggregateKeyDigest::new(&keys.aggregate_key, &params, threshold + 1).unwrap();
+        assert!(other_digest.verify_ciphertext(&ct).is_err());
+    }
+}
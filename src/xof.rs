@@ -0,0 +1,166 @@
+//! Extendable-output functions behind payload keystreams and key derivation.
+//!
+//! The payload keystream ([`XorEncryption`](crate::XorEncryption)) and the key
+//! derivation and transcripts of [`SilentThresholdScheme`](crate::SilentThresholdScheme)
+//! are written against [`Xof`] rather than BLAKE3 directly. BLAKE3 is the
+//! default; deployments standardized on another function, such as SHAKE256,
+//! implement [`Xof`] for it and name it as the scheme's second type parameter:
+//!
+//! ```rust
+//! use sha3_stub::Shake256;
+//! use tess::{PairingEngine, SilentThresholdScheme, Xof, XofReader};
+//!
+//! # mod sha3_stub {
+//! #     // Stand-in for a SHAKE256 wrapper around e.g. the `sha3` crate.
+//! #     #[derive(Clone, Debug, Default)]
+//! #     pub struct Shake256(blake3::Hasher);
+//! #     pub struct Reader(blake3::OutputReader);
+//! #     impl tess::Xof for Shake256 {
+//! #         type Reader = Reader;
+//! #         fn update(&mut self, data: &[u8]) { self.0.update(data); }
+//! #         fn finalize_xof(self) -> Reader { Reader(self.0.finalize_xof()) }
+//! #     }
+//! #     impl tess::XofReader for Reader {
+//! #         fn fill(&mut self, out: &mut [u8]) { self.0.fill(out); }
+//! #     }
+//! # }
+//! let scheme = SilentThresholdScheme::<PairingEngine, Shake256>::new();
+//! ```
+//!
+//! Ciphertexts are only interoperable between schemes using the same function.
+//! Validity proofs, signcryption, re-encryption and PVSS transcripts still
+//! use BLAKE3.
+
+use core::fmt::Debug;
+
+/// Incremental hash with extendable output.
+pub trait Xof: Clone + Debug + Default + Send + Sync + 'static {
+    /// Reader over the output stream.
+    type Reader: XofReader;
+
+    /// Absorbs `data`.
+    fn update(&mut self, data: &[u8]);
+
+    /// Finishes absorbing and returns the output stream.
+    fn finalize_xof(self) -> Self::Reader;
+
+    /// Starts a hash domain-separated for key derivation under `context`.
+    ///
+    /// The default absorbs the length-prefixed context; BLAKE3 uses its
+    /// dedicated key-derivation mode.
+    fn derive_key(context: &'static str) -> Self {
+        let mut xof = Self::default();
+        xof.update(&(context.len() as u64).to_le_bytes());
+        xof.update(context.as_bytes());
+        xof
+    }
+
+    /// Finishes absorbing and returns the first `N` bytes of output.
+    fn finalize_bytes<const N: usize>(self) -> [u8; N] {
+        let mut out = [0u8; N];
+        self.finalize_xof().fill(&mut out);
+        out
+    }
+}
+
+/// Output stream of an [`Xof`].
+pub trait XofReader {
+    /// Fills `out` with the next bytes of output.
+    fn fill(&mut self, out: &mut [u8]);
+}
+
+impl Xof for blake3::Hasher {
+    type Reader = blake3::OutputReader;
+
+    fn update(&mut self, data: &[u8]) {
+        blake3::Hasher::update(self, data);
+    }
+
+    fn finalize_xof(self) -> Self::Reader {
+        blake3::Hasher::finalize_xof(&self)
+    }
+
+    fn derive_key(context: &'static str) -> Self {
+        blake3::Hasher::new_derive_key(context)
+    }
+}
+
+impl XofReader for blake3::OutputReader {
+    fn fill(&mut self, out: &mut [u8]) {
+        blake3::OutputReader::fill(self, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{PairingEngine, SilentThresholdScheme, ThresholdEncryption};
+
+    /// Keyed BLAKE3, standing in for an unrelated XOF such as SHAKE256.
+    #[derive(Clone, Debug)]
+    struct KeyedXof(blake3::Hasher);
+
+    impl Default for KeyedXof {
+        fn default() -> Self {
+            Self(blake3::Hasher::new_keyed(&[7u8; 32]))
+        }
+    }
+
+    impl Xof for KeyedXof {
+        type Reader = blake3::OutputReader;
+
+        fn update(&mut self, data: &[u8]) {
+            self.0.update(data);
+        }
+
+        fn finalize_xof(self) -> Self::Reader {
+            self.0.finalize_xof()
+        }
+    }
+
+    // Switching the scheme to `Xof` must not change any BLAKE3 output.
+    #[test]
+    fn blake3_matches_native_api() {
+        let mut xof = blake3::Hasher::default();
+        Xof::update(&mut xof, b"tess");
+        let native = blake3::hash(b"tess");
+        assert_eq!(&xof.clone().finalize_bytes::<32>(), native.as_bytes());
+
+        let mut derived = <blake3::Hasher as Xof>::derive_key("tess::xof-test");
+        Xof::update(&mut derived, b"material");
+        assert_eq!(
+            derived.finalize_bytes::<32>(),
+            blake3::derive_key("tess::xof-test", b"material")
+        );
+    }
+
+    #[test]
+    fn scheme_runs_on_a_custom_xof() {
+        let mut rng = StdRng::seed_from_u64(1456);
+        let scheme = SilentThresholdScheme::<PairingEngine, KeyedXof>::new();
+        let default = SilentThresholdScheme::<PairingEngine>::new();
+        let params = scheme.param_gen(&mut rng, 4, 2).unwrap();
+        let keys = scheme.keygen_unsafe(&mut rng, 4, &params).unwrap();
+        let ct = scheme
+            .encrypt(&mut rng, &keys.aggregate_key, &params, 2, b"custom xof")
+            .unwrap();
+
+        let selector = [true, true, true, false];
+        let partials: Vec<_> = keys.secret_keys[..3]
+            .iter()
+            .map(|sk| scheme.partial_decrypt(sk, &ct).unwrap())
+            .collect();
+        let result = scheme
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_eq!(result.plaintext.as_deref(), Some(&b"custom xof"[..]));
+
+        let other = default
+            .aggregate_decrypt(&ct, &partials, &selector, &keys.aggregate_key)
+            .unwrap();
+        assert_ne!(other.plaintext.as_deref(), Some(&b"custom xof"[..]));
+    }
+}